@@ -1,3 +1,4 @@
+#![allow(missing_docs)]
 #![deny(unsafe_code, nonstandard_style)]
 
 use std::fmt::{
@@ -12,8 +13,9 @@ use criterion::{
     criterion_main,
 };
 use hypergraph::{
+    CardinalityDistribution,
     HyperedgeIndex,
-    Hypergraph,
+    TopologyBuilder,
     VertexIndex,
 };
 use itertools::Itertools;
@@ -32,7 +34,7 @@ impl Vertex {
 
 impl Display for Vertex {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        write!(f, "{}", self)
+        write!(f, "{}", self.0)
     }
 }
 
@@ -47,7 +49,7 @@ impl Hyperedge {
 
 impl Display for Hyperedge {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        write!(f, "{}", self)
+        write!(f, "{}", self.0)
     }
 }
 
@@ -58,17 +60,26 @@ impl From<Hyperedge> for usize {
 }
 
 fn criterion_benchmark(criterion: &mut Criterion) {
-    let mut graph = Hypergraph::<Vertex, Hyperedge>::new();
-
-    for i in 0..VERTICES {
-        graph.add_vertex(Vertex::new(i)).unwrap();
-    }
-
-    for i in 0..HYPEREDGES {
-        let vertices = (i..i + 1).map(VertexIndex).collect_vec();
-
-        graph.add_hyperedge(vertices, Hyperedge::new(i)).unwrap();
-    }
+    // Generate hyperedges of various sizes sharing some vertices with their
+    // predecessors so that the queries traverse a connected topology.
+    let mut graph = TopologyBuilder::new(VERTICES, HYPEREDGES)
+        .cardinality(CardinalityDistribution::Uniform { min: 2, max: 8 })
+        .overlap(0.5)
+        .seed(42)
+        .build::<Vertex, Hyperedge, _, _>(Vertex::new, Hyperedge::new)
+        .unwrap();
+
+    // Build a topology made of small hyperedges only, which fit inline with
+    // the `smallvec` feature.
+    criterion.bench_function("build-small-hyperedges", |bencher| {
+        bencher.iter(|| {
+            TopologyBuilder::new(VERTICES, HYPEREDGES)
+                .cardinality(CardinalityDistribution::Uniform { min: 2, max: 4 })
+                .overlap(0.5)
+                .seed(42)
+                .build::<Vertex, Hyperedge, _, _>(Vertex::new, Hyperedge::new)
+        })
+    });
 
     criterion.bench_function("get-hyperedge-vertices", |bencher| {
         bencher.iter(|| graph.get_hyperedge_vertices(HyperedgeIndex((HYPEREDGES / 2) - 1)))
@@ -90,11 +101,11 @@ fn criterion_benchmark(criterion: &mut Criterion) {
     });
 
     criterion.bench_function("dijkstra", |bencher| {
-        bencher.iter(|| graph.get_dijkstra_connections(VertexIndex(0), VertexIndex(VERTICES)))
+        bencher.iter(|| graph.get_dijkstra_connections(VertexIndex(0), VertexIndex(VERTICES - 1)))
     });
 
     criterion.bench_function("dijkstra-reversed", |bencher| {
-        bencher.iter(|| graph.get_dijkstra_connections(VertexIndex(VERTICES), VertexIndex(0)))
+        bencher.iter(|| graph.get_dijkstra_connections(VertexIndex(VERTICES - 1), VertexIndex(0)))
     });
 
     criterion.bench_function("remove-vertex", |bencher| {
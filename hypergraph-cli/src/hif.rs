@@ -0,0 +1,52 @@
+use serde_json::{
+    Value,
+    json,
+};
+
+use crate::source::Graph;
+
+/// Renders a hypergraph to the Hypergraph Interchange Format.
+/// <https://github.com/pszufe/HIF-standard>
+/// The nodes and the edges are identified by their stable indexes and hold
+/// their weights as attributes. The hyperedges being ordered, the network is
+/// directed: the first vertex of a hyperedge is its tail, the others are its
+/// heads, and each incidence also holds the position of the vertex within its
+/// hyperedge.
+pub(crate) fn to_hif(graph: &Graph) -> Value {
+    let parts = graph.clone().into_parts();
+
+    let nodes = parts
+        .vertices_indexes
+        .iter()
+        .zip(&parts.vertices)
+        .map(|(index, weight)| json!({ "node": index.0, "attrs": { "weight": weight.0 } }))
+        .collect::<Vec<Value>>();
+    let edges = parts
+        .hyperedges_indexes
+        .iter()
+        .zip(&parts.hyperedges)
+        .map(|(index, (_, weight))| json!({ "edge": index.0, "attrs": { "weight": weight.0 } }))
+        .collect::<Vec<Value>>();
+    let incidences = parts
+        .hyperedges_indexes
+        .iter()
+        .zip(&parts.hyperedges)
+        .flat_map(|(index, (vertices, _))| {
+            vertices.iter().enumerate().map(|(position, vertex)| {
+                json!({
+                    "edge": index.0,
+                    "node": parts.vertices_indexes[*vertex].0,
+                    "direction": if position == 0 { "tail" } else { "head" },
+                    "attrs": { "position": position },
+                })
+            })
+        })
+        .collect::<Vec<Value>>();
+
+    json!({
+        "network-type": "directed",
+        "incidences": incidences,
+        "nodes": nodes,
+        "edges": edges,
+    })
+}
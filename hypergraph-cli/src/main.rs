@@ -0,0 +1,184 @@
+//! Command-line tool to inspect the hypergraphs persisted as snapshots,
+//! journals or snapshot stores, e.g. to debug production data without
+//! writing a Rust program for every question.
+//! The weights are handled as strings since their original types are
+//! unknown.
+
+mod hif;
+mod source;
+
+use std::{
+    error::Error,
+    path::PathBuf,
+    process::ExitCode,
+};
+
+use clap::{
+    Args,
+    Parser,
+    Subcommand,
+    ValueEnum,
+};
+use hypergraph::{
+    Severity,
+    SnapshotStore,
+    VertexIndex,
+};
+
+use crate::{
+    hif::to_hif,
+    source::{
+        Graph,
+        Weight,
+        load,
+    },
+};
+
+#[derive(Debug, Parser)]
+#[command(version, about)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Lists the namespaces of a snapshot store.
+    Namespaces {
+        /// Root directory of the store.
+        store: PathBuf,
+    },
+    /// Prints the statistics of a hypergraph.
+    Stats(Source),
+    /// Prints the cheapest path between two vertices.
+    ///
+    /// The cost of a hyperedge is its weight if it is an unsigned integer,
+    /// one otherwise.
+    Path {
+        #[command(flatten)]
+        source: Source,
+        /// Stable index of the first vertex.
+        from: usize,
+        /// Stable index of the last vertex.
+        to: usize,
+    },
+    /// Exports a hypergraph to the standard output.
+    Export {
+        #[command(flatten)]
+        source: Source,
+        /// Format of the export.
+        #[arg(short, long, value_enum, default_value_t = Format::Dot)]
+        format: Format,
+    },
+}
+
+/// Location of a hypergraph.
+#[derive(Args, Debug)]
+struct Source {
+    /// Snapshot, journal or root directory of a snapshot store.
+    path: PathBuf,
+    /// Namespace to load from a snapshot store.
+    #[arg(short, long)]
+    namespace: Option<String>,
+}
+
+impl Source {
+    fn load(&self) -> Result<Graph, Box<dyn Error>> {
+        load(&self.path, self.namespace.as_deref())
+    }
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum Format {
+    /// Graphviz dot format.
+    Dot,
+    /// Hypergraph Interchange Format, as JSON.
+    Hif,
+}
+
+fn main() -> ExitCode {
+    match run(Cli::parse().command) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("error: {}", error);
+
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(command: Command) -> Result<(), Box<dyn Error>> {
+    match command {
+        Command::Namespaces { store } => {
+            for namespace in SnapshotStore::<Weight, Weight>::open(store)?.list_namespaces()? {
+                println!("{}", namespace);
+            }
+        }
+        Command::Stats(source) => {
+            let graph = source.load()?;
+            let report = graph.validate();
+
+            println!("vertices: {}", graph.count_vertices());
+            println!("hyperedges: {}", graph.count_hyperedges());
+            println!("unary hyperedges: {}", graph.get_unary_hyperedges()?.len());
+            println!("self-loops: {}", graph.get_self_loops()?.len());
+            println!(
+                "strongly connected components: {}",
+                graph.strongly_connected_components()?.len()
+            );
+            println!(
+                "average clustering coefficient: {}",
+                graph.average_clustering_coefficient()?
+            );
+            println!(
+                "degree assortativity: {}",
+                graph
+                    .degree_assortativity()?
+                    .map_or_else(|| "undefined".to_owned(), |value| value.to_string())
+            );
+            println!(
+                "estimated memory: {} bytes",
+                graph.estimated_memory_bytes().total()
+            );
+            println!(
+                "validation: {} errors, {} warnings",
+                report.count(Severity::Error),
+                report.count(Severity::Warning)
+            );
+
+            for issue in report.issues {
+                println!("  {}", issue);
+            }
+        }
+        Command::Path { source, from, to } => {
+            let graph = source.load()?;
+
+            for (vertex_index, hyperedge_index) in
+                graph.get_dijkstra_connections(VertexIndex(from), VertexIndex(to))?
+            {
+                let vertex_weight = graph.get_vertex_weight(vertex_index)?;
+
+                match hyperedge_index {
+                    Some(hyperedge_index) => println!(
+                        "{} ({}) via {} ({})",
+                        vertex_index,
+                        vertex_weight,
+                        hyperedge_index,
+                        graph.get_hyperedge_weight(hyperedge_index)?
+                    ),
+                    None => println!("{} ({})", vertex_index, vertex_weight),
+                }
+            }
+        }
+        Command::Export { source, format } => {
+            let graph = source.load()?;
+
+            match format {
+                Format::Dot => print!("{}", graph.to_graphviz_dot()?),
+                Format::Hif => println!("{}", serde_json::to_string_pretty(&to_hif(&graph))?),
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use std::{
+    error::Error,
+    fmt::{
+        self,
+        Display,
+        Formatter,
+    },
+    fs::File,
+    path::Path,
+    str::FromStr,
+};
+
+use hypergraph::{
+    Hypergraph,
+    SnapshotStore,
+};
+
+/// Weight of the vertices and of the hyperedges, kept as written in the
+/// snapshots and the journals since their original types are unknown.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub(crate) struct Weight(pub(crate) String);
+
+impl Display for Weight {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl FromStr for Weight {
+    type Err = std::convert::Infallible;
+
+    fn from_str(weight: &str) -> Result<Self, Self::Err> {
+        Ok(Weight(weight.to_owned()))
+    }
+}
+
+// The cost of a hyperedge in the path queries is its weight if it is an
+// unsigned integer, one otherwise.
+impl From<Weight> for usize {
+    fn from(weight: Weight) -> Self {
+        weight.0.parse().unwrap_or(1)
+    }
+}
+
+/// Hypergraph loaded by the commands.
+pub(crate) type Graph = Hypergraph<Weight, Weight>;
+
+/// Loads a hypergraph from a path, which is either a snapshot, a journal or
+/// the root directory of a store along with a namespace.
+pub(crate) fn load(path: &Path, namespace: Option<&str>) -> Result<Graph, Box<dyn Error>> {
+    if path.is_dir() {
+        let namespace = namespace.ok_or("the namespace is required to load a store")?;
+        let mut store = SnapshotStore::open(path)?;
+
+        return Ok(store.get(namespace)?.clone());
+    }
+
+    if Graph::is_journal(path) {
+        Ok(Hypergraph::replay(path)?)
+    } else {
+        Ok(Hypergraph::read_snapshot(File::open(path)?)?)
+    }
+}
@@ -0,0 +1,162 @@
+//! Integration tests.
+
+use std::{
+    path::Path,
+    process::Command,
+};
+
+use hypergraph::{
+    Hypergraph,
+    JournaledHypergraph,
+    SnapshotStore,
+};
+use serde_json::{
+    Value,
+    json,
+};
+
+// Private helper to run the binary and to get its standard output, or its
+// standard error on failure.
+fn run(args: &[&str]) -> Result<String, String> {
+    let output = Command::new(env!("CARGO_BIN_EXE_hypergraph-cli"))
+        .args(args)
+        .output()
+        .unwrap();
+
+    if output.status.success() {
+        Ok(String::from_utf8(output.stdout).unwrap())
+    } else {
+        Err(String::from_utf8(output.stderr).unwrap())
+    }
+}
+
+// Private helper to build a small hypergraph.
+fn build_graph() -> Hypergraph<&'static str, usize> {
+    let mut graph = Hypergraph::new();
+
+    let a = graph.add_vertex("a").unwrap();
+    let b = graph.add_vertex("b").unwrap();
+    let c = graph.add_vertex("c").unwrap();
+
+    graph.add_hyperedge(vec![a, b], 1).unwrap();
+    graph.add_hyperedge(vec![b, c], 2).unwrap();
+    graph.add_hyperedge(vec![a, c], 10).unwrap();
+
+    graph
+}
+
+// Private helper to get a path in the temporary directory.
+fn temp_path(name: &str) -> std::path::PathBuf {
+    std::env::temp_dir().join(format!("hypergraph-cli-{}-{}", name, std::process::id()))
+}
+
+// Private helper to convert a path to a string argument.
+fn arg(path: &Path) -> &str {
+    path.to_str().unwrap()
+}
+
+#[test]
+fn integration_cli_snapshot() {
+    let path = temp_path("snapshot");
+    let mut bytes = vec![];
+
+    build_graph().write_snapshot(&mut bytes).unwrap();
+    std::fs::write(&path, bytes).unwrap();
+
+    let stats = run(&["stats", arg(&path)]).unwrap();
+
+    assert!(stats.contains("vertices: 3\n"));
+    assert!(stats.contains("hyperedges: 3\n"));
+    assert!(stats.contains("validation: 0 errors, 0 warnings\n"));
+
+    // The cheapest path goes through the hyperedges with the lowest weights.
+    assert_eq!(
+        run(&["path", arg(&path), "0", "2"]),
+        Ok("0 (a)\n1 (b) via 0 (1)\n2 (c) via 1 (2)\n".to_owned())
+    );
+    assert!(
+        run(&["path", arg(&path), "0", "3"])
+            .unwrap_err()
+            .contains("VertexIndex 3 was not found")
+    );
+
+    assert_eq!(
+        run(&["export", arg(&path)]),
+        Ok(build_graph().to_graphviz_dot().unwrap())
+    );
+
+    let hif =
+        serde_json::from_str::<Value>(&run(&["export", arg(&path), "--format", "hif"]).unwrap())
+            .unwrap();
+
+    assert_eq!(hif["network-type"], "directed");
+    assert_eq!(
+        hif["nodes"][2],
+        json!({ "node": 2, "attrs": { "weight": "c" } })
+    );
+    assert_eq!(
+        hif["edges"][2],
+        json!({ "edge": 2, "attrs": { "weight": "10" } })
+    );
+    assert_eq!(hif["incidences"][4]["direction"], "tail");
+    assert_eq!(
+        hif["incidences"][5],
+        json!({ "edge": 2, "node": 2, "direction": "head", "attrs": { "position": 1 } })
+    );
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn integration_cli_journal() {
+    let path = temp_path("journal");
+    let mut graph = JournaledHypergraph::<usize, usize>::create(&path).unwrap();
+
+    let a = graph.add_vertex(1).unwrap();
+    let b = graph.add_vertex(2).unwrap();
+
+    graph.add_hyperedge(vec![a, b], 3).unwrap();
+    graph.remove_vertex(a).unwrap();
+
+    drop(graph);
+
+    let stats = run(&["stats", arg(&path)]).unwrap();
+
+    assert!(stats.contains("vertices: 1\n"));
+    assert!(stats.contains("hyperedges: 1\n"));
+    assert!(stats.contains("unary hyperedges: 1\n"));
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn integration_cli_store() {
+    let root = temp_path("store");
+    let mut store = SnapshotStore::<usize, usize>::open(&root).unwrap();
+
+    store.update("globex", |graph| graph.add_vertex(1)).unwrap();
+    store.update("acme", |graph| graph.add_vertex(2)).unwrap();
+    store.flush_all().unwrap();
+
+    assert_eq!(
+        run(&["namespaces", arg(&root)]),
+        Ok("acme\nglobex\n".to_owned())
+    );
+    assert!(
+        run(&["stats", arg(&root), "--namespace", "acme"])
+            .unwrap()
+            .contains("vertices: 1\n")
+    );
+    assert!(
+        run(&["stats", arg(&root)])
+            .unwrap_err()
+            .contains("the namespace is required")
+    );
+    assert!(
+        run(&["stats", arg(&root), "-n", "initech"])
+            .unwrap_err()
+            .contains("Store namespace initech was not found")
+    );
+
+    std::fs::remove_dir_all(&root).unwrap();
+}
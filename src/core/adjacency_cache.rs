@@ -0,0 +1,142 @@
+use std::borrow::Cow;
+
+use crate::{
+    HyperedgeIndex,
+    HyperedgeKey,
+    HyperedgeTrait,
+    Hypergraph,
+    VertexIndex,
+    VertexTrait,
+    core::types::AIndexMap,
+    errors::HypergraphError,
+};
+
+/// Adjacent vertices of each vertex, along with the connecting hyperedges.
+pub(crate) type Adjacency = AIndexMap<VertexIndex, Vec<(VertexIndex, Vec<HyperedgeIndex>)>>;
+
+/// Precomputed adjacency of the vertices in both directions.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct AdjacencyCache {
+    /// Vertices adjacent from each vertex, as returned by
+    /// `get_full_adjacent_vertices_from`.
+    pub(crate) from: Adjacency,
+
+    /// Vertices adjacent to each vertex, as returned by
+    /// `get_full_adjacent_vertices_to`, i.e. the transposed adjacency.
+    pub(crate) to: Adjacency,
+
+    /// Costs of the hyperedges, indexed by internal index. Kept up to date
+    /// by `update_hyperedge_weight`.
+    pub(crate) costs: Vec<usize>,
+}
+
+#[allow(clippy::type_complexity)]
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Precomputes the adjacent vertices of every vertex, in both
+    /// directions, so that the adjacency queries and the path algorithms
+    /// don't recompute them on each call. The incoming neighbors of a
+    /// vertex are then found in time proportional to their number, e.g. for
+    /// the backward searches.
+    /// The costs of the hyperedges are cached as well, which saves the
+    /// conversions of their weights during the path queries.
+    /// The cache is dropped by any mutation of the hyperedges - the updates
+    /// of their weights aside - and must be built again afterwards.
+    pub fn build_adjacency_cache(&mut self) -> Result<(), HypergraphError> {
+        let mut cache = AdjacencyCache::default();
+
+        for vertex_index in self.vertices_mapping.right.keys() {
+            cache.from.insert(
+                *vertex_index,
+                self.get_uncached_full_adjacent_vertices_from(*vertex_index)?,
+            );
+            cache.to.insert(
+                *vertex_index,
+                self.get_uncached_full_adjacent_vertices_to(*vertex_index)?,
+            );
+        }
+
+        cache.costs = self
+            .hyperedges
+            .iter()
+            .map(|HyperedgeKey { weight, .. }| weight.clone().into())
+            .collect();
+
+        self.adjacency_cache = Some(cache);
+
+        Ok(())
+    }
+
+    /// Drops the adjacency cache, if any.
+    pub fn clear_adjacency_cache(&mut self) {
+        self.adjacency_cache = None;
+    }
+
+    /// Checks if the adjacency cache is built and up to date.
+    pub fn has_adjacency_cache(&self) -> bool {
+        self.adjacency_cache.is_some()
+    }
+
+    // Private method to get the adjacent vertices of a vertex from the cache
+    // if available, or to compute them otherwise.
+    pub(crate) fn get_cached_full_adjacent_vertices_from(
+        &self,
+        from: VertexIndex,
+    ) -> Result<Cow<'_, [(VertexIndex, Vec<HyperedgeIndex>)]>, HypergraphError> {
+        match self
+            .adjacency_cache
+            .as_ref()
+            .and_then(|cache| cache.from.get(&from))
+        {
+            Some(adjacent_vertices) => Ok(Cow::Borrowed(adjacent_vertices)),
+            None => self
+                .get_uncached_full_adjacent_vertices_from(from)
+                .map(Cow::Owned),
+        }
+    }
+
+    // Private method to get the vertices a vertex is adjacent to from the
+    // cache if available, or to compute them otherwise.
+    pub(crate) fn get_cached_full_adjacent_vertices_to(
+        &self,
+        to: VertexIndex,
+    ) -> Result<Cow<'_, [(VertexIndex, Vec<HyperedgeIndex>)]>, HypergraphError> {
+        match self
+            .adjacency_cache
+            .as_ref()
+            .and_then(|cache| cache.to.get(&to))
+        {
+            Some(adjacent_vertices) => Ok(Cow::Borrowed(adjacent_vertices)),
+            None => self
+                .get_uncached_full_adjacent_vertices_to(to)
+                .map(Cow::Owned),
+        }
+    }
+
+    // Private method to get the cost of a hyperedge from the cache if
+    // available, or to convert its weight otherwise.
+    pub(crate) fn get_hyperedge_cost(
+        &self,
+        hyperedge_index: HyperedgeIndex,
+    ) -> Result<usize, HypergraphError> {
+        let internal_index = self.get_internal_hyperedge(hyperedge_index)?;
+
+        match self
+            .adjacency_cache
+            .as_ref()
+            .and_then(|cache| cache.costs.get(internal_index))
+        {
+            Some(cost) => Ok(*cost),
+            None => self
+                .hyperedges
+                .get(internal_index)
+                .map(|HyperedgeKey { weight, .. }| weight.clone().into())
+                .ok_or(HypergraphError::InternalHyperedgeIndexNotFound(
+                    internal_index,
+                )),
+        }
+    }
+}
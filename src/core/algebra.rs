@@ -0,0 +1,240 @@
+use crate::{
+    HyperedgeKey,
+    HyperedgeTrait,
+    Hypergraph,
+    IndexRemapping,
+    VertexIndex,
+    VertexKey,
+    VertexTrait,
+    core::types::AIndexMap,
+    errors::HypergraphError,
+};
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    // Private method to check whether another hypergraph contains a
+    // hyperedge with the same weight and the same vertices, in order.
+    fn has_same_hyperedge(&self, other: &Self, key: &HyperedgeKey<HE>) -> bool {
+        other
+            .hyperedges_weights
+            .get(&key.weight)
+            .and_then(|internal_index| other.hyperedges.get(*internal_index))
+            .map_or(false, |other_key| {
+                other_key.vertices.len() == key.vertices.len()
+                    && other_key.vertices.iter().zip(key.vertices.iter()).all(
+                        |(other_vertex, vertex)| {
+                            other.vertices.get_index(*other_vertex).map(|(key, _)| key)
+                                == self.vertices.get_index(*vertex).map(|(key, _)| key)
+                        },
+                    )
+            })
+    }
+
+    // Private method to build a new hypergraph from the hyperedges matching
+    // a predicate and from the vertices either matching another predicate or
+    // belonging to the retained hyperedges. The insertion order is preserved.
+    fn build_subgraph<FV, FHE>(
+        &self,
+        mut keep_vertex: FV,
+        mut keep_hyperedge: FHE,
+    ) -> Result<Self, HypergraphError>
+    where
+        FV: FnMut(&VertexKey<V>) -> bool,
+        FHE: FnMut(&HyperedgeKey<HE>) -> bool,
+    {
+        let hyperedges = self
+            .hyperedges
+            .iter()
+            .filter(|key| keep_hyperedge(key))
+            .collect::<Vec<&HyperedgeKey<HE>>>();
+
+        let mut kept_vertices = self
+            .vertices
+            .keys()
+            .map(&mut keep_vertex)
+            .collect::<Vec<bool>>();
+
+        for HyperedgeKey { vertices, .. } in hyperedges.iter() {
+            for vertex in vertices {
+                kept_vertices[*vertex] = true;
+            }
+        }
+
+        let mut graph = Hypergraph::with_duplicate_policy(self.duplicate_policy);
+
+        graph.constraints = self.constraints;
+        graph.vertex_dedup_policy = self.vertex_dedup_policy;
+
+        // Map the internal indexes of the kept vertices to the new ones.
+        let mut mapping: AIndexMap<usize, VertexIndex> = AIndexMap::default();
+
+        for (internal_index, key) in self.vertices.keys().enumerate() {
+            if kept_vertices[internal_index] {
+                let vertex_index = match key.id {
+                    Some(_) => graph.add_vertex_allow_duplicate(key.weight.clone())?,
+                    None => graph.add_vertex(key.weight.clone())?,
+                };
+
+                mapping.insert(internal_index, vertex_index);
+            }
+        }
+
+        for HyperedgeKey { vertices, weight } in hyperedges {
+            let vertices = vertices
+                .iter()
+                .map(|vertex| {
+                    mapping
+                        .get(vertex)
+                        .copied()
+                        .ok_or(HypergraphError::InternalVertexIndexNotFound(*vertex))
+                })
+                .collect::<Result<Vec<VertexIndex>, HypergraphError>>()?;
+
+            graph.add_hyperedge(vertices, weight.clone())?;
+        }
+
+        Ok(graph)
+    }
+
+    /// Creates a new hypergraph with the vertices and the hyperedges present
+    /// in both hypergraphs, keyed by their weights.
+    /// A hyperedge is common when the same weight is assigned to the same
+    /// vertices, in order, in the other hypergraph.
+    /// Vertices added via `add_vertex_allow_duplicate` are keyed by their
+    /// weight along with their auto-generated id.
+    /// The duplicate policy and the constraints are carried over, unlike the
+    /// attributes, the properties and the roles.
+    pub fn intersection(&self, other: &Self) -> Result<Self, HypergraphError> {
+        self.build_subgraph(
+            |key| other.vertices.contains_key(key),
+            |key| self.has_same_hyperedge(other, key),
+        )
+    }
+
+    /// Creates a new hypergraph with the vertices and the hyperedges of the
+    /// hypergraph which are absent from the other one, keyed by their
+    /// weights.
+    /// A hyperedge is absent when the same weight is not assigned to the
+    /// same vertices, in order, in the other hypergraph. The vertices of the
+    /// remaining hyperedges are kept as well.
+    /// Vertices added via `add_vertex_allow_duplicate` are keyed by their
+    /// weight along with their auto-generated id.
+    /// The duplicate policy and the constraints are carried over, unlike the
+    /// attributes, the properties and the roles.
+    pub fn difference(&self, other: &Self) -> Result<Self, HypergraphError> {
+        self.build_subgraph(
+            |key| !other.vertices.contains_key(key),
+            |key| !self.has_same_hyperedge(other, key),
+        )
+    }
+
+    /// Appends the vertices and the hyperedges of another hypergraph, in
+    /// order, while preserving both copies of the shared ones, unlike
+    /// `intersection` and `difference`.
+    /// The appended entities get new stable indexes, after the existing
+    /// ones. The weights clashing with the existing ones are relabeled via
+    /// the closures, e.g. to prefix a name, the relabeled weights having to
+    /// be unique as well.
+    /// Vertices added via `add_vertex_allow_duplicate` are appended as such,
+    /// without any relabeling.
+    /// The attributes, the properties, the roles, the tags and the layers
+    /// are left out.
+    /// On error, the appended entities are removed but their stable indexes
+    /// are not reused.
+    /// Returns the new stable indexes of the appended entities, by stable
+    /// index in the other hypergraph.
+    pub fn append<FV, FHE>(
+        &mut self,
+        other: &Self,
+        relabel_vertex: FV,
+        relabel_hyperedge: FHE,
+    ) -> Result<IndexRemapping, HypergraphError>
+    where
+        FV: FnMut(&V) -> V,
+        FHE: FnMut(&HE) -> HE,
+    {
+        let vertices_count = self.vertices.len();
+        let hyperedges_count = self.hyperedges.len();
+
+        self.append_entities(other, relabel_vertex, relabel_hyperedge)
+            .or_else(|error| {
+                // Roll back by removing the appended entities, which are
+                // pushed at the end.
+                let mut internal_index = 0;
+
+                self.retain_hyperedges(|_, _| {
+                    internal_index += 1;
+
+                    internal_index <= hyperedges_count
+                })?;
+                self.retain_vertices(|internal_index, _, _| internal_index < vertices_count)?;
+
+                Err(error)
+            })
+    }
+
+    // Private method to append the vertices and the hyperedges of another
+    // hypergraph, relabeling the clashing weights.
+    fn append_entities<FV, FHE>(
+        &mut self,
+        other: &Self,
+        mut relabel_vertex: FV,
+        mut relabel_hyperedge: FHE,
+    ) -> Result<IndexRemapping, HypergraphError>
+    where
+        FV: FnMut(&V) -> V,
+        FHE: FnMut(&HE) -> HE,
+    {
+        let mut remapping = IndexRemapping::default();
+
+        // New stable indexes of the vertices, by internal index in the other
+        // hypergraph.
+        let mut appended = Vec::with_capacity(other.vertices.len());
+
+        for (internal_index, key) in other.vertices.keys().enumerate() {
+            let vertex_index = match key.id {
+                Some(_) => self.add_vertex_allow_duplicate(key.weight.clone())?,
+                None => match self.add_vertex(key.weight.clone()) {
+                    Err(HypergraphError::VertexWeightAlreadyAssigned(_)) => {
+                        self.add_vertex(relabel_vertex(&key.weight))?
+                    }
+                    result => result?,
+                },
+            };
+
+            remapping
+                .vertices
+                .insert(other.get_vertex(internal_index)?, vertex_index);
+            appended.push(vertex_index);
+        }
+
+        for (internal_index, HyperedgeKey { vertices, weight }) in
+            other.hyperedges.iter().enumerate()
+        {
+            let vertices = vertices
+                .iter()
+                .map(|vertex| {
+                    appended
+                        .get(*vertex)
+                        .copied()
+                        .ok_or(HypergraphError::InternalVertexIndexNotFound(*vertex))
+                })
+                .collect::<Result<Vec<VertexIndex>, HypergraphError>>()?;
+            let weight = if self.hyperedges_weights.contains_key(weight) {
+                relabel_hyperedge(weight)
+            } else {
+                weight.clone()
+            };
+
+            remapping.hyperedges.insert(
+                other.get_hyperedge(internal_index)?,
+                self.add_hyperedge(vertices, weight)?,
+            );
+        }
+
+        Ok(remapping)
+    }
+}
@@ -0,0 +1,251 @@
+use std::{
+    str::FromStr,
+    sync::Arc,
+};
+
+use arrow_array::{
+    Array,
+    ArrayRef,
+    RecordBatch,
+    StringArray,
+    UInt64Array,
+    builder::{
+        ListBuilder,
+        UInt64Builder,
+    },
+    cast::AsArray,
+    types::UInt64Type,
+};
+use arrow_schema::{
+    DataType,
+    Field,
+    Schema,
+};
+
+use crate::{
+    HyperedgeKey,
+    HyperedgeTrait,
+    Hypergraph,
+    Progress,
+    VertexIndex,
+    VertexTrait,
+    core::types::AIndexMap,
+    errors::HypergraphError,
+};
+
+/// Name of the column holding the stable indexes.
+const INDEX_COLUMN: &str = "index";
+
+/// Name of the column holding the weights.
+const WEIGHT_COLUMN: &str = "weight";
+
+/// Name of the column holding the vertices of the hyperedges.
+const VERTICES_COLUMN: &str = "vertices";
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Exports the hypergraph as a tuple of two Arrow record batches of the
+    /// form (vertices, hyperedges).
+    /// The vertices batch contains an `index` column and a `weight` column.
+    /// The hyperedges batch contains the same columns plus a list-typed
+    /// `vertices` column referencing the indexes of the vertices batch.
+    /// Weights are stored as strings via their `Display` implementation.
+    pub fn to_arrow(&self) -> Result<(RecordBatch, RecordBatch), HypergraphError> {
+        let mut vertices_indexes = Vec::with_capacity(self.vertices.len());
+        let mut vertices_weights = Vec::with_capacity(self.vertices.len());
+
+        for (internal_index, (weight, _)) in self.vertices.iter().enumerate() {
+            let VertexIndex(index) = self.get_vertex(internal_index)?;
+
+            vertices_indexes.push(index as u64);
+            vertices_weights.push(weight.to_string());
+        }
+
+        let mut hyperedges_indexes = Vec::with_capacity(self.hyperedges.len());
+        let mut hyperedges_weights = Vec::with_capacity(self.hyperedges.len());
+        let mut hyperedges_vertices = ListBuilder::new(UInt64Builder::new());
+
+        for (internal_index, HyperedgeKey { vertices, weight }) in
+            self.hyperedges.iter().enumerate()
+        {
+            hyperedges_indexes.push(self.get_hyperedge(internal_index)?.0 as u64);
+            hyperedges_weights.push(weight.to_string());
+
+            for vertex_index in self.get_vertices(vertices)? {
+                hyperedges_vertices
+                    .values()
+                    .append_value(vertex_index.0 as u64);
+            }
+
+            hyperedges_vertices.append(true);
+        }
+
+        let vertices = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![
+                Field::new(INDEX_COLUMN, DataType::UInt64, false),
+                Field::new(WEIGHT_COLUMN, DataType::Utf8, false),
+            ])),
+            vec![
+                Arc::new(UInt64Array::from(vertices_indexes)) as ArrayRef,
+                Arc::new(StringArray::from(vertices_weights)) as ArrayRef,
+            ],
+        )
+        .map_err(|error| HypergraphError::ArrowInvalidRecordBatch(error.to_string()))?;
+
+        let hyperedges_vertices = hyperedges_vertices.finish();
+
+        let hyperedges = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![
+                Field::new(INDEX_COLUMN, DataType::UInt64, false),
+                Field::new(WEIGHT_COLUMN, DataType::Utf8, false),
+                Field::new(
+                    VERTICES_COLUMN,
+                    hyperedges_vertices.data_type().to_owned(),
+                    false,
+                ),
+            ])),
+            vec![
+                Arc::new(UInt64Array::from(hyperedges_indexes)) as ArrayRef,
+                Arc::new(StringArray::from(hyperedges_weights)) as ArrayRef,
+                Arc::new(hyperedges_vertices) as ArrayRef,
+            ],
+        )
+        .map_err(|error| HypergraphError::ArrowInvalidRecordBatch(error.to_string()))?;
+
+        Ok((vertices, hyperedges))
+    }
+
+    /// Creates a new hypergraph from two Arrow record batches of the form
+    /// (vertices, hyperedges) following the layout produced by `to_arrow`.
+    /// Weights are parsed back via their `FromStr` implementation.
+    /// Please note that the indexes of the resulting hypergraph are assigned
+    /// by insertion order and might differ from the ones of the batches.
+    pub fn from_arrow(
+        vertices: &RecordBatch,
+        hyperedges: &RecordBatch,
+    ) -> Result<Self, HypergraphError>
+    where
+        V: FromStr,
+        HE: FromStr,
+    {
+        Self::from_arrow_with_progress(vertices, hyperedges, |_| {})
+    }
+
+    /// Creates a new hypergraph from two Arrow record batches like
+    /// `from_arrow` while reporting the progress of the `vertices` and the
+    /// `hyperedges` stages - one step per row.
+    pub fn from_arrow_with_progress<P>(
+        vertices: &RecordBatch,
+        hyperedges: &RecordBatch,
+        mut progress: P,
+    ) -> Result<Self, HypergraphError>
+    where
+        V: FromStr,
+        HE: FromStr,
+        P: FnMut(Progress),
+    {
+        let mut graph = Hypergraph::with_capacity(vertices.num_rows(), hyperedges.num_rows());
+
+        // Keep track of the indexes of the batch in order to remap the
+        // vertices of the hyperedges.
+        let mut mapping = AIndexMap::<u64, VertexIndex>::default();
+
+        let vertices_indexes = get_index_column(vertices)?;
+        let vertices_weights = get_weight_column(vertices)?;
+
+        for (row, (index, weight)) in vertices_indexes
+            .values()
+            .iter()
+            .zip(vertices_weights.iter())
+            .enumerate()
+        {
+            let weight = parse_weight::<V>(weight)?;
+
+            mapping.insert(*index, graph.add_vertex(weight)?);
+
+            progress(Progress::new("vertices", row + 1, vertices.num_rows()));
+        }
+
+        let hyperedges_weights = get_weight_column(hyperedges)?;
+        let hyperedges_vertices = hyperedges
+            .column_by_name(VERTICES_COLUMN)
+            .and_then(|column| column.as_list_opt::<i32>())
+            .ok_or_else(|| {
+                HypergraphError::ArrowInvalidRecordBatch(format!(
+                    "missing list column {VERTICES_COLUMN}"
+                ))
+            })?;
+
+        for (row, (weight, vertices)) in hyperedges_weights
+            .iter()
+            .zip(hyperedges_vertices.iter())
+            .enumerate()
+        {
+            let weight = parse_weight::<HE>(weight)?;
+
+            let vertices = vertices
+                .as_ref()
+                .and_then(|vertices| vertices.as_primitive_opt::<UInt64Type>())
+                .ok_or_else(|| {
+                    HypergraphError::ArrowInvalidRecordBatch(format!(
+                        "invalid value in column {VERTICES_COLUMN}"
+                    ))
+                })?
+                .values()
+                .iter()
+                .map(|index| {
+                    mapping.get(index).copied().ok_or_else(|| {
+                        HypergraphError::ArrowInvalidRecordBatch(format!(
+                            "unknown vertex index {index}"
+                        ))
+                    })
+                })
+                .collect::<Result<Vec<VertexIndex>, HypergraphError>>()?;
+
+            graph.add_hyperedge(vertices, weight)?;
+
+            progress(Progress::new("hyperedges", row + 1, hyperedges.num_rows()));
+        }
+
+        Ok(graph)
+    }
+}
+
+// Private helper to get the index column of a record batch.
+fn get_index_column(batch: &RecordBatch) -> Result<&UInt64Array, HypergraphError> {
+    batch
+        .column_by_name(INDEX_COLUMN)
+        .and_then(|column| column.as_primitive_opt::<UInt64Type>())
+        .ok_or_else(|| {
+            HypergraphError::ArrowInvalidRecordBatch(format!("missing u64 column {INDEX_COLUMN}"))
+        })
+}
+
+// Private helper to get the weight column of a record batch.
+fn get_weight_column(batch: &RecordBatch) -> Result<&StringArray, HypergraphError> {
+    batch
+        .column_by_name(WEIGHT_COLUMN)
+        .and_then(|column| column.as_string_opt::<i32>())
+        .ok_or_else(|| {
+            HypergraphError::ArrowInvalidRecordBatch(format!(
+                "missing string column {WEIGHT_COLUMN}"
+            ))
+        })
+}
+
+// Private helper to parse a weight from an optional string.
+fn parse_weight<T>(weight: Option<&str>) -> Result<T, HypergraphError>
+where
+    T: FromStr,
+{
+    let weight = weight.ok_or_else(|| {
+        HypergraphError::ArrowInvalidRecordBatch(format!("null value in column {WEIGHT_COLUMN}"))
+    })?;
+
+    weight
+        .parse()
+        .map_err(|_| HypergraphError::ArrowInvalidWeight(weight.to_owned()))
+}
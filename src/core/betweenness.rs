@@ -0,0 +1,180 @@
+use std::collections::{
+    HashMap,
+    VecDeque,
+};
+
+#[cfg(feature = "rand")]
+use rand::{
+    Rng,
+    seq::index::sample,
+};
+
+use crate::{
+    HyperedgeTrait,
+    Hypergraph,
+    VertexIndex,
+    VertexTrait,
+    core::parallel::*,
+    errors::HypergraphError,
+};
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    // Private method to accumulate the dependencies of the internal vertices
+    // on the shortest paths starting from the given internal sources, based
+    // on the algorithm of Brandes.
+    // <https://doi.org/10.1080/0022250X.2001.9990249>
+    fn get_internal_betweenness(&self, sources: &[usize]) -> Vec<f64> {
+        let count = self.vertices.len();
+        let adjacency = self.get_internal_adjacency();
+
+        sources
+            .par_iter()
+            .fold(
+                || vec![0.; count],
+                |mut betweenness, source| {
+                    let mut distances = vec![usize::MAX; count];
+                    let mut paths = vec![0.; count];
+                    let mut predecessors: Vec<Vec<usize>> = vec![vec![]; count];
+                    let mut stack = Vec::with_capacity(count);
+                    let mut queue = VecDeque::new();
+
+                    distances[*source] = 0;
+                    paths[*source] = 1.;
+                    queue.push_back(*source);
+
+                    // Count the shortest paths with a breadth-first search.
+                    while let Some(vertex) = queue.pop_front() {
+                        stack.push(vertex);
+
+                        for adjacent_vertex in adjacency[vertex].iter() {
+                            if distances[*adjacent_vertex] == usize::MAX {
+                                distances[*adjacent_vertex] = distances[vertex] + 1;
+                                queue.push_back(*adjacent_vertex);
+                            }
+
+                            if distances[*adjacent_vertex] == distances[vertex] + 1 {
+                                paths[*adjacent_vertex] += paths[vertex];
+                                predecessors[*adjacent_vertex].push(vertex);
+                            }
+                        }
+                    }
+
+                    // Accumulate the dependencies in reverse order of the
+                    // distances.
+                    let mut dependencies = vec![0.; count];
+
+                    while let Some(vertex) = stack.pop() {
+                        for predecessor in predecessors[vertex].iter() {
+                            dependencies[*predecessor] +=
+                                paths[*predecessor] / paths[vertex] * (1. + dependencies[vertex]);
+                        }
+
+                        if vertex != *source {
+                            betweenness[vertex] += dependencies[vertex];
+                        }
+                    }
+
+                    betweenness
+                },
+            )
+            .reduce(
+                || vec![0.; count],
+                |mut left, right| {
+                    left.iter_mut()
+                        .zip(right)
+                        .for_each(|(value, other)| *value += other);
+
+                    left
+                },
+            )
+    }
+
+    // Private method to map the internal betweenness to the vertices.
+    fn get_betweenness_map(
+        &self,
+        betweenness: Vec<f64>,
+    ) -> Result<HashMap<VertexIndex, f64>, HypergraphError> {
+        betweenness
+            .into_iter()
+            .enumerate()
+            .map(|(internal_index, value)| Ok((self.get_vertex(internal_index)?, value)))
+            .collect()
+    }
+
+    /// Computes the betweenness centrality of all the vertices, i.e. the
+    /// number of shortest paths between the other vertices going through
+    /// each vertex, each pair of vertices contributing fractionally when
+    /// multiple shortest paths exist.
+    /// The paths follow the direction of the hyperedges and are unweighted,
+    /// the vertices being adjacent when they are consecutive in a hyperedge.
+    /// The values are not normalized.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            err,
+            fields(
+                vertices_count = self.vertices.len(),
+                hyperedges_count = self.hyperedges.len(),
+            )
+        )
+    )]
+    pub fn betweenness_centrality(&self) -> Result<HashMap<VertexIndex, f64>, HypergraphError> {
+        let sources = (0..self.vertices.len()).collect::<Vec<usize>>();
+
+        self.get_betweenness_map(self.get_internal_betweenness(&sources))
+    }
+
+    /// Approximates the betweenness centrality of all the vertices by only
+    /// exploring the shortest paths starting from a number of pivots,
+    /// picked uniformly at random without replacement, and by scaling the
+    /// result accordingly.
+    /// The more pivots, the more accurate the approximation, which is exact
+    /// when the number of pivots reaches the number of vertices. Use a seeded
+    /// random number generator for reproducible results.
+    /// See `betweenness_centrality` for more details.
+    #[cfg(feature = "rand")]
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            err,
+            fields(
+                pivots,
+                vertices_count = self.vertices.len(),
+                hyperedges_count = self.hyperedges.len(),
+            )
+        )
+    )]
+    pub fn approximate_betweenness_centrality<R>(
+        &self,
+        pivots: usize,
+        rng: &mut R,
+    ) -> Result<HashMap<VertexIndex, f64>, HypergraphError>
+    where
+        R: Rng + ?Sized,
+    {
+        let count = self.vertices.len();
+        let pivots = pivots.min(count);
+
+        if pivots == 0 {
+            return self.get_betweenness_map(vec![0.; count]);
+        }
+
+        let sources = sample(rng, count, pivots).into_vec();
+        let scale = count as f64 / pivots as f64;
+
+        self.get_betweenness_map(
+            self.get_internal_betweenness(&sources)
+                .into_iter()
+                .map(|value| value * scale)
+                .collect(),
+        )
+    }
+}
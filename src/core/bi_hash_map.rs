@@ -1,11 +1,13 @@
 use std::{
     collections::HashMap,
     fmt::Debug,
+    hash::Hash,
 };
 
 /// Bi-directional hashmap used to store the mapping between the internal
 /// unstable indexes - generated by `IndexMap` and `IndexSet` - and the exposed
 /// stable indexes.
+#[derive(Clone)]
 pub(crate) struct BiHashMap<Index>
 where
     Index: Copy + Debug + Eq,
@@ -16,7 +18,7 @@ where
 
 impl<Index> BiHashMap<Index>
 where
-    Index: Copy + Debug + Eq,
+    Index: Copy + Debug + Eq + Hash,
 {
     /// Creates a new `BiHashMap` with no allocation.
     pub(crate) fn new() -> BiHashMap<Index> {
@@ -25,11 +27,31 @@ where
             right: HashMap::<Index, usize>::with_capacity(0),
         }
     }
+
+    /// Creates a new `BiHashMap` with the specified capacity.
+    pub(crate) fn with_capacity(capacity: usize) -> BiHashMap<Index> {
+        Self {
+            left: HashMap::<usize, Index>::with_capacity(capacity),
+            right: HashMap::<Index, usize>::with_capacity(capacity),
+        }
+    }
+
+    /// Reserves capacity for at least `additional` more entries.
+    pub(crate) fn reserve(&mut self, additional: usize) {
+        self.left.reserve(additional);
+        self.right.reserve(additional);
+    }
+
+    /// Shrinks the capacity as much as possible.
+    pub(crate) fn shrink_to_fit(&mut self) {
+        self.left.shrink_to_fit();
+        self.right.shrink_to_fit();
+    }
 }
 
 impl<Index> Default for BiHashMap<Index>
 where
-    Index: Copy + Debug + Eq,
+    Index: Copy + Debug + Eq + Hash,
 {
     fn default() -> Self {
         BiHashMap::new()
@@ -0,0 +1,81 @@
+use crate::{
+    HyperedgeIndex,
+    HyperedgeKey,
+    HyperedgeTrait,
+    Hypergraph,
+    VertexIndex,
+    VertexTrait,
+    errors::HypergraphError,
+};
+
+/// Incidence of the bipartite graph produced by `to_bipartite`, i.e. an
+/// edge between a hyperedge node and a vertex node.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct BipartiteIncidence {
+    /// Position of the hyperedge in the hyperedges of the bipartite graph.
+    pub hyperedge: usize,
+    /// Position of the vertex in the vertices of the bipartite graph.
+    pub vertex: usize,
+    /// Position of the vertex in the hyperedge, which preserves the
+    /// direction.
+    pub position: usize,
+}
+
+/// Bipartite incidence graph of a hypergraph as plain vectors, produced by
+/// `to_bipartite`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BipartiteGraph<V, HE> {
+    /// Vertex nodes with their indexes and their weights.
+    pub vertices: Vec<(VertexIndex, V)>,
+    /// Hyperedge nodes with their indexes and their weights.
+    pub hyperedges: Vec<(HyperedgeIndex, HE)>,
+    /// Incidences, grouped by hyperedge and ordered by position.
+    pub incidences: Vec<BipartiteIncidence>,
+}
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Converts the hypergraph to its bipartite incidence graph as plain
+    /// vectors, in insertion order.
+    /// Both the vertices and the hyperedges become nodes and each hyperedge
+    /// node is connected to its vertices, once per occurrence. The nodes are
+    /// referenced by their positions in the vectors, which makes the result
+    /// usable as is by external algorithms.
+    /// See `to_petgraph` for the petgraph counterpart.
+    pub fn to_bipartite(&self) -> Result<BipartiteGraph<V, HE>, HypergraphError> {
+        let vertices = self
+            .vertices
+            .keys()
+            .enumerate()
+            .map(|(internal_index, key)| Ok((self.get_vertex(internal_index)?, key.weight.clone())))
+            .collect::<Result<Vec<(VertexIndex, V)>, HypergraphError>>()?;
+
+        let mut hyperedges = Vec::with_capacity(self.hyperedges.len());
+        let mut incidences = vec![];
+
+        for (internal_index, HyperedgeKey { vertices, weight }) in
+            self.hyperedges.iter().enumerate()
+        {
+            hyperedges.push((self.get_hyperedge(internal_index)?, weight.clone()));
+
+            // Internal vertex indexes map directly to the positions of the
+            // vertex nodes.
+            incidences.extend(vertices.iter().enumerate().map(|(position, vertex)| {
+                BipartiteIncidence {
+                    hyperedge: internal_index,
+                    vertex: *vertex,
+                    position,
+                }
+            }));
+        }
+
+        Ok(BipartiteGraph {
+            vertices,
+            hyperedges,
+            incidences,
+        })
+    }
+}
@@ -0,0 +1,111 @@
+use roaring::RoaringTreemap;
+
+use crate::{
+    HyperedgeIndex,
+    HyperedgeKey,
+    HyperedgeTrait,
+    Hypergraph,
+    VertexTrait,
+    errors::HypergraphError,
+};
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Gets the unique vertices of a hyperedge as a bitmap of their stable
+    /// indexes, mirrored alongside the ordered vertices.
+    pub fn get_hyperedge_bitmap(
+        &self,
+        hyperedge_index: HyperedgeIndex,
+    ) -> Result<&RoaringTreemap, HypergraphError> {
+        let internal_index = self.get_internal_hyperedge(hyperedge_index)?;
+
+        self.hyperedges_bitmaps.get(internal_index).ok_or(
+            HypergraphError::InternalHyperedgeIndexNotFound(internal_index),
+        )
+    }
+
+    /// Gets the vertices shared by a set of hyperedges as a bitmap of their
+    /// stable indexes. Unlike `get_hyperedges_intersections`, the bitmaps
+    /// are intersected word by word.
+    pub fn get_hyperedges_bitmap_intersection(
+        &self,
+        hyperedges: &[HyperedgeIndex],
+    ) -> Result<RoaringTreemap, HypergraphError> {
+        let (first, others) = hyperedges
+            .split_first()
+            .ok_or(HypergraphError::HyperedgesInvalidIntersections)?;
+
+        let mut intersection = self.get_hyperedge_bitmap(*first)?.clone();
+
+        for hyperedge_index in others {
+            intersection &= self.get_hyperedge_bitmap(*hyperedge_index)?;
+        }
+
+        Ok(intersection)
+    }
+
+    /// Gets the vertices of any of a set of hyperedges as a bitmap of their
+    /// stable indexes.
+    pub fn get_hyperedges_bitmap_union(
+        &self,
+        hyperedges: &[HyperedgeIndex],
+    ) -> Result<RoaringTreemap, HypergraphError> {
+        let mut union = RoaringTreemap::new();
+
+        for hyperedge_index in hyperedges {
+            union |= self.get_hyperedge_bitmap(*hyperedge_index)?;
+        }
+
+        Ok(union)
+    }
+
+    /// Checks if the unique vertices of a hyperedge are all part of another
+    /// one, regardless of their order.
+    pub fn is_hyperedge_subset(
+        &self,
+        hyperedge_index: HyperedgeIndex,
+        other_hyperedge_index: HyperedgeIndex,
+    ) -> Result<bool, HypergraphError> {
+        Ok(self
+            .get_hyperedge_bitmap(hyperedge_index)?
+            .is_subset(self.get_hyperedge_bitmap(other_hyperedge_index)?))
+    }
+
+    // Private method to mirror the vertices of a hyperedge in its bitmap,
+    // either pushed or updated in place.
+    pub(crate) fn sync_hyperedge_bitmap(
+        &mut self,
+        internal_index: usize,
+    ) -> Result<(), HypergraphError> {
+        let HyperedgeKey { vertices, .. } = self.hyperedges.get(internal_index).ok_or(
+            HypergraphError::InternalHyperedgeIndexNotFound(internal_index),
+        )?;
+        let bitmap = self
+            .get_vertices(vertices)?
+            .into_iter()
+            .map(|vertex_index| vertex_index.0 as u64)
+            .collect::<RoaringTreemap>();
+
+        match self.hyperedges_bitmaps.get_mut(internal_index) {
+            Some(current) => *current = bitmap,
+            None => self.hyperedges_bitmaps.push(bitmap),
+        }
+
+        Ok(())
+    }
+
+    // Private method to rebuild all the bitmaps of the hyperedges, e.g. once
+    // the stable indexes of the vertices have been reassigned.
+    pub(crate) fn rebuild_hyperedges_bitmaps(&mut self) -> Result<(), HypergraphError> {
+        self.hyperedges_bitmaps.clear();
+
+        for internal_index in 0..self.hyperedges.len() {
+            self.sync_hyperedge_bitmap(internal_index)?;
+        }
+
+        Ok(())
+    }
+}
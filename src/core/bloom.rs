@@ -0,0 +1,209 @@
+use std::{
+    f64::consts::LN_2,
+    hash::Hash,
+    mem::size_of,
+};
+
+use crate::{
+    HyperedgeKey,
+    HyperedgeTrait,
+    Hypergraph,
+    VertexIndex,
+    VertexTrait,
+    core::types::ARandomState,
+    errors::HypergraphError,
+};
+
+/// Fixed seeds of the hasher so that the signatures are deterministic.
+const SEEDS: [u64; 4] = [
+    0x243f_6a88_85a3_08d3,
+    0x1319_8a2e_0370_7344,
+    0xa409_3822_299f_31d0,
+    0x082e_fa98_ec4e_6c89,
+];
+
+/// Number of bits of each block of the filter.
+const BLOCK_BITS: usize = u64::BITS as usize;
+
+/// Bloom filter over the signatures of the hyperedges, i.e. their sets of
+/// vertices along with their weights.
+/// The entries can't be removed, so the removed or updated hyperedges stay
+/// in the filter until it is rebuilt.
+#[derive(Clone, Debug)]
+pub(crate) struct HyperedgeFilter {
+    /// Bits of the filter.
+    blocks: Vec<u64>,
+
+    /// Number of bits set per signature.
+    hashes: usize,
+
+    /// Number of hyperedges the filter is sized for.
+    capacity: usize,
+
+    /// Target rate of false positives at capacity.
+    false_positive_rate: f64,
+
+    /// Hasher of the signatures.
+    hasher: ARandomState,
+}
+
+impl HyperedgeFilter {
+    /// Creates an empty filter sized for a number of hyperedges and a target
+    /// rate of false positives.
+    fn new(capacity: usize, false_positive_rate: f64) -> Self {
+        let false_positive_rate = false_positive_rate.clamp(f64::MIN_POSITIVE, 0.5);
+        let capacity = capacity.max(1);
+        let bits = (-(capacity as f64) * false_positive_rate.ln() / (LN_2 * LN_2)).ceil() as usize;
+        let hashes = ((bits as f64 / capacity as f64) * LN_2).round().max(1.) as usize;
+
+        Self {
+            blocks: vec![0; (bits + BLOCK_BITS - 1) / BLOCK_BITS],
+            hashes,
+            capacity,
+            false_positive_rate,
+            hasher: ARandomState::with_seeds(SEEDS[0], SEEDS[1], SEEDS[2], SEEDS[3]),
+        }
+    }
+
+    // Private method to get the positions of the bits of a signature, via
+    // double hashing.
+    fn get_positions<W>(&self, vertices: &[VertexIndex], weight: &W) -> impl Iterator<Item = usize>
+    where
+        W: Hash,
+    {
+        let mut vertices = vertices.to_vec();
+
+        vertices.sort_unstable();
+        vertices.dedup();
+
+        let hash = self.hasher.hash_one((vertices, weight));
+        let (first, second) = (hash as u32 as usize, (hash >> 32) as usize | 1);
+        let bits = self.blocks.len() * BLOCK_BITS;
+
+        (0..self.hashes).map(move |round| first.wrapping_add(round.wrapping_mul(second)) % bits)
+    }
+
+    /// Inserts the signature of a hyperedge.
+    fn insert<W>(&mut self, vertices: &[VertexIndex], weight: &W)
+    where
+        W: Hash,
+    {
+        for position in self.get_positions(vertices, weight).collect::<Vec<usize>>() {
+            self.blocks[position / BLOCK_BITS] |= 1 << (position % BLOCK_BITS);
+        }
+    }
+
+    /// Checks if the signature of a hyperedge may have been inserted.
+    fn maybe_contains<W>(&self, vertices: &[VertexIndex], weight: &W) -> bool
+    where
+        W: Hash,
+    {
+        self.get_positions(vertices, weight).all(|position| {
+            self.blocks[position / BLOCK_BITS] & (1 << (position % BLOCK_BITS)) != 0
+        })
+    }
+
+    /// Clears the filter while keeping its size.
+    pub(crate) fn clear(&mut self) {
+        self.blocks.iter_mut().for_each(|block| *block = 0);
+    }
+
+    /// Estimates the heap usage of the filter in bytes.
+    pub(crate) fn estimated_memory_bytes(&self) -> usize {
+        self.blocks.capacity() * size_of::<u64>()
+    }
+}
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Enables an approximate membership filter over the hyperedges, e.g. to
+    /// short-circuit the exact checks when deduplicating them at ingestion
+    /// time. See `maybe_contains_hyperedge`.
+    /// The filter is sized for a number of hyperedges - at least the current
+    /// ones - and a target rate of false positives, e.g. `0.01`. It is kept
+    /// up to date by the insertions and the updates of the hyperedges but
+    /// the removed or updated ones are only dropped from it by
+    /// `rebuild_hyperedge_filter`.
+    pub fn enable_hyperedge_filter(
+        &mut self,
+        capacity: usize,
+        false_positive_rate: f64,
+    ) -> Result<(), HypergraphError> {
+        let mut filter =
+            HyperedgeFilter::new(capacity.max(self.hyperedges.len()), false_positive_rate);
+
+        for HyperedgeKey { vertices, weight } in self.hyperedges.iter() {
+            filter.insert(&self.get_vertices(vertices)?, weight);
+        }
+
+        self.hyperedges_filter = Some(filter);
+
+        Ok(())
+    }
+
+    /// Rebuilds the approximate membership filter from the current
+    /// hyperedges, with the same target rate of false positives, e.g. to
+    /// drop the removed ones or once it has been outgrown.
+    pub fn rebuild_hyperedge_filter(&mut self) -> Result<(), HypergraphError> {
+        let (capacity, false_positive_rate) = self
+            .hyperedges_filter
+            .as_ref()
+            .map(|filter| (filter.capacity, filter.false_positive_rate))
+            .ok_or(HypergraphError::HyperedgeFilterNotEnabled)?;
+
+        self.enable_hyperedge_filter(capacity, false_positive_rate)
+    }
+
+    /// Disables the approximate membership filter and drops it.
+    pub fn disable_hyperedge_filter(&mut self) {
+        self.hyperedges_filter = None;
+    }
+
+    /// Checks if the approximate membership filter is enabled.
+    pub fn has_hyperedge_filter(&self) -> bool {
+        self.hyperedges_filter.is_some()
+    }
+
+    /// Checks if a hyperedge with a set of vertices - regardless of their
+    /// order and of their repetitions - and a weight may exist.
+    /// A `false` result is definitive while a `true` one may be a false
+    /// positive, or a hyperedge removed or updated since the last rebuild of
+    /// the filter, to confirm with an exact check.
+    pub fn maybe_contains_hyperedge(
+        &self,
+        vertices: &[VertexIndex],
+        weight: &HE,
+    ) -> Result<bool, HypergraphError> {
+        let filter = self
+            .hyperedges_filter
+            .as_ref()
+            .ok_or(HypergraphError::HyperedgeFilterNotEnabled)?;
+
+        Ok(filter.maybe_contains(vertices, weight))
+    }
+
+    // Private method to insert the signature of a hyperedge into the
+    // approximate membership filter, if enabled.
+    pub(crate) fn filter_hyperedge(
+        &mut self,
+        internal_index: usize,
+    ) -> Result<(), HypergraphError> {
+        if self.hyperedges_filter.is_none() {
+            return Ok(());
+        }
+
+        let HyperedgeKey { vertices, weight } = self.hyperedges.get(internal_index).ok_or(
+            HypergraphError::InternalHyperedgeIndexNotFound(internal_index),
+        )?;
+        let vertices = self.get_vertices(vertices)?;
+
+        if let Some(filter) = self.hyperedges_filter.as_mut() {
+            filter.insert(&vertices, weight);
+        }
+
+        Ok(())
+    }
+}
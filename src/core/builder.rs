@@ -0,0 +1,142 @@
+use crate::{
+    Constraints,
+    DuplicatePolicy,
+    HyperedgeIndex,
+    HyperedgeTrait,
+    Hypergraph,
+    VertexDedupPolicy,
+    VertexIndex,
+    VertexTrait,
+    errors::HypergraphError,
+};
+
+/// Builder of hypergraphs with method chaining.
+/// The vertices and the hyperedges are recorded as is and only validated
+/// when the hypergraph is built, all at once, the internal allocations being
+/// sized upfront.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HypergraphBuilder<V, HE> {
+    constraints: Constraints,
+    duplicate_policy: DuplicatePolicy,
+    hyperedges: Vec<(Vec<VertexIndex>, HE)>,
+    hyperedges_capacity: usize,
+    vertex_dedup_policy: VertexDedupPolicy,
+    vertices: Vec<V>,
+    vertices_capacity: usize,
+}
+
+impl<V, HE> Default for HypergraphBuilder<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    fn default() -> Self {
+        HypergraphBuilder::new()
+    }
+}
+
+impl<V, HE> HypergraphBuilder<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Creates a new builder with no allocation.
+    pub fn new() -> Self {
+        HypergraphBuilder::with_capacity(0, 0)
+    }
+
+    /// Creates a new builder with the specified capacity, which is also
+    /// reserved for the built hypergraph.
+    pub fn with_capacity(vertices: usize, hyperedges: usize) -> Self {
+        Self {
+            constraints: Constraints::default(),
+            duplicate_policy: DuplicatePolicy::default(),
+            hyperedges: Vec::with_capacity(hyperedges),
+            hyperedges_capacity: hyperedges,
+            vertex_dedup_policy: VertexDedupPolicy::default(),
+            vertices: Vec::with_capacity(vertices),
+            vertices_capacity: vertices,
+        }
+    }
+
+    /// Sets the duplicate policy of the built hypergraph.
+    pub fn duplicate_policy(mut self, duplicate_policy: DuplicatePolicy) -> Self {
+        self.duplicate_policy = duplicate_policy;
+
+        self
+    }
+
+    /// Sets the policy deduplicating the vertices of the updated or joined
+    /// hyperedges of the built hypergraph.
+    pub fn vertex_dedup_policy(mut self, vertex_dedup_policy: VertexDedupPolicy) -> Self {
+        self.vertex_dedup_policy = vertex_dedup_policy;
+
+        self
+    }
+
+    /// Sets the constraints of the built hypergraph.
+    pub fn constraints(mut self, constraints: Constraints) -> Self {
+        self.constraints = constraints;
+
+        self
+    }
+
+    /// Adds a vertex and returns the `VertexIndex` it will have once the
+    /// hypergraph is built. The index can be used right away to add
+    /// hyperedges.
+    pub fn add_vertex(&mut self, weight: V) -> VertexIndex {
+        self.vertices.push(weight);
+
+        VertexIndex(self.vertices.len() - 1)
+    }
+
+    /// Adds a hyperedge and returns the `HyperedgeIndex` it will have once
+    /// the hypergraph is built.
+    pub fn add_hyperedge(&mut self, vertices: Vec<VertexIndex>, weight: HE) -> HyperedgeIndex {
+        self.hyperedges.push((vertices, weight));
+
+        HyperedgeIndex(self.hyperedges.len() - 1)
+    }
+
+    /// Adds a vertex, for method chaining.
+    pub fn vertex(mut self, weight: V) -> Self {
+        self.add_vertex(weight);
+
+        self
+    }
+
+    /// Adds a hyperedge, for method chaining.
+    pub fn hyperedge(mut self, vertices: Vec<VertexIndex>, weight: HE) -> Self {
+        self.add_hyperedge(vertices, weight);
+
+        self
+    }
+
+    /// Builds the hypergraph, validating the vertices and the hyperedges in
+    /// their insertion order. Returns the first error encountered, if any.
+    pub fn build(self) -> Result<Hypergraph<V, HE>, HypergraphError> {
+        // Don't reserve beyond the maximum counts of the constraints.
+        let mut graph = Hypergraph::with_capacity(
+            self.vertices_capacity
+                .max(self.vertices.len())
+                .min(self.constraints.max_vertices.unwrap_or(usize::MAX)),
+            self.hyperedges_capacity
+                .max(self.hyperedges.len())
+                .min(self.constraints.max_hyperedges.unwrap_or(usize::MAX)),
+        );
+
+        graph.duplicate_policy = self.duplicate_policy;
+        graph.vertex_dedup_policy = self.vertex_dedup_policy;
+        graph.constraints = self.constraints;
+
+        for weight in self.vertices {
+            graph.add_vertex(weight)?;
+        }
+
+        for (vertices, weight) in self.hyperedges {
+            graph.add_hyperedge(vertices, weight)?;
+        }
+
+        Ok(graph)
+    }
+}
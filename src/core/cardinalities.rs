@@ -0,0 +1,40 @@
+use std::{
+    collections::BTreeMap,
+    ops::RangeBounds,
+};
+
+/// Histogram of the cardinalities of the hyperedges, i.e. the number of
+/// hyperedges by number of vertices, maintained by the mutating methods.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub(crate) struct CardinalityHistogram(BTreeMap<usize, usize>);
+
+impl CardinalityHistogram {
+    /// Records a hyperedge with the given cardinality.
+    pub(crate) fn insert(&mut self, cardinality: usize) {
+        *self.0.entry(cardinality).or_insert(0) += 1;
+    }
+
+    /// Forgets a hyperedge with the given cardinality.
+    pub(crate) fn remove(&mut self, cardinality: usize) {
+        if let Some(count) = self.0.get_mut(&cardinality) {
+            *count -= 1;
+
+            if *count == 0 {
+                self.0.remove(&cardinality);
+            }
+        }
+    }
+
+    /// Forgets all the hyperedges.
+    pub(crate) fn clear(&mut self) {
+        self.0.clear();
+    }
+
+    /// Counts the hyperedges with a cardinality within the range.
+    pub(crate) fn count<R>(&self, range: R) -> usize
+    where
+        R: RangeBounds<usize>,
+    {
+        self.0.range(range).map(|(_, count)| count).sum()
+    }
+}
@@ -0,0 +1,194 @@
+use itertools::Itertools;
+
+use crate::{
+    HyperedgeKey,
+    HyperedgeTrait,
+    Hypergraph,
+    VertexIndex,
+    VertexTrait,
+    errors::HypergraphError,
+};
+
+#[allow(clippy::type_complexity)]
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    // Private method to get the strongly connected components as vectors of
+    // internal vertices, based on an iterative version of the algorithm of
+    // Tarjan.
+    // The components are sorted by their first vertex and the vertices of
+    // each component are sorted too, following the insertion order.
+    pub(crate) fn get_internal_strongly_connected_components(&self) -> Vec<Vec<usize>> {
+        let count = self.vertices.len();
+        let adjacency = self.get_internal_adjacency();
+
+        let mut indexes = vec![usize::MAX; count];
+        let mut lowlinks = vec![0; count];
+        let mut on_stack = vec![false; count];
+        let mut stack = vec![];
+        let mut next_index = 0;
+        let mut components = vec![];
+
+        for root in 0..count {
+            if indexes[root] != usize::MAX {
+                continue;
+            }
+
+            // Each frame holds a vertex and the position of the next adjacent
+            // vertex to visit.
+            let mut frames = vec![(root, 0)];
+
+            indexes[root] = next_index;
+            lowlinks[root] = next_index;
+            next_index += 1;
+            stack.push(root);
+            on_stack[root] = true;
+
+            while let Some(&(vertex, position)) = frames.last() {
+                if let Some(&adjacent_vertex) = adjacency[vertex].get(position) {
+                    let top = frames.len() - 1;
+
+                    frames[top].1 += 1;
+
+                    if indexes[adjacent_vertex] == usize::MAX {
+                        indexes[adjacent_vertex] = next_index;
+                        lowlinks[adjacent_vertex] = next_index;
+                        next_index += 1;
+                        stack.push(adjacent_vertex);
+                        on_stack[adjacent_vertex] = true;
+                        frames.push((adjacent_vertex, 0));
+                    } else if on_stack[adjacent_vertex] {
+                        lowlinks[vertex] = lowlinks[vertex].min(indexes[adjacent_vertex]);
+                    }
+                } else {
+                    frames.pop();
+
+                    if let Some(&(parent, _)) = frames.last() {
+                        lowlinks[parent] = lowlinks[parent].min(lowlinks[vertex]);
+                    }
+
+                    // The vertex is the root of a component.
+                    if lowlinks[vertex] == indexes[vertex] {
+                        let mut component = vec![];
+
+                        while let Some(member) = stack.pop() {
+                            on_stack[member] = false;
+                            component.push(member);
+
+                            if member == vertex {
+                                break;
+                            }
+                        }
+
+                        component.sort_unstable();
+                        components.push(component);
+                    }
+                }
+            }
+        }
+
+        components.sort_unstable_by_key(|component| component[0]);
+
+        components
+    }
+
+    /// Gets the strongly connected components of the hypergraph as vectors
+    /// of `VertexIndex`, i.e. the maximal sets of vertices which can all
+    /// reach each other by following the direction of the hyperedges.
+    /// Every vertex belongs to exactly one component.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            err,
+            fields(
+                vertices_count = self.vertices.len(),
+                hyperedges_count = self.hyperedges.len(),
+            )
+        )
+    )]
+    pub fn strongly_connected_components(&self) -> Result<Vec<Vec<VertexIndex>>, HypergraphError> {
+        self.get_internal_strongly_connected_components()
+            .into_iter()
+            .map(|component| self.get_vertices(&component))
+            .collect()
+    }
+
+    /// Creates the condensation of the hypergraph, i.e. a new hypergraph
+    /// whose vertices are the strongly connected components and whose
+    /// hyperedges are the original ones remapped to the components. The
+    /// steps between vertices of the same component are dropped, as well as
+    /// the hyperedges left within a single component. The condensation is
+    /// thus acyclic.
+    /// Each component is weighted by its first vertex, following the
+    /// insertion order. Returns the condensation along with the components,
+    /// the vertex `VertexIndex(n)` of the condensation matching the n-th
+    /// component.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            err,
+            fields(
+                vertices_count = self.vertices.len(),
+                hyperedges_count = self.hyperedges.len(),
+            )
+        )
+    )]
+    pub fn condense(&self) -> Result<(Self, Vec<Vec<VertexIndex>>), HypergraphError> {
+        let components = self.get_internal_strongly_connected_components();
+
+        let mut component_indexes = vec![0; self.vertices.len()];
+
+        for (component_index, component) in components.iter().enumerate() {
+            for vertex in component {
+                component_indexes[*vertex] = component_index;
+            }
+        }
+
+        let mut graph = Hypergraph::new();
+
+        let vertices = components
+            .iter()
+            .map(|component| {
+                let (key, _) = self
+                    .vertices
+                    .get_index(component[0])
+                    .ok_or(HypergraphError::InternalVertexIndexNotFound(component[0]))?;
+
+                match key.id {
+                    Some(_) => graph.add_vertex_allow_duplicate(key.weight.clone()),
+                    None => graph.add_vertex(key.weight.clone()),
+                }
+            })
+            .collect::<Result<Vec<VertexIndex>, HypergraphError>>()?;
+
+        for HyperedgeKey {
+            vertices: hyperedge_vertices,
+            weight,
+        } in self.hyperedges.iter()
+        {
+            let remapped_vertices = hyperedge_vertices
+                .iter()
+                .map(|vertex| vertices[component_indexes[*vertex]])
+                .dedup()
+                .collect::<Vec<VertexIndex>>();
+
+            // Skip the hyperedges within a single component.
+            if remapped_vertices.len() > 1 {
+                graph.add_hyperedge(remapped_vertices, weight.clone())?;
+            }
+        }
+
+        let components = components
+            .into_iter()
+            .map(|component| self.get_vertices(&component))
+            .collect::<Result<Vec<Vec<VertexIndex>>, HypergraphError>>()?;
+
+        Ok((graph, components))
+    }
+}
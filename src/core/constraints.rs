@@ -0,0 +1,196 @@
+use crate::{
+    HyperedgeKey,
+    HyperedgeTrait,
+    Hypergraph,
+    VertexTrait,
+    errors::HypergraphError,
+};
+
+/// Constraints enforced by the mutating methods of the hypergraph, on top of
+/// the duplicate policy. No constraint is enforced by default.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Constraints {
+    /// Maximum number of vertices of a hyperedge, repetitions included.
+    pub max_hyperedge_cardinality: Option<usize>,
+    /// Maximum number of hyperedges a vertex can belong to.
+    pub max_vertex_degree: Option<usize>,
+    /// Whether the hyperedges with a self-loop, i.e. with the same vertex
+    /// twice in a row, are rejected.
+    pub forbid_self_loops: bool,
+    /// Maximum number of vertices of the hypergraph, e.g. to bound the
+    /// memory usage of a tenant in a shared service.
+    pub max_vertices: Option<usize>,
+    /// Maximum number of hyperedges of the hypergraph.
+    pub max_hyperedges: Option<usize>,
+}
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Creates a new hypergraph with the specified constraints.
+    pub fn with_constraints(constraints: Constraints) -> Self {
+        Hypergraph {
+            constraints,
+            ..Hypergraph::new()
+        }
+    }
+
+    /// Gets the constraints of the hypergraph.
+    pub fn get_constraints(&self) -> Constraints {
+        self.constraints
+    }
+
+    // Private method to check that one more vertex doesn't exceed the
+    // maximum number of vertices.
+    pub(crate) fn check_vertices_count(&self) -> Result<(), HypergraphError> {
+        match self.constraints.max_vertices {
+            Some(max) if self.vertices.len() >= max => {
+                Err(HypergraphError::VerticesCountExceeded { max })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    // Private method to check that one more hyperedge doesn't exceed the
+    // maximum number of hyperedges.
+    pub(crate) fn check_hyperedges_count(&self) -> Result<(), HypergraphError> {
+        match self.constraints.max_hyperedges {
+            Some(max) if self.hyperedges.len() >= max => {
+                Err(HypergraphError::HyperedgesCountExceeded { max })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    // Private method to check that a vertex doesn't exceed the maximum
+    // degree once part of the given number of hyperedges.
+    pub(crate) fn check_vertex_degree(
+        &self,
+        vertex: usize,
+        degree: usize,
+    ) -> Result<(), HypergraphError> {
+        match self.constraints.max_vertex_degree {
+            Some(max) if degree > max => Err(HypergraphError::VertexDegreeExceeded {
+                index: self.get_vertex(vertex)?,
+                max,
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    // Private method to check the cardinality and the self-loops of some
+    // internal vertices of a hyperedge.
+    fn check_hyperedge_constraints(&self, vertices: &[usize]) -> Result<(), HypergraphError> {
+        if let Some(max) = self.constraints.max_hyperedge_cardinality {
+            if vertices.len() > max {
+                return Err(HypergraphError::HyperedgeCardinalityExceeded {
+                    cardinality: vertices.len(),
+                    max,
+                });
+            }
+        }
+
+        if self.constraints.forbid_self_loops {
+            if let Some(pair) = vertices.windows(2).find(|pair| pair[0] == pair[1]) {
+                return Err(HypergraphError::HyperedgeSelfLoopForbidden(
+                    self.get_vertex(pair[0])?,
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    // Private method to check that the whole hypergraph complies with the
+    // constraints, e.g. once read from a snapshot.
+    pub(crate) fn check_all_constraints(&self) -> Result<(), HypergraphError> {
+        match self.constraints.max_vertices {
+            Some(max) if self.vertices.len() > max => {
+                return Err(HypergraphError::VerticesCountExceeded { max });
+            }
+            _ => {}
+        }
+
+        match self.constraints.max_hyperedges {
+            Some(max) if self.hyperedges.len() > max => {
+                return Err(HypergraphError::HyperedgesCountExceeded { max });
+            }
+            _ => {}
+        }
+
+        for HyperedgeKey { vertices, .. } in self.hyperedges.iter() {
+            self.check_hyperedge_constraints(vertices)?;
+        }
+
+        for (vertex, (_, hyperedges)) in self.vertices.iter().enumerate() {
+            self.check_vertex_degree(vertex, hyperedges.len())?;
+        }
+
+        Ok(())
+    }
+
+    // Private method to check that some internal vertices of a hyperedge
+    // comply with the constraints. The replaced hyperedges, if any, are not
+    // taken into account for the degrees of the vertices.
+    pub(crate) fn check_constraints(
+        &self,
+        vertices: &[usize],
+        replaced: &[usize],
+    ) -> Result<(), HypergraphError> {
+        self.check_hyperedge_constraints(vertices)?;
+
+        if self.constraints.max_vertex_degree.is_some() {
+            for vertex in vertices {
+                let (_, hyperedges) = self
+                    .vertices
+                    .get_index(*vertex)
+                    .ok_or(HypergraphError::InternalVertexIndexNotFound(*vertex))?;
+
+                let degree = hyperedges
+                    .iter()
+                    .filter(|hyperedge| !replaced.contains(hyperedge))
+                    .count();
+
+                self.check_vertex_degree(*vertex, degree + 1)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    // Private method to check upfront that removing a vertex from some
+    // hyperedges doesn't create self-loops, e.g. when it was surrounded by
+    // the same vertex.
+    pub(crate) fn check_constraints_on_removal(
+        &self,
+        vertex: usize,
+        hyperedges: &[usize],
+    ) -> Result<(), HypergraphError> {
+        if !self.constraints.forbid_self_loops {
+            return Ok(());
+        }
+
+        for hyperedge in hyperedges {
+            let HyperedgeKey { vertices, .. } = self
+                .hyperedges
+                .get(*hyperedge)
+                .ok_or(HypergraphError::InternalHyperedgeIndexNotFound(*hyperedge))?;
+
+            let remaining = vertices
+                .iter()
+                .copied()
+                .filter(|current| *current != vertex)
+                .collect::<Vec<usize>>();
+
+            if let Some(pair) = remaining.windows(2).find(|pair| pair[0] == pair[1]) {
+                return Err(HypergraphError::HyperedgeSelfLoopForbidden(
+                    self.get_vertex(pair[0])?,
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
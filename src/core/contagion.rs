@@ -0,0 +1,242 @@
+use rand::Rng;
+
+use crate::{
+    HyperedgeKey,
+    HyperedgeTrait,
+    Hypergraph,
+    VertexIndex,
+    VertexTrait,
+    errors::HypergraphError,
+};
+
+/// State of a vertex in a contagion model.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ContagionState {
+    /// Not infected yet, or inactive in a threshold model.
+    Susceptible,
+    /// Infected, or active in a threshold model.
+    Infected,
+    /// Recovered and immune, only reached in the SIR model.
+    Recovered,
+}
+
+/// Time series of the states of the vertices returned by the contagion
+/// models.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ContagionSeries {
+    /// Vertices, in insertion order.
+    pub vertices: Vec<VertexIndex>,
+    /// States of the vertices at each step, aligned with them, starting with
+    /// the seeds.
+    pub states: Vec<Vec<ContagionState>>,
+}
+
+impl ContagionSeries {
+    /// Gets the number of steps, the seeds aside.
+    pub fn steps(&self) -> usize {
+        self.states.len().saturating_sub(1)
+    }
+
+    /// Gets the state of a vertex at a step, if any.
+    pub fn get_state(&self, step: usize, vertex_index: VertexIndex) -> Option<ContagionState> {
+        let position = self
+            .vertices
+            .iter()
+            .position(|index| *index == vertex_index)?;
+
+        self.states
+            .get(step)
+            .and_then(|states| states.get(position))
+            .copied()
+    }
+
+    /// Counts the vertices in a state at a step, zero past the last one.
+    pub fn count(&self, step: usize, state: ContagionState) -> usize {
+        self.states.get(step).map_or(0, |states| {
+            states.iter().filter(|current| **current == state).count()
+        })
+    }
+}
+
+// Private helper to check that a rate or a fraction is a probability.
+fn check_probability(name: &str, value: f64) -> Result<(), HypergraphError> {
+    if (0. ..=1.).contains(&value) {
+        Ok(())
+    } else {
+        Err(HypergraphError::ContagionInvalidParameter(name.to_string()))
+    }
+}
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Simulates a higher-order SIR spreading process, in discrete time and
+    /// with synchronous updates, from some infected seeds.
+    /// At each step, every hyperedge of a susceptible vertex containing at
+    /// least another infected vertex transmits the infection with a
+    /// probability of `infection_rate`, and every infected vertex recovers
+    /// with a probability of `recovery_rate`. The direction of the
+    /// hyperedges is ignored.
+    /// The simulation stops once no vertex is infected or after `max_steps`
+    /// steps. The random number generator is injected, e.g. seeded for
+    /// reproducible runs.
+    pub fn simulate_sir<R>(
+        &self,
+        seeds: &[VertexIndex],
+        infection_rate: f64,
+        recovery_rate: f64,
+        max_steps: usize,
+        rng: &mut R,
+    ) -> Result<ContagionSeries, HypergraphError>
+    where
+        R: Rng + ?Sized,
+    {
+        check_probability("infection_rate", infection_rate)?;
+        check_probability("recovery_rate", recovery_rate)?;
+
+        let mut series = self.get_contagion_series(seeds)?;
+
+        while series.steps() < max_steps {
+            let states = series.states.last().cloned().unwrap_or_default();
+
+            if !states.contains(&ContagionState::Infected) {
+                break;
+            }
+
+            let mut next = states.clone();
+
+            for (internal_index, (_, hyperedges)) in self.vertices.iter().enumerate() {
+                match states[internal_index] {
+                    ContagionState::Susceptible => {
+                        for hyperedge in hyperedges.iter() {
+                            let HyperedgeKey { vertices, .. } =
+                                self.hyperedges.get(*hyperedge).ok_or(
+                                    HypergraphError::InternalHyperedgeIndexNotFound(*hyperedge),
+                                )?;
+
+                            if vertices.iter().any(|vertex| {
+                                *vertex != internal_index
+                                    && states[*vertex] == ContagionState::Infected
+                            }) && rng.gen_bool(infection_rate)
+                            {
+                                next[internal_index] = ContagionState::Infected;
+
+                                break;
+                            }
+                        }
+                    }
+                    ContagionState::Infected => {
+                        if rng.gen_bool(recovery_rate) {
+                            next[internal_index] = ContagionState::Recovered;
+                        }
+                    }
+                    ContagionState::Recovered => {}
+                }
+            }
+
+            series.states.push(next);
+        }
+
+        Ok(series)
+    }
+
+    /// Simulates a higher-order threshold spreading process, in discrete
+    /// time and with synchronous updates, from some active seeds.
+    /// At each step, a hyperedge is active when the fraction of its unique
+    /// vertices being active reaches `threshold`, and every active hyperedge
+    /// of an inactive vertex activates it with a probability of
+    /// `activation_rate`. The active vertices stay active, i.e. infected.
+    /// The simulation stops once no inactive vertex belongs to an active
+    /// hyperedge or after `max_steps` steps. The random number generator is
+    /// injected, e.g. seeded for reproducible runs.
+    pub fn simulate_threshold<R>(
+        &self,
+        seeds: &[VertexIndex],
+        threshold: f64,
+        activation_rate: f64,
+        max_steps: usize,
+        rng: &mut R,
+    ) -> Result<ContagionSeries, HypergraphError>
+    where
+        R: Rng + ?Sized,
+    {
+        check_probability("threshold", threshold)?;
+        check_probability("activation_rate", activation_rate)?;
+
+        let mut series = self.get_contagion_series(seeds)?;
+
+        while series.steps() < max_steps {
+            let states = series.states.last().cloned().unwrap_or_default();
+
+            // Find the active hyperedges upfront.
+            let active = self
+                .hyperedges
+                .iter()
+                .map(|HyperedgeKey { vertices, .. }| {
+                    let mut vertices = vertices.to_vec();
+
+                    vertices.sort_unstable();
+                    vertices.dedup();
+
+                    let count = vertices
+                        .iter()
+                        .filter(|vertex| states[**vertex] == ContagionState::Infected)
+                        .count();
+
+                    count as f64 >= threshold * vertices.len() as f64
+                })
+                .collect::<Vec<bool>>();
+
+            let candidates = self
+                .vertices
+                .values()
+                .enumerate()
+                .filter(|(internal_index, hyperedges)| {
+                    states[*internal_index] == ContagionState::Susceptible
+                        && hyperedges.iter().any(|hyperedge| active[*hyperedge])
+                })
+                .collect::<Vec<_>>();
+
+            // Early exit if there's nothing left to activate.
+            if candidates.is_empty() {
+                break;
+            }
+
+            let mut next = states.clone();
+
+            for (internal_index, hyperedges) in candidates {
+                if hyperedges
+                    .iter()
+                    .filter(|hyperedge| active[**hyperedge])
+                    .any(|_| rng.gen_bool(activation_rate))
+                {
+                    next[internal_index] = ContagionState::Infected;
+                }
+            }
+
+            series.states.push(next);
+        }
+
+        Ok(series)
+    }
+
+    // Private method to initialize the time series of a contagion model
+    // with the seeds.
+    fn get_contagion_series(
+        &self,
+        seeds: &[VertexIndex],
+    ) -> Result<ContagionSeries, HypergraphError> {
+        let mut states = vec![ContagionState::Susceptible; self.vertices.len()];
+
+        for internal_index in self.get_internal_vertices(seeds.to_vec())? {
+            states[internal_index] = ContagionState::Infected;
+        }
+
+        Ok(ContagionSeries {
+            vertices: self.get_vertices(&(0..self.vertices.len()).collect::<Vec<usize>>())?,
+            states: vec![states],
+        })
+    }
+}
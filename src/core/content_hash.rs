@@ -0,0 +1,85 @@
+use std::hash::{
+    Hash,
+    Hasher,
+};
+
+use crate::{
+    HyperedgeTrait,
+    Hypergraph,
+    VertexTrait,
+};
+
+// FNV-1a offset basis and prime for 64 bits.
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+// Private FNV-1a hasher. Unlike the standard library's default hasher, its
+// output is guaranteed to be the same across Rust releases and processes.
+pub(crate) struct FnvHasher(u64);
+
+impl Default for FnvHasher {
+    fn default() -> Self {
+        Self(FNV_OFFSET_BASIS)
+    }
+}
+
+impl Hasher for FnvHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(FNV_PRIME);
+        }
+    }
+}
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Computes a stable digest of the content of the hypergraph.
+    /// The digest covers the weights of the vertices and of the hyperedges
+    /// along with the weights of the vertices of each hyperedge. It doesn't
+    /// depend on the insertion order of the vertices and of the hyperedges
+    /// but it does depend on the order of the vertices within a hyperedge.
+    pub fn content_hash(&self) -> u64 {
+        // Combine the vertices' digests with a commutative operation so that
+        // the result is order-insensitive.
+        let vertices_digest = self.vertices.keys().fold(0u64, |digest, key| {
+            let mut hasher = FnvHasher::default();
+
+            key.weight.hash(&mut hasher);
+
+            digest.wrapping_add(hasher.finish())
+        });
+
+        let hyperedges_digest = self.hyperedges.iter().fold(0u64, |digest, key| {
+            let mut hasher = FnvHasher::default();
+
+            key.weight.hash(&mut hasher);
+
+            // Hash the weights of the vertices instead of their internal
+            // indexes, in order.
+            for vertex in key.vertices.iter() {
+                if let Some((vertex_key, _)) = self.vertices.get_index(*vertex) {
+                    vertex_key.weight.hash(&mut hasher);
+                }
+            }
+
+            digest.wrapping_add(hasher.finish())
+        });
+
+        let mut hasher = FnvHasher::default();
+
+        self.vertices.len().hash(&mut hasher);
+        vertices_digest.hash(&mut hasher);
+        self.hyperedges.len().hash(&mut hasher);
+        hyperedges_digest.hash(&mut hasher);
+
+        hasher.finish()
+    }
+}
@@ -0,0 +1,232 @@
+use std::{
+    cmp::Reverse,
+    collections::{
+        BinaryHeap,
+        HashMap,
+    },
+    iter::FusedIterator,
+};
+
+use crate::{
+    HyperedgeIndex,
+    HyperedgeTrait,
+    Hypergraph,
+    VertexIndex,
+    VertexTrait,
+    errors::HypergraphError,
+};
+
+/// Direction in which the hyperedges are followed by a search.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum Direction {
+    /// Follow the hyperedges from a vertex to its successors.
+    Forward,
+    /// Follow the hyperedges from a vertex to its predecessors.
+    Backward,
+}
+
+/// Outcome of a shortest paths search.
+#[derive(Debug, Default)]
+pub(crate) struct ShortestPaths {
+    /// Distances of the reached vertices from the nearest source. They are
+    /// final for the settled vertices, i.e. all of them unless the search
+    /// has stopped at a target.
+    pub(crate) distances: HashMap<VertexIndex, usize>,
+
+    /// Vertex and hyperedge via which each vertex has been reached, only
+    /// tracked on demand.
+    pub(crate) links: HashMap<VertexIndex, (VertexIndex, HyperedgeIndex)>,
+}
+
+/// Lazy iterator over the steps of a cheapest path, as returned by
+/// `Hypergraph::get_dijkstra_path_iter`.
+/// Each step is a tuple of the form `(VertexIndex, Option<HyperedgeIndex>)`
+/// where the second member is the hyperedge that has been traversed to reach
+/// the vertex, `None` for the first step.
+#[derive(Clone, Debug)]
+pub struct PathIter {
+    successors: HashMap<VertexIndex, (VertexIndex, HyperedgeIndex)>,
+    next: Option<(VertexIndex, Option<HyperedgeIndex>)>,
+    to: VertexIndex,
+}
+
+impl PathIter {
+    /// Creates an iterator following the successors from a vertex to
+    /// another one, empty if the latter is not reachable.
+    pub(crate) fn new(
+        successors: HashMap<VertexIndex, (VertexIndex, HyperedgeIndex)>,
+        from: VertexIndex,
+        to: VertexIndex,
+    ) -> Self {
+        let next = (from == to || successors.contains_key(&from)).then(|| (from, None));
+
+        Self {
+            successors,
+            next,
+            to,
+        }
+    }
+}
+
+impl Iterator for PathIter {
+    type Item = (VertexIndex, Option<HyperedgeIndex>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next.take()?;
+
+        if current.0 != self.to {
+            self.next = self
+                .successors
+                .get(&current.0)
+                .map(|(vertex_index, hyperedge_index)| (*vertex_index, Some(*hyperedge_index)));
+        }
+
+        Some(current)
+    }
+}
+
+impl FusedIterator for PathIter {}
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    // Private method to run a Dijkstra search from many sources at once,
+    // each vertex being reached from the nearest one. The cost of moving
+    // between two consecutive vertices of a hyperedge is the cheapest weight
+    // of the hyperedges connecting them.
+    // The search stops as soon as the optional target is settled and doesn't
+    // explore beyond the optional maximum cost.
+    pub(crate) fn get_shortest_paths(
+        &self,
+        sources: &[VertexIndex],
+        target: Option<VertexIndex>,
+        direction: Direction,
+        track_links: bool,
+        max_cost: Option<usize>,
+    ) -> Result<ShortestPaths, HypergraphError> {
+        self.get_filtered_shortest_paths(
+            sources,
+            target,
+            direction,
+            track_links,
+            max_cost,
+            |_, _| true,
+        )
+    }
+
+    // Private method to run a Dijkstra search like `get_shortest_paths`
+    // while only moving to the adjacent vertices via the hyperedges matching
+    // a predicate called with both of their indexes.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            err,
+            fields(
+                ?direction,
+                ?max_cost,
+                vertices_count = self.vertices.len(),
+                hyperedges_count = self.hyperedges.len(),
+            )
+        )
+    )]
+    pub(crate) fn get_filtered_shortest_paths<F>(
+        &self,
+        sources: &[VertexIndex],
+        target: Option<VertexIndex>,
+        direction: Direction,
+        track_links: bool,
+        max_cost: Option<usize>,
+        predicate: F,
+    ) -> Result<ShortestPaths, HypergraphError>
+    where
+        F: Fn(VertexIndex, HyperedgeIndex) -> bool,
+    {
+        let mut shortest_paths = ShortestPaths::default();
+        let mut to_traverse = BinaryHeap::new();
+
+        for source in sources {
+            self.get_internal_vertex(*source)?;
+
+            shortest_paths.distances.insert(*source, 0);
+            to_traverse.push(Reverse((0, *source)));
+        }
+
+        if let Some(target) = target {
+            self.get_internal_vertex(target)?;
+        }
+
+        while let Some(Reverse((distance, vertex_index))) = to_traverse.pop() {
+            // Skip if a better path has already been found.
+            if distance > shortest_paths.distances[&vertex_index] {
+                continue;
+            }
+
+            if Some(vertex_index) == target {
+                break;
+            }
+
+            let adjacent_vertices = match direction {
+                Direction::Forward => self
+                    .get_cached_full_adjacent_vertices_from(vertex_index)?
+                    .into_owned(),
+                Direction::Backward => self
+                    .get_cached_full_adjacent_vertices_to(vertex_index)?
+                    .into_owned(),
+            };
+
+            for (adjacent_index, hyperedges) in adjacent_vertices {
+                let mut best = None;
+
+                // Get the lower cost out of all the hyperedges which can be
+                // traversed.
+                for hyperedge_index in hyperedges {
+                    if !predicate(adjacent_index, hyperedge_index) {
+                        continue;
+                    }
+
+                    let cost = self.get_hyperedge_cost(hyperedge_index)?;
+
+                    if best.map_or(true, |(min_cost, _)| cost < min_cost) {
+                        best = Some((cost, hyperedge_index));
+                    }
+                }
+
+                let Some((cost, hyperedge_index)) = best else {
+                    continue;
+                };
+
+                let next_distance = distance.saturating_add(cost);
+
+                // Skip the vertices beyond the budget.
+                if max_cost.map_or(false, |max_cost| next_distance > max_cost) {
+                    continue;
+                }
+
+                // Relax the adjacent vertex if this is a shorter distance.
+                if shortest_paths
+                    .distances
+                    .get(&adjacent_index)
+                    .map_or(true, |current| next_distance < *current)
+                {
+                    shortest_paths
+                        .distances
+                        .insert(adjacent_index, next_distance);
+
+                    if track_links {
+                        shortest_paths
+                            .links
+                            .insert(adjacent_index, (vertex_index, hyperedge_index));
+                    }
+
+                    to_traverse.push(Reverse((next_distance, adjacent_index)));
+                }
+            }
+        }
+
+        Ok(shortest_paths)
+    }
+}
@@ -0,0 +1,101 @@
+use std::fmt::{
+    Display,
+    Error,
+    Formatter,
+    Result,
+};
+
+use crate::{
+    HyperedgeTrait,
+    Hypergraph,
+    VertexTrait,
+};
+
+// Maximum number of vertices and hyperedges listed by the summary.
+const LISTING_LIMIT: usize = 10;
+
+// Private helper to write the min, max and mean of some statistics.
+fn write_stats(f: &mut Formatter<'_>, label: &str, values: &[usize]) -> Result {
+    match (values.iter().min(), values.iter().max()) {
+        (Some(min), Some(max)) => {
+            let mean = values.iter().sum::<usize>() as f64 / values.len() as f64;
+
+            writeln!(f, "{}: min {}, max {}, mean {:.2}", label, min, max, mean)
+        }
+        _ => writeln!(f, "{}: none", label),
+    }
+}
+
+/// Human-readable summary of the hypergraph, with the counts, the metadata,
+/// the degree and cardinality statistics and a truncated listing of the
+/// vertices and of the hyperedges.
+impl<V, HE> Display for Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        writeln!(
+            f,
+            "Hypergraph: {} vertices, {} hyperedges",
+            self.vertices.len(),
+            self.hyperedges.len()
+        )?;
+
+        // The metadata are only listed when there are some.
+        if !self.metadata.is_empty() {
+            writeln!(f, "Metadata:")?;
+
+            for (key, value) in self.metadata.iter() {
+                writeln!(f, "  {}: {}", key, value)?;
+            }
+        }
+
+        let degrees = self
+            .vertices
+            .values()
+            .map(|hyperedges| hyperedges.len())
+            .collect::<Vec<usize>>();
+        let cardinalities = self
+            .hyperedges
+            .iter()
+            .map(|key| key.vertices.len())
+            .collect::<Vec<usize>>();
+
+        write_stats(f, "Vertex degrees", &degrees)?;
+        write_stats(f, "Hyperedge cardinalities", &cardinalities)?;
+
+        writeln!(f, "Vertices:")?;
+
+        for (internal_index, key) in self.vertices.keys().enumerate().take(LISTING_LIMIT) {
+            let vertex_index = self.get_vertex(internal_index).map_err(|_| Error)?;
+
+            writeln!(f, "  {}: {}", vertex_index, key.weight)?;
+        }
+
+        if self.vertices.len() > LISTING_LIMIT {
+            writeln!(f, "  ... {} more", self.vertices.len() - LISTING_LIMIT)?;
+        }
+
+        write!(f, "Hyperedges:")?;
+
+        for (internal_index, key) in self.hyperedges.iter().enumerate().take(LISTING_LIMIT) {
+            let hyperedge_index = self.get_hyperedge(internal_index).map_err(|_| Error)?;
+            let vertices = self
+                .get_vertices(&key.vertices)
+                .map_err(|_| Error)?
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<String>>()
+                .join(", ");
+
+            write!(f, "\n  {}: {} [{}]", hyperedge_index, key.weight, vertices)?;
+        }
+
+        if self.hyperedges.len() > LISTING_LIMIT {
+            write!(f, "\n  ... {} more", self.hyperedges.len() - LISTING_LIMIT)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,404 @@
+use std::{
+    collections::HashMap,
+    fmt::{
+        Display,
+        Write,
+    },
+    iter::Peekable,
+    str::{
+        Chars,
+        FromStr,
+    },
+};
+
+use crate::{
+    HyperedgeIndex,
+    HyperedgeKey,
+    HyperedgeTrait,
+    Hypergraph,
+    VertexIndex,
+    VertexTrait,
+    errors::HypergraphError,
+};
+
+/// Palette used to color the hyperedges.
+const PALETTE: [&str; 8] = [
+    "#e6194b", "#3cb44b", "#4363d8", "#f58231", "#911eb4", "#42d4f4", "#f032e6", "#9a6324",
+];
+
+/// Default color provider of the dot rendering, cycling through a fixed
+/// palette by stable index so that the colors are deterministic, e.g. for
+/// golden-file tests.
+pub fn palette_color(hyperedge_index: HyperedgeIndex) -> &'static str {
+    PALETTE[hyperedge_index.0 % PALETTE.len()]
+}
+
+/// Tokens of the restricted dot syntax.
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Token {
+    Arrow,
+    Comma,
+    Equal,
+    LeftBrace,
+    LeftBracket,
+    RightBrace,
+    RightBracket,
+    Semicolon,
+    Identifier(String),
+    Quoted(String),
+}
+
+// Private helper to escape a weight as a dot quoted string.
+fn escape(weight: &str) -> String {
+    weight
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+// Private helper to read a quoted string, the opening quote being consumed.
+fn read_quoted(chars: &mut Peekable<Chars<'_>>) -> Result<String, HypergraphError> {
+    let mut value = String::new();
+
+    loop {
+        match chars.next() {
+            Some('"') => return Ok(value),
+            Some('\\') => match chars.next() {
+                Some('n') => value.push('\n'),
+                Some(escaped) => value.push(escaped),
+                None => break,
+            },
+            Some(character) => value.push(character),
+            None => break,
+        }
+    }
+
+    Err(HypergraphError::DotInvalidStatement(format!(
+        "unterminated string \"{value}"
+    )))
+}
+
+// Private helper to split the dot input into tokens.
+fn tokenize(dot: &str) -> Result<Vec<Token>, HypergraphError> {
+    let mut tokens = vec![];
+    let mut chars = dot.chars().peekable();
+
+    while let Some(character) = chars.next() {
+        match character {
+            '{' => tokens.push(Token::LeftBrace),
+            '}' => tokens.push(Token::RightBrace),
+            '[' => tokens.push(Token::LeftBracket),
+            ']' => tokens.push(Token::RightBracket),
+            '=' => tokens.push(Token::Equal),
+            ',' => tokens.push(Token::Comma),
+            ';' => tokens.push(Token::Semicolon),
+            '"' => tokens.push(Token::Quoted(read_quoted(&mut chars)?)),
+            '-' if chars.peek() == Some(&'>') => {
+                chars.next();
+                tokens.push(Token::Arrow);
+            }
+            // Skip the line comments.
+            '/' if chars.peek() == Some(&'/') => {
+                for current in chars.by_ref() {
+                    if current == '\n' {
+                        break;
+                    }
+                }
+            }
+            character if character.is_whitespace() => {}
+            character if character.is_alphanumeric() || character == '_' || character == '.' => {
+                let mut identifier = character.to_string();
+
+                while let Some(&next) = chars.peek() {
+                    if next.is_alphanumeric() || next == '_' || next == '.' {
+                        identifier.push(next);
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                }
+
+                tokens.push(Token::Identifier(identifier));
+            }
+            character => {
+                return Err(HypergraphError::DotInvalidStatement(format!(
+                    "unexpected character {character}"
+                )));
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+// Private helper to parse an attributes list, the opening bracket being
+// consumed.
+fn parse_attributes(
+    tokens: &mut impl Iterator<Item = Token>,
+) -> Result<HashMap<String, String>, HypergraphError> {
+    let mut attributes = HashMap::new();
+
+    loop {
+        match tokens.next() {
+            Some(Token::RightBracket) => return Ok(attributes),
+            Some(Token::Comma | Token::Semicolon) => {}
+            Some(Token::Identifier(key)) => match (tokens.next(), tokens.next()) {
+                (Some(Token::Equal), Some(Token::Identifier(value) | Token::Quoted(value))) => {
+                    attributes.insert(key, value);
+                }
+                _ => {
+                    return Err(HypergraphError::DotInvalidStatement(format!(
+                        "invalid attribute {key}"
+                    )));
+                }
+            },
+            token => {
+                return Err(HypergraphError::DotInvalidStatement(format!(
+                    "unexpected token {token:?} in attributes"
+                )));
+            }
+        }
+    }
+}
+
+// Private helper to parse a weight from an optional label.
+fn parse_weight<T>(label: Option<&String>) -> Result<T, HypergraphError>
+where
+    T: FromStr,
+{
+    let label = label.ok_or_else(|| {
+        HypergraphError::DotInvalidStatement("missing label attribute".to_owned())
+    })?;
+
+    label
+        .parse()
+        .map_err(|_| HypergraphError::DotInvalidWeight(label.to_owned()))
+}
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Renders the hypergraph to the Graphviz dot format.
+    /// Vertices are declared as nodes identified by their indexes and
+    /// labelled by their weights. Each hyperedge is rendered as a colored
+    /// chain of edges labelled by its weight. Unaries are rendered as
+    /// undirected self-loops.
+    /// The hyperedges are colored via `palette_color`. See
+    /// `to_graphviz_dot_with` for a custom color provider.
+    pub fn to_graphviz_dot(&self) -> Result<String, HypergraphError> {
+        self.to_graphviz_dot_with(|hyperedge_index, _| palette_color(hyperedge_index))
+    }
+
+    /// Renders the hypergraph to the Graphviz dot format like
+    /// `to_graphviz_dot`, the color of each hyperedge being provided by a
+    /// closure called with its index and its weight.
+    pub fn to_graphviz_dot_with<C, F>(
+        &self,
+        mut color_provider: F,
+    ) -> Result<String, HypergraphError>
+    where
+        C: Display,
+        F: FnMut(HyperedgeIndex, &HE) -> C,
+    {
+        let mut dot = String::from("digraph {\n");
+
+        // Writing to a string can't fail so the results are safely ignored.
+        let _ = writeln!(
+            dot,
+            "    edge [penwidth=0.5, arrowhead=normal, arrowsize=0.5, fontsize=8.0];"
+        );
+        let _ = writeln!(
+            dot,
+            "    node [color=gray20, fontsize=8.0, fontcolor=white, style=filled, shape=circle];"
+        );
+        let _ = writeln!(dot, "    rankdir=LR;");
+
+        for (internal_index, (weight, _)) in self.vertices.iter().enumerate() {
+            let _ = writeln!(
+                dot,
+                "    {} [label=\"{}\"];",
+                self.get_vertex(internal_index)?,
+                escape(&weight.to_string())
+            );
+        }
+
+        for (internal_index, HyperedgeKey { vertices, weight }) in
+            self.hyperedges.iter().enumerate()
+        {
+            let vertices = self.get_vertices(vertices)?;
+            let color =
+                escape(&color_provider(self.get_hyperedge(internal_index)?, weight).to_string());
+            let label = escape(&weight.to_string());
+
+            if let [vertex] = vertices.as_slice() {
+                let _ = writeln!(
+                    dot,
+                    "    {vertex} -> {vertex} [color=\"{color}\", fontcolor=\"{color}\", label=\"{label}\", dir=none];"
+                );
+            } else {
+                let _ = writeln!(
+                    dot,
+                    "    {} [color=\"{color}\", fontcolor=\"{color}\", label=\"{label}\"];",
+                    vertices
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect::<Vec<String>>()
+                        .join(" -> ")
+                );
+            }
+        }
+
+        dot.push_str("}\n");
+
+        Ok(dot)
+    }
+
+    /// Creates a new hypergraph from the restricted Graphviz dot format
+    /// produced by `to_graphviz_dot`.
+    /// Weights are parsed back from the labels via their `FromStr`
+    /// implementation.
+    /// Please note that the indexes of the resulting hypergraph are assigned
+    /// by insertion order and might differ from the ones of the nodes.
+    /// The nodes sharing a label become duplicate vertices, the first one
+    /// being added via `add_vertex` and the others via
+    /// `add_vertex_allow_duplicate`.
+    pub fn from_graphviz_dot(dot: &str) -> Result<Self, HypergraphError>
+    where
+        V: FromStr,
+        HE: FromStr,
+    {
+        let mut graph = Hypergraph::new();
+
+        // Keep track of the node identifiers in order to remap the vertices
+        // of the hyperedges.
+        let mut mapping = HashMap::<String, VertexIndex>::new();
+
+        let mut tokens = tokenize(dot)?.into_iter().peekable();
+
+        // Parse the header.
+        match (tokens.next(), tokens.peek()) {
+            (Some(Token::Identifier(keyword)), Some(Token::LeftBrace)) if keyword == "digraph" => {
+                tokens.next();
+            }
+            (Some(Token::Identifier(keyword)), Some(Token::Identifier(_)))
+                if keyword == "digraph" =>
+            {
+                tokens.next();
+
+                if tokens.next() != Some(Token::LeftBrace) {
+                    return Err(HypergraphError::DotInvalidStatement(
+                        "missing opening brace".to_owned(),
+                    ));
+                }
+            }
+            _ => {
+                return Err(HypergraphError::DotInvalidStatement(
+                    "missing digraph header".to_owned(),
+                ));
+            }
+        }
+
+        loop {
+            let identifier = match tokens.next() {
+                Some(Token::RightBrace) => return Ok(graph),
+                Some(Token::Semicolon) => continue,
+                Some(Token::Identifier(identifier)) => identifier,
+                token => {
+                    return Err(HypergraphError::DotInvalidStatement(format!(
+                        "unexpected token {token:?}"
+                    )));
+                }
+            };
+
+            match tokens.peek() {
+                // Default attributes statements are skipped.
+                Some(Token::LeftBracket)
+                    if ["edge", "graph", "node"].contains(&identifier.as_str()) =>
+                {
+                    tokens.next();
+                    parse_attributes(&mut tokens)?;
+                }
+                // Graph attributes statements are skipped too.
+                Some(Token::Equal) => {
+                    tokens.next();
+                    tokens.next();
+                }
+                // Vertex statement.
+                Some(Token::LeftBracket) => {
+                    tokens.next();
+
+                    let attributes = parse_attributes(&mut tokens)?;
+                    let weight = parse_weight::<V>(attributes.get("label"))?;
+
+                    if mapping.contains_key(&identifier) {
+                        return Err(HypergraphError::DotInvalidStatement(format!(
+                            "duplicate node {identifier}"
+                        )));
+                    }
+
+                    // The nodes sharing a label are duplicate vertices, e.g.
+                    // added via `add_vertex_allow_duplicate`.
+                    let vertex_index = match graph.add_vertex(weight.clone()) {
+                        Err(HypergraphError::VertexWeightAlreadyAssigned(_)) => {
+                            graph.add_vertex_allow_duplicate(weight)?
+                        }
+                        vertex_index => vertex_index?,
+                    };
+
+                    mapping.insert(identifier, vertex_index);
+                }
+                // Hyperedge statement.
+                Some(Token::Arrow) => {
+                    let mut identifiers = vec![identifier];
+
+                    while tokens.peek() == Some(&Token::Arrow) {
+                        tokens.next();
+
+                        match tokens.next() {
+                            Some(Token::Identifier(identifier)) => identifiers.push(identifier),
+                            token => {
+                                return Err(HypergraphError::DotInvalidStatement(format!(
+                                    "unexpected token {token:?} in edge"
+                                )));
+                            }
+                        }
+                    }
+
+                    if tokens.next() != Some(Token::LeftBracket) {
+                        return Err(HypergraphError::DotInvalidStatement(
+                            "missing hyperedge attributes".to_owned(),
+                        ));
+                    }
+
+                    let attributes = parse_attributes(&mut tokens)?;
+                    let weight = parse_weight::<HE>(attributes.get("label"))?;
+
+                    // Undirected self-loops are unaries.
+                    if attributes.get("dir").map(String::as_str) == Some("none") {
+                        identifiers.dedup();
+                    }
+
+                    let vertices = identifiers
+                        .into_iter()
+                        .map(|identifier| {
+                            mapping.get(&identifier).copied().ok_or_else(|| {
+                                HypergraphError::DotInvalidStatement(format!(
+                                    "unknown node {identifier}"
+                                ))
+                            })
+                        })
+                        .collect::<Result<Vec<VertexIndex>, HypergraphError>>()?;
+
+                    graph.add_hyperedge(vertices, weight)?;
+                }
+                token => {
+                    return Err(HypergraphError::DotInvalidStatement(format!(
+                        "unexpected token {token:?} after {identifier}"
+                    )));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,190 @@
+use std::hash::Hash;
+
+use itertools::Itertools;
+
+use crate::{
+    HyperedgeKey,
+    HyperedgeTrait,
+    Hypergraph,
+    VertexTrait,
+    errors::HypergraphError,
+};
+
+/// Policy enforced on the vertices of the hyperedges.
+/// The weights of the hyperedges are always unique, whatever the policy.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum DuplicatePolicy {
+    /// Different hyperedges can share the same vertices.
+    AllowDuplicateVertices,
+    /// Different hyperedges can't share the same vertices in the same order.
+    RejectDuplicateVertexLists,
+    /// Different hyperedges can't share the same set of vertices, regardless
+    /// of their order and of their repetitions, i.e. simple-hypergraph
+    /// semantics.
+    RejectDuplicateVertexSets,
+}
+
+impl Default for DuplicatePolicy {
+    fn default() -> Self {
+        DuplicatePolicy::AllowDuplicateVertices
+    }
+}
+
+/// Deduplication applied to the vertices of a hyperedge when they are
+/// updated, e.g. by `update_hyperedge_vertices`, or joined via
+/// `join_hyperedges`. The order of the kept vertices is preserved.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum VertexDedupPolicy {
+    /// The vertices are kept as is, duplicates included.
+    KeepDuplicates,
+    /// The consecutive duplicates are merged, e.g. `[a, a, b, a]` becomes
+    /// `[a, b, a]`.
+    DedupConsecutive,
+    /// Only the first occurrence of each vertex is kept, e.g. `[a, a, b, a]`
+    /// becomes `[a, b]`.
+    DedupAll,
+}
+
+impl Default for VertexDedupPolicy {
+    fn default() -> Self {
+        VertexDedupPolicy::KeepDuplicates
+    }
+}
+
+impl VertexDedupPolicy {
+    // Private method to dedupe some vertices according to the policy.
+    pub(crate) fn dedup<T>(&self, vertices: Vec<T>) -> Vec<T>
+    where
+        T: Copy + Eq + Hash,
+    {
+        match self {
+            VertexDedupPolicy::KeepDuplicates => vertices,
+            VertexDedupPolicy::DedupConsecutive => vertices.into_iter().dedup().collect(),
+            VertexDedupPolicy::DedupAll => vertices.into_iter().unique().collect(),
+        }
+    }
+}
+
+impl DuplicatePolicy {
+    // Private method to check whether two lists of internal vertices clash
+    // according to the policy.
+    pub(crate) fn is_duplicate(&self, left: &[usize], right: &[usize]) -> bool {
+        match self {
+            DuplicatePolicy::AllowDuplicateVertices => false,
+            DuplicatePolicy::RejectDuplicateVertexLists => left == right,
+            DuplicatePolicy::RejectDuplicateVertexSets => left
+                .iter()
+                .sorted_unstable()
+                .dedup()
+                .eq(right.iter().sorted_unstable().dedup()),
+        }
+    }
+}
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Creates a new hypergraph with the specified duplicate policy.
+    pub fn with_duplicate_policy(duplicate_policy: DuplicatePolicy) -> Self {
+        Hypergraph {
+            duplicate_policy,
+            ..Hypergraph::new()
+        }
+    }
+
+    /// Gets the duplicate policy of the hypergraph.
+    pub fn get_duplicate_policy(&self) -> DuplicatePolicy {
+        self.duplicate_policy
+    }
+
+    /// Gets the policy deduplicating the vertices of the updated or joined
+    /// hyperedges. It is set via `HypergraphBuilder::vertex_dedup_policy`.
+    pub fn get_vertex_dedup_policy(&self) -> VertexDedupPolicy {
+        self.vertex_dedup_policy
+    }
+
+    // Private method to check that some internal vertices don't clash with
+    // the ones of the other hyperedges according to the duplicate policy.
+    // The hyperedge being updated, if any, is skipped.
+    pub(crate) fn check_duplicate_vertices(
+        &self,
+        vertices: &[usize],
+        skipped: Option<usize>,
+    ) -> Result<(), HypergraphError> {
+        if self.duplicate_policy == DuplicatePolicy::AllowDuplicateVertices {
+            return Ok(());
+        }
+
+        match self.hyperedges.iter().enumerate().find(
+            |(
+                internal_index,
+                HyperedgeKey {
+                    vertices: current, ..
+                },
+            )| {
+                Some(*internal_index) != skipped
+                    && self.duplicate_policy.is_duplicate(vertices, current)
+            },
+        ) {
+            Some((internal_index, _)) => Err(HypergraphError::HyperedgeVerticesAlreadyAssigned(
+                self.get_hyperedge(internal_index)?,
+            )),
+            None => Ok(()),
+        }
+    }
+
+    // Private method to check upfront that removing a vertex from some
+    // hyperedges doesn't make them clash according to the duplicate policy.
+    // The hyperedges left without vertices are skipped since they are
+    // removed altogether.
+    pub(crate) fn check_duplicate_vertices_on_removal(
+        &self,
+        vertex: usize,
+        hyperedges: &[usize],
+    ) -> Result<(), HypergraphError> {
+        if self.duplicate_policy == DuplicatePolicy::AllowDuplicateVertices {
+            return Ok(());
+        }
+
+        let mut updated: Vec<(usize, Vec<usize>)> = vec![];
+
+        for hyperedge in hyperedges {
+            let HyperedgeKey { vertices, .. } = self
+                .hyperedges
+                .get(*hyperedge)
+                .ok_or(HypergraphError::InternalHyperedgeIndexNotFound(*hyperedge))?;
+
+            let remaining = self.vertex_dedup_policy.dedup(
+                vertices
+                    .iter()
+                    .copied()
+                    .filter(|current| *current != vertex)
+                    .collect::<Vec<usize>>(),
+            );
+
+            if remaining.is_empty() {
+                continue;
+            }
+
+            // The current vertices of the impacted hyperedges include the
+            // removed one, so only the other hyperedges can clash here.
+            self.check_duplicate_vertices(&remaining, None)?;
+
+            // Check the impacted hyperedges against each other.
+            if let Some((other, _)) = updated.iter().find(|(_, other_vertices)| {
+                self.duplicate_policy
+                    .is_duplicate(&remaining, other_vertices)
+            }) {
+                return Err(HypergraphError::HyperedgeVerticesAlreadyAssigned(
+                    self.get_hyperedge(*other)?,
+                ));
+            }
+
+            updated.push((*hyperedge, remaining));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,147 @@
+use std::collections::HashMap;
+
+use crate::{
+    HyperedgeIndex,
+    HyperedgeTrait,
+    Hypergraph,
+    VertexKey,
+    VertexRef,
+    VertexTrait,
+    core::parallel::*,
+    errors::HypergraphError,
+};
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Runs a synchronous step of a dynamics over the vertices, e.g. a
+    /// higher-order contagion model, their weights holding their states.
+    /// The next weight of every vertex is computed in parallel from the
+    /// current weights only, i.e. double-buffered, by calling the update
+    /// function with the vertex, its incident hyperedges, in insertion
+    /// order, and the weights of the other vertices of each of them, in
+    /// order. The direction of the hyperedges is ignored.
+    /// The next weights are then assigned all at once, the duplicated
+    /// vertices staying duplicated. If a next weight clashes with the one of
+    /// another vertex, nothing is assigned.
+    /// Returns the number of vertices whose weight has changed, e.g. to
+    /// detect a steady state.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            err,
+            fields(
+                vertices_count = self.vertices.len(),
+                hyperedges_count = self.hyperedges.len(),
+            )
+        )
+    )]
+    pub fn step<F>(&mut self, update: F) -> Result<usize, HypergraphError>
+    where
+        F: Fn(VertexRef<'_, V, HE>, &[HyperedgeIndex], &[Vec<&V>]) -> V + Send + Sync,
+    {
+        let weights = (0..self.vertices.len())
+            .into_par_iter()
+            .map(|internal_index| self.get_next_vertex_weight(internal_index, &update))
+            .collect::<Result<Vec<V>, HypergraphError>>()?;
+
+        let keys = self
+            .vertices
+            .keys()
+            .zip(weights)
+            .map(|(key, weight)| VertexKey::new(weight, key.id))
+            .collect::<Vec<VertexKey<V>>>();
+
+        // Check the uniqueness of the next weights upfront, before any
+        // mutation.
+        let mut assigned = HashMap::with_capacity(keys.len());
+
+        for (internal_index, key) in keys.iter().enumerate() {
+            if let Some(other_index) = assigned.insert(key, internal_index) {
+                return Err(HypergraphError::VertexWeightAlreadyAssigned(
+                    self.get_vertex(other_index)?,
+                ));
+            }
+        }
+
+        let changed = self
+            .vertices
+            .keys()
+            .zip(keys.iter())
+            .enumerate()
+            .filter(|(_, (previous_key, key))| previous_key != key)
+            .map(|(internal_index, _)| internal_index)
+            .collect::<Vec<usize>>();
+
+        // Early exit if there's nothing to assign.
+        if changed.is_empty() {
+            return Ok(0);
+        }
+
+        // Rebuild the map of the vertices, in the same order so that the
+        // internal indexes are preserved.
+        self.vertices = self
+            .vertices
+            .drain(..)
+            .zip(keys)
+            .map(|((_, hyperedges), key)| (key, hyperedges))
+            .collect();
+
+        // Update the full-text search index.
+        for internal_index in changed.iter() {
+            self.index_vertex_weight(self.get_vertex(*internal_index)?, *internal_index);
+        }
+
+        Ok(changed.len())
+    }
+
+    // Private method to compute the next weight of a vertex.
+    fn get_next_vertex_weight<F>(
+        &self,
+        internal_index: usize,
+        update: &F,
+    ) -> Result<V, HypergraphError>
+    where
+        F: Fn(VertexRef<'_, V, HE>, &[HyperedgeIndex], &[Vec<&V>]) -> V,
+    {
+        let (_, index_set) = self
+            .vertices
+            .get_index(internal_index)
+            .ok_or(HypergraphError::InternalVertexIndexNotFound(internal_index))?;
+
+        let mut hyperedges = Vec::with_capacity(index_set.len());
+        let mut neighbors = Vec::with_capacity(index_set.len());
+
+        for hyperedge in index_set.iter() {
+            let hyperedge_key = self
+                .hyperedges
+                .get(*hyperedge)
+                .ok_or(HypergraphError::InternalHyperedgeIndexNotFound(*hyperedge))?;
+
+            hyperedges.push(self.get_hyperedge(*hyperedge)?);
+            neighbors.push(
+                hyperedge_key
+                    .vertices
+                    .iter()
+                    .filter(|vertex| **vertex != internal_index)
+                    .map(|vertex| {
+                        self.vertices
+                            .get_index(*vertex)
+                            .map(|(key, _)| &key.weight)
+                            .ok_or(HypergraphError::InternalVertexIndexNotFound(*vertex))
+                    })
+                    .collect::<Result<Vec<&V>, HypergraphError>>()?,
+            );
+        }
+
+        Ok(update(
+            self.vertex(self.get_vertex(internal_index)?)?,
+            &hyperedges,
+            &neighbors,
+        ))
+    }
+}
@@ -0,0 +1,205 @@
+use std::collections::HashMap;
+
+use rand::Rng;
+
+use crate::{
+    HyperedgeTrait,
+    Hypergraph,
+    Progress,
+    VertexIndex,
+    VertexTrait,
+    errors::HypergraphError,
+};
+
+/// Options of the skip-gram training used by `get_vertex_embeddings`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EmbeddingsOptions {
+    /// Number of dimensions of the embeddings.
+    pub dimensions: usize,
+    /// Number of passes over the walks.
+    pub epochs: usize,
+    /// Learning rate of the gradient descent.
+    pub learning_rate: f32,
+    /// Number of negative samples drawn for each positive pair.
+    pub negative_samples: usize,
+    /// Maximum number of vertices of each walk.
+    pub walk_length: usize,
+    /// Number of walks starting from each vertex.
+    pub walks_per_vertex: usize,
+    /// Maximum distance between two vertices of a walk forming a pair.
+    pub window: usize,
+}
+
+impl Default for EmbeddingsOptions {
+    fn default() -> Self {
+        Self {
+            dimensions: 32,
+            epochs: 5,
+            learning_rate: 0.025,
+            negative_samples: 5,
+            walk_length: 20,
+            walks_per_vertex: 10,
+            window: 3,
+        }
+    }
+}
+
+// Private helper to compute the logistic function.
+fn sigmoid(value: f32) -> f32 {
+    1. / (1. + (-value).exp())
+}
+
+// Private helper to train a single pair of vertices and to accumulate the
+// gradient of the input vector.
+fn train_pair(
+    input: &[f32],
+    output: &mut [f32],
+    gradient: &mut [f32],
+    label: f32,
+    learning_rate: f32,
+) {
+    let dot = input
+        .iter()
+        .zip(output.iter())
+        .map(|(left, right)| left * right)
+        .sum::<f32>();
+    let step = learning_rate * (label - sigmoid(dot));
+
+    for ((input, output), gradient) in input.iter().zip(output.iter_mut()).zip(gradient.iter_mut())
+    {
+        *gradient += step * *output;
+        *output += step * input;
+    }
+}
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Generates vertex embeddings in a `DeepWalk` fashion.
+    /// Random walks are performed via `random_walk` from every vertex and a
+    /// skip-gram model with negative sampling is trained over them, such
+    /// that vertices sharing hyperedges end up with similar embeddings.
+    /// <https://arxiv.org/abs/1403.6652>
+    pub fn get_vertex_embeddings<R>(
+        &self,
+        options: EmbeddingsOptions,
+        rng: &mut R,
+    ) -> Result<HashMap<VertexIndex, Vec<f32>>, HypergraphError>
+    where
+        R: Rng + ?Sized,
+    {
+        self.get_vertex_embeddings_with_progress(options, rng, |_| {})
+    }
+
+    /// Generates vertex embeddings like `get_vertex_embeddings` while
+    /// reporting the progress of the `walks` stage - one step per walk - and
+    /// of the `training` stage - one step per epoch and walk.
+    pub fn get_vertex_embeddings_with_progress<R, P>(
+        &self,
+        options: EmbeddingsOptions,
+        rng: &mut R,
+        mut progress: P,
+    ) -> Result<HashMap<VertexIndex, Vec<f32>>, HypergraphError>
+    where
+        R: Rng + ?Sized,
+        P: FnMut(Progress),
+    {
+        let EmbeddingsOptions {
+            dimensions,
+            epochs,
+            learning_rate,
+            negative_samples,
+            walk_length,
+            walks_per_vertex,
+            window,
+        } = options;
+        let length = self.vertices.len();
+
+        // Generate the corpus of walks.
+        let total_walks = length * walks_per_vertex;
+        let mut walks = Vec::with_capacity(total_walks);
+
+        for _ in 0..walks_per_vertex {
+            for vertex in 0..length {
+                walks.push(self.get_internal_random_walk(vertex, walk_length, rng)?);
+
+                progress(Progress::new("walks", walks.len(), total_walks));
+            }
+        }
+
+        // Input vectors are randomly initialized, output ones are zeroed.
+        let mut inputs = (0..length * dimensions)
+            .map(|_| (rng.gen::<f32>() - 0.5) / dimensions as f32)
+            .collect::<Vec<f32>>();
+        let mut outputs = vec![0.; length * dimensions];
+        let mut gradient = vec![0.; dimensions];
+
+        let total_steps = epochs * total_walks;
+        let mut completed_steps = 0;
+
+        for _ in 0..epochs {
+            for walk in &walks {
+                for (position, center) in walk.iter().enumerate() {
+                    let start = position.saturating_sub(window);
+                    let end = (position + window + 1).min(walk.len());
+
+                    for (context_position, context) in walk[start..end].iter().enumerate() {
+                        if start + context_position == position {
+                            continue;
+                        }
+
+                        gradient.iter_mut().for_each(|value| *value = 0.);
+
+                        let input = &inputs[center * dimensions..(center + 1) * dimensions];
+
+                        train_pair(
+                            input,
+                            &mut outputs[context * dimensions..(context + 1) * dimensions],
+                            &mut gradient,
+                            1.,
+                            learning_rate,
+                        );
+
+                        for _ in 0..negative_samples {
+                            let negative = rng.gen_range(0..length);
+
+                            if negative != *context {
+                                train_pair(
+                                    input,
+                                    &mut outputs
+                                        [negative * dimensions..(negative + 1) * dimensions],
+                                    &mut gradient,
+                                    0.,
+                                    learning_rate,
+                                );
+                            }
+                        }
+
+                        inputs[center * dimensions..(center + 1) * dimensions]
+                            .iter_mut()
+                            .zip(gradient.iter())
+                            .for_each(|(value, gradient)| *value += gradient);
+                    }
+                }
+
+                completed_steps += 1;
+
+                progress(Progress::new("training", completed_steps, total_steps));
+            }
+        }
+
+        (0..length)
+            .map(|internal_index| {
+                self.get_vertex(internal_index).map(|vertex_index| {
+                    (
+                        vertex_index,
+                        inputs[internal_index * dimensions..(internal_index + 1) * dimensions]
+                            .to_vec(),
+                    )
+                })
+            })
+            .collect()
+    }
+}
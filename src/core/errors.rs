@@ -2,16 +2,17 @@ use thiserror::Error;
 
 use crate::{
     HyperedgeIndex,
+    LayerIndex,
+    ValidationIssue,
     VertexIndex,
 };
 
 /// Enumeration of all the possible errors.
+/// Errors reference vertices and hyperedges by their indexes - or by the
+/// display representation of their weights when no index is available - so
+/// that the type isn't generic over the weights.
 #[derive(Clone, Debug, Eq, Error, PartialEq)]
-pub enum HypergraphError<V, HE>
-where
-    V: Copy + Eq,
-    HE: Copy + Eq,
-{
+pub enum HypergraphError {
     /// Error when a `HyperedgeIndex` was not found.
     #[error("HyperedgeIndex {0} was not found")]
     HyperedgeIndexNotFound(HyperedgeIndex),
@@ -22,19 +23,19 @@ where
 
     /// Error when a hyperedge weight was not found.
     #[error("Hyperedge weight {0} was not found")]
-    HyperedgeWeightNotFound(HE),
+    HyperedgeWeightNotFound(String),
 
     /// Error when a hyperedge is updated with the same weight.
-    #[error("HyperedgeIndex {index:?} weight {weight:?} is unchanged (no-op)")]
-    HyperedgeWeightUnchanged { index: HyperedgeIndex, weight: HE },
+    #[error("HyperedgeIndex {0} weight is unchanged (no-op)")]
+    HyperedgeWeightUnchanged(HyperedgeIndex),
 
     /// Error when a hyperedge is updated with the same vertices.
     #[error("HyperedgeIndex {0} vertices are unchanged (no-op)")]
     HyperedgeVerticesUnchanged(HyperedgeIndex),
 
     /// Error when a hyperedge is updated with no vertices.
-    #[error("HyperedgeIndex {0} vertices are missing")]
-    HyperedgeCreationNoVertices(HE),
+    #[error("Hyperedge weight {0} vertices are missing")]
+    HyperedgeCreationNoVertices(String),
 
     /// Error when a hyperedge is updated with no vertices.
     #[error("HyperedgeIndex {0} vertices are missing")]
@@ -57,9 +58,49 @@ where
         vertices: Vec<VertexIndex>,
     },
 
+    /// Error when a contraction of vertices across the hypergraph is invalid,
+    /// i.e. when the target isn't one of the contracted vertices.
+    #[error("Contraction of vertices {vertices:?} into vertex {target:?} is invalid")]
+    VerticesInvalidContraction {
+        target: VertexIndex,
+        vertices: Vec<VertexIndex>,
+    },
+
     /// Error when a hyperedge is updated with the weight of another one.
-    #[error("Hyperedge weight {0} was already assigned")]
-    HyperedgeWeightAlreadyAssigned(HE),
+    /// Holds the index of the hyperedge the weight is assigned to.
+    #[error("Hyperedge weight was already assigned to HyperedgeIndex {0}")]
+    HyperedgeWeightAlreadyAssigned(HyperedgeIndex),
+
+    /// Error when a hyperedge is created or updated with the vertices of
+    /// another one while the duplicate policy rejects it.
+    /// Holds the index of the clashing hyperedge.
+    #[error("Hyperedge vertices were already assigned to HyperedgeIndex {0}")]
+    HyperedgeVerticesAlreadyAssigned(HyperedgeIndex),
+
+    /// Error when a hyperedge would exceed the maximum cardinality of the
+    /// constraints.
+    #[error("Hyperedge cardinality {cardinality} exceeds the maximum of {max}")]
+    HyperedgeCardinalityExceeded { cardinality: usize, max: usize },
+
+    /// Error when a hyperedge would include a self-loop while the
+    /// constraints forbid it.
+    /// Holds the index of the vertex directed to itself.
+    #[error("Self-loop on VertexIndex {0} is forbidden")]
+    HyperedgeSelfLoopForbidden(VertexIndex),
+
+    /// Error when adding a hyperedge would exceed the maximum number of
+    /// hyperedges of the constraints.
+    #[error("Hyperedges count exceeds the maximum of {max}")]
+    HyperedgesCountExceeded { max: usize },
+
+    /// Error when a hyperedge is updated with a stale expected version,
+    /// e.g. by a concurrent writer.
+    #[error("HyperedgeIndex {index} version is {actual} instead of {expected}")]
+    HyperedgeVersionConflict {
+        index: HyperedgeIndex,
+        expected: usize,
+        actual: usize,
+    },
 
     /// Error when trying to get the intersections of less than two hyperedges.
     #[error("At least two hyperedges must be provided to find their intersections")]
@@ -79,13 +120,209 @@ where
 
     /// Error when a vertex weight was not found.
     #[error("Vertex weight {0} was not found")]
-    VertexWeightNotFound(V),
+    VertexWeightNotFound(String),
 
     /// Error when a vertex weight is updated with the same value.
-    #[error("VertexIndex {index:?} weight {weight:?} unchanged (no-op)")]
-    VertexWeightUnchanged { index: VertexIndex, weight: V },
+    #[error("VertexIndex {0} weight is unchanged (no-op)")]
+    VertexWeightUnchanged(VertexIndex),
 
     /// Error when a vertex weight is updated with the weight of another one.
-    #[error("Vertex weight {0} was already assigned")]
-    VertexWeightAlreadyAssigned(V),
+    /// Holds the index of the vertex the weight is assigned to.
+    #[error("Vertex weight was already assigned to VertexIndex {0}")]
+    VertexWeightAlreadyAssigned(VertexIndex),
+
+    /// Error when a vertex would exceed the maximum degree of the
+    /// constraints, i.e. belong to too many hyperedges.
+    #[error("VertexIndex {index} degree exceeds the maximum of {max}")]
+    VertexDegreeExceeded { index: VertexIndex, max: usize },
+
+    /// Error when adding a vertex would exceed the maximum number of
+    /// vertices of the constraints.
+    #[error("Vertices count exceeds the maximum of {max}")]
+    VerticesCountExceeded { max: usize },
+
+    /// Error when a vertex is updated with a stale expected version, e.g. by
+    /// a concurrent writer.
+    #[error("VertexIndex {index} version is {actual} instead of {expected}")]
+    VertexVersionConflict {
+        index: VertexIndex,
+        expected: usize,
+        actual: usize,
+    },
+
+    /// Error when a vertex has no embedding.
+    #[error("VertexIndex {0} embedding was not found")]
+    VertexEmbeddingNotFound(VertexIndex),
+
+    /// Error when the embedding of a vertex doesn't have the same dimensions
+    /// as the other ones.
+    #[error("VertexIndex {index} embedding has {actual} dimensions instead of {expected}")]
+    VertexEmbeddingInvalidDimensions {
+        index: VertexIndex,
+        expected: usize,
+        actual: usize,
+    },
+
+    /// Error when the sampling weight of a vertex is negative or not finite.
+    #[error("VertexIndex {0} sampling weight is invalid")]
+    VertexSamplingInvalidWeight(VertexIndex),
+
+    /// Error when the sampling weight of a hyperedge is negative or not
+    /// finite.
+    #[error("HyperedgeIndex {0} sampling weight is invalid")]
+    HyperedgeSamplingInvalidWeight(HyperedgeIndex),
+
+    /// Error when a rate or a threshold of a contagion model is not a
+    /// probability. Holds the name of the parameter.
+    #[error("Contagion parameter {0} is invalid")]
+    ContagionInvalidParameter(String),
+
+    /// Error when an Arrow record batch doesn't match the expected layout.
+    #[error("Arrow record batch is invalid: {0}")]
+    ArrowInvalidRecordBatch(String),
+
+    /// Error when a weight from an Arrow record batch can't be parsed.
+    #[error("Arrow weight {0} can't be parsed")]
+    ArrowInvalidWeight(String),
+
+    /// Error when the owned parts of a hypergraph are inconsistent.
+    #[error("Parts are invalid: {0}")]
+    PartsInvalid(String),
+
+    /// Error when a dot statement is invalid.
+    #[error("Dot statement is invalid: {0}")]
+    DotInvalidStatement(String),
+
+    /// Error when a weight from a dot label can't be parsed.
+    #[error("Dot weight {0} can't be parsed")]
+    DotInvalidWeight(String),
+
+    /// Error when a snapshot can't be read or written.
+    #[error("Snapshot I/O failed: {0}")]
+    SnapshotIo(String),
+
+    /// Error when a snapshot doesn't start with the expected magic bytes.
+    #[error("Snapshot header is invalid")]
+    SnapshotInvalidHeader,
+
+    /// Error when a snapshot was written with an unsupported version of the
+    /// format, e.g. by a newer release.
+    #[error("Snapshot format version {0} is not supported")]
+    SnapshotUnsupportedVersion(u32),
+
+    /// Error when the content of a snapshot is invalid.
+    #[error("Snapshot is corrupted: {0}")]
+    SnapshotCorrupted(String),
+
+    /// Error when a weight from a snapshot can't be parsed.
+    #[error("Snapshot weight {0} can't be parsed")]
+    SnapshotInvalidWeight(String),
+
+    /// Error when a store can't be read or written.
+    #[error("Store I/O failed: {0}")]
+    StoreIo(String),
+
+    /// Error when a namespace of a store has an invalid name.
+    #[error("Store namespace {0} is invalid")]
+    StoreInvalidNamespace(String),
+
+    /// Error when a namespace of a store was not found.
+    #[error("Store namespace {0} was not found")]
+    StoreNamespaceNotFound(String),
+
+    /// Error when flushing a namespace whose snapshot has been written by
+    /// another writer since it was loaded.
+    #[error("Store namespace {0} was modified by another writer")]
+    StoreNamespaceConflict(String),
+
+    /// Error when the server can't bind or serve its address.
+    #[error("Server I/O failed: {0}")]
+    ServerIo(String),
+
+    /// Error when a weight sent to the server can't be parsed.
+    #[error("Server weight {0} can't be parsed")]
+    ServerInvalidWeight(String),
+
+    /// Error when a journal can't be read or written.
+    #[error("Journal I/O failed: {0}")]
+    JournalIo(String),
+
+    /// Error when a journal doesn't start with the expected magic bytes.
+    #[error("Journal header is invalid")]
+    JournalInvalidHeader,
+
+    /// Error when a journal was written with an unsupported version of the
+    /// format, e.g. by a newer release.
+    #[error("Journal format version {0} is not supported")]
+    JournalUnsupportedVersion(u32),
+
+    /// Error when a record of a journal is invalid.
+    #[error("Journal is corrupted: {0}")]
+    JournalCorrupted(String),
+
+    /// Error when a weight from a journal can't be parsed.
+    #[error("Journal weight {0} can't be parsed")]
+    JournalInvalidWeight(String),
+
+    /// Error when querying a journal beyond its last record.
+    #[error("Journal sequence number {0} was not found")]
+    JournalSequenceNumberNotFound(usize),
+
+    /// Error when mutating a journaled hypergraph after a record failed to
+    /// be appended to its journal. The journal must be reopened.
+    #[error("Journal failed to append a record and must be reopened")]
+    JournalFailed,
+
+    /// Error when a frozen hypergraph can't be read or written.
+    #[error("Frozen hypergraph I/O failed: {0}")]
+    FrozenIo(String),
+
+    /// Error when a frozen hypergraph doesn't start with the expected magic
+    /// bytes.
+    #[error("Frozen hypergraph header is invalid")]
+    FrozenInvalidHeader,
+
+    /// Error when a frozen hypergraph was written with an unsupported version
+    /// of the format, e.g. by a newer release.
+    #[error("Frozen hypergraph format version {0} is not supported")]
+    FrozenUnsupportedVersion(u32),
+
+    /// Error when the layout of a frozen hypergraph is invalid.
+    #[error("Frozen hypergraph is corrupted: {0}")]
+    FrozenCorrupted(String),
+
+    /// Error when a weight from a frozen hypergraph can't be parsed.
+    #[error("Frozen hypergraph weight {0} can't be parsed")]
+    FrozenInvalidWeight(String),
+
+    /// Error when a query is aborted via its cancellation flag.
+    #[error("Query was cancelled")]
+    Cancelled,
+
+    /// Error when querying the distance oracle without building it first.
+    #[error("Distance oracle is not built")]
+    DistanceOracleNotBuilt,
+
+    /// Error when the validation of a hypergraph found an error.
+    /// Holds the first one, see `Hypergraph::validate` for the full report.
+    #[error("Validation failed: {0}")]
+    ValidationFailed(Box<ValidationIssue>),
+
+    /// Error when searching without enabling the search index first.
+    #[error("Search index is not enabled")]
+    SearchIndexNotEnabled,
+
+    /// Error when querying the approximate membership filter of the
+    /// hyperedges without enabling it first.
+    #[error("Hyperedge filter is not enabled")]
+    HyperedgeFilterNotEnabled,
+
+    /// Error when a `LayerIndex` was not found.
+    #[error("LayerIndex {0} was not found")]
+    LayerIndexNotFound(LayerIndex),
+
+    /// Error when a layer is added with the name of another one.
+    /// Holds the index of the layer the name is assigned to.
+    #[error("Layer name {0} is already assigned")]
+    LayerNameAlreadyAssigned(LayerIndex),
 }
@@ -0,0 +1,1015 @@
+use std::{
+    fs::File,
+    io::{
+        BufWriter,
+        Write,
+    },
+    marker::PhantomData,
+    ops::Deref,
+    path::Path,
+    str::FromStr,
+};
+
+use crate::{
+    HyperedgeIndex,
+    HyperedgeKey,
+    HyperedgeTrait,
+    Hypergraph,
+    VertexIndex,
+    VertexKey,
+    VertexTrait,
+    core::{
+        snapshot::{
+            SnapshotReader,
+            write_u64,
+        },
+        types::AIndexSet,
+    },
+    errors::HypergraphError,
+};
+
+/// Magic bytes at the start of every frozen hypergraph.
+const MAGIC: &[u8; 8] = b"HGFROZEN";
+
+/// Version of the frozen format written by this release. It must be bumped
+/// on any change of the layout below.
+pub const FROZEN_VERSION: u32 = 1;
+
+// Private helper to convert an I/O error.
+fn io_error(error: std::io::Error) -> HypergraphError {
+    HypergraphError::FrozenIo(error.to_string())
+}
+
+// Private helper to convert the errors of the shared reader.
+fn reader_error(error: HypergraphError) -> HypergraphError {
+    match error {
+        HypergraphError::SnapshotCorrupted(reason) => HypergraphError::FrozenCorrupted(reason),
+        error => error,
+    }
+}
+
+// Private helper to return a corruption error.
+fn corrupted(reason: &str) -> HypergraphError {
+    HypergraphError::FrozenCorrupted(reason.to_owned())
+}
+
+// Private storage of the bytes, either owned or memory-mapped.
+#[derive(Debug)]
+enum Storage {
+    Owned(Vec<u8>),
+    #[cfg(feature = "mmap")]
+    Mapped(memmap2::Mmap),
+}
+
+impl Deref for Storage {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        match self {
+            Storage::Owned(bytes) => bytes,
+            #[cfg(feature = "mmap")]
+            Storage::Mapped(mmap) => mmap,
+        }
+    }
+}
+
+// Private location of an array of little-endian u64 values, or of raw bytes,
+// within the storage.
+#[derive(Clone, Copy, Debug)]
+struct Section {
+    start: usize,
+    len: usize,
+}
+
+// Private layout of the arrays of either the vertices or the hyperedges,
+// following the compressed sparse row format.
+#[derive(Clone, Copy, Debug)]
+struct Layout {
+    // Stable indexes, in internal order.
+    indexes: Section,
+    // Pairs of stable and internal indexes, sorted by stable index.
+    lookup: Section,
+    // Offsets of the incidences of each element.
+    offsets: Section,
+    // Internal indexes of the incident elements of the other kind.
+    incidences: Section,
+    // Offsets of the weight of each element.
+    weights_offsets: Section,
+    // Weights as strings.
+    weights: Section,
+}
+
+// Private owned arrays of either the vertices or the hyperedges, as written
+// to the storage.
+#[derive(Debug)]
+struct Arrays {
+    indexes: Vec<usize>,
+    offsets: Vec<usize>,
+    incidences: Vec<usize>,
+    weights_offsets: Vec<usize>,
+    weights: Vec<u8>,
+}
+
+impl Default for Arrays {
+    fn default() -> Self {
+        Self {
+            indexes: vec![],
+            offsets: vec![0],
+            incidences: vec![],
+            weights_offsets: vec![0],
+            weights: vec![],
+        }
+    }
+}
+
+// Private helper to write the arrays of the vertices and of the hyperedges.
+fn write_arrays<W>(
+    mut writer: W,
+    vertices: &Arrays,
+    hyperedges: &Arrays,
+) -> Result<(), HypergraphError>
+where
+    W: Write,
+{
+    let mut buffer = Vec::new();
+
+    buffer.extend_from_slice(MAGIC);
+    buffer.extend_from_slice(&FROZEN_VERSION.to_le_bytes());
+    // Reserved, keeps the arrays aligned on 8 bytes.
+    buffer.extend_from_slice(&[0; 4]);
+
+    for length in [
+        vertices.indexes.len(),
+        vertices.incidences.len(),
+        vertices.weights.len(),
+        hyperedges.indexes.len(),
+        hyperedges.incidences.len(),
+        hyperedges.weights.len(),
+    ] {
+        write_u64(&mut buffer, length);
+    }
+
+    for arrays in [vertices, hyperedges] {
+        for index in arrays.indexes.iter() {
+            write_u64(&mut buffer, *index);
+        }
+
+        let mut lookup = arrays
+            .indexes
+            .iter()
+            .copied()
+            .enumerate()
+            .collect::<Vec<_>>();
+
+        lookup.sort_unstable_by_key(|(_, index)| *index);
+
+        for (internal_index, index) in lookup {
+            write_u64(&mut buffer, index);
+            write_u64(&mut buffer, internal_index);
+        }
+
+        for value in arrays
+            .offsets
+            .iter()
+            .chain(arrays.incidences.iter())
+            .chain(arrays.weights_offsets.iter())
+        {
+            write_u64(&mut buffer, *value);
+        }
+    }
+
+    buffer.extend_from_slice(&vertices.weights);
+    buffer.extend_from_slice(&hyperedges.weights);
+
+    writer.write_all(&buffer).map_err(io_error)?;
+    writer.flush().map_err(io_error)
+}
+
+/// Compact and immutable representation of a hypergraph, based on flat
+/// arrays following the compressed sparse row format.
+/// It can be written to a file via `Hypergraph::save_frozen` and loaded back
+/// instantly via `FrozenHypergraph::load`, without rebuilding any index.
+/// Only queries are supported. The weights are stored as strings via their
+/// `Display` implementation and parsed back on access via their `FromStr`
+/// implementation.
+#[derive(Debug)]
+pub struct FrozenHypergraph<V, HE> {
+    storage: Storage,
+    vertices: Layout,
+    hyperedges: Layout,
+    phantom: PhantomData<fn() -> (V, HE)>,
+}
+
+/// Report of the integrity check of a frozen hypergraph file, as returned
+/// by `FrozenHypergraph::verify_store`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct FrozenReport {
+    /// Pairs of a vertex and of a hyperedge listed by the vertex but which
+    /// doesn't contain it.
+    pub dangling_vertices_references: Vec<(VertexIndex, HyperedgeIndex)>,
+
+    /// Pairs of a hyperedge and of a vertex contained by the hyperedge but
+    /// which doesn't list it.
+    pub dangling_hyperedges_references: Vec<(HyperedgeIndex, VertexIndex)>,
+
+    /// Vertices whose weight can't be parsed.
+    pub invalid_vertices_weights: Vec<VertexIndex>,
+
+    /// Hyperedges whose weight can't be parsed.
+    pub invalid_hyperedges_weights: Vec<HyperedgeIndex>,
+
+    /// Whether the file has been repaired.
+    pub repaired: bool,
+}
+
+impl FrozenReport {
+    /// Checks whether no damage has been found.
+    pub fn is_consistent(&self) -> bool {
+        self.dangling_vertices_references.is_empty()
+            && self.dangling_hyperedges_references.is_empty()
+            && self.invalid_vertices_weights.is_empty()
+            && self.invalid_hyperedges_weights.is_empty()
+    }
+}
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Writes the frozen representation of the hypergraph to a writer.
+    /// The format starts with magic bytes and a version. It only holds the
+    /// vertices and the hyperedges along with their weights.
+    pub fn write_frozen<W>(&self, writer: W) -> Result<(), HypergraphError>
+    where
+        W: Write,
+    {
+        let mut vertices = Arrays::default();
+
+        for (internal_index, (key, hyperedges)) in self.vertices.iter().enumerate() {
+            vertices.indexes.push(self.get_vertex(internal_index)?.0);
+            vertices.incidences.extend(hyperedges.iter().copied());
+            vertices.offsets.push(vertices.incidences.len());
+            vertices.weights.extend(key.weight.to_string().bytes());
+            vertices.weights_offsets.push(vertices.weights.len());
+        }
+
+        let mut hyperedges = Arrays::default();
+
+        for (internal_index, HyperedgeKey { vertices, weight }) in
+            self.hyperedges.iter().enumerate()
+        {
+            hyperedges
+                .indexes
+                .push(self.get_hyperedge(internal_index)?.0);
+            hyperedges.incidences.extend(vertices.iter().copied());
+            hyperedges.offsets.push(hyperedges.incidences.len());
+            hyperedges.weights.extend(weight.to_string().bytes());
+            hyperedges.weights_offsets.push(hyperedges.weights.len());
+        }
+
+        write_arrays(writer, &vertices, &hyperedges)
+    }
+
+    /// Saves the frozen representation of the hypergraph to a file, to be
+    /// loaded via `FrozenHypergraph::load`.
+    /// See `write_frozen` for more details.
+    pub fn save_frozen<P>(&self, path: P) -> Result<(), HypergraphError>
+    where
+        P: AsRef<Path>,
+    {
+        self.write_frozen(BufWriter::new(File::create(path).map_err(io_error)?))
+    }
+
+    /// Creates the frozen representation of the hypergraph in memory.
+    pub fn freeze(&self) -> Result<FrozenHypergraph<V, HE>, HypergraphError>
+    where
+        V: FromStr,
+        HE: FromStr,
+    {
+        let mut bytes = Vec::new();
+
+        self.write_frozen(&mut bytes)?;
+
+        FrozenHypergraph::from_bytes(bytes)
+    }
+
+    /// Loads a hypergraph from a file written by `save_frozen` in a single
+    /// pass, e.g. to edit a frozen hypergraph.
+    /// See `FrozenHypergraph::thaw` for more details.
+    pub fn load_frozen<P>(path: P) -> Result<Self, HypergraphError>
+    where
+        P: AsRef<Path>,
+        V: FromStr,
+        HE: FromStr,
+    {
+        FrozenHypergraph::load(path)?.thaw()
+    }
+}
+
+impl<V, HE> FrozenHypergraph<V, HE>
+where
+    V: VertexTrait + FromStr,
+    HE: HyperedgeTrait + FromStr,
+{
+    /// Creates a frozen hypergraph from bytes written by
+    /// `Hypergraph::write_frozen`. The layout is validated upfront.
+    pub fn from_bytes(bytes: Vec<u8>) -> Result<Self, HypergraphError> {
+        Self::from_storage(Storage::Owned(bytes))
+    }
+
+    /// Loads a frozen hypergraph from a file written by
+    /// `Hypergraph::save_frozen`. The file is read into memory, see
+    /// `load_mapped` to memory-map it instead.
+    pub fn load<P>(path: P) -> Result<Self, HypergraphError>
+    where
+        P: AsRef<Path>,
+    {
+        Self::from_bytes(std::fs::read(path).map_err(io_error)?)
+    }
+
+    /// Loads a frozen hypergraph from a file written by
+    /// `Hypergraph::save_frozen` by memory-mapping it read-only, the
+    /// operating system paging it on demand. The layout is validated
+    /// upfront.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified, truncated or replaced in place - by
+    /// this process or by another one - while the frozen hypergraph is
+    /// alive. Otherwise the mapped bytes can change after their validation,
+    /// which is undefined behavior.
+    #[cfg(feature = "mmap")]
+    #[allow(unsafe_code)]
+    pub unsafe fn load_mapped<P>(path: P) -> Result<Self, HypergraphError>
+    where
+        P: AsRef<Path>,
+    {
+        let file = File::open(path).map_err(io_error)?;
+
+        // SAFETY: the caller guarantees that the file isn't modified while
+        // it is mapped.
+        let mmap = unsafe { memmap2::Mmap::map(&file) }.map_err(io_error)?;
+
+        Self::from_storage(Storage::Mapped(mmap))
+    }
+
+    /// Checks the integrity of a frozen hypergraph file, e.g. after a crash.
+    /// The layout is validated first, a damaged one being returned as an
+    /// error. The incidences of the vertices are then cross-checked against
+    /// the ones of the hyperedges and the weights are parsed.
+    /// With `repair`, the incidences of the vertices are rebuilt from the
+    /// hyperedges, which are authoritative as they hold the order of their
+    /// vertices, and the file is replaced atomically. The invalid weights
+    /// can't be repaired.
+    pub fn verify_store<P>(path: P, repair: bool) -> Result<FrozenReport, HypergraphError>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        let frozen = Self::load(path)?;
+        let mut report = FrozenReport::default();
+
+        let mut vertices_incidences = AIndexSet::default();
+        let mut hyperedges_incidences = AIndexSet::default();
+
+        for vertex in 0..frozen.vertices.indexes.len {
+            vertices_incidences.extend(
+                frozen
+                    .read_range(frozen.vertices.incidences, frozen.vertices.offsets, vertex)
+                    .map(|hyperedge| (vertex, hyperedge)),
+            );
+
+            if frozen.parse_weight::<V>(&frozen.vertices, vertex).is_err() {
+                report
+                    .invalid_vertices_weights
+                    .push(VertexIndex(frozen.read(frozen.vertices.indexes, vertex)));
+            }
+        }
+
+        for hyperedge in 0..frozen.hyperedges.indexes.len {
+            hyperedges_incidences.extend(
+                frozen
+                    .read_range(
+                        frozen.hyperedges.incidences,
+                        frozen.hyperedges.offsets,
+                        hyperedge,
+                    )
+                    .map(|vertex| (vertex, hyperedge)),
+            );
+
+            if frozen
+                .parse_weight::<HE>(&frozen.hyperedges, hyperedge)
+                .is_err()
+            {
+                report.invalid_hyperedges_weights.push(HyperedgeIndex(
+                    frozen.read(frozen.hyperedges.indexes, hyperedge),
+                ));
+            }
+        }
+
+        let stable = |vertex: usize, hyperedge: usize| {
+            (
+                VertexIndex(frozen.read(frozen.vertices.indexes, vertex)),
+                HyperedgeIndex(frozen.read(frozen.hyperedges.indexes, hyperedge)),
+            )
+        };
+
+        report.dangling_vertices_references = vertices_incidences
+            .difference(&hyperedges_incidences)
+            .map(|(vertex, hyperedge)| stable(*vertex, *hyperedge))
+            .collect();
+        report.dangling_hyperedges_references = hyperedges_incidences
+            .difference(&vertices_incidences)
+            .map(|(vertex, hyperedge)| {
+                let (vertex_index, hyperedge_index) = stable(*vertex, *hyperedge);
+
+                (hyperedge_index, vertex_index)
+            })
+            .collect();
+        report.dangling_vertices_references.sort_unstable();
+        report.dangling_hyperedges_references.sort_unstable();
+
+        if repair
+            && (!report.dangling_vertices_references.is_empty()
+                || !report.dangling_hyperedges_references.is_empty())
+        {
+            let hyperedges = frozen.get_arrays(&frozen.hyperedges);
+            let mut vertices = frozen.get_arrays(&frozen.vertices);
+            let mut incidences = vec![vec![]; vertices.indexes.len()];
+
+            for (hyperedge, range) in hyperedges.offsets.windows(2).enumerate() {
+                for vertex in &hyperedges.incidences[range[0]..range[1]] {
+                    if incidences[*vertex].last() != Some(&hyperedge) {
+                        incidences[*vertex].push(hyperedge);
+                    }
+                }
+            }
+
+            vertices.offsets = vec![0];
+            vertices.incidences = vec![];
+
+            for hyperedges in incidences {
+                vertices.incidences.extend(hyperedges);
+                vertices.offsets.push(vertices.incidences.len());
+            }
+
+            // Release the storage before replacing the file.
+            drop(frozen);
+
+            let mut temporary = path.as_os_str().to_owned();
+
+            temporary.push(".repair");
+
+            write_arrays(
+                BufWriter::new(File::create(&temporary).map_err(io_error)?),
+                &vertices,
+                &hyperedges,
+            )?;
+            std::fs::rename(&temporary, path).map_err(io_error)?;
+
+            report.repaired = true;
+        }
+
+        Ok(report)
+    }
+
+    // Private method to copy the arrays of a layout.
+    fn get_arrays(&self, layout: &Layout) -> Arrays {
+        let read_all =
+            |section: Section| (0..section.len).map(move |position| self.read(section, position));
+
+        Arrays {
+            indexes: read_all(layout.indexes).collect(),
+            offsets: read_all(layout.offsets).collect(),
+            incidences: read_all(layout.incidences).collect(),
+            weights_offsets: read_all(layout.weights_offsets).collect(),
+            weights: self.storage[layout.weights.start..layout.weights.start + layout.weights.len]
+                .to_vec(),
+        }
+    }
+
+    // Private method to validate the layout of the storage.
+    fn from_storage(storage: Storage) -> Result<Self, HypergraphError> {
+        let mut reader = SnapshotReader {
+            bytes: &storage,
+            position: 0,
+        };
+
+        if reader.read_bytes(MAGIC.len()).ok() != Some(&MAGIC[..]) {
+            return Err(HypergraphError::FrozenInvalidHeader);
+        }
+
+        let version = reader.read_u32().map_err(reader_error)?;
+
+        if version != FROZEN_VERSION {
+            return Err(HypergraphError::FrozenUnsupportedVersion(version));
+        }
+
+        reader.read_u32().map_err(reader_error)?;
+
+        let mut lengths = [0; 6];
+
+        for length in lengths.iter_mut() {
+            *length = reader.read_usize().map_err(reader_error)?;
+        }
+
+        let [
+            vertices_count,
+            vertices_incidences,
+            vertices_weights,
+            hyperedges_count,
+            hyperedges_incidences,
+            hyperedges_weights,
+        ] = lengths;
+
+        // Reserve a section of u64 values or of raw bytes.
+        let mut section = |len: usize, size: usize| {
+            let start = reader.position;
+
+            len.checked_mul(size)
+                .ok_or_else(|| corrupted("invalid length"))
+                .and_then(|bytes| reader.read_bytes(bytes).map_err(reader_error))
+                .map(|_| Section { start, len })
+        };
+
+        let mut layout = |count: usize, incidences: usize| -> Result<Layout, HypergraphError> {
+            Ok(Layout {
+                indexes: section(count, 8)?,
+                lookup: section(
+                    count
+                        .checked_mul(2)
+                        .ok_or_else(|| corrupted("invalid length"))?,
+                    8,
+                )?,
+                offsets: section(count + 1, 8)?,
+                incidences: section(incidences, 8)?,
+                weights_offsets: section(count + 1, 8)?,
+                weights: Section { start: 0, len: 0 },
+            })
+        };
+
+        let mut vertices = layout(vertices_count, vertices_incidences)?;
+        let mut hyperedges = layout(hyperedges_count, hyperedges_incidences)?;
+
+        vertices.weights = section(vertices_weights, 1)?;
+        hyperedges.weights = section(hyperedges_weights, 1)?;
+
+        if reader.position != storage.len() {
+            return Err(corrupted("trailing bytes"));
+        }
+
+        let frozen = Self {
+            storage,
+            vertices,
+            hyperedges,
+            phantom: PhantomData,
+        };
+
+        frozen.validate(&frozen.vertices, hyperedges_count)?;
+        frozen.validate(&frozen.hyperedges, vertices_count)?;
+
+        Ok(frozen)
+    }
+
+    // Private method to check that the arrays of a layout are consistent, so
+    // that the queries can't go out of bounds.
+    fn validate(&self, layout: &Layout, other_count: usize) -> Result<(), HypergraphError> {
+        let count = layout.indexes.len;
+
+        for (offsets, len) in [
+            (layout.offsets, layout.incidences.len),
+            (layout.weights_offsets, layout.weights.len),
+        ] {
+            if self.read(offsets, 0) != 0 || self.read(offsets, count) != len {
+                return Err(corrupted("invalid offsets"));
+            }
+
+            if (0..count)
+                .any(|position| self.read(offsets, position) > self.read(offsets, position + 1))
+            {
+                return Err(corrupted("invalid offsets"));
+            }
+        }
+
+        if (0..layout.incidences.len)
+            .any(|position| self.read(layout.incidences, position) >= other_count)
+        {
+            return Err(corrupted("invalid incidence"));
+        }
+
+        for position in 0..count {
+            let index = self.read(layout.lookup, 2 * position);
+            let internal_index = self.read(layout.lookup, 2 * position + 1);
+
+            if internal_index >= count
+                || self.read(layout.indexes, internal_index) != index
+                || (position > 0 && self.read(layout.lookup, 2 * position - 2) >= index)
+            {
+                return Err(corrupted("invalid lookup"));
+            }
+        }
+
+        Ok(())
+    }
+
+    // Private method to read a value from a section of u64 values.
+    // The position must be within the bounds of the section.
+    fn read(&self, section: Section, position: usize) -> usize {
+        let start = section.start + position * 8;
+        let mut bytes = [0; 8];
+
+        bytes.copy_from_slice(&self.storage[start..start + 8]);
+
+        u64::from_le_bytes(bytes) as usize
+    }
+
+    // Private method to read a range of values from a section of u64 values.
+    fn read_range(
+        &self,
+        section: Section,
+        offsets: Section,
+        position: usize,
+    ) -> impl Iterator<Item = usize> + '_ {
+        (self.read(offsets, position)..self.read(offsets, position + 1))
+            .map(move |position| self.read(section, position))
+    }
+
+    // Private method to find the internal index matching a stable index via
+    // a binary search.
+    fn find(&self, layout: &Layout, index: usize) -> Option<usize> {
+        let (mut low, mut high) = (0, layout.indexes.len);
+
+        while low < high {
+            let middle = low + (high - low) / 2;
+            let current = self.read(layout.lookup, 2 * middle);
+
+            match current.cmp(&index) {
+                std::cmp::Ordering::Equal => return Some(self.read(layout.lookup, 2 * middle + 1)),
+                std::cmp::Ordering::Less => low = middle + 1,
+                std::cmp::Ordering::Greater => high = middle,
+            }
+        }
+
+        None
+    }
+
+    // Private method to get the internal index of a vertex.
+    fn get_internal_vertex(&self, vertex_index: VertexIndex) -> Result<usize, HypergraphError> {
+        self.find(&self.vertices, vertex_index.0)
+            .ok_or(HypergraphError::VertexIndexNotFound(vertex_index))
+    }
+
+    // Private method to get the internal index of a hyperedge.
+    fn get_internal_hyperedge(
+        &self,
+        hyperedge_index: HyperedgeIndex,
+    ) -> Result<usize, HypergraphError> {
+        self.find(&self.hyperedges, hyperedge_index.0)
+            .ok_or(HypergraphError::HyperedgeIndexNotFound(hyperedge_index))
+    }
+
+    // Private method to parse a weight.
+    fn parse_weight<T>(&self, layout: &Layout, internal_index: usize) -> Result<T, HypergraphError>
+    where
+        T: FromStr,
+    {
+        let start = layout.weights.start + self.read(layout.weights_offsets, internal_index);
+        let end = layout.weights.start + self.read(layout.weights_offsets, internal_index + 1);
+        let weight = std::str::from_utf8(&self.storage[start..end])
+            .map_err(|_| corrupted("invalid string"))?;
+
+        weight
+            .parse()
+            .map_err(|_| HypergraphError::FrozenInvalidWeight(weight.to_owned()))
+    }
+
+    // Private method to get the internal vertices of a hyperedge.
+    fn get_internal_hyperedge_vertices(
+        &self,
+        hyperedge_index: HyperedgeIndex,
+    ) -> Result<Vec<usize>, HypergraphError> {
+        let internal_index = self.get_internal_hyperedge(hyperedge_index)?;
+
+        Ok(self
+            .read_range(
+                self.hyperedges.incidences,
+                self.hyperedges.offsets,
+                internal_index,
+            )
+            .collect())
+    }
+
+    // Private method to get the consecutive pairs of vertices of the
+    // hyperedges of a vertex, as stable indexes.
+    fn get_windows(
+        &self,
+        vertex_index: VertexIndex,
+    ) -> Result<Vec<(usize, usize)>, HypergraphError> {
+        let internal_index = self.get_internal_vertex(vertex_index)?;
+
+        let mut windows = vec![];
+
+        for internal_hyperedge in self.read_range(
+            self.vertices.incidences,
+            self.vertices.offsets,
+            internal_index,
+        ) {
+            let vertices = self
+                .read_range(
+                    self.hyperedges.incidences,
+                    self.hyperedges.offsets,
+                    internal_hyperedge,
+                )
+                .map(|vertex| self.read(self.vertices.indexes, vertex))
+                .collect::<Vec<usize>>();
+
+            windows.extend(vertices.windows(2).map(|pair| (pair[0], pair[1])));
+        }
+
+        Ok(windows)
+    }
+
+    // Private method to stream the consecutive pairs of vertices of all the
+    // hyperedges, as internal indexes, without collecting them.
+    fn for_each_window<F>(&self, mut callback: F)
+    where
+        F: FnMut(usize, usize),
+    {
+        for internal_hyperedge in 0..self.hyperedges.indexes.len {
+            let mut previous = None;
+
+            for vertex in self.read_range(
+                self.hyperedges.incidences,
+                self.hyperedges.offsets,
+                internal_hyperedge,
+            ) {
+                if let Some(previous) = previous {
+                    callback(previous, vertex);
+                }
+
+                previous = Some(vertex);
+            }
+        }
+    }
+
+    /// Returns the number of vertices.
+    pub fn count_vertices(&self) -> usize {
+        self.vertices.indexes.len
+    }
+
+    /// Returns the number of hyperedges.
+    pub fn count_hyperedges(&self) -> usize {
+        self.hyperedges.indexes.len
+    }
+
+    /// Gets the weight of a vertex from its index.
+    pub fn get_vertex_weight(&self, vertex_index: VertexIndex) -> Result<V, HypergraphError> {
+        let internal_index = self.get_internal_vertex(vertex_index)?;
+
+        self.parse_weight(&self.vertices, internal_index)
+    }
+
+    /// Gets the weight of a hyperedge from its index.
+    pub fn get_hyperedge_weight(
+        &self,
+        hyperedge_index: HyperedgeIndex,
+    ) -> Result<HE, HypergraphError> {
+        let internal_index = self.get_internal_hyperedge(hyperedge_index)?;
+
+        self.parse_weight(&self.hyperedges, internal_index)
+    }
+
+    /// Gets the vertices of a hyperedge.
+    pub fn get_hyperedge_vertices(
+        &self,
+        hyperedge_index: HyperedgeIndex,
+    ) -> Result<Vec<VertexIndex>, HypergraphError> {
+        Ok(self
+            .get_internal_hyperedge_vertices(hyperedge_index)?
+            .into_iter()
+            .map(|vertex| VertexIndex(self.read(self.vertices.indexes, vertex)))
+            .collect())
+    }
+
+    /// Gets the hyperedges of a vertex.
+    pub fn get_vertex_hyperedges(
+        &self,
+        vertex_index: VertexIndex,
+    ) -> Result<Vec<HyperedgeIndex>, HypergraphError> {
+        let internal_index = self.get_internal_vertex(vertex_index)?;
+
+        Ok(self
+            .read_range(
+                self.vertices.incidences,
+                self.vertices.offsets,
+                internal_index,
+            )
+            .map(|hyperedge| HyperedgeIndex(self.read(self.hyperedges.indexes, hyperedge)))
+            .collect())
+    }
+
+    /// Gets the list of all vertices connected from a given vertex.
+    pub fn get_adjacent_vertices_from(
+        &self,
+        from: VertexIndex,
+    ) -> Result<Vec<VertexIndex>, HypergraphError> {
+        let mut results = self
+            .get_windows(from)?
+            .into_iter()
+            .filter(|(window_from, _)| *window_from == from.0)
+            .map(|(_, window_to)| VertexIndex(window_to))
+            .collect::<Vec<VertexIndex>>();
+
+        results.sort_unstable();
+        results.dedup();
+
+        Ok(results)
+    }
+
+    /// Gets the list of all vertices connected to a given vertex.
+    pub fn get_adjacent_vertices_to(
+        &self,
+        to: VertexIndex,
+    ) -> Result<Vec<VertexIndex>, HypergraphError> {
+        let mut results = self
+            .get_windows(to)?
+            .into_iter()
+            .filter(|(_, window_to)| *window_to == to.0)
+            .map(|(window_from, _)| VertexIndex(window_from))
+            .collect::<Vec<VertexIndex>>();
+
+        results.sort_unstable();
+        results.dedup();
+
+        Ok(results)
+    }
+
+    /// Gets the in-degree of a vertex.
+    /// <https://en.wikipedia.org/wiki/Directed_graph#Indegree_and_outdegree>
+    pub fn get_vertex_degree_in(&self, to: VertexIndex) -> Result<usize, HypergraphError> {
+        Ok(self
+            .get_windows(to)?
+            .into_iter()
+            .filter(|(_, window_to)| *window_to == to.0)
+            .count())
+    }
+
+    /// Gets the out-degree of a vertex.
+    /// <https://en.wikipedia.org/wiki/Directed_graph#Indegree_and_outdegree>
+    pub fn get_vertex_degree_out(&self, from: VertexIndex) -> Result<usize, HypergraphError> {
+        Ok(self
+            .get_windows(from)?
+            .into_iter()
+            .filter(|(window_from, _)| *window_from == from.0)
+            .count())
+    }
+
+    /// Computes the PageRank of the vertices, each pair of consecutive
+    /// vertices of a hyperedge being a directed edge. The ranks of the
+    /// vertices without outgoing edge are spread evenly.
+    /// The hyperedges are streamed from the storage at each iteration and
+    /// only the rank vectors are kept in memory, which suits memory-mapped
+    /// hypergraphs larger than the available memory.
+    /// The damping factor is clamped between 0 and 1. The iterations stop
+    /// once the L1 distance between two successive rank vectors is below the
+    /// tolerance. The ranks follow the insertion order of the vertices.
+    /// <https://en.wikipedia.org/wiki/PageRank>
+    pub fn page_rank(
+        &self,
+        damping: f64,
+        max_iterations: usize,
+        tolerance: f64,
+    ) -> Vec<(VertexIndex, f64)> {
+        let count = self.count_vertices();
+
+        if count == 0 {
+            return vec![];
+        }
+
+        let damping = damping.clamp(0., 1.);
+        let mut out_degrees = vec![0usize; count];
+
+        self.for_each_window(|from, _| out_degrees[from] += 1);
+
+        let mut ranks = vec![1. / count as f64; count];
+
+        for _ in 0..max_iterations {
+            let dangling = ranks
+                .iter()
+                .zip(out_degrees.iter())
+                .filter(|(_, degree)| **degree == 0)
+                .map(|(rank, _)| rank)
+                .sum::<f64>();
+            let base = (1. - damping + damping * dangling) / count as f64;
+            let mut next_ranks = vec![base; count];
+
+            self.for_each_window(|from, to| {
+                next_ranks[to] += damping * ranks[from] / out_degrees[from] as f64;
+            });
+
+            let distance = ranks
+                .iter()
+                .zip(next_ranks.iter())
+                .map(|(rank, next_rank)| (rank - next_rank).abs())
+                .sum::<f64>();
+
+            ranks = next_ranks;
+
+            if distance < tolerance {
+                break;
+            }
+        }
+
+        ranks
+            .into_iter()
+            .enumerate()
+            .map(|(internal_index, rank)| {
+                (
+                    VertexIndex(self.read(self.vertices.indexes, internal_index)),
+                    rank,
+                )
+            })
+            .collect()
+    }
+
+    /// Converts the frozen hypergraph back to a mutable one, in a single pass
+    /// over the arrays.
+    /// The stable indexes are preserved, so that no mapping is needed
+    /// between the two representations. The vertices sharing the same
+    /// weight are kept as duplicates. The duplicate policy is the default
+    /// one.
+    pub fn thaw(&self) -> Result<Hypergraph<V, HE>, HypergraphError> {
+        let mut graph =
+            Hypergraph::with_capacity(self.vertices.indexes.len, self.hyperedges.indexes.len);
+
+        for internal_index in 0..self.vertices.indexes.len {
+            let vertex_index = VertexIndex(self.read(self.vertices.indexes, internal_index));
+            let weight = self.parse_weight::<V>(&self.vertices, internal_index)?;
+            let hyperedges = self
+                .read_range(
+                    self.vertices.incidences,
+                    self.vertices.offsets,
+                    internal_index,
+                )
+                .collect();
+
+            let mut key = VertexKey::new(weight, None);
+
+            // Follow `add_vertex_allow_duplicate` for the duplicates.
+            if graph.vertices.contains_key(&key) {
+                key.id = Some(vertex_index.0);
+            }
+
+            graph.vertices.insert(key, hyperedges);
+            graph
+                .vertices_mapping
+                .left
+                .insert(internal_index, vertex_index);
+            graph
+                .vertices_mapping
+                .right
+                .insert(vertex_index, internal_index);
+            graph.vertices_count = graph.vertices_count.max(vertex_index.0 + 1);
+        }
+
+        for internal_index in 0..self.hyperedges.indexes.len {
+            let hyperedge_index =
+                HyperedgeIndex(self.read(self.hyperedges.indexes, internal_index));
+            let weight = self.parse_weight::<HE>(&self.hyperedges, internal_index)?;
+            let vertices = self
+                .read_range(
+                    self.hyperedges.incidences,
+                    self.hyperedges.offsets,
+                    internal_index,
+                )
+                .collect::<Vec<usize>>();
+
+            if graph
+                .hyperedges_weights
+                .insert(weight.clone(), internal_index)
+                .is_some()
+            {
+                return Err(corrupted("duplicate hyperedge"));
+            }
+
+            graph.hyperedges_cardinalities.insert(vertices.len());
+            graph.hyperedges.push(HyperedgeKey::new(vertices, weight));
+            graph
+                .hyperedges_mapping
+                .left
+                .insert(internal_index, hyperedge_index);
+            graph
+                .hyperedges_mapping
+                .right
+                .insert(hyperedge_index, internal_index);
+            graph.hyperedges_count = graph.hyperedges_count.max(hyperedge_index.0 + 1);
+        }
+
+        // Mirror the vertices of the hyperedges in their bitmaps.
+        #[cfg(feature = "roaring")]
+        graph.rebuild_hyperedges_bitmaps()?;
+
+        Ok(graph)
+    }
+}
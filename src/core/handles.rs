@@ -0,0 +1,157 @@
+use std::fmt::{
+    Debug,
+    Formatter,
+    Result as FmtResult,
+};
+
+use crate::{
+    HyperedgeIndex,
+    HyperedgeTrait,
+    Hypergraph,
+    VertexIndex,
+    VertexTrait,
+    errors::HypergraphError,
+};
+
+/// Lightweight handle to a vertex of a hypergraph, as returned by
+/// `Hypergraph::vertex`, exposing the queries about the vertex as methods.
+#[derive(Clone, Copy)]
+pub struct VertexRef<'a, V, HE> {
+    graph: &'a Hypergraph<V, HE>,
+    index: VertexIndex,
+    weight: &'a V,
+}
+
+/// Lightweight handle to a hyperedge of a hypergraph, as returned by
+/// `Hypergraph::hyperedge`, exposing the queries about the hyperedge as
+/// methods.
+#[derive(Clone, Copy)]
+pub struct HyperedgeRef<'a, V, HE> {
+    graph: &'a Hypergraph<V, HE>,
+    index: HyperedgeIndex,
+    weight: &'a HE,
+}
+
+impl<V, HE> Debug for VertexRef<'_, V, HE>
+where
+    V: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("VertexRef")
+            .field("index", &self.index)
+            .field("weight", self.weight)
+            .finish()
+    }
+}
+
+impl<V, HE> Debug for HyperedgeRef<'_, V, HE>
+where
+    HE: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("HyperedgeRef")
+            .field("index", &self.index)
+            .field("weight", self.weight)
+            .finish()
+    }
+}
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Gets a handle to a vertex from its index.
+    pub fn vertex(
+        &self,
+        vertex_index: VertexIndex,
+    ) -> Result<VertexRef<'_, V, HE>, HypergraphError> {
+        Ok(VertexRef {
+            graph: self,
+            index: vertex_index,
+            weight: self.get_vertex_weight(vertex_index)?,
+        })
+    }
+
+    /// Gets a handle to a hyperedge from its index.
+    pub fn hyperedge(
+        &self,
+        hyperedge_index: HyperedgeIndex,
+    ) -> Result<HyperedgeRef<'_, V, HE>, HypergraphError> {
+        Ok(HyperedgeRef {
+            graph: self,
+            index: hyperedge_index,
+            weight: self.get_hyperedge_weight(hyperedge_index)?,
+        })
+    }
+}
+
+impl<'a, V, HE> VertexRef<'a, V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Gets the index of the vertex.
+    pub fn index(&self) -> VertexIndex {
+        self.index
+    }
+
+    /// Gets the weight of the vertex.
+    pub fn weight(&self) -> &'a V {
+        self.weight
+    }
+
+    /// Gets the hyperedges of the vertex.
+    pub fn hyperedges(&self) -> Result<Vec<HyperedgeIndex>, HypergraphError> {
+        self.graph.get_vertex_hyperedges(self.index)
+    }
+
+    /// Gets the in-degree of the vertex.
+    pub fn degree_in(&self) -> Result<usize, HypergraphError> {
+        self.graph.get_vertex_degree_in(self.index)
+    }
+
+    /// Gets the out-degree of the vertex.
+    pub fn degree_out(&self) -> Result<usize, HypergraphError> {
+        self.graph.get_vertex_degree_out(self.index)
+    }
+
+    /// Gets the list of all vertices connected from the vertex.
+    pub fn adjacent_from(&self) -> Result<Vec<VertexIndex>, HypergraphError> {
+        self.graph.get_adjacent_vertices_from(self.index)
+    }
+
+    /// Gets the list of all vertices connected to the vertex.
+    pub fn adjacent_to(&self) -> Result<Vec<VertexIndex>, HypergraphError> {
+        self.graph.get_adjacent_vertices_to(self.index)
+    }
+}
+
+impl<'a, V, HE> HyperedgeRef<'a, V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Gets the index of the hyperedge.
+    pub fn index(&self) -> HyperedgeIndex {
+        self.index
+    }
+
+    /// Gets the weight of the hyperedge.
+    pub fn weight(&self) -> &'a HE {
+        self.weight
+    }
+
+    /// Gets the vertices of the hyperedge.
+    pub fn vertices(&self) -> Result<Vec<VertexIndex>, HypergraphError> {
+        self.graph.get_hyperedge_vertices(self.index)
+    }
+
+    /// Gets handles to the vertices of the hyperedge.
+    pub fn vertex_refs(&self) -> Result<Vec<VertexRef<'a, V, HE>>, HypergraphError> {
+        self.vertices()?
+            .into_iter()
+            .map(|vertex_index| self.graph.vertex(vertex_index))
+            .collect()
+    }
+}
@@ -0,0 +1,527 @@
+use std::{
+    fmt::{
+        self,
+        Debug,
+        Formatter,
+    },
+    ops::Deref,
+    str::FromStr,
+};
+
+use crate::{
+    HyperedgeIndex,
+    HyperedgeKey,
+    HyperedgeTrait,
+    Hypergraph,
+    IndexRemapping,
+    JournaledHypergraph,
+    LayerIndex,
+    VertexIndex,
+    VertexTrait,
+    core::{
+        journal::{
+            ADD_HYPEREDGE,
+            ADD_HYPEREDGE_WITH_ROLES,
+            ADD_LAYER,
+            ADD_VERTEX,
+            ADD_VERTEX_ALLOW_DUPLICATE,
+            CLEAR,
+            CLEAR_HYPEREDGES,
+            CONTRACT_HYPEREDGE_VERTICES,
+            JOIN_HYPEREDGES,
+            JOIN_HYPEREDGES_WITH,
+            PRUNE_HYPEREDGES_BY_CARDINALITY,
+            PRUNE_ISOLATED_VERTICES,
+            RECYCLE_INDEXES,
+            REMAP_INDEXES,
+            REMOVE_HYPEREDGE,
+            REMOVE_HYPEREDGE_PROPERTY,
+            REMOVE_LAYER,
+            REMOVE_METADATA,
+            REMOVE_VERTEX,
+            REMOVE_VERTEX_ATTR,
+            REVERSE_HYPEREDGE,
+            SET_HYPEREDGE_LAYER,
+            SET_HYPEREDGE_PROPERTY,
+            SET_METADATA,
+            SET_VERTEX_ATTR,
+            TAG_HYPEREDGE,
+            UNTAG_HYPEREDGE,
+            UPDATE_HYPEREDGE_VERTICES,
+            UPDATE_HYPEREDGE_WEIGHT,
+            UPDATE_VERTEX_WEIGHT,
+            apply_record,
+            read_indexes,
+            read_records,
+            reader_error,
+        },
+        snapshot::SnapshotReader,
+    },
+    errors::HypergraphError,
+};
+
+/// Entity whose history can be queried via `JournaledHypergraph::history_of`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum JournalEntity {
+    /// A vertex, by index.
+    Vertex(VertexIndex),
+    /// A hyperedge, by index.
+    Hyperedge(HyperedgeIndex),
+}
+
+impl From<VertexIndex> for JournalEntity {
+    fn from(vertex_index: VertexIndex) -> Self {
+        JournalEntity::Vertex(vertex_index)
+    }
+}
+
+impl From<HyperedgeIndex> for JournalEntity {
+    fn from(hyperedge_index: HyperedgeIndex) -> Self {
+        JournalEntity::Hyperedge(hyperedge_index)
+    }
+}
+
+/// Mutation recorded in a journal, named after the method of the journaled
+/// hypergraph which recorded it.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum JournalOperation {
+    /// Recorded by `add_vertex`.
+    AddVertex,
+    /// Recorded by `add_vertex_allow_duplicate`.
+    AddVertexAllowDuplicate,
+    /// Recorded by `add_hyperedge`.
+    AddHyperedge,
+    /// Recorded by `add_hyperedge_with_roles`.
+    AddHyperedgeWithRoles,
+    /// Recorded by `remove_vertex`.
+    RemoveVertex,
+    /// Recorded by `remove_hyperedge`.
+    RemoveHyperedge,
+    /// Recorded by `update_vertex_weight`.
+    UpdateVertexWeight,
+    /// Recorded by `update_hyperedge_weight`.
+    UpdateHyperedgeWeight,
+    /// Recorded by `update_hyperedge_vertices`.
+    UpdateHyperedgeVertices,
+    /// Recorded by `reverse_hyperedge`.
+    ReverseHyperedge,
+    /// Recorded by `join_hyperedges` and `join_hyperedges_with`.
+    JoinHyperedges,
+    /// Recorded by `contract_hyperedge_vertices`.
+    ContractHyperedgeVertices,
+    /// Recorded by `clear`.
+    Clear,
+    /// Recorded by `clear_hyperedges`.
+    ClearHyperedges,
+    /// Recorded by `set_vertex_attr`.
+    SetVertexAttr,
+    /// Recorded by `remove_vertex_attr`.
+    RemoveVertexAttr,
+    /// Recorded by `set_hyperedge_property`.
+    SetHyperedgeProperty,
+    /// Recorded by `remove_hyperedge_property`.
+    RemoveHyperedgeProperty,
+    /// Recorded by `prune_isolated_vertices`.
+    PruneIsolatedVertices,
+    /// Recorded by `prune_hyperedges_by_cardinality`.
+    PruneHyperedgesByCardinality,
+    /// Recorded by `set_metadata`.
+    SetMetadata,
+    /// Recorded by `remove_metadata`.
+    RemoveMetadata,
+    /// Recorded by `tag_hyperedge`.
+    TagHyperedge,
+    /// Recorded by `untag_hyperedge`.
+    UntagHyperedge,
+    /// Recorded by `add_layer`.
+    AddLayer,
+    /// Recorded by `remove_layer`.
+    RemoveLayer,
+    /// Recorded by `set_hyperedge_layer`.
+    SetHyperedgeLayer,
+    /// Recorded by `remap_indexes`.
+    RemapIndexes,
+    /// Recorded by `recycle_indexes`.
+    RecycleIndexes,
+}
+
+impl JournalOperation {
+    // Private helper to get the operation of a record by tag of the journal.
+    fn from_tag(tag: u8) -> Result<Self, HypergraphError> {
+        use JournalOperation::*;
+
+        Ok(match tag {
+            ADD_VERTEX => AddVertex,
+            ADD_VERTEX_ALLOW_DUPLICATE => AddVertexAllowDuplicate,
+            ADD_HYPEREDGE => AddHyperedge,
+            ADD_HYPEREDGE_WITH_ROLES => AddHyperedgeWithRoles,
+            REMOVE_VERTEX => RemoveVertex,
+            REMOVE_HYPEREDGE => RemoveHyperedge,
+            UPDATE_VERTEX_WEIGHT => UpdateVertexWeight,
+            UPDATE_HYPEREDGE_WEIGHT => UpdateHyperedgeWeight,
+            UPDATE_HYPEREDGE_VERTICES => UpdateHyperedgeVertices,
+            REVERSE_HYPEREDGE => ReverseHyperedge,
+            JOIN_HYPEREDGES | JOIN_HYPEREDGES_WITH => JoinHyperedges,
+            CONTRACT_HYPEREDGE_VERTICES => ContractHyperedgeVertices,
+            CLEAR => Clear,
+            CLEAR_HYPEREDGES => ClearHyperedges,
+            SET_VERTEX_ATTR => SetVertexAttr,
+            REMOVE_VERTEX_ATTR => RemoveVertexAttr,
+            SET_HYPEREDGE_PROPERTY => SetHyperedgeProperty,
+            REMOVE_HYPEREDGE_PROPERTY => RemoveHyperedgeProperty,
+            PRUNE_ISOLATED_VERTICES => PruneIsolatedVertices,
+            PRUNE_HYPEREDGES_BY_CARDINALITY => PruneHyperedgesByCardinality,
+            SET_METADATA => SetMetadata,
+            REMOVE_METADATA => RemoveMetadata,
+            TAG_HYPEREDGE => TagHyperedge,
+            UNTAG_HYPEREDGE => UntagHyperedge,
+            ADD_LAYER => AddLayer,
+            REMOVE_LAYER => RemoveLayer,
+            SET_HYPEREDGE_LAYER => SetHyperedgeLayer,
+            REMAP_INDEXES => RemapIndexes,
+            RECYCLE_INDEXES => RecycleIndexes,
+            _ => {
+                return Err(HypergraphError::JournalCorrupted(format!(
+                    "invalid record tag {tag}"
+                )));
+            }
+        })
+    }
+}
+
+/// Mutation affecting an entity, as returned by
+/// `JournaledHypergraph::history_of`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct JournalEntry {
+    /// Sequence number of the record, i.e. of the first state including the
+    /// mutation. See `JournaledHypergraph::as_of`.
+    pub sequence_number: usize,
+    /// Recorded mutation.
+    pub operation: JournalOperation,
+}
+
+/// Read-only view of a journaled hypergraph at a past point, as returned by
+/// `JournaledHypergraph::as_of`.
+/// Queries are available via `Deref`.
+pub struct HistoricalHypergraph<V, HE> {
+    graph: Hypergraph<V, HE>,
+    sequence_number: usize,
+}
+
+impl<V, HE> Debug for HistoricalHypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HistoricalHypergraph")
+            .field("graph", &self.graph)
+            .field("sequence_number", &self.sequence_number)
+            .finish()
+    }
+}
+
+impl<V, HE> Deref for HistoricalHypergraph<V, HE> {
+    type Target = Hypergraph<V, HE>;
+
+    fn deref(&self) -> &Hypergraph<V, HE> {
+        &self.graph
+    }
+}
+
+impl<V, HE> HistoricalHypergraph<V, HE> {
+    /// Gets the sequence number of the view.
+    pub fn get_sequence_number(&self) -> usize {
+        self.sequence_number
+    }
+
+    /// Consumes the view and returns a detached copy of the hypergraph.
+    pub fn into_inner(self) -> Hypergraph<V, HE> {
+        self.graph
+    }
+}
+
+/// Entities affected by a record.
+#[derive(Debug)]
+struct Changes {
+    /// Recorded mutation.
+    operation: JournalOperation,
+
+    /// Whether every entity is affected.
+    all: bool,
+
+    /// Affected vertices, by index before the mutation.
+    vertices: Vec<VertexIndex>,
+
+    /// Affected hyperedges, by index before the mutation.
+    hyperedges: Vec<HyperedgeIndex>,
+
+    /// Created entity, if any.
+    created: Option<JournalEntity>,
+
+    /// Remapping of the indexes, if any.
+    remapping: Option<IndexRemapping>,
+}
+
+impl Changes {
+    // Private method to get the index of an entity before the mutation, if
+    // it existed. Only the remappings change the indexes.
+    fn get_previous(&self, entity: JournalEntity) -> Option<JournalEntity> {
+        let remapping = match self.remapping.as_ref() {
+            Some(remapping) => remapping,
+            None => return Some(entity),
+        };
+
+        match entity {
+            JournalEntity::Vertex(vertex_index) => remapping
+                .vertices
+                .iter()
+                .find(|(_, current)| **current == vertex_index)
+                .map(|(previous, _)| JournalEntity::Vertex(*previous)),
+            JournalEntity::Hyperedge(hyperedge_index) => remapping
+                .hyperedges
+                .iter()
+                .find(|(_, current)| **current == hyperedge_index)
+                .map(|(previous, _)| JournalEntity::Hyperedge(*previous)),
+        }
+    }
+
+    // Private method to check if an entity - by index after the mutation -
+    // is affected by the mutation.
+    fn affects(&self, entity: JournalEntity, previous: Option<JournalEntity>) -> bool {
+        if self.all || self.created == Some(entity) {
+            return true;
+        }
+
+        match previous {
+            Some(previous) if self.remapping.is_some() => previous != entity,
+            Some(JournalEntity::Vertex(vertex_index)) => self.vertices.contains(&vertex_index),
+            Some(JournalEntity::Hyperedge(hyperedge_index)) => {
+                self.hyperedges.contains(&hyperedge_index)
+            }
+            None => false,
+        }
+    }
+}
+
+// Private helper to get the entities affected by a record before applying it
+// to a hypergraph.
+fn get_changes<V, HE>(
+    graph: &Hypergraph<V, HE>,
+    reader: &mut SnapshotReader<'_>,
+) -> Result<Changes, HypergraphError>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    use JournalOperation::*;
+
+    let mut changes = Changes {
+        operation: JournalOperation::from_tag(reader.read_u8()?)?,
+        all: false,
+        vertices: vec![],
+        hyperedges: vec![],
+        created: None,
+        remapping: None,
+    };
+
+    match changes.operation {
+        AddVertex | AddVertexAllowDuplicate => {
+            changes.created = Some(JournalEntity::Vertex(graph.next_vertex_index()));
+        }
+        AddHyperedge => {
+            changes.vertices = read_indexes(reader, VertexIndex)?;
+            changes.created = Some(JournalEntity::Hyperedge(graph.next_hyperedge_index()));
+        }
+        AddHyperedgeWithRoles => {
+            changes.vertices = (0..reader.read_length()?)
+                .map(|_| {
+                    let vertex_index = VertexIndex(reader.read_usize()?);
+
+                    reader.read_string()?;
+
+                    Ok(vertex_index)
+                })
+                .collect::<Result<Vec<VertexIndex>, HypergraphError>>()?;
+            changes.created = Some(JournalEntity::Hyperedge(graph.next_hyperedge_index()));
+        }
+        RemoveVertex => {
+            let vertex_index = VertexIndex(reader.read_usize()?);
+
+            changes.hyperedges = graph.get_vertex_hyperedges(vertex_index)?;
+            changes.vertices = vec![vertex_index];
+        }
+        UpdateVertexWeight | SetVertexAttr | RemoveVertexAttr => {
+            changes.vertices = vec![VertexIndex(reader.read_usize()?)];
+        }
+        RemoveHyperedge | UpdateHyperedgeVertices | ContractHyperedgeVertices => {
+            let hyperedge_index = HyperedgeIndex(reader.read_usize()?);
+
+            // The new vertices are affected as well as the previous ones.
+            changes.vertices = graph.get_hyperedge_vertices(hyperedge_index)?;
+            changes.hyperedges = vec![hyperedge_index];
+
+            if changes.operation != RemoveHyperedge {
+                changes.vertices.extend(read_indexes(reader, VertexIndex)?);
+            }
+
+            // The target of a contraction as well.
+            if changes.operation == ContractHyperedgeVertices {
+                changes.vertices.push(VertexIndex(reader.read_usize()?));
+            }
+        }
+        UpdateHyperedgeWeight
+        | ReverseHyperedge
+        | SetHyperedgeProperty
+        | RemoveHyperedgeProperty
+        | TagHyperedge
+        | UntagHyperedge
+        | SetHyperedgeLayer => {
+            changes.hyperedges = vec![HyperedgeIndex(reader.read_usize()?)];
+        }
+        JoinHyperedges => {
+            changes.hyperedges = read_indexes(reader, HyperedgeIndex)?;
+
+            for hyperedge_index in changes.hyperedges.iter() {
+                changes
+                    .vertices
+                    .extend(graph.get_hyperedge_vertices(*hyperedge_index)?);
+            }
+        }
+        Clear | ClearHyperedges => changes.all = true,
+        PruneIsolatedVertices => {
+            for (internal_index, (_, hyperedges)) in graph.vertices.iter().enumerate() {
+                if hyperedges.is_empty() {
+                    changes.vertices.push(graph.get_vertex(internal_index)?);
+                }
+            }
+        }
+        PruneHyperedgesByCardinality => {
+            let (min, max) = (reader.read_usize()?, reader.read_usize()?);
+
+            for (internal_index, HyperedgeKey { vertices, .. }) in
+                graph.hyperedges.iter().enumerate()
+            {
+                if !(min..=max).contains(&vertices.len()) {
+                    changes.vertices.extend(graph.get_vertices(vertices)?);
+                    changes
+                        .hyperedges
+                        .push(graph.get_hyperedge(internal_index)?);
+                }
+            }
+        }
+        RemoveLayer => {
+            changes.hyperedges = graph.get_layer_hyperedges(LayerIndex(reader.read_usize()?))?;
+        }
+        SetMetadata | RemoveMetadata | AddLayer | RemapIndexes | RecycleIndexes => {}
+    }
+
+    Ok(changes)
+}
+
+impl<V, HE> JournaledHypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Reconstructs a read-only view of the hypergraph as of a sequence
+    /// number, i.e. after its first records, by replaying the journal.
+    /// The sequence number zero refers to the empty hypergraph.
+    pub fn as_of(
+        &self,
+        sequence_number: usize,
+    ) -> Result<HistoricalHypergraph<V, HE>, HypergraphError>
+    where
+        V: FromStr,
+        HE: FromStr,
+    {
+        if sequence_number > self.get_sequence_number() {
+            return Err(HypergraphError::JournalSequenceNumberNotFound(
+                sequence_number,
+            ));
+        }
+
+        let bytes = self.read_journal()?;
+        let (records, _) = read_records(&bytes)?;
+        let mut graph = Hypergraph::new();
+
+        for record in records.iter().take(sequence_number) {
+            let mut reader = SnapshotReader {
+                bytes: record,
+                position: 0,
+            };
+
+            apply_record(&mut graph, &mut reader).map_err(reader_error)?;
+        }
+
+        Ok(HistoricalHypergraph {
+            graph,
+            sequence_number,
+        })
+    }
+
+    /// Lists the recorded mutations affecting an entity, sorted by sequence
+    /// number, e.g. for auditing purposes.
+    /// The entity is referred to by its current index and followed across
+    /// the remappings of the indexes, back to its creation. A mutation
+    /// affects a vertex if it creates, removes or updates it - its weight,
+    /// its attributes or its hyperedges - and a hyperedge if it creates,
+    /// removes or updates it - its weight, its vertices, its properties, its
+    /// tags or its layer.
+    pub fn history_of(
+        &self,
+        entity: impl Into<JournalEntity>,
+    ) -> Result<Vec<JournalEntry>, HypergraphError>
+    where
+        V: FromStr,
+        HE: FromStr,
+    {
+        let bytes = self.read_journal()?;
+        let (records, _) = read_records(&bytes)?;
+        let mut graph = Hypergraph::<V, HE>::new();
+        let mut all_changes = Vec::with_capacity(self.get_sequence_number());
+
+        // Replay the journal while collecting the affected entities.
+        for record in records.iter().take(self.get_sequence_number()) {
+            let mut reader = SnapshotReader {
+                bytes: record,
+                position: 0,
+            };
+            let mut changes = get_changes(&graph, &mut reader).map_err(reader_error)?;
+
+            reader.position = 0;
+            changes.remapping = apply_record(&mut graph, &mut reader).map_err(reader_error)?;
+            all_changes.push(changes);
+        }
+
+        // Follow the entity backwards, from its current index to its
+        // creation.
+        let mut history = vec![];
+        let mut current = Some(entity.into());
+
+        for (position, changes) in all_changes.iter().enumerate().rev() {
+            let entity = match current {
+                Some(entity) => entity,
+                None => break,
+            };
+            let previous = changes.get_previous(entity);
+
+            if changes.affects(entity, previous) {
+                history.push(JournalEntry {
+                    sequence_number: position + 1,
+                    operation: changes.operation,
+                });
+            }
+
+            if changes.created == Some(entity) {
+                break;
+            }
+
+            current = previous;
+        }
+
+        history.reverse();
+
+        Ok(history)
+    }
+}
@@ -15,47 +15,107 @@ where
 {
     /// Adds a hyperedge as an array of vertices indexes and a custom weight in the hypergraph.
     /// Returns the weighted index of the hyperedge.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            err,
+            fields(
+                ?vertices,
+                vertices_count = self.vertices.len(),
+                hyperedges_count = self.hyperedges.len(),
+            )
+        )
+    )]
     pub fn add_hyperedge(
         &mut self,
         vertices: Vec<VertexIndex>,
         weight: HE,
-    ) -> Result<HyperedgeIndex, HypergraphError<V, HE>> {
+    ) -> Result<HyperedgeIndex, HypergraphError> {
         // If the provided vertices are empty, skip the update.
         if vertices.is_empty() {
-            return Err(HypergraphError::HyperedgeCreationNoVertices(weight));
+            return Err(HypergraphError::HyperedgeCreationNoVertices(
+                weight.to_string(),
+            ));
         }
 
         let internal_vertices = self.get_internal_vertices(vertices)?;
 
         // Return an error if the weight is already assigned to another
         // hyperedge.
-        // We can't use the contains method here since the key is a combination
-        // of the weight and the vertices.
-        if self.hyperedges.iter().any(
-            |HyperedgeKey {
-                 weight: current_weight,
-                 ..
-             }| { *current_weight == weight },
-        ) {
-            return Err(HypergraphError::HyperedgeWeightAlreadyAssigned(weight));
+        if let Some(internal_index) = self.hyperedges_weights.get(&weight) {
+            return Err(HypergraphError::HyperedgeWeightAlreadyAssigned(
+                self.get_hyperedge(*internal_index)?,
+            ));
         }
 
-        // We don't care about the second member of the tuple returned from
-        // the insertion since this is an infallible operation.
-        let (internal_index, _) = self
-            .hyperedges
-            .insert_full(HyperedgeKey::new(internal_vertices.clone(), weight));
+        // Return an error if the vertices clash with the ones of another
+        // hyperedge according to the duplicate policy.
+        self.check_duplicate_vertices(&internal_vertices, None)?;
+
+        // Return an error if the vertices don't comply with the constraints.
+        self.check_constraints(&internal_vertices, &[])?;
+
+        // Return an error if the maximum number of hyperedges is reached.
+        self.check_hyperedges_count()?;
+
+        // Drop the adjacency cache and the distance oracle.
+        self.adjacency_cache = None;
+        self.distance_oracle = None;
+
+        // The new hyperedge is pushed at the end of the vector.
+        let internal_index = self.hyperedges.len();
 
         // Update the vertices so that we keep directly track of the hyperedge.
-        for vertex in internal_vertices {
+        for vertex in internal_vertices.iter() {
             let (_, index_set) = self
                 .vertices
-                .get_index_mut(vertex)
-                .ok_or(HypergraphError::InternalVertexIndexNotFound(vertex))?;
+                .get_index_mut(*vertex)
+                .ok_or(HypergraphError::InternalVertexIndexNotFound(*vertex))?;
 
             index_set.insert(internal_index);
         }
 
-        Ok(self.add_hyperedge_index(internal_index))
+        self.hyperedges_cardinalities
+            .insert(internal_vertices.len());
+
+        // The weight is owned by both the key and the weights index.
+        self.hyperedges_weights
+            .insert(weight.clone(), internal_index);
+
+        // Move the vertices into the key, which saves a copy.
+        self.hyperedges
+            .push(HyperedgeKey::new(internal_vertices, weight));
+
+        // Mirror the vertices in the bitmap of the hyperedge.
+        #[cfg(feature = "roaring")]
+        self.sync_hyperedge_bitmap(internal_index)?;
+
+        let hyperedge_index = self.add_hyperedge_index(internal_index);
+
+        // Index the weight for the full-text search.
+        self.index_hyperedge_weight(hyperedge_index, internal_index);
+
+        // Insert the signature into the approximate membership filter.
+        #[cfg(feature = "bloom")]
+        self.filter_hyperedge(internal_index)?;
+
+        Ok(hyperedge_index)
+    }
+
+    // Private method to index the weight of a hyperedge, owned by its key,
+    // for the full-text search.
+    pub(crate) fn index_hyperedge_weight(
+        &mut self,
+        hyperedge_index: HyperedgeIndex,
+        internal_index: usize,
+    ) {
+        if let (Some(search_index), Some(HyperedgeKey { weight, .. })) = (
+            self.hyperedges_search_index.as_mut(),
+            self.hyperedges.get(internal_index),
+        ) {
+            search_index.insert(hyperedge_index, weight);
+        }
     }
 }
@@ -16,7 +16,7 @@ where
         if let Some(hyperedge_index) = self.hyperedges_mapping.left.get(&internal_index) {
             *hyperedge_index
         } else {
-            let hyperedge_index = HyperedgeIndex(self.hyperedges_count);
+            let hyperedge_index = self.next_hyperedge_index();
 
             if self
                 .hyperedges_mapping
@@ -24,15 +24,34 @@ where
                 .insert(internal_index, hyperedge_index)
                 .is_none()
             {
-                // Update the counter only for the first insertion.
-                self.hyperedges_count += 1;
+                // Consume the recycled index or update the counter only for
+                // the first insertion.
+                if self.hyperedges_recycled.last() == Some(&hyperedge_index) {
+                    self.hyperedges_recycled.pop();
+                } else {
+                    self.hyperedges_count += 1;
+                }
             }
 
             self.hyperedges_mapping
                 .right
                 .insert(hyperedge_index, internal_index);
 
+            // Issue a new version, e.g. in case the index is reused.
+            let version = self.next_version();
+
+            self.hyperedges_versions.insert(hyperedge_index, version);
+
             hyperedge_index
         }
     }
+
+    // Private method to get the upcoming stable index of a hyperedge, i.e. the
+    // lowest recycled one if any or the next one of the counter.
+    pub(crate) fn next_hyperedge_index(&self) -> HyperedgeIndex {
+        self.hyperedges_recycled
+            .last()
+            .copied()
+            .unwrap_or(HyperedgeIndex(self.hyperedges_count))
+    }
 }
@@ -0,0 +1,40 @@
+use crate::{
+    HyperedgeIndex,
+    HyperedgeTrait,
+    Hypergraph,
+    VertexIndex,
+    VertexTrait,
+    errors::HypergraphError,
+};
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Adds a hyperedge as an array of vertices indexes along with their
+    /// roles and a custom weight in the hypergraph.
+    /// Roles are labels attached to each vertex of the hyperedge - e.g. the
+    /// reactants and the catalysts of a chemical reaction - and are stored
+    /// apart from the weight.
+    /// Returns the weighted index of the hyperedge.
+    pub fn add_hyperedge_with_roles<R>(
+        &mut self,
+        vertices: Vec<(VertexIndex, R)>,
+        weight: HE,
+    ) -> Result<HyperedgeIndex, HypergraphError>
+    where
+        R: Into<String>,
+    {
+        let (vertices, roles): (Vec<VertexIndex>, Vec<String>) = vertices
+            .into_iter()
+            .map(|(vertex_index, role)| (vertex_index, role.into()))
+            .unzip();
+
+        let hyperedge_index = self.add_hyperedge(vertices, weight)?;
+
+        self.hyperedges_roles.insert(hyperedge_index, roles);
+
+        Ok(hyperedge_index)
+    }
+}
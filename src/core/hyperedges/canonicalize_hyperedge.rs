@@ -0,0 +1,84 @@
+use itertools::Itertools;
+
+use crate::{
+    HyperedgeIndex,
+    HyperedgeTrait,
+    Hypergraph,
+    VertexDedupPolicy,
+    VertexTrait,
+    errors::HypergraphError,
+};
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Canonicalizes the vertices of a hyperedge by index, i.e. sorts them by
+    /// index and dedupes them according to a policy, e.g. to normalize the
+    /// hyperedges of an external source without a full rebuild.
+    /// The roles of the vertices, if any, follow them unless some duplicates
+    /// are merged, in which case they are dropped.
+    /// Returns whether the vertices have changed.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            err,
+            fields(
+                ?hyperedge_index,
+                ?policy,
+                vertices_count = self.vertices.len(),
+                hyperedges_count = self.hyperedges.len(),
+            )
+        )
+    )]
+    pub fn canonicalize_hyperedge(
+        &mut self,
+        hyperedge_index: HyperedgeIndex,
+        policy: VertexDedupPolicy,
+    ) -> Result<bool, HypergraphError> {
+        let vertices = self.get_hyperedge_vertices(hyperedge_index)?;
+
+        // Sort the positions of the vertices, the sort being stable so that
+        // the roles of the duplicates keep their relative order.
+        let positions = (0..vertices.len())
+            .sorted_by_key(|position| vertices[*position])
+            .collect_vec();
+        let canonical = policy.dedup(
+            positions
+                .iter()
+                .map(|position| vertices[*position])
+                .collect_vec(),
+        );
+
+        // Early exit if the hyperedge is already canonical.
+        if canonical == vertices {
+            return Ok(false);
+        }
+
+        // Keep track of the sorted roles, dropped by the update.
+        let roles = self
+            .hyperedges_roles
+            .get(&hyperedge_index)
+            .and_then(|roles| {
+                positions
+                    .iter()
+                    .map(|position| roles.get(*position).cloned())
+                    .collect::<Option<Vec<String>>>()
+            });
+
+        self.update_hyperedge_vertices(hyperedge_index, canonical)?;
+
+        // Restore the sorted roles if they are still aligned with the
+        // vertices.
+        if let Some(roles) = roles {
+            if roles.len() == self.get_hyperedge_vertices(hyperedge_index)?.len() {
+                self.hyperedges_roles.insert(hyperedge_index, roles);
+            }
+        }
+
+        Ok(true)
+    }
+}
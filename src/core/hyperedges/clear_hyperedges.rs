@@ -1,10 +1,9 @@
-use rayon::prelude::*;
-
 use crate::{
     HyperedgeTrait,
     Hypergraph,
     VertexTrait,
     bi_hash_map::BiHashMap,
+    core::parallel::*,
     errors::HypergraphError,
 };
 
@@ -14,15 +13,60 @@ where
     HE: HyperedgeTrait,
 {
     /// Clears all the hyperedges from the hypergraph.
-    pub fn clear_hyperedges(&mut self) -> Result<(), HypergraphError<V, HE>> {
-        // Clear the set while keeping its capacity.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            err,
+            fields(
+                vertices_count = self.vertices.len(),
+                hyperedges_count = self.hyperedges.len(),
+            )
+        )
+    )]
+    pub fn clear_hyperedges(&mut self) -> Result<(), HypergraphError> {
+        // Clear the vector and the weights index while keeping their
+        // capacities.
         self.hyperedges.clear();
+        #[cfg(feature = "roaring")]
+        self.hyperedges_bitmaps.clear();
+        self.hyperedges_weights.clear();
+        self.hyperedges_cardinalities.clear();
 
         // Reset the hyperedges mapping.
         self.hyperedges_mapping = BiHashMap::default();
 
-        // Reset the hyperedges counter.
+        // Reset the hyperedges counter and the recycled indexes.
         self.hyperedges_count = 0;
+        self.hyperedges_recycled.clear();
+
+        // Drop the adjacency cache and the distance oracle.
+        self.adjacency_cache = None;
+        self.distance_oracle = None;
+
+        // Clear the properties, the roles, the versions and the tags, and
+        // empty the layers while keeping them.
+        self.hyperedges_properties.clear();
+        self.hyperedges_roles.clear();
+        self.hyperedges_versions.clear();
+        self.hyperedges_tags.clear();
+        self.hyperedges_layers.clear();
+
+        for layer in self.layers.values_mut() {
+            layer.hyperedges.clear();
+        }
+
+        // Clear the full-text search index while keeping it enabled.
+        if let Some(search_index) = self.hyperedges_search_index.as_mut() {
+            search_index.clear();
+        }
+
+        // Clear the approximate membership filter while keeping it enabled.
+        #[cfg(feature = "bloom")]
+        if let Some(filter) = self.hyperedges_filter.as_mut() {
+            filter.clear();
+        }
 
         // Update the vertices accordingly.
         self.vertices
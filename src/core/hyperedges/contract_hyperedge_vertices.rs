@@ -1,5 +1,4 @@
 use itertools::Itertools;
-use rayon::prelude::*;
 
 use crate::{
     HyperedgeIndex,
@@ -7,7 +6,10 @@ use crate::{
     Hypergraph,
     VertexIndex,
     VertexTrait,
-    core::utils::are_slices_equal,
+    core::{
+        parallel::*,
+        utils::are_slices_equal,
+    },
     errors::HypergraphError,
 };
 
@@ -19,12 +21,27 @@ where
     /// Contracts a set of the vertices of a hyperedge into one single vertex.
     /// Returns the updated vertices.
     /// Based on <https://en.wikipedia.org/wiki/Edge_contraction>
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            err,
+            fields(
+                ?hyperedge_index,
+                ?vertices,
+                ?target,
+                vertices_count = self.vertices.len(),
+                hyperedges_count = self.hyperedges.len(),
+            )
+        )
+    )]
     pub fn contract_hyperedge_vertices(
         &mut self,
         hyperedge_index: HyperedgeIndex,
         vertices: Vec<VertexIndex>,
         target: VertexIndex,
-    ) -> Result<Vec<VertexIndex>, HypergraphError<V, HE>> {
+    ) -> Result<Vec<VertexIndex>, HypergraphError> {
         // Get all the vertices of the hyperedge.
         let hyperedge_vertices = self.get_hyperedge_vertices(hyperedge_index)?;
 
@@ -86,8 +103,18 @@ where
             all_hyperedges.append(&mut vertex_hyperedges);
         }
 
+        let all_hyperedges = all_hyperedges
+            .into_iter()
+            .sorted()
+            .dedup()
+            .collect::<Vec<HyperedgeIndex>>();
+
+        // Check upfront that the target doesn't exceed the maximum degree
+        // once part of all the hyperedges, before any mutation.
+        self.check_vertex_degree(self.get_internal_vertex(target)?, all_hyperedges.len())?;
+
         // Iterate over all the deduped hyperedges.
-        for &hyperedge in all_hyperedges.iter().sorted().dedup() {
+        for &hyperedge in all_hyperedges.iter() {
             let hyperedge_vertices = self.get_hyperedge_vertices(hyperedge)?;
 
             // Contract the vertices of the hyperedge.
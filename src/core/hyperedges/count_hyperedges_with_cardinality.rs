@@ -0,0 +1,18 @@
+use crate::{
+    HyperedgeTrait,
+    Hypergraph,
+    VertexTrait,
+};
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Returns the number of hyperedges with the given cardinality - i.e.
+    /// number of vertices, duplicates included - in constant time.
+    pub fn count_hyperedges_with_cardinality(&self, cardinality: usize) -> usize {
+        self.hyperedges_cardinalities
+            .count(cardinality..=cardinality)
+    }
+}
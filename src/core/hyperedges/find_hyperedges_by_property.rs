@@ -0,0 +1,34 @@
+use crate::{
+    HyperedgeIndex,
+    HyperedgeTrait,
+    Hypergraph,
+    Value,
+    VertexTrait,
+};
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Finds the hyperedges whose property matches a given value.
+    /// Hyperedges are returned by ascending index.
+    pub fn find_hyperedges_by_property(
+        &self,
+        key: &str,
+        value: impl Into<Value>,
+    ) -> Vec<HyperedgeIndex> {
+        let value = value.into();
+
+        let mut results = self
+            .hyperedges_properties
+            .iter()
+            .filter(|(_, properties)| properties.get(key) == Some(&value))
+            .map(|(hyperedge_index, _)| *hyperedge_index)
+            .collect::<Vec<HyperedgeIndex>>();
+
+        results.sort_unstable();
+
+        results
+    }
+}
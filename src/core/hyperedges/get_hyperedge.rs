@@ -15,7 +15,7 @@ where
     pub(crate) fn get_hyperedge(
         &self,
         hyperedge_index: usize,
-    ) -> Result<HyperedgeIndex, HypergraphError<V, HE>> {
+    ) -> Result<HyperedgeIndex, HypergraphError> {
         match self.hyperedges_mapping.left.get(&hyperedge_index) {
             Some(index) => Ok(*index),
             None => Err(HypergraphError::InternalHyperedgeIndexNotFound(
@@ -0,0 +1,88 @@
+use std::collections::VecDeque;
+
+use crate::{
+    HyperedgeIndex,
+    HyperedgeKey,
+    HyperedgeTrait,
+    Hypergraph,
+    VertexTrait,
+    errors::HypergraphError,
+};
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Gets one of the shortest sequences of hyperedges connecting a
+    /// hyperedge to another, two consecutive hyperedges sharing at least one
+    /// vertex regardless of its position, i.e. a path in the line graph.
+    /// Returns an empty vector if the hyperedges are not connected.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            err,
+            fields(
+                ?from,
+                ?to,
+                vertices_count = self.vertices.len(),
+                hyperedges_count = self.hyperedges.len(),
+            )
+        )
+    )]
+    pub fn get_hyperedge_path(
+        &self,
+        from: HyperedgeIndex,
+        to: HyperedgeIndex,
+    ) -> Result<Vec<HyperedgeIndex>, HypergraphError> {
+        let internal_from = self.get_internal_hyperedge(from)?;
+        let internal_to = self.get_internal_hyperedge(to)?;
+
+        // Keep track of the predecessor of each visited hyperedge.
+        let mut predecessors = vec![None; self.hyperedges.len()];
+        let mut visited = vec![false; self.hyperedges.len()];
+        let mut queue = VecDeque::new();
+
+        visited[internal_from] = true;
+        queue.push_back(internal_from);
+
+        while let Some(hyperedge) = queue.pop_front() {
+            if hyperedge == internal_to {
+                let mut path = vec![hyperedge];
+
+                while let Some(predecessor) = predecessors[path[path.len() - 1]] {
+                    path.push(predecessor);
+                }
+
+                path.reverse();
+
+                return self.get_hyperedges(&path);
+            }
+
+            let HyperedgeKey { vertices, .. } = self
+                .hyperedges
+                .get(hyperedge)
+                .ok_or(HypergraphError::InternalHyperedgeIndexNotFound(hyperedge))?;
+
+            // Visit the hyperedges sharing a vertex with the current one.
+            for vertex in vertices {
+                let (_, hyperedges) = self
+                    .vertices
+                    .get_index(*vertex)
+                    .ok_or(HypergraphError::InternalVertexIndexNotFound(*vertex))?;
+
+                for adjacent_hyperedge in hyperedges {
+                    if !visited[*adjacent_hyperedge] {
+                        visited[*adjacent_hyperedge] = true;
+                        predecessors[*adjacent_hyperedge] = Some(hyperedge);
+                        queue.push_back(*adjacent_hyperedge);
+                    }
+                }
+            }
+        }
+
+        Ok(vec![])
+    }
+}
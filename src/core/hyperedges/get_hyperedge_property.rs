@@ -0,0 +1,29 @@
+use crate::{
+    HyperedgeIndex,
+    HyperedgeTrait,
+    Hypergraph,
+    Value,
+    VertexTrait,
+    errors::HypergraphError,
+};
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Gets a property of a hyperedge.
+    pub fn get_hyperedge_property(
+        &self,
+        hyperedge_index: HyperedgeIndex,
+        key: &str,
+    ) -> Result<Option<&Value>, HypergraphError> {
+        // Check that the hyperedge exists.
+        self.get_internal_hyperedge(hyperedge_index)?;
+
+        Ok(self
+            .hyperedges_properties
+            .get(&hyperedge_index)
+            .and_then(|properties| properties.get(key)))
+    }
+}
@@ -0,0 +1,37 @@
+use crate::{
+    HyperedgeIndex,
+    HyperedgeTrait,
+    Hypergraph,
+    VertexIndex,
+    VertexTrait,
+    errors::HypergraphError,
+};
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Gets the vertices of a hyperedge along with their roles, if any.
+    pub fn get_hyperedge_roles(
+        &self,
+        hyperedge_index: HyperedgeIndex,
+    ) -> Result<Vec<(VertexIndex, Option<&str>)>, HypergraphError> {
+        let vertices = self.get_hyperedge_vertices(hyperedge_index)?;
+
+        let roles = self.hyperedges_roles.get(&hyperedge_index);
+
+        Ok(vertices
+            .into_iter()
+            .enumerate()
+            .map(|(position, vertex_index)| {
+                (
+                    vertex_index,
+                    roles
+                        .and_then(|roles| roles.get(position))
+                        .map(String::as_str),
+                )
+            })
+            .collect())
+    }
+}
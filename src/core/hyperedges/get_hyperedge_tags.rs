@@ -0,0 +1,30 @@
+use crate::{
+    HyperedgeIndex,
+    HyperedgeTrait,
+    Hypergraph,
+    VertexTrait,
+    errors::HypergraphError,
+};
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Gets the tags of a hyperedge, in the order in which the tags have
+    /// been created.
+    pub fn get_hyperedge_tags(
+        &self,
+        hyperedge_index: HyperedgeIndex,
+    ) -> Result<Vec<&str>, HypergraphError> {
+        // Check that the hyperedge exists.
+        self.get_internal_hyperedge(hyperedge_index)?;
+
+        Ok(self
+            .hyperedges_tags
+            .iter()
+            .filter(|(_, hyperedges)| hyperedges.contains(&hyperedge_index))
+            .map(|(tag, _)| tag.as_str())
+            .collect())
+    }
+}
@@ -17,13 +17,32 @@ where
     pub fn get_hyperedge_vertices(
         &self,
         hyperedge_index: HyperedgeIndex,
-    ) -> Result<Vec<VertexIndex>, HypergraphError<V, HE>> {
+    ) -> Result<Vec<VertexIndex>, HypergraphError> {
         let internal_index = self.get_internal_hyperedge(hyperedge_index)?;
 
-        let HyperedgeKey { vertices, .. } = self.hyperedges.get_index(internal_index).ok_or(
+        let HyperedgeKey { vertices, .. } = self.hyperedges.get(internal_index).ok_or(
             HypergraphError::InternalHyperedgeIndexNotFound(internal_index),
         )?;
 
         self.get_vertices(vertices)
     }
+
+    /// Gets the vertices of a hyperedge as an iterator, mapping them lazily
+    /// without allocating.
+    pub fn get_hyperedge_vertices_iter(
+        &self,
+        hyperedge_index: HyperedgeIndex,
+    ) -> Result<impl Iterator<Item = VertexIndex> + '_, HypergraphError> {
+        let internal_index = self.get_internal_hyperedge(hyperedge_index)?;
+
+        let HyperedgeKey { vertices, .. } = self.hyperedges.get(internal_index).ok_or(
+            HypergraphError::InternalHyperedgeIndexNotFound(internal_index),
+        )?;
+
+        // The internal indexes of the vertices of a hyperedge are always
+        // mapped, hence nothing is skipped here.
+        Ok(vertices
+            .iter()
+            .filter_map(move |vertex| self.vertices_mapping.left.get(vertex).copied()))
+    }
 }
@@ -0,0 +1,33 @@
+use crate::{
+    HyperedgeIndex,
+    HyperedgeTrait,
+    Hypergraph,
+    VertexIndex,
+    VertexTrait,
+    errors::HypergraphError,
+};
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Gets the vertices of a hyperedge having a given role, in order.
+    pub fn get_hyperedge_vertices_by_role(
+        &self,
+        hyperedge_index: HyperedgeIndex,
+        role: &str,
+    ) -> Result<Vec<VertexIndex>, HypergraphError> {
+        Ok(self
+            .get_hyperedge_roles(hyperedge_index)?
+            .into_iter()
+            .filter_map(|(vertex_index, current_role)| {
+                if current_role == Some(role) {
+                    Some(vertex_index)
+                } else {
+                    None
+                }
+            })
+            .collect())
+    }
+}
@@ -15,12 +15,12 @@ where
     pub fn get_hyperedge_weight(
         &self,
         hyperedge_index: HyperedgeIndex,
-    ) -> Result<&HE, HypergraphError<V, HE>> {
+    ) -> Result<&HE, HypergraphError> {
         let internal_index = self.get_internal_hyperedge(hyperedge_index)?;
 
         let hyperedge_key = self
             .hyperedges
-            .get_index(internal_index)
+            .get(internal_index)
             .ok_or(HypergraphError::InternalVertexIndexNotFound(internal_index))?;
 
         Ok(&**hyperedge_key)
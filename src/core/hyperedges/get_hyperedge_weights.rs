@@ -0,0 +1,25 @@
+use crate::{
+    HyperedgeIndex,
+    HyperedgeTrait,
+    Hypergraph,
+    VertexTrait,
+    errors::HypergraphError,
+};
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Gets the weights of many hyperedges at once from their indexes, in
+    /// the same order, as references without copying them.
+    pub fn get_hyperedge_weights(
+        &self,
+        hyperedges: &[HyperedgeIndex],
+    ) -> Result<Vec<&HE>, HypergraphError> {
+        hyperedges
+            .iter()
+            .map(|hyperedge_index| self.get_hyperedge_weight(*hyperedge_index))
+            .collect()
+    }
+}
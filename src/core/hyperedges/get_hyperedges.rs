@@ -1,10 +1,9 @@
-use rayon::prelude::*;
-
 use crate::{
     HyperedgeIndex,
     HyperedgeTrait,
     Hypergraph,
     VertexTrait,
+    core::parallel::*,
     errors::HypergraphError,
 };
 
@@ -17,7 +16,7 @@ where
     pub(crate) fn get_hyperedges(
         &self,
         hyperedges: &[usize],
-    ) -> Result<Vec<HyperedgeIndex>, HypergraphError<V, HE>> {
+    ) -> Result<Vec<HyperedgeIndex>, HypergraphError> {
         hyperedges
             .par_iter()
             .map(|hyperedge_index| self.get_hyperedge(*hyperedge_index))
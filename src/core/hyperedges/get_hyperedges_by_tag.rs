@@ -0,0 +1,26 @@
+use crate::{
+    HyperedgeIndex,
+    HyperedgeTrait,
+    Hypergraph,
+    VertexTrait,
+};
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Gets the hyperedges having a given tag.
+    /// Hyperedges are returned by ascending index.
+    pub fn get_hyperedges_by_tag(&self, tag: &str) -> Vec<HyperedgeIndex> {
+        let mut results = self
+            .hyperedges_tags
+            .get(tag)
+            .map(|hyperedges| hyperedges.iter().copied().collect::<Vec<HyperedgeIndex>>())
+            .unwrap_or_default();
+
+        results.sort_unstable();
+
+        results
+    }
+}
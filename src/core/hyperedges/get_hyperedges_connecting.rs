@@ -1,12 +1,13 @@
-use rayon::prelude::*;
-
 use crate::{
     HyperedgeIndex,
     HyperedgeTrait,
     Hypergraph,
     VertexIndex,
     VertexTrait,
-    core::shared::Connection,
+    core::{
+        parallel::*,
+        shared::Connection,
+    },
     errors::HypergraphError,
 };
 
@@ -16,11 +17,25 @@ where
     HE: HyperedgeTrait,
 {
     /// Gets the hyperedges directly connecting a vertex to another.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            err,
+            fields(
+                ?from,
+                ?to,
+                vertices_count = self.vertices.len(),
+                hyperedges_count = self.hyperedges.len(),
+            )
+        )
+    )]
     pub fn get_hyperedges_connecting(
         &self,
         from: VertexIndex,
         to: VertexIndex,
-    ) -> Result<Vec<HyperedgeIndex>, HypergraphError<V, HE>> {
+    ) -> Result<Vec<HyperedgeIndex>, HypergraphError> {
         let results = self.get_connections(&Connection::InAndOut(from, to))?;
 
         Ok(results
@@ -0,0 +1,56 @@
+use crate::{
+    HyperedgeIndex,
+    HyperedgeKey,
+    HyperedgeTrait,
+    Hypergraph,
+    VertexIndex,
+    VertexTrait,
+    errors::HypergraphError,
+};
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Gets the hyperedges directly connecting a vertex to another like
+    /// `get_hyperedges_connecting`, along with the positions where the
+    /// transition occurs in each of them, i.e. the indexes of the windows of
+    /// two consecutive vertices starting with the first vertex and ending
+    /// with the second one.
+    /// Each connecting hyperedge is returned once.
+    pub fn get_hyperedges_connecting_with_positions(
+        &self,
+        from: VertexIndex,
+        to: VertexIndex,
+    ) -> Result<Vec<(HyperedgeIndex, Vec<usize>)>, HypergraphError> {
+        let internal_from = self.get_internal_vertex(from)?;
+        let internal_to = self.get_internal_vertex(to)?;
+
+        let (_, hyperedges) = self
+            .vertices
+            .get_index(internal_from)
+            .ok_or(HypergraphError::InternalVertexIndexNotFound(internal_from))?;
+
+        let mut results = vec![];
+
+        for internal_hyperedge in hyperedges {
+            let HyperedgeKey { vertices, .. } = self.hyperedges.get(*internal_hyperedge).ok_or(
+                HypergraphError::InternalHyperedgeIndexNotFound(*internal_hyperedge),
+            )?;
+
+            let positions = vertices
+                .windows(2)
+                .enumerate()
+                .filter(|(_, window)| window[0] == internal_from && window[1] == internal_to)
+                .map(|(position, _)| position)
+                .collect::<Vec<usize>>();
+
+            if !positions.is_empty() {
+                results.push((self.get_hyperedge(*internal_hyperedge)?, positions));
+            }
+        }
+
+        Ok(results)
+    }
+}
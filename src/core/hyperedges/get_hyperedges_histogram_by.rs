@@ -0,0 +1,37 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    HyperedgeRef,
+    HyperedgeTrait,
+    Hypergraph,
+    VertexTrait,
+    errors::HypergraphError,
+};
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Gets the histogram of the hyperedges by key, e.g. a bucket of their
+    /// costs, as the number of hyperedges per key, sorted by key.
+    /// The key is computed once per hyperedge from a handle to it.
+    pub fn get_hyperedges_histogram_by<K, F>(
+        &self,
+        mut key: F,
+    ) -> Result<BTreeMap<K, usize>, HypergraphError>
+    where
+        K: Ord,
+        F: FnMut(HyperedgeRef<'_, V, HE>) -> K,
+    {
+        let mut histogram = BTreeMap::new();
+
+        for internal_index in 0..self.hyperedges.len() {
+            *histogram
+                .entry(key(self.hyperedge(self.get_hyperedge(internal_index)?)?))
+                .or_insert(0) += 1;
+        }
+
+        Ok(histogram)
+    }
+}
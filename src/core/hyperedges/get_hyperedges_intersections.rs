@@ -1,3 +1,8 @@
+use std::sync::atomic::{
+    AtomicBool,
+    Ordering,
+};
+
 use itertools::Itertools;
 
 use crate::{
@@ -19,7 +24,42 @@ where
     pub fn get_hyperedges_intersections(
         &self,
         hyperedges: Vec<HyperedgeIndex>,
-    ) -> Result<Vec<VertexIndex>, HypergraphError<V, HE>> {
+    ) -> Result<Vec<VertexIndex>, HypergraphError> {
+        self.get_hyperedges_intersections_inner(hyperedges, None)
+    }
+
+    /// Gets the intersections of a set of hyperedges like
+    /// `get_hyperedges_intersections` while checking a cancellation flag
+    /// before processing each hyperedge. Setting the flag from another thread
+    /// aborts the query with a `Cancelled` error.
+    pub fn get_hyperedges_intersections_cancellable(
+        &self,
+        hyperedges: Vec<HyperedgeIndex>,
+        cancelled: &AtomicBool,
+    ) -> Result<Vec<VertexIndex>, HypergraphError> {
+        self.get_hyperedges_intersections_inner(hyperedges, Some(cancelled))
+    }
+
+    // Private method to get the intersections of a set of hyperedges with an
+    // optional cancellation flag.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            err,
+            fields(
+                ?hyperedges,
+                vertices_count = self.vertices.len(),
+                hyperedges_count = self.hyperedges.len(),
+            )
+        )
+    )]
+    fn get_hyperedges_intersections_inner(
+        &self,
+        hyperedges: Vec<HyperedgeIndex>,
+        cancelled: Option<&AtomicBool>,
+    ) -> Result<Vec<VertexIndex>, HypergraphError> {
         // Keep track of the number of hyperedges.
         let number_of_hyperedges = hyperedges.len();
 
@@ -32,10 +72,15 @@ where
         let vertices = hyperedges
             .into_iter()
             .map(|hyperedge_index| {
+                // Abort if the query has been cancelled.
+                if cancelled.map_or(false, |cancelled| cancelled.load(Ordering::Relaxed)) {
+                    return Err(HypergraphError::Cancelled);
+                }
+
                 self.get_internal_hyperedge(hyperedge_index)
                     .and_then(|internal_index| {
                         self.hyperedges
-                            .get_index(internal_index)
+                            .get(internal_index)
                             .ok_or(HypergraphError::InternalHyperedgeIndexNotFound(
                                 internal_index,
                             ))
@@ -44,7 +89,7 @@ where
                             })
                     })
             })
-            .collect::<Result<Vec<Vec<usize>>, HypergraphError<V, HE>>>();
+            .collect::<Result<Vec<Vec<usize>>, HypergraphError>>();
 
         vertices.and_then(|vertices| {
             self.get_vertices(
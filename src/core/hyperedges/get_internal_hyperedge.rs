@@ -15,7 +15,7 @@ where
     pub(crate) fn get_internal_hyperedge(
         &self,
         hyperedge_index: HyperedgeIndex,
-    ) -> Result<usize, HypergraphError<V, HE>> {
+    ) -> Result<usize, HypergraphError> {
         match self.hyperedges_mapping.right.get(&hyperedge_index) {
             Some(index) => Ok(*index),
             None => Err(HypergraphError::HyperedgeIndexNotFound(hyperedge_index)),
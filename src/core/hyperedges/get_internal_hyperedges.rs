@@ -15,7 +15,7 @@ where
     pub(crate) fn get_internal_hyperedges(
         &self,
         hyperedges: &[HyperedgeIndex],
-    ) -> Result<Vec<usize>, HypergraphError<V, HE>> {
+    ) -> Result<Vec<usize>, HypergraphError> {
         hyperedges
             .iter()
             .map(|hyperedge_index| self.get_internal_hyperedge(*hyperedge_index))
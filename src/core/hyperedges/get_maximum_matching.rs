@@ -0,0 +1,67 @@
+use crate::{
+    HyperedgeIndex,
+    HyperedgeKey,
+    HyperedgeTrait,
+    Hypergraph,
+    VertexTrait,
+    core::types::AIndexSet,
+    errors::HypergraphError,
+};
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Gets an approximation of a maximum matching, i.e. a largest set of
+    /// pairwise vertex-disjoint hyperedges.
+    /// Hyperedges are greedily picked by increasing number of distinct
+    /// vertices, which guarantees a maximal matching whose size is within a
+    /// factor of the largest hyperedge cardinality of the optimum.
+    /// <https://en.wikipedia.org/wiki/Matching_in_hypergraphs>
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            err,
+            fields(
+                vertices_count = self.vertices.len(),
+                hyperedges_count = self.hyperedges.len(),
+            )
+        )
+    )]
+    pub fn get_maximum_matching(&self) -> Result<Vec<HyperedgeIndex>, HypergraphError> {
+        let mut candidates = self
+            .hyperedges
+            .iter()
+            .enumerate()
+            .map(|(internal_index, HyperedgeKey { vertices, .. })| {
+                (
+                    internal_index,
+                    vertices.iter().copied().collect::<AIndexSet<usize>>(),
+                )
+            })
+            .collect::<Vec<(usize, AIndexSet<usize>)>>();
+
+        // The sort is stable so that ties are broken by insertion order.
+        candidates.sort_by_key(|(_, vertices)| vertices.len());
+
+        let mut covered = vec![false; self.vertices.len()];
+        let mut matching = Vec::new();
+
+        for (internal_index, vertices) in candidates {
+            if vertices.iter().all(|vertex| !covered[*vertex]) {
+                for vertex in vertices {
+                    covered[vertex] = true;
+                }
+
+                matching.push(internal_index);
+            }
+        }
+
+        matching.sort_unstable();
+
+        self.get_hyperedges(&matching)
+    }
+}
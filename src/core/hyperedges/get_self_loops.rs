@@ -0,0 +1,33 @@
+use crate::{
+    HyperedgeIndex,
+    HyperedgeKey,
+    HyperedgeTrait,
+    Hypergraph,
+    VertexTrait,
+    errors::HypergraphError,
+};
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Gets the self-looping hyperedges as a vector of `HyperedgeIndex`.
+    /// See `is_self_looping` for more details.
+    pub fn get_self_loops(&self) -> Result<Vec<HyperedgeIndex>, HypergraphError> {
+        self.get_hyperedges(
+            &self
+                .hyperedges
+                .iter()
+                .enumerate()
+                .filter_map(|(internal_index, HyperedgeKey { vertices, .. })| {
+                    if vertices.windows(2).any(|pair| pair[0] == pair[1]) {
+                        Some(internal_index)
+                    } else {
+                        None
+                    }
+                })
+                .collect::<Vec<usize>>(),
+        )
+    }
+}
@@ -0,0 +1,17 @@
+use crate::{
+    HyperedgeTrait,
+    Hypergraph,
+    VertexTrait,
+};
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Gets the tags assigned to at least one hyperedge, in the order in
+    /// which they have been created.
+    pub fn get_tags(&self) -> Vec<&str> {
+        self.hyperedges_tags.keys().map(String::as_str).collect()
+    }
+}
@@ -0,0 +1,33 @@
+use crate::{
+    HyperedgeIndex,
+    HyperedgeKey,
+    HyperedgeTrait,
+    Hypergraph,
+    VertexTrait,
+    errors::HypergraphError,
+};
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Gets the unary hyperedges - i.e. the ones containing a unique vertex -
+    /// as a vector of `HyperedgeIndex`.
+    pub fn get_unary_hyperedges(&self) -> Result<Vec<HyperedgeIndex>, HypergraphError> {
+        self.get_hyperedges(
+            &self
+                .hyperedges
+                .iter()
+                .enumerate()
+                .filter_map(|(internal_index, HyperedgeKey { vertices, .. })| {
+                    if vertices.len() == 1 {
+                        Some(internal_index)
+                    } else {
+                        None
+                    }
+                })
+                .collect::<Vec<usize>>(),
+        )
+    }
+}
@@ -0,0 +1,29 @@
+use crate::{
+    HyperedgeIndex,
+    HyperedgeKey,
+    HyperedgeTrait,
+    Hypergraph,
+    VertexTrait,
+    errors::HypergraphError,
+};
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Checks if a hyperedge is self-looping, i.e. if at least one of its
+    /// vertices is directed to itself.
+    pub fn is_self_looping(
+        &self,
+        hyperedge_index: HyperedgeIndex,
+    ) -> Result<bool, HypergraphError> {
+        let internal_index = self.get_internal_hyperedge(hyperedge_index)?;
+
+        let HyperedgeKey { vertices, .. } = self.hyperedges.get(internal_index).ok_or(
+            HypergraphError::InternalHyperedgeIndexNotFound(internal_index),
+        )?;
+
+        Ok(vertices.windows(2).any(|pair| pair[0] == pair[1]))
+    }
+}
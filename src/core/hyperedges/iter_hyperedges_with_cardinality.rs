@@ -0,0 +1,39 @@
+use std::ops::RangeBounds;
+
+use crate::{
+    HyperedgeIndex,
+    HyperedgeKey,
+    HyperedgeTrait,
+    Hypergraph,
+    VertexTrait,
+};
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Iterates over the hyperedges whose cardinality - i.e. number of
+    /// vertices, duplicates included - is within the range, following the
+    /// insertion order. The iteration stops as soon as all the matching
+    /// hyperedges have been found, according to the histogram of the
+    /// cardinalities.
+    pub fn iter_hyperedges_with_cardinality<R>(
+        &self,
+        range: R,
+    ) -> impl Iterator<Item = HyperedgeIndex> + '_
+    where
+        R: RangeBounds<usize> + Clone + 'static,
+    {
+        let count = self.hyperedges_cardinalities.count(range.clone());
+
+        self.hyperedges
+            .iter()
+            .enumerate()
+            .filter(move |(_, HyperedgeKey { vertices, .. })| range.contains(&vertices.len()))
+            .filter_map(|(internal_index, _)| {
+                self.hyperedges_mapping.left.get(&internal_index).copied()
+            })
+            .take(count)
+    }
+}
@@ -1,11 +1,10 @@
-use rayon::prelude::*;
-
 use crate::{
     HyperedgeIndex,
     HyperedgeTrait,
     Hypergraph,
     VertexIndex,
     VertexTrait,
+    core::parallel::*,
     errors::HypergraphError,
 };
 
@@ -15,11 +14,25 @@ where
     HE: HyperedgeTrait,
 {
     /// Joins two or more hyperedges from the hypergraph into one single entity.
-    /// All the vertices are moved to the first hyperedge in the provided order.
+    /// All the vertices are moved to the first hyperedge in the provided order,
+    /// deduped according to the vertex dedup policy of the hypergraph.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            err,
+            fields(
+                ?hyperedges,
+                vertices_count = self.vertices.len(),
+                hyperedges_count = self.hyperedges.len(),
+            )
+        )
+    )]
     pub fn join_hyperedges(
         &mut self,
         hyperedges: &[HyperedgeIndex],
-    ) -> Result<(), HypergraphError<V, HE>> {
+    ) -> Result<(), HypergraphError> {
         // If the provided hyperedges are less than two, skip the operation.
         if hyperedges.len() < 2 {
             return Err(HypergraphError::HyperedgesInvalidJoin);
@@ -29,26 +42,45 @@ where
         match hyperedges
             .par_iter()
             .map(|hyperedge_index| self.get_hyperedge_vertices(*hyperedge_index))
-            .collect::<Result<Vec<Vec<VertexIndex>>, HypergraphError<V, HE>>>()
+            .collect::<Result<Vec<Vec<VertexIndex>>, HypergraphError>>()
         {
             Err(err) => Err(err),
             Ok(joined_vertices) => {
-                // The goal is to move all the vertices from the provided
-                // hyperedges to the first one.
-                self.update_hyperedge_vertices(
-                    hyperedges[0],
-                    joined_vertices.into_par_iter().flatten().collect(),
-                )?;
+                let first_vertices = joined_vertices[0].clone();
+                let joined_vertices = self.vertex_dedup_policy.dedup(
+                    joined_vertices
+                        .into_par_iter()
+                        .flatten()
+                        .collect::<Vec<VertexIndex>>(),
+                );
+
+                // Check upfront that the joined vertices comply with the
+                // duplicate policy and with the constraints, before any
+                // mutation. The joined hyperedges don't count in the degrees
+                // of the vertices.
+                let internal_hyperedges = self.get_internal_hyperedges(hyperedges)?;
+                let internal_vertices = self.get_internal_vertices(&joined_vertices)?;
+
+                self.check_duplicate_vertices(&internal_vertices, Some(internal_hyperedges[0]))?;
+                self.check_constraints(&internal_vertices, &internal_hyperedges)?;
 
                 // Get the tail.
                 let tail = &hyperedges[1..];
 
-                // Removes the other hyperedges.
+                // Removes the other hyperedges first so that their vertices
+                // are not counted twice in the degrees.
                 for hyperedge_index in tail {
                     self.remove_hyperedge(*hyperedge_index)?;
                 }
 
-                Ok(())
+                // The goal is to move all the vertices from the provided
+                // hyperedges to the first one, which is left untouched if it
+                // already includes them once deduped.
+                if joined_vertices == first_vertices {
+                    return Ok(());
+                }
+
+                self.update_hyperedge_vertices(hyperedges[0], joined_vertices)
             }
         }
     }
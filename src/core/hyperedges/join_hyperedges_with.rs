@@ -0,0 +1,60 @@
+use crate::{
+    HyperedgeIndex,
+    HyperedgeTrait,
+    Hypergraph,
+    VertexTrait,
+    errors::HypergraphError,
+};
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Joins two or more hyperedges like `join_hyperedges` while aggregating
+    /// their weights, e.g. by summing their costs or concatenating their
+    /// labels, instead of only keeping the weight of the first one.
+    /// The combiner is called with the weights in the provided order and its
+    /// result becomes the weight of the first hyperedge. It must not be
+    /// assigned to any other hyperedge than the joined ones.
+    pub fn join_hyperedges_with<F>(
+        &mut self,
+        hyperedges: &[HyperedgeIndex],
+        combiner: F,
+    ) -> Result<(), HypergraphError>
+    where
+        F: FnOnce(Vec<HE>) -> HE,
+    {
+        // If the provided hyperedges are less than two, skip the operation.
+        if hyperedges.len() < 2 {
+            return Err(HypergraphError::HyperedgesInvalidJoin);
+        }
+
+        let weights = self
+            .get_hyperedge_weights(hyperedges)?
+            .into_iter()
+            .cloned()
+            .collect::<Vec<HE>>();
+        let previous_weight = weights[0].clone();
+        let weight = combiner(weights);
+
+        // Check upfront that the combined weight is not assigned to another
+        // hyperedge, before any mutation.
+        if let Some(other_index) = self.hyperedges_weights.get(&weight) {
+            let other_index = self.get_hyperedge(*other_index)?;
+
+            if !hyperedges.contains(&other_index) {
+                return Err(HypergraphError::HyperedgeWeightAlreadyAssigned(other_index));
+            }
+        }
+
+        self.join_hyperedges(hyperedges)?;
+
+        // The weights of the other hyperedges are released by the join.
+        if weight != previous_weight {
+            self.update_hyperedge_weight(hyperedges[0], weight)?;
+        }
+
+        Ok(())
+    }
+}
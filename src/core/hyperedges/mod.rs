@@ -3,17 +3,46 @@ pub(crate) mod get_hyperedge;
 pub(crate) mod get_hyperedges;
 pub(crate) mod get_internal_hyperedge;
 pub(crate) mod get_internal_hyperedges;
+pub(crate) mod retain_hyperedges;
 
 pub mod add_hyperedge;
+pub mod add_hyperedge_with_roles;
+pub mod canonicalize_hyperedge;
 pub mod clear_hyperedges;
 pub mod contract_hyperedge_vertices;
 pub mod count_hyperedges;
+pub mod count_hyperedges_with_cardinality;
+pub mod find_hyperedges_by_property;
+pub mod get_hyperedge_path;
+pub mod get_hyperedge_property;
+pub mod get_hyperedge_roles;
+pub mod get_hyperedge_tags;
 pub mod get_hyperedge_vertices;
+pub mod get_hyperedge_vertices_by_role;
 pub mod get_hyperedge_weight;
+pub mod get_hyperedge_weights;
+pub mod get_hyperedges_by_tag;
 pub mod get_hyperedges_connecting;
+pub mod get_hyperedges_connecting_with_positions;
+pub mod get_hyperedges_histogram_by;
 pub mod get_hyperedges_intersections;
+pub mod get_maximum_matching;
+pub mod get_self_loops;
+pub mod get_tags;
+pub mod get_unary_hyperedges;
+pub mod is_self_looping;
+pub mod iter_hyperedges_with_cardinality;
 pub mod join_hyperedges;
+pub mod join_hyperedges_with;
+pub mod prune_hyperedges_by_cardinality;
 pub mod remove_hyperedge;
+pub mod remove_hyperedge_property;
+pub mod reverse_all_hyperedges;
 pub mod reverse_hyperedge;
+pub mod set_hyperedge_property;
+pub mod sort_hyperedges_by;
+pub mod tag_hyperedge;
+pub mod top_k_hyperedges_by;
+pub mod untag_hyperedge;
 pub mod update_hyperedge_vertices;
 pub mod update_hyperedge_weight;
@@ -0,0 +1,24 @@
+use crate::{
+    HyperedgeTrait,
+    Hypergraph,
+    VertexTrait,
+    errors::HypergraphError,
+};
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Removes all the hyperedges whose cardinality - i.e. their number of
+    /// vertices, duplicates included - is not within the inclusive range
+    /// from `min` to `max` in one single pass.
+    /// Returns the number of removed hyperedges.
+    pub fn prune_hyperedges_by_cardinality(
+        &mut self,
+        min: usize,
+        max: usize,
+    ) -> Result<usize, HypergraphError> {
+        self.retain_hyperedges(|vertices, _| (min..=max).contains(&vertices.len()))
+    }
+}
@@ -13,27 +13,61 @@ where
     HE: HyperedgeTrait,
 {
     /// Removes a hyperedge by index.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            err,
+            fields(
+                ?hyperedge_index,
+                vertices_count = self.vertices.len(),
+                hyperedges_count = self.hyperedges.len(),
+            )
+        )
+    )]
     pub fn remove_hyperedge(
         &mut self,
         hyperedge_index: HyperedgeIndex,
-    ) -> Result<(), HypergraphError<V, HE>> {
+    ) -> Result<(), HypergraphError> {
         let internal_index = self.get_internal_hyperedge(hyperedge_index)?;
 
-        let HyperedgeKey { vertices, .. } =
-            self.hyperedges.get_index(internal_index).cloned().ok_or(
+        let HyperedgeKey { vertices, weight } =
+            self.hyperedges.get(internal_index).cloned().ok_or(
                 HypergraphError::InternalHyperedgeIndexNotFound(internal_index),
             )?;
 
+        // Drop the adjacency cache and the distance oracle.
+        self.adjacency_cache = None;
+        self.distance_oracle = None;
+
         // Find the last index.
         let last_index = self.hyperedges.len() - 1;
 
         // Swap and remove by index.
-        self.hyperedges.swap_remove_index(internal_index);
+        self.hyperedges.swap_remove(internal_index);
+        #[cfg(feature = "roaring")]
+        self.hyperedges_bitmaps.swap_remove(internal_index);
+        self.hyperedges_weights.swap_remove(&weight);
+        self.hyperedges_cardinalities.remove(vertices.len());
 
         // Update the mapping for the removed hyperedge.
         self.hyperedges_mapping.left.remove(&internal_index);
         self.hyperedges_mapping.right.remove(&hyperedge_index);
 
+        // Remove the properties, the roles, the version, the tags and the
+        // layer of the hyperedge.
+        self.hyperedges_properties.swap_remove(&hyperedge_index);
+        self.hyperedges_roles.swap_remove(&hyperedge_index);
+        self.hyperedges_versions.swap_remove(&hyperedge_index);
+        self.untag_removed_hyperedge(hyperedge_index);
+        self.unlayer_removed_hyperedge(hyperedge_index);
+
+        // Remove the hyperedge from the full-text search index.
+        if let Some(search_index) = self.hyperedges_search_index.as_mut() {
+            search_index.remove(hyperedge_index);
+        }
+
         // Remove the hyperedge from the vertices.
         for vertex in vertices {
             match self.vertices.get_index_mut(vertex) {
@@ -106,11 +140,15 @@ where
             // Get the vertices of the swapped hyperedge.
             let HyperedgeKey {
                 vertices: swapped_vertices,
-                ..
-            } = self.hyperedges.get_index(internal_index).cloned().ok_or(
+                weight: swapped_weight,
+            } = self.hyperedges.get(internal_index).cloned().ok_or(
                 HypergraphError::InternalHyperedgeIndexNotFound(internal_index),
             )?;
 
+            // Update the weights index of the swapped hyperedge.
+            self.hyperedges_weights
+                .insert(swapped_weight, internal_index);
+
             // Update the impacted vertices accordingly.
             for vertex in swapped_vertices {
                 match self.vertices.get_index_mut(vertex) {
@@ -0,0 +1,30 @@
+use crate::{
+    HyperedgeIndex,
+    HyperedgeTrait,
+    Hypergraph,
+    Value,
+    VertexTrait,
+    errors::HypergraphError,
+};
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Removes a property of a hyperedge.
+    /// Returns the removed value, if any.
+    pub fn remove_hyperedge_property(
+        &mut self,
+        hyperedge_index: HyperedgeIndex,
+        key: &str,
+    ) -> Result<Option<Value>, HypergraphError> {
+        // Check that the hyperedge exists.
+        self.get_internal_hyperedge(hyperedge_index)?;
+
+        Ok(self
+            .hyperedges_properties
+            .get_mut(&hyperedge_index)
+            .and_then(|properties| properties.shift_remove(key)))
+    }
+}
@@ -0,0 +1,152 @@
+use crate::{
+    HyperedgeKey,
+    HyperedgeTrait,
+    Hypergraph,
+    VertexTrait,
+    bi_hash_map::BiHashMap,
+    errors::HypergraphError,
+};
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    // Private method to retain only the hyperedges matching a predicate in
+    // one single pass. The predicate is called with the internal vertices of
+    // the hyperedge and its weight.
+    // Unlike repeated calls to `remove_hyperedge`, the internal indexes are
+    // shifted only once and all the references are remapped at the end.
+    // Returns the number of removed hyperedges.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            err,
+            fields(
+                vertices_count = self.vertices.len(),
+                hyperedges_count = self.hyperedges.len(),
+            )
+        )
+    )]
+    pub(crate) fn retain_hyperedges<F>(
+        &mut self,
+        mut predicate: F,
+    ) -> Result<usize, HypergraphError>
+    where
+        F: FnMut(&[usize], &HE) -> bool,
+    {
+        // Compute the new internal indexes of the hyperedges to keep upfront.
+        let mut next_index = 0;
+        let remapping = self
+            .hyperedges
+            .iter()
+            .map(|HyperedgeKey { vertices, weight }| {
+                if predicate(vertices, weight) {
+                    next_index += 1;
+
+                    Some(next_index - 1)
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<Option<usize>>>();
+
+        let removed = self.hyperedges.len() - next_index;
+
+        // Early exit if there's nothing to remove.
+        if removed == 0 {
+            return Ok(0);
+        }
+
+        // Drop the adjacency cache and the distance oracle.
+        self.adjacency_cache = None;
+        self.distance_oracle = None;
+
+        // Rebuild the hyperedges while preserving their relative order.
+        let mut cursor = 0;
+
+        self.hyperedges.retain(|HyperedgeKey { vertices, .. }| {
+            cursor += 1;
+
+            let keep = remapping[cursor - 1].is_some();
+
+            if !keep {
+                self.hyperedges_cardinalities.remove(vertices.len());
+            }
+
+            keep
+        });
+
+        #[cfg(feature = "roaring")]
+        {
+            let mut cursor = 0;
+
+            self.hyperedges_bitmaps.retain(|_| {
+                cursor += 1;
+
+                remapping[cursor - 1].is_some()
+            });
+        }
+
+        // Rebuild the weights index.
+        self.hyperedges_weights = self
+            .hyperedges
+            .iter()
+            .enumerate()
+            .map(|(internal_index, HyperedgeKey { weight, .. })| (weight.clone(), internal_index))
+            .collect();
+
+        // Remap the stable indexes.
+        let mut hyperedges_mapping = BiHashMap::default();
+
+        for (internal_index, hyperedge_index) in self.hyperedges_mapping.left.drain() {
+            if let Some(Some(updated_index)) = remapping.get(internal_index) {
+                hyperedges_mapping
+                    .left
+                    .insert(*updated_index, hyperedge_index);
+                hyperedges_mapping
+                    .right
+                    .insert(hyperedge_index, *updated_index);
+            } else {
+                // Remove the properties, the roles, the version, the tags and
+                // the layer of the removed hyperedge.
+                self.hyperedges_properties.swap_remove(&hyperedge_index);
+                self.hyperedges_roles.swap_remove(&hyperedge_index);
+                self.hyperedges_versions.swap_remove(&hyperedge_index);
+
+                for hyperedges in self.hyperedges_tags.values_mut() {
+                    hyperedges.swap_remove(&hyperedge_index);
+                }
+
+                if let Some(layer_index) = self.hyperedges_layers.swap_remove(&hyperedge_index) {
+                    if let Some(layer) = self.layers.get_mut(&layer_index) {
+                        layer.hyperedges.swap_remove(&hyperedge_index);
+                    }
+                }
+
+                // Remove the hyperedge from the full-text search index.
+                if let Some(search_index) = self.hyperedges_search_index.as_mut() {
+                    search_index.remove(hyperedge_index);
+                }
+            }
+        }
+
+        self.hyperedges_mapping = hyperedges_mapping;
+
+        // Drop the tags which are now unused.
+        self.hyperedges_tags
+            .retain(|_, hyperedges| !hyperedges.is_empty());
+
+        // Remap the hyperedges of the vertices.
+        for (_, hyperedges) in self.vertices.iter_mut() {
+            *hyperedges = hyperedges
+                .iter()
+                .filter_map(|hyperedge| remapping.get(*hyperedge).copied().flatten())
+                .collect();
+        }
+
+        Ok(removed)
+    }
+}
@@ -0,0 +1,45 @@
+use crate::{
+    HyperedgeKey,
+    HyperedgeTrait,
+    Hypergraph,
+    VertexTrait,
+    core::parallel::*,
+};
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Reverses all the hyperedges in place, i.e. inverts the direction of
+    /// the whole hypergraph. The roles of the vertices are reversed too.
+    /// Unlike `reverse_hyperedge`, the hyperedges whose vertices are
+    /// unchanged by the reversal are not an error.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            fields(
+                vertices_count = self.vertices.len(),
+                hyperedges_count = self.hyperedges.len(),
+            )
+        )
+    )]
+    pub fn reverse_all_hyperedges(&mut self) {
+        // The vertices of the hyperedges are left untouched, only their
+        // order changes, which preserves both the duplicate policy and the
+        // constraints.
+        self.hyperedges
+            .par_iter_mut()
+            .for_each(|HyperedgeKey { vertices, .. }| vertices.reverse());
+
+        self.hyperedges_roles
+            .par_values_mut()
+            .for_each(|roles| roles.reverse());
+
+        // Drop the adjacency cache and the distance oracle.
+        self.adjacency_cache = None;
+        self.distance_oracle = None;
+    }
+}
@@ -1,10 +1,9 @@
-use rayon::prelude::*;
-
 use crate::{
     HyperedgeIndex,
     HyperedgeTrait,
     Hypergraph,
     VertexTrait,
+    core::parallel::*,
     errors::HypergraphError,
 };
 
@@ -14,14 +13,39 @@ where
     HE: HyperedgeTrait,
 {
     // Reverses a hyperedge.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            err,
+            fields(
+                ?hyperedge_index,
+                vertices_count = self.vertices.len(),
+                hyperedges_count = self.hyperedges.len(),
+            )
+        )
+    )]
     pub fn reverse_hyperedge(
         &mut self,
         hyperedge_index: HyperedgeIndex,
-    ) -> Result<(), HypergraphError<V, HE>> {
+    ) -> Result<(), HypergraphError> {
         // Get the vertices of the hyperedge.
         let vertices = self.get_hyperedge_vertices(hyperedge_index)?;
 
+        // Keep track of the roles, dropped by the update.
+        let roles = self.hyperedges_roles.get(&hyperedge_index).cloned();
+
         // Update the hyperedge with the reversed vertices.
-        self.update_hyperedge_vertices(hyperedge_index, vertices.into_par_iter().rev().collect())
+        self.update_hyperedge_vertices(hyperedge_index, vertices.into_par_iter().rev().collect())?;
+
+        // Restore the reversed roles.
+        if let Some(mut roles) = roles {
+            roles.reverse();
+
+            self.hyperedges_roles.insert(hyperedge_index, roles);
+        }
+
+        Ok(())
     }
 }
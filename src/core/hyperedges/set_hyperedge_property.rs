@@ -0,0 +1,33 @@
+use crate::{
+    HyperedgeIndex,
+    HyperedgeTrait,
+    Hypergraph,
+    Value,
+    VertexTrait,
+    errors::HypergraphError,
+};
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Sets a property of a hyperedge.
+    /// Properties are stored apart from the weight of the hyperedge.
+    /// Returns the previous value of the property, if any.
+    pub fn set_hyperedge_property(
+        &mut self,
+        hyperedge_index: HyperedgeIndex,
+        key: impl Into<String>,
+        value: impl Into<Value>,
+    ) -> Result<Option<Value>, HypergraphError> {
+        // Check that the hyperedge exists.
+        self.get_internal_hyperedge(hyperedge_index)?;
+
+        Ok(self
+            .hyperedges_properties
+            .entry(hyperedge_index)
+            .or_default()
+            .insert(key.into(), value.into()))
+    }
+}
@@ -0,0 +1,140 @@
+use crate::{
+    HyperedgeIndex,
+    HyperedgeRef,
+    HyperedgeTrait,
+    Hypergraph,
+    IndexRemapping,
+    VertexTrait,
+    bi_hash_map::BiHashMap,
+    errors::HypergraphError,
+};
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Sorts the hyperedges by key, i.e. the order in which they are
+    /// iterated and exported, e.g. to normalize a hypergraph without a full
+    /// rebuild. The key is computed once per hyperedge from a handle to it
+    /// and the sort is stable.
+    /// If `relabel` is set, the stable indexes in use are reassigned in the
+    /// sorted order as well - the lowest one to the first hyperedge - and
+    /// everything stored by stable index follows them. The returned mapping
+    /// allows to update the references held by downstream systems. It is
+    /// empty otherwise.
+    /// The adjacency cache and the distance oracle are dropped.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            err,
+            fields(
+                relabel,
+                vertices_count = self.vertices.len(),
+                hyperedges_count = self.hyperedges.len(),
+            )
+        )
+    )]
+    pub fn sort_hyperedges_by<K, F>(
+        &mut self,
+        mut key: F,
+        relabel: bool,
+    ) -> Result<IndexRemapping, HypergraphError>
+    where
+        K: Ord,
+        F: FnMut(HyperedgeRef<'_, V, HE>) -> K,
+    {
+        // Compute the keys upfront, by internal index.
+        let keys = (0..self.hyperedges.len())
+            .map(|internal_index| Ok(key(self.hyperedge(self.get_hyperedge(internal_index)?)?)))
+            .collect::<Result<Vec<K>, HypergraphError>>()?;
+
+        let mut order = (0..keys.len()).collect::<Vec<usize>>();
+
+        order.sort_by(|left, right| keys[*left].cmp(&keys[*right]));
+
+        // New internal index of each hyperedge, by previous internal index.
+        let mut positions = vec![0; order.len()];
+
+        for (position, internal_index) in order.iter().enumerate() {
+            positions[*internal_index] = position;
+        }
+
+        // Drop the adjacency cache and the distance oracle.
+        self.adjacency_cache = None;
+        self.distance_oracle = None;
+
+        // Reorder the hyperedges.
+        let mut hyperedges = self.hyperedges.drain(..).map(Some).collect::<Vec<_>>();
+
+        self.hyperedges = order
+            .iter()
+            .filter_map(|internal_index| hyperedges[*internal_index].take())
+            .collect();
+
+        #[cfg(feature = "roaring")]
+        {
+            let mut bitmaps = self
+                .hyperedges_bitmaps
+                .drain(..)
+                .map(Some)
+                .collect::<Vec<_>>();
+
+            self.hyperedges_bitmaps = order
+                .iter()
+                .filter_map(|internal_index| bitmaps[*internal_index].take())
+                .collect();
+        }
+
+        // Remap the weights index and the hyperedges of the vertices.
+        for internal_index in self.hyperedges_weights.values_mut() {
+            *internal_index = positions[*internal_index];
+        }
+
+        for (_, hyperedges) in self.vertices.iter_mut() {
+            *hyperedges = hyperedges
+                .iter()
+                .map(|internal_index| positions[*internal_index])
+                .collect();
+        }
+
+        // Remap the stable indexes.
+        let mut hyperedges_mapping = BiHashMap::with_capacity(positions.len());
+
+        for (internal_index, hyperedge_index) in self.hyperedges_mapping.left.drain() {
+            hyperedges_mapping
+                .left
+                .insert(positions[internal_index], hyperedge_index);
+            hyperedges_mapping
+                .right
+                .insert(hyperedge_index, positions[internal_index]);
+        }
+
+        self.hyperedges_mapping = hyperedges_mapping;
+
+        let mut remapping = IndexRemapping::default();
+
+        if relabel {
+            let mut indexes = self
+                .hyperedges_mapping
+                .right
+                .keys()
+                .copied()
+                .collect::<Vec<HyperedgeIndex>>();
+
+            indexes.sort_unstable();
+
+            for (internal_index, index) in indexes.into_iter().enumerate() {
+                remapping
+                    .hyperedges
+                    .insert(self.get_hyperedge(internal_index)?, index);
+            }
+
+            self.remap_hyperedges(&remapping.hyperedges);
+        }
+
+        Ok(remapping)
+    }
+}
@@ -0,0 +1,33 @@
+use crate::{
+    HyperedgeIndex,
+    HyperedgeTrait,
+    Hypergraph,
+    VertexTrait,
+    errors::HypergraphError,
+};
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Assigns a hyperedge to a named group, i.e. a tag, e.g. to select the
+    /// hyperedges of a layer without cloning a sub-hypergraph.
+    /// A hyperedge can have many tags. Tags are stored apart from the
+    /// weight of the hyperedge.
+    /// Returns `false` if the hyperedge already had the tag.
+    pub fn tag_hyperedge(
+        &mut self,
+        hyperedge_index: HyperedgeIndex,
+        tag: impl Into<String>,
+    ) -> Result<bool, HypergraphError> {
+        // Check that the hyperedge exists.
+        self.get_internal_hyperedge(hyperedge_index)?;
+
+        Ok(self
+            .hyperedges_tags
+            .entry(tag.into())
+            .or_default()
+            .insert(hyperedge_index))
+    }
+}
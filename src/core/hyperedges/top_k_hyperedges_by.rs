@@ -0,0 +1,37 @@
+use crate::{
+    HyperedgeIndex,
+    HyperedgeRef,
+    HyperedgeTrait,
+    Hypergraph,
+    VertexTrait,
+    core::utils::get_top_k,
+    errors::HypergraphError,
+};
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Gets the k hyperedges with the greatest keys, e.g. the heaviest ones,
+    /// sorted by descending key, the earliest inserted one winning the ties.
+    /// The key is computed once per hyperedge from a handle to it and only
+    /// k of them are kept at a time, which avoids a full sort.
+    pub fn top_k_hyperedges_by<K, F>(
+        &self,
+        mut key: F,
+        k: usize,
+    ) -> Result<Vec<HyperedgeIndex>, HypergraphError>
+    where
+        K: Ord,
+        F: FnMut(HyperedgeRef<'_, V, HE>) -> K,
+    {
+        let keys = (0..self.hyperedges.len())
+            .map(|internal_index| Ok(key(self.hyperedge(self.get_hyperedge(internal_index)?)?)));
+
+        get_top_k(keys, k)?
+            .into_iter()
+            .map(|internal_index| self.get_hyperedge(internal_index))
+            .collect()
+    }
+}
@@ -0,0 +1,47 @@
+use crate::{
+    HyperedgeIndex,
+    HyperedgeTrait,
+    Hypergraph,
+    VertexTrait,
+    errors::HypergraphError,
+};
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Removes a tag from a hyperedge.
+    /// Returns `false` if the hyperedge didn't have the tag.
+    pub fn untag_hyperedge(
+        &mut self,
+        hyperedge_index: HyperedgeIndex,
+        tag: &str,
+    ) -> Result<bool, HypergraphError> {
+        // Check that the hyperedge exists.
+        self.get_internal_hyperedge(hyperedge_index)?;
+
+        let Some(hyperedges) = self.hyperedges_tags.get_mut(tag) else {
+            return Ok(false);
+        };
+
+        let removed = hyperedges.swap_remove(&hyperedge_index);
+
+        // Drop the tag once unused.
+        if hyperedges.is_empty() {
+            self.hyperedges_tags.shift_remove(tag);
+        }
+
+        Ok(removed)
+    }
+
+    // Private method to remove a hyperedge from all its tags, e.g. when the
+    // hyperedge is removed.
+    pub(crate) fn untag_removed_hyperedge(&mut self, hyperedge_index: HyperedgeIndex) {
+        self.hyperedges_tags.retain(|_, hyperedges| {
+            hyperedges.swap_remove(&hyperedge_index);
+
+            !hyperedges.is_empty()
+        });
+    }
+}
@@ -1,5 +1,3 @@
-use rayon::prelude::*;
-
 use crate::{
     HyperedgeIndex,
     HyperedgeKey,
@@ -7,7 +5,10 @@ use crate::{
     Hypergraph,
     VertexIndex,
     VertexTrait,
-    core::utils::are_slices_equal,
+    core::{
+        parallel::*,
+        utils::are_slices_equal,
+    },
     errors::HypergraphError,
 };
 
@@ -17,11 +18,26 @@ where
     HE: HyperedgeTrait,
 {
     /// Updates the vertices of a hyperedge by index.
+    /// The roles of the vertices, if any, are dropped.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            err,
+            fields(
+                ?hyperedge_index,
+                ?vertices,
+                vertices_count = self.vertices.len(),
+                hyperedges_count = self.hyperedges.len(),
+            )
+        )
+    )]
     pub fn update_hyperedge_vertices(
         &mut self,
         hyperedge_index: HyperedgeIndex,
         vertices: Vec<VertexIndex>,
-    ) -> Result<(), HypergraphError<V, HE>> {
+    ) -> Result<(), HypergraphError> {
         // If the provided vertices are empty, skip the update.
         if vertices.is_empty() {
             return Err(HypergraphError::HyperedgeUpdateNoVertices(hyperedge_index));
@@ -29,12 +45,15 @@ where
 
         let internal_index = self.get_internal_hyperedge(hyperedge_index)?;
 
-        let internal_vertices = self.get_internal_vertices(vertices)?;
+        // Dedupe the vertices according to the policy.
+        let internal_vertices = self
+            .vertex_dedup_policy
+            .dedup(self.get_internal_vertices(vertices)?);
 
         let HyperedgeKey {
             vertices: previous_vertices,
             weight,
-        } = self.hyperedges.get_index(internal_index).cloned().ok_or(
+        } = self.hyperedges.get(internal_index).cloned().ok_or(
             HypergraphError::InternalHyperedgeIndexNotFound(internal_index),
         )?;
 
@@ -43,6 +62,13 @@ where
             return Err(HypergraphError::HyperedgeVerticesUnchanged(hyperedge_index));
         }
 
+        // Return an error if the vertices clash with the ones of another
+        // hyperedge according to the duplicate policy.
+        self.check_duplicate_vertices(&internal_vertices, Some(internal_index))?;
+
+        // Return an error if the vertices don't comply with the constraints.
+        self.check_constraints(&internal_vertices, &[internal_index])?;
+
         // Find the vertices which have been added.
         let mut added = internal_vertices
             .par_iter()
@@ -64,7 +90,8 @@ where
 
         // Find the vertices which have been removed.
         let mut removed = previous_vertices
-            .into_par_iter()
+            .par_iter()
+            .copied()
             .filter_map(|index| {
                 if internal_vertices
                     .par_iter()
@@ -80,6 +107,10 @@ where
         removed.par_sort_unstable();
         removed.dedup();
 
+        // Drop the adjacency cache and the distance oracle.
+        self.adjacency_cache = None;
+        self.distance_oracle = None;
+
         // Update the added vertices.
         for index in added {
             match self.vertices.get_index_mut(index) {
@@ -94,27 +125,38 @@ where
         for index in removed {
             match self.vertices.get_index_mut(index) {
                 Some((_, index_set)) => {
-                    // This has an impact on the internal indexing for the set.
-                    // However since this is not exposed to the user - i.e. no
-                    // mapping is involved - we can safely perform the operation.
-                    index_set.swap_remove_index(internal_index);
+                    // Remove the hyperedge by value, its internal index not
+                    // being its position within the set.
+                    index_set.swap_remove(&internal_index);
                 }
                 None => return Err(HypergraphError::InternalVertexIndexNotFound(index)),
             }
         }
 
-        // Insert the new entry.
-        // Since we are not altering the weight, we can safely perform the
-        // operation without checking its output.
-        self.hyperedges.insert(HyperedgeKey {
-            vertices: internal_vertices,
-            weight,
-        });
+        // Update the histogram of the cardinalities.
+        self.hyperedges_cardinalities
+            .remove(previous_vertices.len());
+        self.hyperedges_cardinalities
+            .insert(internal_vertices.len());
+
+        // Update the vertices in place, which doesn't alter the indexing.
+        self.hyperedges[internal_index] = HyperedgeKey::new(internal_vertices, weight);
+
+        // Mirror the vertices in the bitmap of the hyperedge.
+        #[cfg(feature = "roaring")]
+        self.sync_hyperedge_bitmap(internal_index)?;
+
+        // Drop the roles since the positions of the vertices have changed.
+        self.hyperedges_roles.swap_remove(&hyperedge_index);
+
+        // Bump the version of the hyperedge.
+        let version = self.next_version();
+
+        self.hyperedges_versions.insert(hyperedge_index, version);
 
-        // Swap and remove by index.
-        // Since we know that the internal index is correct, we can safely
-        // perform the operation without checking its output.
-        self.hyperedges.swap_remove_index(internal_index);
+        // Insert the new signature into the approximate membership filter.
+        #[cfg(feature = "bloom")]
+        self.filter_hyperedge(internal_index)?;
 
         // Return a unit.
         Ok(())
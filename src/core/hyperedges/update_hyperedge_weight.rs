@@ -13,89 +13,78 @@ where
     HE: HyperedgeTrait,
 {
     /// Updates the weight of a hyperedge by index.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            err,
+            fields(
+                ?hyperedge_index,
+                vertices_count = self.vertices.len(),
+                hyperedges_count = self.hyperedges.len(),
+            )
+        )
+    )]
     pub fn update_hyperedge_weight(
         &mut self,
         hyperedge_index: HyperedgeIndex,
         weight: HE,
-    ) -> Result<(), HypergraphError<V, HE>> {
+    ) -> Result<(), HypergraphError> {
         let internal_index = self.get_internal_hyperedge(hyperedge_index)?;
 
-        let HyperedgeKey {
-            vertices,
-            weight: previous_weight,
-        } = self.hyperedges.get_index(internal_index).ok_or(
-            HypergraphError::InternalHyperedgeIndexNotFound(internal_index),
-        )?;
+        let previous_weight = self
+            .hyperedges
+            .get(internal_index)
+            .map(|HyperedgeKey { weight, .. }| weight.clone())
+            .ok_or(HypergraphError::InternalHyperedgeIndexNotFound(
+                internal_index,
+            ))?;
 
         // Return an error if the new weight is the same as the previous one.
-        if weight == *previous_weight {
-            return Err(HypergraphError::HyperedgeWeightUnchanged {
-                index: hyperedge_index,
-                weight,
-            });
+        if weight == previous_weight {
+            return Err(HypergraphError::HyperedgeWeightUnchanged(hyperedge_index));
         }
 
         // Return an error if the new weight is already assigned to another
         // hyperedge.
-        // We can't use the contains method here since the key is a combination
-        // of the weight and the vertices.
-        if self.hyperedges.iter().any(
-            |HyperedgeKey {
-                 weight: current_weight,
-                 ..
-             }| { *current_weight == weight },
-        ) {
-            return Err(HypergraphError::HyperedgeWeightAlreadyAssigned(weight));
+        if let Some(other_index) = self.hyperedges_weights.get(&weight) {
+            return Err(HypergraphError::HyperedgeWeightAlreadyAssigned(
+                self.get_hyperedge(*other_index)?,
+            ));
         }
 
-        // IndexMap doesn't allow holes by design, see:
-        // https://github.com/bluss/indexmap/issues/90#issuecomment-455381877
-        //
-        // As a consequence, we have two options. Either we use shift_remove
-        // and it will result in an expensive regeneration of all the indexes
-        // in the map/set or we use swap_remove methods and deal with the fact
-        // that the last element will be swapped in place of the removed one
-        // and will thus get a new index.
-        //
-        // In our case, since we are inserting an entry upfront, it circumvents
-        // the aforementioned issue.
-        //
-        // First case: index alteration is avoided.
-        //
-        // Entry to remove
-        //  |              1.Insert new entry
-        //  |                     |
-        //  v                     v
-        // [a, b, c] -> [a, b, c, d] -> [d, b, c, _]
-        //                               ^        ^
-        //                               |        |
-        //                               +--------+
-        //                         2.Swap and remove
-        //
-        // -----------------------------------------
-        //
-        // Second case: no index alteration.
-        //
-        // Entry to remove
-        //        |        1.Insert new entry
-        //        |               |
-        //        v               v
-        // [a, b, c] -> [a, b, c, d] -> [a, b, d, _]
-        //                                     ^  ^
-        //                                     |  |
-        //                                     +--+
-        //                         2.Swap and remove
-        //
-        // Insert the new entry.
-        // Since we have already checked that the new weight is not in the
-        // map, we can safely perform the operation without checking its output.
-        self.hyperedges
-            .insert(HyperedgeKey::new(vertices.clone(), weight));
+        // Since the uniqueness is only enforced by the weights index, the
+        // weight can be updated in place without altering the indexing.
+        self.hyperedges_weights.swap_remove(&previous_weight);
+        self.hyperedges_weights
+            .insert(weight.clone(), internal_index);
 
-        // Swap and remove by index.
-        // Since we know that the internal index is correct, we can safely
-        // perform the operation without checking its output.
-        self.hyperedges.swap_remove_index(internal_index);
+        self.hyperedges[internal_index].weight = weight;
+
+        // Drop the distance oracle since the cost of the hyperedge changes.
+        self.distance_oracle = None;
+
+        // Keep the cached cost of the hyperedge up to date.
+        if let Some(cost) = self
+            .adjacency_cache
+            .as_mut()
+            .and_then(|cache| cache.costs.get_mut(internal_index))
+        {
+            *cost = self.hyperedges[internal_index].weight.clone().into();
+        }
+
+        // Update the full-text search index.
+        self.index_hyperedge_weight(hyperedge_index, internal_index);
+
+        // Bump the version of the hyperedge.
+        let version = self.next_version();
+
+        self.hyperedges_versions.insert(hyperedge_index, version);
+
+        // Insert the new signature into the approximate membership filter.
+        #[cfg(feature = "bloom")]
+        self.filter_hyperedge(internal_index)?;
 
         // Return a unit.
         Ok(())
@@ -1,15 +1,40 @@
-use std::fmt::{
-    Display,
-    Formatter,
-    Result,
+use std::{
+    fmt::{
+        Display,
+        Formatter,
+        Result,
+    },
+    num::TryFromIntError,
 };
 
 /// Vertex stable index representation as usize.
 /// Uses the newtype index pattern.
 /// <https://matklad.github.io/2018/06/04/newtype-index-pattern.html>
+///
+/// A `HyperedgeIndex` can't be used where a `VertexIndex` is expected:
+///
+/// ```compile_fail
+/// use hypergraph::{HyperedgeIndex, Hypergraph};
+///
+/// let graph = Hypergraph::<usize, usize>::new();
+///
+/// graph.get_vertex_weight(HyperedgeIndex(0));
+/// ```
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 pub struct VertexIndex(pub usize);
 
+impl VertexIndex {
+    /// Gets the index as a usize.
+    pub fn as_usize(self) -> usize {
+        self.0
+    }
+}
+
 impl Display for VertexIndex {
     fn fmt(&self, formatter: &mut Formatter<'_>) -> Result {
         write!(formatter, "{}", self.0)
@@ -22,12 +47,48 @@ impl From<usize> for VertexIndex {
     }
 }
 
+impl TryFrom<u32> for VertexIndex {
+    type Error = TryFromIntError;
+
+    fn try_from(index: u32) -> std::result::Result<Self, Self::Error> {
+        usize::try_from(index).map(VertexIndex)
+    }
+}
+
+impl From<VertexIndex> for usize {
+    fn from(VertexIndex(index): VertexIndex) -> Self {
+        index
+    }
+}
+
 /// Hyperedge stable index representation as usize.
 /// Uses the newtype index pattern.
 /// <https://matklad.github.io/2018/06/04/newtype-index-pattern.html>
+///
+/// A `VertexIndex` can't be used where a `HyperedgeIndex` is expected:
+///
+/// ```compile_fail
+/// use hypergraph::{Hypergraph, VertexIndex};
+///
+/// let graph = Hypergraph::<usize, usize>::new();
+///
+/// graph.get_hyperedge_weight(VertexIndex(0));
+/// ```
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 pub struct HyperedgeIndex(pub usize);
 
+impl HyperedgeIndex {
+    /// Gets the index as a usize.
+    pub fn as_usize(self) -> usize {
+        self.0
+    }
+}
+
 impl Display for HyperedgeIndex {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         write!(f, "{}", self.0)
@@ -39,3 +100,53 @@ impl From<usize> for HyperedgeIndex {
         HyperedgeIndex(index)
     }
 }
+
+impl TryFrom<u32> for HyperedgeIndex {
+    type Error = TryFromIntError;
+
+    fn try_from(index: u32) -> std::result::Result<Self, Self::Error> {
+        usize::try_from(index).map(HyperedgeIndex)
+    }
+}
+
+impl From<HyperedgeIndex> for usize {
+    fn from(HyperedgeIndex(index): HyperedgeIndex) -> Self {
+        index
+    }
+}
+
+/// Layer stable index representation as usize.
+/// Uses the newtype index pattern.
+/// <https://matklad.github.io/2018/06/04/newtype-index-pattern.html>
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct LayerIndex(pub usize);
+
+impl LayerIndex {
+    /// Gets the index as a usize.
+    pub fn as_usize(self) -> usize {
+        self.0
+    }
+}
+
+impl Display for LayerIndex {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result {
+        write!(formatter, "{}", self.0)
+    }
+}
+
+impl From<usize> for LayerIndex {
+    fn from(index: usize) -> Self {
+        LayerIndex(index)
+    }
+}
+
+impl From<LayerIndex> for usize {
+    fn from(LayerIndex(index): LayerIndex) -> Self {
+        index
+    }
+}
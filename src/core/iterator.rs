@@ -1,10 +1,9 @@
-use rayon::prelude::*;
-
 use crate::{
     HyperedgeKey,
     HyperedgeTrait,
     Hypergraph,
     VertexTrait,
+    core::parallel::*,
     errors::HypergraphError,
 };
 
@@ -45,7 +44,7 @@ where
 
     fn next(&mut self) -> Option<Self::Item> {
         // Get the current hyperedge matching the index.
-        match self.hypergraph.hyperedges.iter().nth(self.index) {
+        match self.hypergraph.hyperedges.get(self.index) {
             // Extract the internal vertices and its weight.
             Some(HyperedgeKey { vertices, weight }) => {
                 // Convert the internal vertices to a vector of VertexIndex.
@@ -55,13 +54,16 @@ where
                     indexes
                         .par_iter()
                         .map(|index| self.hypergraph.get_vertex_weight(*index))
-                        .collect::<Result<Vec<&V>, HypergraphError<V, HE>>>()
+                        .collect::<Result<Vec<&V>, HypergraphError>>()
                         .ok()
                         .map(|vertices_weights| {
                             // Now we can increment the inner index.
                             self.index += 1;
 
-                            (*weight, vertices_weights.into_par_iter().cloned().collect())
+                            (
+                                weight.clone(),
+                                vertices_weights.into_par_iter().cloned().collect(),
+                            )
                         })
                 } else {
                     None
@@ -0,0 +1,1139 @@
+use std::{
+    fmt::{
+        self,
+        Debug,
+        Formatter,
+    },
+    fs::{
+        File,
+        OpenOptions,
+    },
+    io::{
+        Read,
+        Seek,
+        SeekFrom,
+        Write,
+    },
+    ops::Deref,
+    path::Path,
+    str::FromStr,
+};
+
+use crate::{
+    HyperedgeIndex,
+    HyperedgeTrait,
+    Hypergraph,
+    IndexRemapping,
+    LayerIndex,
+    RecycledIndexes,
+    Value,
+    VertexIndex,
+    VertexTrait,
+    core::snapshot::{
+        SnapshotReader,
+        write_string,
+        write_u64,
+        write_value,
+    },
+    errors::HypergraphError,
+};
+
+/// Magic bytes at the start of every journal.
+const MAGIC: &[u8; 8] = b"HGJOURNL";
+
+/// Version of the journal format written by this release. It must be bumped
+/// on any change of the records below.
+pub const JOURNAL_VERSION: u32 = 1;
+
+// Tags of the records.
+pub(crate) const ADD_VERTEX: u8 = 0;
+pub(crate) const ADD_VERTEX_ALLOW_DUPLICATE: u8 = 1;
+pub(crate) const ADD_HYPEREDGE: u8 = 2;
+pub(crate) const ADD_HYPEREDGE_WITH_ROLES: u8 = 3;
+pub(crate) const REMOVE_VERTEX: u8 = 4;
+pub(crate) const REMOVE_HYPEREDGE: u8 = 5;
+pub(crate) const UPDATE_VERTEX_WEIGHT: u8 = 6;
+pub(crate) const UPDATE_HYPEREDGE_WEIGHT: u8 = 7;
+pub(crate) const UPDATE_HYPEREDGE_VERTICES: u8 = 8;
+pub(crate) const REVERSE_HYPEREDGE: u8 = 9;
+pub(crate) const JOIN_HYPEREDGES: u8 = 10;
+pub(crate) const CONTRACT_HYPEREDGE_VERTICES: u8 = 11;
+pub(crate) const CLEAR: u8 = 12;
+pub(crate) const CLEAR_HYPEREDGES: u8 = 13;
+pub(crate) const SET_VERTEX_ATTR: u8 = 14;
+pub(crate) const REMOVE_VERTEX_ATTR: u8 = 15;
+pub(crate) const SET_HYPEREDGE_PROPERTY: u8 = 16;
+pub(crate) const REMOVE_HYPEREDGE_PROPERTY: u8 = 17;
+pub(crate) const PRUNE_ISOLATED_VERTICES: u8 = 18;
+pub(crate) const PRUNE_HYPEREDGES_BY_CARDINALITY: u8 = 19;
+pub(crate) const SET_METADATA: u8 = 20;
+pub(crate) const REMOVE_METADATA: u8 = 21;
+pub(crate) const TAG_HYPEREDGE: u8 = 22;
+pub(crate) const UNTAG_HYPEREDGE: u8 = 23;
+pub(crate) const ADD_LAYER: u8 = 24;
+pub(crate) const REMOVE_LAYER: u8 = 25;
+pub(crate) const SET_HYPEREDGE_LAYER: u8 = 26;
+pub(crate) const REMAP_INDEXES: u8 = 27;
+pub(crate) const RECYCLE_INDEXES: u8 = 28;
+pub(crate) const JOIN_HYPEREDGES_WITH: u8 = 29;
+
+// Private helper to convert an I/O error.
+fn io_error(error: std::io::Error) -> HypergraphError {
+    HypergraphError::JournalIo(error.to_string())
+}
+
+// Private helper to convert the errors of the shared reader.
+pub(crate) fn reader_error(error: HypergraphError) -> HypergraphError {
+    match error {
+        HypergraphError::SnapshotCorrupted(reason) => HypergraphError::JournalCorrupted(reason),
+        HypergraphError::SnapshotInvalidWeight(weight) => {
+            HypergraphError::JournalInvalidWeight(weight)
+        }
+        error => error,
+    }
+}
+
+// Private helper to write a vector of indexes.
+fn write_indexes(buffer: &mut Vec<u8>, indexes: impl ExactSizeIterator<Item = usize>) {
+    write_u64(buffer, indexes.len());
+
+    for index in indexes {
+        write_u64(buffer, index);
+    }
+}
+
+// Private helper to read a vector of indexes.
+pub(crate) fn read_indexes<I>(
+    reader: &mut SnapshotReader<'_>,
+    index: impl Fn(usize) -> I,
+) -> Result<Vec<I>, HypergraphError> {
+    (0..reader.read_length()?)
+        .map(|_| reader.read_usize().map(&index))
+        .collect()
+}
+
+// Private helper to apply a single record to a hypergraph.
+// Returns the remapping of the indexes, if any, e.g. to follow the entities
+// across the records.
+pub(crate) fn apply_record<V, HE>(
+    graph: &mut Hypergraph<V, HE>,
+    reader: &mut SnapshotReader<'_>,
+) -> Result<Option<IndexRemapping>, HypergraphError>
+where
+    V: VertexTrait + FromStr,
+    HE: HyperedgeTrait + FromStr,
+{
+    match reader.read_u8()? {
+        ADD_VERTEX => {
+            graph.add_vertex(reader.read_weight()?)?;
+        }
+        ADD_VERTEX_ALLOW_DUPLICATE => {
+            graph.add_vertex_allow_duplicate(reader.read_weight()?)?;
+        }
+        ADD_HYPEREDGE => {
+            let vertices = read_indexes(reader, VertexIndex)?;
+
+            graph.add_hyperedge(vertices, reader.read_weight()?)?;
+        }
+        ADD_HYPEREDGE_WITH_ROLES => {
+            let vertices = (0..reader.read_length()?)
+                .map(|_| Ok((VertexIndex(reader.read_usize()?), reader.read_string()?)))
+                .collect::<Result<Vec<(VertexIndex, String)>, HypergraphError>>()?;
+
+            graph.add_hyperedge_with_roles(vertices, reader.read_weight()?)?;
+        }
+        REMOVE_VERTEX => graph.remove_vertex(VertexIndex(reader.read_usize()?))?,
+        REMOVE_HYPEREDGE => graph.remove_hyperedge(HyperedgeIndex(reader.read_usize()?))?,
+        UPDATE_VERTEX_WEIGHT => {
+            let vertex_index = VertexIndex(reader.read_usize()?);
+
+            graph.update_vertex_weight(vertex_index, reader.read_weight()?)?;
+        }
+        UPDATE_HYPEREDGE_WEIGHT => {
+            let hyperedge_index = HyperedgeIndex(reader.read_usize()?);
+
+            graph.update_hyperedge_weight(hyperedge_index, reader.read_weight()?)?;
+        }
+        UPDATE_HYPEREDGE_VERTICES => {
+            let hyperedge_index = HyperedgeIndex(reader.read_usize()?);
+
+            graph.update_hyperedge_vertices(hyperedge_index, read_indexes(reader, VertexIndex)?)?;
+        }
+        REVERSE_HYPEREDGE => graph.reverse_hyperedge(HyperedgeIndex(reader.read_usize()?))?,
+        JOIN_HYPEREDGES => graph.join_hyperedges(&read_indexes(reader, HyperedgeIndex)?)?,
+        JOIN_HYPEREDGES_WITH => {
+            let hyperedges = read_indexes(reader, HyperedgeIndex)?;
+            let weight = reader.read_weight()?;
+
+            graph.join_hyperedges_with(&hyperedges, |_| weight)?;
+        }
+        CONTRACT_HYPEREDGE_VERTICES => {
+            let hyperedge_index = HyperedgeIndex(reader.read_usize()?);
+            let vertices = read_indexes(reader, VertexIndex)?;
+
+            graph.contract_hyperedge_vertices(
+                hyperedge_index,
+                vertices,
+                VertexIndex(reader.read_usize()?),
+            )?;
+        }
+        CLEAR => graph.clear(),
+        CLEAR_HYPEREDGES => graph.clear_hyperedges()?,
+        SET_VERTEX_ATTR => {
+            let vertex_index = VertexIndex(reader.read_usize()?);
+            let key = reader.read_string()?;
+
+            graph.set_vertex_attr(vertex_index, key, reader.read_value()?)?;
+        }
+        REMOVE_VERTEX_ATTR => {
+            let vertex_index = VertexIndex(reader.read_usize()?);
+
+            graph.remove_vertex_attr(vertex_index, &reader.read_string()?)?;
+        }
+        SET_HYPEREDGE_PROPERTY => {
+            let hyperedge_index = HyperedgeIndex(reader.read_usize()?);
+            let key = reader.read_string()?;
+
+            graph.set_hyperedge_property(hyperedge_index, key, reader.read_value()?)?;
+        }
+        REMOVE_HYPEREDGE_PROPERTY => {
+            let hyperedge_index = HyperedgeIndex(reader.read_usize()?);
+
+            graph.remove_hyperedge_property(hyperedge_index, &reader.read_string()?)?;
+        }
+        PRUNE_ISOLATED_VERTICES => {
+            graph.prune_isolated_vertices()?;
+        }
+        PRUNE_HYPEREDGES_BY_CARDINALITY => {
+            let min = reader.read_usize()?;
+
+            graph.prune_hyperedges_by_cardinality(min, reader.read_usize()?)?;
+        }
+        SET_METADATA => {
+            let key = reader.read_string()?;
+
+            graph.set_metadata(key, reader.read_value()?);
+        }
+        REMOVE_METADATA => {
+            graph.remove_metadata(&reader.read_string()?);
+        }
+        TAG_HYPEREDGE => {
+            let hyperedge_index = HyperedgeIndex(reader.read_usize()?);
+
+            graph.tag_hyperedge(hyperedge_index, reader.read_string()?)?;
+        }
+        UNTAG_HYPEREDGE => {
+            let hyperedge_index = HyperedgeIndex(reader.read_usize()?);
+
+            graph.untag_hyperedge(hyperedge_index, &reader.read_string()?)?;
+        }
+        ADD_LAYER => {
+            graph.add_layer(reader.read_string()?)?;
+        }
+        REMOVE_LAYER => {
+            graph.remove_layer(LayerIndex(reader.read_usize()?))?;
+        }
+        SET_HYPEREDGE_LAYER => {
+            let hyperedge_index = HyperedgeIndex(reader.read_usize()?);
+            let layer_index = match reader.read_u8()? {
+                0 => None,
+                _ => Some(LayerIndex(reader.read_usize()?)),
+            };
+
+            graph.set_hyperedge_layer(hyperedge_index, layer_index)?;
+        }
+        REMAP_INDEXES => {
+            check_consumed(reader)?;
+
+            return graph.remap_indexes().map(Some);
+        }
+        RECYCLE_INDEXES => {
+            graph.recycle_indexes();
+        }
+        tag => {
+            return Err(HypergraphError::JournalCorrupted(format!(
+                "invalid record tag {tag}"
+            )));
+        }
+    }
+
+    check_consumed(reader)?;
+
+    Ok(None)
+}
+
+// Private helper to check that a record was fully consumed, e.g. that it
+// doesn't swallow the following records after a partial write.
+fn check_consumed(reader: &SnapshotReader<'_>) -> Result<(), HypergraphError> {
+    if reader.position != reader.bytes.len() {
+        return Err(HypergraphError::JournalCorrupted(
+            "trailing bytes in record".to_owned(),
+        ));
+    }
+
+    Ok(())
+}
+
+// Private helper to split a journal into its records.
+// Returns the records along with the length of the valid part of the
+// journal, a truncated trailing record - e.g. after a crash while
+// appending - being ignored.
+pub(crate) fn read_records(bytes: &[u8]) -> Result<(Vec<&[u8]>, usize), HypergraphError> {
+    let mut reader = SnapshotReader { bytes, position: 0 };
+
+    if reader.read_bytes(MAGIC.len()).ok() != Some(&MAGIC[..]) {
+        return Err(HypergraphError::JournalInvalidHeader);
+    }
+
+    let version = reader.read_u32().map_err(reader_error)?;
+
+    if version != JOURNAL_VERSION {
+        return Err(HypergraphError::JournalUnsupportedVersion(version));
+    }
+
+    let mut records = vec![];
+
+    while reader.position < bytes.len() {
+        let start = reader.position;
+
+        // Each record is prefixed by its length.
+        match reader
+            .read_u64()
+            .ok()
+            .and_then(|length| usize::try_from(length).ok())
+            .and_then(|length| reader.read_bytes(length).ok())
+        {
+            Some(record) => records.push(record),
+            None => return Ok((records, start)),
+        };
+    }
+
+    Ok((records, bytes.len()))
+}
+
+// Private helper to replay the records of a journal.
+// Returns the hypergraph along with the number of records and the length of
+// the valid part of the journal.
+fn replay_bytes<V, HE>(bytes: &[u8]) -> Result<(Hypergraph<V, HE>, usize, usize), HypergraphError>
+where
+    V: VertexTrait + FromStr,
+    HE: HyperedgeTrait + FromStr,
+{
+    let (records, length) = read_records(bytes)?;
+    let mut graph = Hypergraph::new();
+
+    for record in records.iter() {
+        let mut reader = SnapshotReader {
+            bytes: record,
+            position: 0,
+        };
+
+        apply_record(&mut graph, &mut reader).map_err(reader_error)?;
+    }
+
+    Ok((graph, records.len(), length))
+}
+
+/// Hypergraph whose successful mutations are appended as compact records to
+/// a journal file, which can be replayed to reconstruct its state.
+/// Queries are available via `Deref` while mutations must go through the
+/// methods of the journaled hypergraph to be recorded.
+/// Weights are stored as strings via their `Display` implementation and
+/// parsed back via their `FromStr` implementation.
+/// When a record fails to be appended, further mutations are rejected with
+/// `HypergraphError::JournalFailed` until the journal is reopened.
+pub struct JournaledHypergraph<V, HE> {
+    failed: bool,
+    file: File,
+    graph: Hypergraph<V, HE>,
+    sequence_number: usize,
+}
+
+impl<V, HE> Debug for JournaledHypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("JournaledHypergraph")
+            .field("failed", &self.failed)
+            .field("file", &self.file)
+            .field("graph", &self.graph)
+            .field("sequence_number", &self.sequence_number)
+            .finish()
+    }
+}
+
+impl<V, HE> Deref for JournaledHypergraph<V, HE> {
+    type Target = Hypergraph<V, HE>;
+
+    fn deref(&self) -> &Hypergraph<V, HE> {
+        &self.graph
+    }
+}
+
+impl<V, HE> JournaledHypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Creates a new empty journaled hypergraph, truncating the journal
+    /// file if it already exists.
+    pub fn create<P>(path: P) -> Result<Self, HypergraphError>
+    where
+        P: AsRef<Path>,
+    {
+        // The journal is read back by the time-travel queries.
+        let mut file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(path)
+            .map_err(io_error)?;
+
+        let mut header = MAGIC.to_vec();
+
+        header.extend_from_slice(&JOURNAL_VERSION.to_le_bytes());
+
+        file.write_all(&header).map_err(io_error)?;
+
+        Ok(Self {
+            failed: false,
+            file,
+            graph: Hypergraph::new(),
+            sequence_number: 0,
+        })
+    }
+
+    /// Opens a journaled hypergraph by replaying an existing journal file,
+    /// further mutations being appended to it. A truncated trailing record
+    /// is discarded.
+    pub fn open<P>(path: P) -> Result<Self, HypergraphError>
+    where
+        P: AsRef<Path>,
+        V: FromStr,
+        HE: FromStr,
+    {
+        let mut file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .open(path)
+            .map_err(io_error)?;
+
+        let mut bytes = Vec::new();
+
+        file.read_to_end(&mut bytes).map_err(io_error)?;
+
+        let (graph, sequence_number, length) = replay_bytes(&bytes)?;
+
+        // Drop the truncated trailing record, if any, and move to the end.
+        file.set_len(length as u64).map_err(io_error)?;
+        file.seek(SeekFrom::End(0)).map_err(io_error)?;
+
+        Ok(Self {
+            failed: false,
+            file,
+            graph,
+            sequence_number,
+        })
+    }
+
+    /// Consumes the journaled hypergraph and returns the hypergraph.
+    pub fn into_inner(self) -> Hypergraph<V, HE> {
+        self.graph
+    }
+
+    /// Gets the sequence number of the journal, i.e. the number of records
+    /// appended to it so far. The record of a mutation is numbered after
+    /// the state it results in, starting from one.
+    pub fn get_sequence_number(&self) -> usize {
+        self.sequence_number
+    }
+
+    // Private method to read the whole journal back.
+    pub(crate) fn read_journal(&self) -> Result<Vec<u8>, HypergraphError> {
+        let mut file = &self.file;
+        let mut bytes = Vec::new();
+
+        file.seek(SeekFrom::Start(0)).map_err(io_error)?;
+        file.read_to_end(&mut bytes).map_err(io_error)?;
+
+        // The records are appended at the end.
+        file.seek(SeekFrom::End(0)).map_err(io_error)?;
+
+        Ok(bytes)
+    }
+
+    // Private method to check that the journal didn't fail, before any
+    // mutation of the in-memory hypergraph.
+    fn check_writable(&self) -> Result<(), HypergraphError> {
+        if self.failed {
+            return Err(HypergraphError::JournalFailed);
+        }
+
+        Ok(())
+    }
+
+    // Private method to append a record to the journal.
+    // On failure, the partial record is truncated and the journal is marked
+    // as failed since the in-memory hypergraph is ahead of it.
+    fn append(&mut self, tag: u8, payload: Vec<u8>) -> Result<(), HypergraphError> {
+        let mut record = Vec::with_capacity(payload.len() + 9);
+
+        write_u64(&mut record, payload.len() + 1);
+        record.push(tag);
+        record.extend_from_slice(&payload);
+
+        let length = match self.file.metadata() {
+            Ok(metadata) => metadata.len(),
+            Err(error) => {
+                self.failed = true;
+
+                return Err(io_error(error));
+            }
+        };
+
+        // Write the whole record at once.
+        if let Err(error) = self.file.write_all(&record) {
+            self.failed = true;
+
+            // Best effort, a leftover partial record being discarded when
+            // the journal is reopened.
+            let _ = self.file.set_len(length);
+            let _ = self.file.seek(SeekFrom::End(0));
+
+            return Err(io_error(error));
+        }
+
+        self.sequence_number += 1;
+
+        Ok(())
+    }
+
+    /// Adds a vertex and records it. See `Hypergraph::add_vertex`.
+    pub fn add_vertex(&mut self, weight: V) -> Result<VertexIndex, HypergraphError> {
+        self.check_writable()?;
+
+        let mut payload = vec![];
+
+        write_string(&mut payload, &weight.to_string());
+
+        let vertex_index = self.graph.add_vertex(weight)?;
+
+        self.append(ADD_VERTEX, payload)?;
+
+        Ok(vertex_index)
+    }
+
+    /// Adds a vertex without checking the uniqueness of its weight and
+    /// records it. See `Hypergraph::add_vertex_allow_duplicate`.
+    pub fn add_vertex_allow_duplicate(
+        &mut self,
+        weight: V,
+    ) -> Result<VertexIndex, HypergraphError> {
+        self.check_writable()?;
+
+        let mut payload = vec![];
+
+        write_string(&mut payload, &weight.to_string());
+
+        let vertex_index = self.graph.add_vertex_allow_duplicate(weight)?;
+
+        self.append(ADD_VERTEX_ALLOW_DUPLICATE, payload)?;
+
+        Ok(vertex_index)
+    }
+
+    /// Adds a hyperedge and records it. See `Hypergraph::add_hyperedge`.
+    pub fn add_hyperedge(
+        &mut self,
+        vertices: Vec<VertexIndex>,
+        weight: HE,
+    ) -> Result<HyperedgeIndex, HypergraphError> {
+        self.check_writable()?;
+
+        let mut payload = vec![];
+
+        write_indexes(
+            &mut payload,
+            vertices.iter().map(|VertexIndex(index)| *index),
+        );
+        write_string(&mut payload, &weight.to_string());
+
+        let hyperedge_index = self.graph.add_hyperedge(vertices, weight)?;
+
+        self.append(ADD_HYPEREDGE, payload)?;
+
+        Ok(hyperedge_index)
+    }
+
+    /// Adds a hyperedge with the roles of its vertices and records it. See
+    /// `Hypergraph::add_hyperedge_with_roles`.
+    pub fn add_hyperedge_with_roles<R>(
+        &mut self,
+        vertices: Vec<(VertexIndex, R)>,
+        weight: HE,
+    ) -> Result<HyperedgeIndex, HypergraphError>
+    where
+        R: Into<String>,
+    {
+        self.check_writable()?;
+
+        let vertices = vertices
+            .into_iter()
+            .map(|(vertex_index, role)| (vertex_index, role.into()))
+            .collect::<Vec<(VertexIndex, String)>>();
+
+        let mut payload = vec![];
+
+        write_u64(&mut payload, vertices.len());
+
+        for (VertexIndex(index), role) in vertices.iter() {
+            write_u64(&mut payload, *index);
+            write_string(&mut payload, role);
+        }
+
+        write_string(&mut payload, &weight.to_string());
+
+        let hyperedge_index = self.graph.add_hyperedge_with_roles(vertices, weight)?;
+
+        self.append(ADD_HYPEREDGE_WITH_ROLES, payload)?;
+
+        Ok(hyperedge_index)
+    }
+
+    /// Removes a vertex and records it. See `Hypergraph::remove_vertex`.
+    pub fn remove_vertex(&mut self, vertex_index: VertexIndex) -> Result<(), HypergraphError> {
+        self.check_writable()?;
+
+        self.graph.remove_vertex(vertex_index)?;
+
+        let mut payload = vec![];
+
+        write_u64(&mut payload, vertex_index.0);
+
+        self.append(REMOVE_VERTEX, payload)
+    }
+
+    /// Removes a hyperedge and records it. See `Hypergraph::remove_hyperedge`.
+    pub fn remove_hyperedge(
+        &mut self,
+        hyperedge_index: HyperedgeIndex,
+    ) -> Result<(), HypergraphError> {
+        self.check_writable()?;
+
+        self.graph.remove_hyperedge(hyperedge_index)?;
+
+        let mut payload = vec![];
+
+        write_u64(&mut payload, hyperedge_index.0);
+
+        self.append(REMOVE_HYPEREDGE, payload)
+    }
+
+    /// Updates the weight of a vertex and records it. See
+    /// `Hypergraph::update_vertex_weight`.
+    pub fn update_vertex_weight(
+        &mut self,
+        vertex_index: VertexIndex,
+        weight: V,
+    ) -> Result<(), HypergraphError> {
+        self.check_writable()?;
+
+        let mut payload = vec![];
+
+        write_u64(&mut payload, vertex_index.0);
+        write_string(&mut payload, &weight.to_string());
+
+        self.graph.update_vertex_weight(vertex_index, weight)?;
+
+        self.append(UPDATE_VERTEX_WEIGHT, payload)
+    }
+
+    /// Updates the weight of a hyperedge and records it. See
+    /// `Hypergraph::update_hyperedge_weight`.
+    pub fn update_hyperedge_weight(
+        &mut self,
+        hyperedge_index: HyperedgeIndex,
+        weight: HE,
+    ) -> Result<(), HypergraphError> {
+        self.check_writable()?;
+
+        let mut payload = vec![];
+
+        write_u64(&mut payload, hyperedge_index.0);
+        write_string(&mut payload, &weight.to_string());
+
+        self.graph
+            .update_hyperedge_weight(hyperedge_index, weight)?;
+
+        self.append(UPDATE_HYPEREDGE_WEIGHT, payload)
+    }
+
+    /// Updates the vertices of a hyperedge and records it. See
+    /// `Hypergraph::update_hyperedge_vertices`.
+    pub fn update_hyperedge_vertices(
+        &mut self,
+        hyperedge_index: HyperedgeIndex,
+        vertices: Vec<VertexIndex>,
+    ) -> Result<(), HypergraphError> {
+        self.check_writable()?;
+
+        let mut payload = vec![];
+
+        write_u64(&mut payload, hyperedge_index.0);
+        write_indexes(
+            &mut payload,
+            vertices.iter().map(|VertexIndex(index)| *index),
+        );
+
+        self.graph
+            .update_hyperedge_vertices(hyperedge_index, vertices)?;
+
+        self.append(UPDATE_HYPEREDGE_VERTICES, payload)
+    }
+
+    /// Reverses a hyperedge and records it. See
+    /// `Hypergraph::reverse_hyperedge`.
+    pub fn reverse_hyperedge(
+        &mut self,
+        hyperedge_index: HyperedgeIndex,
+    ) -> Result<(), HypergraphError> {
+        self.check_writable()?;
+
+        self.graph.reverse_hyperedge(hyperedge_index)?;
+
+        let mut payload = vec![];
+
+        write_u64(&mut payload, hyperedge_index.0);
+
+        self.append(REVERSE_HYPEREDGE, payload)
+    }
+
+    /// Joins some hyperedges and records it. See
+    /// `Hypergraph::join_hyperedges`.
+    pub fn join_hyperedges(
+        &mut self,
+        hyperedges: &[HyperedgeIndex],
+    ) -> Result<(), HypergraphError> {
+        self.check_writable()?;
+
+        self.graph.join_hyperedges(hyperedges)?;
+
+        let mut payload = vec![];
+
+        write_indexes(
+            &mut payload,
+            hyperedges.iter().map(|HyperedgeIndex(index)| *index),
+        );
+
+        self.append(JOIN_HYPEREDGES, payload)
+    }
+
+    /// Joins two or more hyperedges while aggregating their weights and
+    /// records it. See `Hypergraph::join_hyperedges_with`.
+    /// The join is recorded along with the aggregated weight, in a single
+    /// record.
+    pub fn join_hyperedges_with<F>(
+        &mut self,
+        hyperedges: &[HyperedgeIndex],
+        combiner: F,
+    ) -> Result<(), HypergraphError>
+    where
+        F: FnOnce(Vec<HE>) -> HE,
+    {
+        self.check_writable()?;
+
+        let mut payload = vec![];
+
+        write_indexes(
+            &mut payload,
+            hyperedges.iter().map(|HyperedgeIndex(index)| *index),
+        );
+
+        self.graph.join_hyperedges_with(hyperedges, |weights| {
+            let weight = combiner(weights);
+
+            write_string(&mut payload, &weight.to_string());
+
+            weight
+        })?;
+
+        self.append(JOIN_HYPEREDGES_WITH, payload)
+    }
+
+    /// Contracts some vertices of a hyperedge and records it. See
+    /// `Hypergraph::contract_hyperedge_vertices`.
+    pub fn contract_hyperedge_vertices(
+        &mut self,
+        hyperedge_index: HyperedgeIndex,
+        vertices: Vec<VertexIndex>,
+        target: VertexIndex,
+    ) -> Result<Vec<VertexIndex>, HypergraphError> {
+        self.check_writable()?;
+
+        let mut payload = vec![];
+
+        write_u64(&mut payload, hyperedge_index.0);
+        write_indexes(
+            &mut payload,
+            vertices.iter().map(|VertexIndex(index)| *index),
+        );
+        write_u64(&mut payload, target.0);
+
+        let contracted =
+            self.graph
+                .contract_hyperedge_vertices(hyperedge_index, vertices, target)?;
+
+        self.append(CONTRACT_HYPEREDGE_VERTICES, payload)?;
+
+        Ok(contracted)
+    }
+
+    /// Clears the hypergraph and records it. See `Hypergraph::clear`.
+    pub fn clear(&mut self) -> Result<(), HypergraphError> {
+        self.check_writable()?;
+
+        self.graph.clear();
+
+        self.append(CLEAR, vec![])
+    }
+
+    /// Clears the hyperedges and records it. See
+    /// `Hypergraph::clear_hyperedges`.
+    pub fn clear_hyperedges(&mut self) -> Result<(), HypergraphError> {
+        self.check_writable()?;
+
+        self.graph.clear_hyperedges()?;
+
+        self.append(CLEAR_HYPEREDGES, vec![])
+    }
+
+    /// Sets an attribute of a vertex and records it. See
+    /// `Hypergraph::set_vertex_attr`.
+    pub fn set_vertex_attr(
+        &mut self,
+        vertex_index: VertexIndex,
+        key: impl Into<String>,
+        value: impl Into<Value>,
+    ) -> Result<Option<Value>, HypergraphError> {
+        self.check_writable()?;
+
+        let (key, value) = (key.into(), value.into());
+
+        let mut payload = vec![];
+
+        write_u64(&mut payload, vertex_index.0);
+        write_string(&mut payload, &key);
+        write_value(&mut payload, &value);
+
+        let previous = self.graph.set_vertex_attr(vertex_index, key, value)?;
+
+        self.append(SET_VERTEX_ATTR, payload)?;
+
+        Ok(previous)
+    }
+
+    /// Removes an attribute of a vertex and records it. See
+    /// `Hypergraph::remove_vertex_attr`.
+    pub fn remove_vertex_attr(
+        &mut self,
+        vertex_index: VertexIndex,
+        key: &str,
+    ) -> Result<Option<Value>, HypergraphError> {
+        self.check_writable()?;
+
+        let previous = self.graph.remove_vertex_attr(vertex_index, key)?;
+
+        let mut payload = vec![];
+
+        write_u64(&mut payload, vertex_index.0);
+        write_string(&mut payload, key);
+
+        self.append(REMOVE_VERTEX_ATTR, payload)?;
+
+        Ok(previous)
+    }
+
+    /// Sets a property of a hyperedge and records it. See
+    /// `Hypergraph::set_hyperedge_property`.
+    pub fn set_hyperedge_property(
+        &mut self,
+        hyperedge_index: HyperedgeIndex,
+        key: impl Into<String>,
+        value: impl Into<Value>,
+    ) -> Result<Option<Value>, HypergraphError> {
+        self.check_writable()?;
+
+        let (key, value) = (key.into(), value.into());
+
+        let mut payload = vec![];
+
+        write_u64(&mut payload, hyperedge_index.0);
+        write_string(&mut payload, &key);
+        write_value(&mut payload, &value);
+
+        let previous = self
+            .graph
+            .set_hyperedge_property(hyperedge_index, key, value)?;
+
+        self.append(SET_HYPEREDGE_PROPERTY, payload)?;
+
+        Ok(previous)
+    }
+
+    /// Removes a property of a hyperedge and records it. See
+    /// `Hypergraph::remove_hyperedge_property`.
+    pub fn remove_hyperedge_property(
+        &mut self,
+        hyperedge_index: HyperedgeIndex,
+        key: &str,
+    ) -> Result<Option<Value>, HypergraphError> {
+        self.check_writable()?;
+
+        let previous = self.graph.remove_hyperedge_property(hyperedge_index, key)?;
+
+        let mut payload = vec![];
+
+        write_u64(&mut payload, hyperedge_index.0);
+        write_string(&mut payload, key);
+
+        self.append(REMOVE_HYPEREDGE_PROPERTY, payload)?;
+
+        Ok(previous)
+    }
+
+    /// Tags a hyperedge and records it. See `Hypergraph::tag_hyperedge`.
+    pub fn tag_hyperedge(
+        &mut self,
+        hyperedge_index: HyperedgeIndex,
+        tag: impl Into<String>,
+    ) -> Result<bool, HypergraphError> {
+        self.check_writable()?;
+
+        let tag = tag.into();
+
+        let mut payload = vec![];
+
+        write_u64(&mut payload, hyperedge_index.0);
+        write_string(&mut payload, &tag);
+
+        let tagged = self.graph.tag_hyperedge(hyperedge_index, tag)?;
+
+        self.append(TAG_HYPEREDGE, payload)?;
+
+        Ok(tagged)
+    }
+
+    /// Removes a tag from a hyperedge and records it. See
+    /// `Hypergraph::untag_hyperedge`.
+    pub fn untag_hyperedge(
+        &mut self,
+        hyperedge_index: HyperedgeIndex,
+        tag: &str,
+    ) -> Result<bool, HypergraphError> {
+        self.check_writable()?;
+
+        let untagged = self.graph.untag_hyperedge(hyperedge_index, tag)?;
+
+        let mut payload = vec![];
+
+        write_u64(&mut payload, hyperedge_index.0);
+        write_string(&mut payload, tag);
+
+        self.append(UNTAG_HYPEREDGE, payload)?;
+
+        Ok(untagged)
+    }
+
+    /// Adds a layer and records it. See `Hypergraph::add_layer`.
+    pub fn add_layer(&mut self, name: impl Into<String>) -> Result<LayerIndex, HypergraphError> {
+        self.check_writable()?;
+
+        let name = name.into();
+
+        let mut payload = vec![];
+
+        write_string(&mut payload, &name);
+
+        let layer_index = self.graph.add_layer(name)?;
+
+        self.append(ADD_LAYER, payload)?;
+
+        Ok(layer_index)
+    }
+
+    /// Removes a layer and records it. See `Hypergraph::remove_layer`.
+    pub fn remove_layer(&mut self, layer_index: LayerIndex) -> Result<(), HypergraphError> {
+        self.check_writable()?;
+
+        self.graph.remove_layer(layer_index)?;
+
+        let mut payload = vec![];
+
+        write_u64(&mut payload, layer_index.0);
+
+        self.append(REMOVE_LAYER, payload)
+    }
+
+    /// Assigns a hyperedge to a layer, or to none of them, and records it.
+    /// See `Hypergraph::set_hyperedge_layer`.
+    pub fn set_hyperedge_layer(
+        &mut self,
+        hyperedge_index: HyperedgeIndex,
+        layer_index: Option<LayerIndex>,
+    ) -> Result<Option<LayerIndex>, HypergraphError> {
+        self.check_writable()?;
+
+        let previous = self
+            .graph
+            .set_hyperedge_layer(hyperedge_index, layer_index)?;
+
+        let mut payload = vec![];
+
+        write_u64(&mut payload, hyperedge_index.0);
+
+        match layer_index {
+            Some(layer_index) => {
+                payload.push(1);
+                write_u64(&mut payload, layer_index.0);
+            }
+            None => payload.push(0),
+        }
+
+        self.append(SET_HYPEREDGE_LAYER, payload)?;
+
+        Ok(previous)
+    }
+
+    /// Reassigns compact stable indexes and records it. See
+    /// `Hypergraph::remap_indexes`.
+    pub fn remap_indexes(&mut self) -> Result<IndexRemapping, HypergraphError> {
+        self.check_writable()?;
+
+        let remapping = self.graph.remap_indexes()?;
+
+        self.append(REMAP_INDEXES, vec![])?;
+
+        Ok(remapping)
+    }
+
+    /// Frees the stable indexes of the removed entities for reuse and
+    /// records it. See `Hypergraph::recycle_indexes`.
+    pub fn recycle_indexes(&mut self) -> Result<RecycledIndexes, HypergraphError> {
+        self.check_writable()?;
+
+        let recycled = self.graph.recycle_indexes();
+
+        self.append(RECYCLE_INDEXES, vec![])?;
+
+        Ok(recycled)
+    }
+
+    /// Removes the isolated vertices and records it. See
+    /// `Hypergraph::prune_isolated_vertices`.
+    pub fn prune_isolated_vertices(&mut self) -> Result<usize, HypergraphError> {
+        self.check_writable()?;
+
+        let removed = self.graph.prune_isolated_vertices()?;
+
+        self.append(PRUNE_ISOLATED_VERTICES, vec![])?;
+
+        Ok(removed)
+    }
+
+    /// Removes the hyperedges whose cardinality is out of range and records
+    /// it. See `Hypergraph::prune_hyperedges_by_cardinality`.
+    pub fn prune_hyperedges_by_cardinality(
+        &mut self,
+        min: usize,
+        max: usize,
+    ) -> Result<usize, HypergraphError> {
+        self.check_writable()?;
+
+        let removed = self.graph.prune_hyperedges_by_cardinality(min, max)?;
+
+        let mut payload = vec![];
+
+        write_u64(&mut payload, min);
+        write_u64(&mut payload, max);
+
+        self.append(PRUNE_HYPEREDGES_BY_CARDINALITY, payload)?;
+
+        Ok(removed)
+    }
+
+    /// Sets a metadata entry of the hypergraph and records it. See
+    /// `Hypergraph::set_metadata`.
+    pub fn set_metadata(
+        &mut self,
+        key: impl Into<String>,
+        value: impl Into<Value>,
+    ) -> Result<Option<Value>, HypergraphError> {
+        self.check_writable()?;
+
+        let (key, value) = (key.into(), value.into());
+
+        let mut payload = vec![];
+
+        write_string(&mut payload, &key);
+        write_value(&mut payload, &value);
+
+        let previous = self.graph.set_metadata(key, value);
+
+        self.append(SET_METADATA, payload)?;
+
+        Ok(previous)
+    }
+
+    /// Removes a metadata entry of the hypergraph and records it. See
+    /// `Hypergraph::remove_metadata`.
+    pub fn remove_metadata(&mut self, key: &str) -> Result<Option<Value>, HypergraphError> {
+        self.check_writable()?;
+
+        let previous = self.graph.remove_metadata(key);
+
+        let mut payload = vec![];
+
+        write_string(&mut payload, key);
+
+        self.append(REMOVE_METADATA, payload)?;
+
+        Ok(previous)
+    }
+}
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Reconstructs a hypergraph by replaying a journal file written by a
+    /// `JournaledHypergraph`. A truncated trailing record is ignored.
+    pub fn replay<P>(path: P) -> Result<Self, HypergraphError>
+    where
+        P: AsRef<Path>,
+        V: FromStr,
+        HE: FromStr,
+    {
+        let bytes = std::fs::read(path).map_err(io_error)?;
+
+        replay_bytes(&bytes).map(|(graph, ..)| graph)
+    }
+
+    /// Checks whether a file is a journal written by a `JournaledHypergraph`
+    /// by reading its magic bytes, e.g. to tell it apart from a snapshot
+    /// before replaying it. An unreadable file is not a journal.
+    pub fn is_journal<P>(path: P) -> bool
+    where
+        P: AsRef<Path>,
+    {
+        let mut magic = [0; MAGIC.len()];
+
+        File::open(path)
+            .and_then(|mut file| file.read_exact(&mut magic))
+            .is_ok()
+            && &magic == MAGIC
+    }
+}
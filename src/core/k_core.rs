@@ -0,0 +1,165 @@
+use std::{
+    cmp::Reverse,
+    collections::{
+        BinaryHeap,
+        HashMap,
+    },
+};
+
+use crate::{
+    HyperedgeKey,
+    HyperedgeTrait,
+    Hypergraph,
+    VertexIndex,
+    VertexKey,
+    VertexTrait,
+    core::types::AIndexSet,
+    errors::HypergraphError,
+};
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    // Private method to compute the core numbers of the vertices, ordered by
+    // their internal indexes.
+    // The degree of a vertex is the number of hyperedges it belongs to.
+    // Vertices are peeled by increasing degree and removing a vertex removes
+    // all its hyperedges.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            err,
+            fields(
+                vertices_count = self.vertices.len(),
+                hyperedges_count = self.hyperedges.len(),
+            )
+        )
+    )]
+    pub(crate) fn get_internal_core_numbers(&self) -> Result<Vec<usize>, HypergraphError> {
+        let mut degrees = self
+            .vertices
+            .values()
+            .map(AIndexSet::len)
+            .collect::<Vec<usize>>();
+        let mut core_numbers = vec![0; degrees.len()];
+        let mut peeled_vertices = vec![false; degrees.len()];
+        let mut peeled_hyperedges = vec![false; self.hyperedges.len()];
+
+        let mut heap = degrees
+            .iter()
+            .enumerate()
+            .map(|(vertex, degree)| Reverse((*degree, vertex)))
+            .collect::<BinaryHeap<Reverse<(usize, usize)>>>();
+
+        let mut core = 0;
+
+        while let Some(Reverse((degree, vertex))) = heap.pop() {
+            // Skip the outdated entries.
+            if peeled_vertices[vertex] || degree != degrees[vertex] {
+                continue;
+            }
+
+            core = core.max(degree);
+            core_numbers[vertex] = core;
+            peeled_vertices[vertex] = true;
+
+            let (_, hyperedges) = self
+                .vertices
+                .get_index(vertex)
+                .ok_or(HypergraphError::InternalVertexIndexNotFound(vertex))?;
+
+            for hyperedge in hyperedges {
+                if peeled_hyperedges[*hyperedge] {
+                    continue;
+                }
+
+                peeled_hyperedges[*hyperedge] = true;
+
+                let HyperedgeKey { vertices, .. } = self
+                    .hyperedges
+                    .get(*hyperedge)
+                    .ok_or(HypergraphError::InternalHyperedgeIndexNotFound(*hyperedge))?;
+
+                // Vertices can be repeated within a hyperedge.
+                for other in vertices.iter().collect::<AIndexSet<&usize>>() {
+                    if !peeled_vertices[*other] {
+                        degrees[*other] -= 1;
+                        heap.push(Reverse((degrees[*other], *other)));
+                    }
+                }
+            }
+        }
+
+        Ok(core_numbers)
+    }
+
+    /// Gets the core number of each vertex, i.e. the largest `k` such that
+    /// the vertex belongs to the k-core of the hypergraph.
+    /// The degree of a vertex is the number of hyperedges it belongs to.
+    /// <https://en.wikipedia.org/wiki/Degeneracy_(graph_theory)#k-Cores>
+    pub fn core_numbers(&self) -> Result<HashMap<VertexIndex, usize>, HypergraphError> {
+        self.get_internal_core_numbers()?
+            .into_iter()
+            .enumerate()
+            .map(|(internal_index, core_number)| {
+                self.get_vertex(internal_index)
+                    .map(|vertex_index| (vertex_index, core_number))
+            })
+            .collect()
+    }
+
+    /// Gets the k-core of the hypergraph, i.e. the maximal sub-hypergraph in
+    /// which every vertex belongs to at least `k` hyperedges.
+    /// The k-core is induced by its vertices: only the hyperedges whose
+    /// vertices are all kept are included.
+    /// Please note that the indexes of the resulting hypergraph are assigned
+    /// by insertion order and might differ from the original ones.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            err,
+            fields(
+                k,
+                vertices_count = self.vertices.len(),
+                hyperedges_count = self.hyperedges.len(),
+            )
+        )
+    )]
+    pub fn k_core(&self, k: usize) -> Result<Self, HypergraphError> {
+        let core_numbers = self.get_internal_core_numbers()?;
+
+        let mut graph = Hypergraph::new();
+
+        // Keep track of the new indexes of the kept vertices.
+        let mut mapping = vec![None; core_numbers.len()];
+
+        for (internal_index, (VertexKey { weight, id }, _)) in self.vertices.iter().enumerate() {
+            if core_numbers[internal_index] >= k {
+                // Keep the duplicated vertices duplicated.
+                mapping[internal_index] = Some(if id.is_some() {
+                    graph.add_vertex_allow_duplicate(weight.clone())?
+                } else {
+                    graph.add_vertex(weight.clone())?
+                });
+            }
+        }
+
+        for HyperedgeKey { vertices, weight } in &self.hyperedges {
+            if let Some(vertices) = vertices
+                .iter()
+                .map(|vertex| mapping[*vertex])
+                .collect::<Option<Vec<VertexIndex>>>()
+            {
+                graph.add_hyperedge(vertices, weight.clone())?;
+            }
+        }
+
+        Ok(graph)
+    }
+}
@@ -0,0 +1,275 @@
+use std::{
+    fmt::{
+        Display,
+        Formatter,
+    },
+    hash::{
+        Hash,
+        Hasher,
+    },
+};
+
+use crate::{
+    HyperedgeIndex,
+    Hypergraph,
+    VertexIndex,
+    core::types::AIndexSet,
+    errors::HypergraphError,
+};
+
+/// Vertex weight used by the language bindings.
+/// Holds the index of an interned label.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub(crate) struct LabeledVertex(usize);
+
+impl Display for LabeledVertex {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(formatter, "{}", self.0)
+    }
+}
+
+/// Hyperedge weight used by the language bindings.
+/// Holds the index of an interned label and a cost.
+/// Only the label is compared and hashed, so that two hyperedges can't share
+/// a label.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct LabeledHyperedge {
+    cost: usize,
+    label: usize,
+}
+
+impl PartialEq for LabeledHyperedge {
+    fn eq(&self, other: &Self) -> bool {
+        self.label == other.label
+    }
+}
+
+impl Eq for LabeledHyperedge {}
+
+impl Hash for LabeledHyperedge {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.label.hash(state);
+    }
+}
+
+impl Display for LabeledHyperedge {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(formatter, "{}", self.label)
+    }
+}
+
+impl From<LabeledHyperedge> for usize {
+    fn from(LabeledHyperedge { cost, .. }: LabeledHyperedge) -> Self {
+        cost
+    }
+}
+
+/// Hypergraph whose vertices and hyperedges are weighted by string labels
+/// and whose indexes are exposed as plain numbers.
+/// The labels are interned so that equal labels map to equal weights which
+/// are cheap to hash and compare.
+/// Interned labels are shared and never freed on removal, so the interner
+/// grows with every distinct label until the hypergraph is cleared.
+/// Shared by the language bindings.
+#[derive(Debug, Default)]
+pub(crate) struct LabeledHypergraph {
+    graph: Hypergraph<LabeledVertex, LabeledHyperedge>,
+    labels: AIndexSet<String>,
+}
+
+impl LabeledHypergraph {
+    // Private method to intern a label.
+    fn intern(&mut self, label: String) -> usize {
+        self.labels.insert_full(label).0
+    }
+
+    // Private method to get an interned label.
+    fn label(&self, index: usize) -> String {
+        self.labels.get_index(index).cloned().unwrap_or_default()
+    }
+
+    // Private method to resolve the labels of the weights held by an error,
+    // which are formatted as interned indexes.
+    fn resolve(&self, error: HypergraphError) -> HypergraphError {
+        let resolve = |weight: String| match weight.parse() {
+            Ok(index) => self.label(index),
+            Err(_) => weight,
+        };
+
+        match error {
+            HypergraphError::HyperedgeCreationNoVertices(weight) => {
+                HypergraphError::HyperedgeCreationNoVertices(resolve(weight))
+            }
+            HypergraphError::HyperedgeWeightNotFound(weight) => {
+                HypergraphError::HyperedgeWeightNotFound(resolve(weight))
+            }
+            HypergraphError::VertexWeightNotFound(weight) => {
+                HypergraphError::VertexWeightNotFound(resolve(weight))
+            }
+            error => error,
+        }
+    }
+
+    /// Adds a vertex with a label.
+    pub(crate) fn add_vertex(&mut self, label: String) -> Result<usize, HypergraphError> {
+        let weight = LabeledVertex(self.intern(label));
+
+        self.graph
+            .add_vertex(weight)
+            .map(|VertexIndex(index)| index)
+            .map_err(|error| self.resolve(error))
+    }
+
+    /// Adds a hyperedge with a label and a cost.
+    /// The labels of the hyperedges are unique, regardless of their costs.
+    pub(crate) fn add_hyperedge(
+        &mut self,
+        vertices: Vec<usize>,
+        label: String,
+        cost: usize,
+    ) -> Result<usize, HypergraphError> {
+        let weight = LabeledHyperedge {
+            cost,
+            label: self.intern(label),
+        };
+
+        self.graph
+            .add_hyperedge(vertices.into_iter().map(VertexIndex).collect(), weight)
+            .map(|HyperedgeIndex(index)| index)
+            .map_err(|error| self.resolve(error))
+    }
+
+    /// Returns the number of vertices.
+    pub(crate) fn count_vertices(&self) -> usize {
+        self.graph.count_vertices()
+    }
+
+    /// Returns the number of hyperedges.
+    pub(crate) fn count_hyperedges(&self) -> usize {
+        self.graph.count_hyperedges()
+    }
+
+    /// Gets the label of a vertex.
+    pub(crate) fn get_vertex_weight(&self, index: usize) -> Result<String, HypergraphError> {
+        self.graph
+            .get_vertex_weight(VertexIndex(index))
+            .map(|LabeledVertex(label)| self.label(*label))
+    }
+
+    /// Gets the label and the cost of a hyperedge.
+    pub(crate) fn get_hyperedge_weight(
+        &self,
+        index: usize,
+    ) -> Result<(String, usize), HypergraphError> {
+        self.graph
+            .get_hyperedge_weight(HyperedgeIndex(index))
+            .map(|LabeledHyperedge { cost, label }| (self.label(*label), *cost))
+    }
+
+    /// Gets the vertices of a hyperedge.
+    pub(crate) fn get_hyperedge_vertices(
+        &self,
+        index: usize,
+    ) -> Result<Vec<usize>, HypergraphError> {
+        self.graph
+            .get_hyperedge_vertices(HyperedgeIndex(index))
+            .map(|vertices| {
+                vertices
+                    .into_iter()
+                    .map(|VertexIndex(index)| index)
+                    .collect()
+            })
+    }
+
+    /// Gets the vertices connected from a vertex.
+    pub(crate) fn get_adjacent_vertices_from(
+        &self,
+        index: usize,
+    ) -> Result<Vec<usize>, HypergraphError> {
+        self.graph
+            .get_adjacent_vertices_from(VertexIndex(index))
+            .map(|vertices| {
+                vertices
+                    .into_iter()
+                    .map(|VertexIndex(index)| index)
+                    .collect()
+            })
+    }
+
+    /// Gets the vertices connected to a vertex.
+    pub(crate) fn get_adjacent_vertices_to(
+        &self,
+        index: usize,
+    ) -> Result<Vec<usize>, HypergraphError> {
+        self.graph
+            .get_adjacent_vertices_to(VertexIndex(index))
+            .map(|vertices| {
+                vertices
+                    .into_iter()
+                    .map(|VertexIndex(index)| index)
+                    .collect()
+            })
+    }
+
+    /// Gets the cheapest path between two vertices as tuples of the form
+    /// (vertex, traversed hyperedge).
+    pub(crate) fn get_dijkstra_connections(
+        &self,
+        from: usize,
+        to: usize,
+    ) -> Result<Vec<(usize, Option<usize>)>, HypergraphError> {
+        self.graph
+            .get_dijkstra_connections(VertexIndex(from), VertexIndex(to))
+            .map(|path| {
+                path.into_iter()
+                    .map(|(VertexIndex(vertex), hyperedge)| {
+                        (vertex, hyperedge.map(|HyperedgeIndex(index)| index))
+                    })
+                    .collect()
+            })
+    }
+
+    /// Removes a vertex.
+    pub(crate) fn remove_vertex(&mut self, index: usize) -> Result<(), HypergraphError> {
+        self.graph.remove_vertex(VertexIndex(index))
+    }
+
+    /// Removes a hyperedge.
+    pub(crate) fn remove_hyperedge(&mut self, index: usize) -> Result<(), HypergraphError> {
+        self.graph.remove_hyperedge(HyperedgeIndex(index))
+    }
+
+    /// Clears the hypergraph and the interned labels.
+    pub(crate) fn clear(&mut self) {
+        self.graph.clear();
+        self.labels.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_errors() {
+        let mut graph = LabeledHypergraph::default();
+
+        let a = graph.add_vertex("a".to_owned()).unwrap();
+
+        graph.add_hyperedge(vec![a], "alpha".to_owned(), 1).unwrap();
+
+        // The errors are converted to strings by the bindings.
+        assert_eq!(
+            graph
+                .add_hyperedge(vec![a, a], "alpha".to_owned(), 2)
+                .map_err(|error| error.to_string()),
+            Err("Hyperedge weight was already assigned to HyperedgeIndex 0".to_owned())
+        );
+        assert_eq!(
+            graph
+                .add_hyperedge(vec![], "beta".to_owned(), 1)
+                .map_err(|error| error.to_string()),
+            Err("Hyperedge weight beta vertices are missing".to_owned())
+        );
+    }
+}
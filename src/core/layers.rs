@@ -0,0 +1,366 @@
+use crate::{
+    HyperedgeIndex,
+    HyperedgeTrait,
+    Hypergraph,
+    LayerIndex,
+    VertexIndex,
+    VertexTrait,
+    core::types::AIndexSet,
+    errors::HypergraphError,
+};
+
+/// Number of layers tracked by each block of a `LayerMask`.
+const BLOCK_BITS: usize = u64::BITS as usize;
+
+/// Layer of a hypergraph, i.e. a named group of hyperedges sharing the same
+/// vertices as the other layers.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub(crate) struct Layer {
+    /// Name of the layer, unique within the hypergraph.
+    pub(crate) name: String,
+
+    /// Hyperedges belonging to the layer.
+    pub(crate) hyperedges: AIndexSet<HyperedgeIndex>,
+}
+
+/// Set of layers restricting the hyperedges traversed by an algorithm, e.g.
+/// `Hypergraph::get_dijkstra_connections_in_layers`.
+/// Stored as a bitset over the stable indexes of the layers.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct LayerMask {
+    blocks: Vec<u64>,
+}
+
+impl LayerMask {
+    /// Creates an empty mask.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a layer to the mask.
+    pub fn insert(&mut self, layer_index: LayerIndex) {
+        let block = layer_index.0 / BLOCK_BITS;
+
+        if block >= self.blocks.len() {
+            self.blocks.resize(block + 1, 0);
+        }
+
+        self.blocks[block] |= 1 << (layer_index.0 % BLOCK_BITS);
+    }
+
+    /// Removes a layer from the mask.
+    pub fn remove(&mut self, layer_index: LayerIndex) {
+        if let Some(block) = self.blocks.get_mut(layer_index.0 / BLOCK_BITS) {
+            *block &= !(1 << (layer_index.0 % BLOCK_BITS));
+        }
+    }
+
+    /// Checks if the mask contains a layer.
+    pub fn contains(&self, layer_index: LayerIndex) -> bool {
+        self.blocks
+            .get(layer_index.0 / BLOCK_BITS)
+            .map_or(false, |block| {
+                block & (1 << (layer_index.0 % BLOCK_BITS)) != 0
+            })
+    }
+
+    /// Checks if the mask doesn't contain any layer.
+    pub fn is_empty(&self) -> bool {
+        self.blocks.iter().all(|block| *block == 0)
+    }
+}
+
+impl FromIterator<LayerIndex> for LayerMask {
+    fn from_iter<I>(layers: I) -> Self
+    where
+        I: IntoIterator<Item = LayerIndex>,
+    {
+        let mut mask = Self::new();
+
+        for layer_index in layers {
+            mask.insert(layer_index);
+        }
+
+        mask
+    }
+}
+
+impl From<&[LayerIndex]> for LayerMask {
+    fn from(layers: &[LayerIndex]) -> Self {
+        layers.iter().copied().collect()
+    }
+}
+
+#[allow(clippy::type_complexity)]
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Adds a named layer to the hypergraph, e.g. to model a multiplex
+    /// network in which the same vertices are connected by several kinds of
+    /// relations.
+    /// The hyperedges are assigned to a layer via `set_hyperedge_layer`.
+    /// Returns the stable index of the layer.
+    pub fn add_layer(&mut self, name: impl Into<String>) -> Result<LayerIndex, HypergraphError> {
+        let name = name.into();
+
+        if let Some(layer_index) = self.get_layer_by_name(&name) {
+            return Err(HypergraphError::LayerNameAlreadyAssigned(layer_index));
+        }
+
+        let layer_index = LayerIndex(self.layers_count);
+
+        self.layers.insert(
+            layer_index,
+            Layer {
+                name,
+                hyperedges: AIndexSet::default(),
+            },
+        );
+        self.layers_count += 1;
+
+        Ok(layer_index)
+    }
+
+    /// Removes a layer from the hypergraph.
+    /// Its hyperedges are kept but don't belong to any layer anymore.
+    pub fn remove_layer(&mut self, layer_index: LayerIndex) -> Result<(), HypergraphError> {
+        let layer = self
+            .layers
+            .shift_remove(&layer_index)
+            .ok_or(HypergraphError::LayerIndexNotFound(layer_index))?;
+
+        for hyperedge_index in layer.hyperedges {
+            self.hyperedges_layers.swap_remove(&hyperedge_index);
+        }
+
+        Ok(())
+    }
+
+    /// Gets the layers of the hypergraph along with their names, in creation
+    /// order.
+    pub fn get_layers(&self) -> Vec<(LayerIndex, &str)> {
+        self.layers
+            .iter()
+            .map(|(layer_index, layer)| (*layer_index, layer.name.as_str()))
+            .collect()
+    }
+
+    /// Gets a layer by its name.
+    pub fn get_layer_by_name(&self, name: &str) -> Option<LayerIndex> {
+        self.layers
+            .iter()
+            .find(|(_, layer)| layer.name == name)
+            .map(|(layer_index, _)| *layer_index)
+    }
+
+    /// Gets the name of a layer.
+    pub fn get_layer_name(&self, layer_index: LayerIndex) -> Result<&str, HypergraphError> {
+        self.get_layer(layer_index).map(|layer| layer.name.as_str())
+    }
+
+    /// Assigns a hyperedge to a layer, or to none of them.
+    /// A hyperedge belongs to at most one layer.
+    /// Returns the previous layer of the hyperedge, if any.
+    pub fn set_hyperedge_layer(
+        &mut self,
+        hyperedge_index: HyperedgeIndex,
+        layer_index: Option<LayerIndex>,
+    ) -> Result<Option<LayerIndex>, HypergraphError> {
+        // Check that the hyperedge and the layer exist.
+        self.get_internal_hyperedge(hyperedge_index)?;
+
+        if let Some(layer_index) = layer_index {
+            self.get_layer(layer_index)?;
+        }
+
+        let previous = match layer_index {
+            Some(layer_index) => self.hyperedges_layers.insert(hyperedge_index, layer_index),
+            None => self.hyperedges_layers.swap_remove(&hyperedge_index),
+        };
+
+        if let Some(layer) = previous.and_then(|previous| self.layers.get_mut(&previous)) {
+            layer.hyperedges.swap_remove(&hyperedge_index);
+        }
+
+        if let Some(layer) = layer_index.and_then(|layer_index| self.layers.get_mut(&layer_index)) {
+            layer.hyperedges.insert(hyperedge_index);
+        }
+
+        Ok(previous)
+    }
+
+    /// Gets the layer of a hyperedge, if any.
+    pub fn get_hyperedge_layer(
+        &self,
+        hyperedge_index: HyperedgeIndex,
+    ) -> Result<Option<LayerIndex>, HypergraphError> {
+        // Check that the hyperedge exists.
+        self.get_internal_hyperedge(hyperedge_index)?;
+
+        Ok(self.hyperedges_layers.get(&hyperedge_index).copied())
+    }
+
+    /// Gets the hyperedges of a layer, sorted by index.
+    pub fn get_layer_hyperedges(
+        &self,
+        layer_index: LayerIndex,
+    ) -> Result<Vec<HyperedgeIndex>, HypergraphError> {
+        let mut hyperedges = self
+            .get_layer(layer_index)?
+            .hyperedges
+            .iter()
+            .copied()
+            .collect::<Vec<HyperedgeIndex>>();
+
+        hyperedges.sort_unstable();
+
+        Ok(hyperedges)
+    }
+
+    /// Gets the vertices connected by at least one hyperedge of a layer,
+    /// sorted by index.
+    pub fn get_layer_vertices(
+        &self,
+        layer_index: LayerIndex,
+    ) -> Result<Vec<VertexIndex>, HypergraphError> {
+        let mut vertices = self
+            .get_internal_layer_vertices(layer_index)?
+            .into_iter()
+            .map(|internal_index| self.get_vertex(internal_index))
+            .collect::<Result<Vec<VertexIndex>, HypergraphError>>()?;
+
+        vertices.sort_unstable();
+
+        Ok(vertices)
+    }
+
+    /// Gets the hyperedges belonging to the layers of a mask, sorted by
+    /// index. The hyperedges without any layer are left out.
+    pub fn get_hyperedges_in_layers(&self, mask: &LayerMask) -> Vec<HyperedgeIndex> {
+        let mut hyperedges = self
+            .hyperedges_layers
+            .iter()
+            .filter(|(_, layer_index)| mask.contains(**layer_index))
+            .map(|(hyperedge_index, _)| *hyperedge_index)
+            .collect::<Vec<HyperedgeIndex>>();
+
+        hyperedges.sort_unstable();
+
+        hyperedges
+    }
+
+    /// Gets the layers in which a vertex is connected by at least one
+    /// hyperedge, i.e. the interlayer couplings of the vertex, sorted by
+    /// index.
+    pub fn get_vertex_layers(
+        &self,
+        vertex_index: VertexIndex,
+    ) -> Result<Vec<LayerIndex>, HypergraphError> {
+        let internal_index = self.get_internal_vertex(vertex_index)?;
+        let (_, hyperedges) = self
+            .vertices
+            .get_index(internal_index)
+            .ok_or(HypergraphError::InternalVertexIndexNotFound(internal_index))?;
+
+        let mut layers = AIndexSet::default();
+
+        for hyperedge in hyperedges {
+            if let Some(layer_index) = self.hyperedges_layers.get(&self.get_hyperedge(*hyperedge)?)
+            {
+                layers.insert(*layer_index);
+            }
+        }
+
+        let mut layers = layers.into_iter().collect::<Vec<LayerIndex>>();
+
+        layers.sort_unstable();
+
+        Ok(layers)
+    }
+
+    /// Gets the vertices coupling two layers, i.e. the ones connected by at
+    /// least one hyperedge of each layer, sorted by index.
+    pub fn get_coupled_vertices(
+        &self,
+        layer_index: LayerIndex,
+        other_layer_index: LayerIndex,
+    ) -> Result<Vec<VertexIndex>, HypergraphError> {
+        let vertices = self.get_internal_layer_vertices(layer_index)?;
+        let other_vertices = self.get_internal_layer_vertices(other_layer_index)?;
+
+        let mut coupled = vertices
+            .intersection(&other_vertices)
+            .map(|internal_index| self.get_vertex(*internal_index))
+            .collect::<Result<Vec<VertexIndex>, HypergraphError>>()?;
+
+        coupled.sort_unstable();
+
+        Ok(coupled)
+    }
+
+    /// Gets the cheapest path between two vertices like
+    /// `get_dijkstra_connections` while only traversing the hyperedges of the
+    /// layers of a mask.
+    pub fn get_dijkstra_connections_in_layers(
+        &self,
+        from: VertexIndex,
+        to: VertexIndex,
+        mask: &LayerMask,
+    ) -> Result<Vec<(VertexIndex, Option<HyperedgeIndex>)>, HypergraphError> {
+        self.get_filtered_dijkstra_connections(
+            from,
+            to,
+            |hyperedge_index, _| {
+                self.hyperedges_layers
+                    .get(&hyperedge_index)
+                    .map_or(false, |layer_index| mask.contains(*layer_index))
+            },
+            None,
+        )
+    }
+
+    // Private method to get a layer.
+    fn get_layer(&self, layer_index: LayerIndex) -> Result<&Layer, HypergraphError> {
+        self.layers
+            .get(&layer_index)
+            .ok_or(HypergraphError::LayerIndexNotFound(layer_index))
+    }
+
+    // Private method to get the internal vertices of the hyperedges of a
+    // layer.
+    fn get_internal_layer_vertices(
+        &self,
+        layer_index: LayerIndex,
+    ) -> Result<AIndexSet<usize>, HypergraphError> {
+        let mut vertices = AIndexSet::default();
+
+        for hyperedge_index in self.get_layer(layer_index)?.hyperedges.iter() {
+            let internal_index = self.get_internal_hyperedge(*hyperedge_index)?;
+
+            vertices.extend(
+                self.hyperedges
+                    .get(internal_index)
+                    .ok_or(HypergraphError::InternalHyperedgeIndexNotFound(
+                        internal_index,
+                    ))?
+                    .vertices
+                    .iter()
+                    .copied(),
+            );
+        }
+
+        Ok(vertices)
+    }
+
+    // Private method to remove a hyperedge from its layer, e.g. when the
+    // hyperedge is removed.
+    pub(crate) fn unlayer_removed_hyperedge(&mut self, hyperedge_index: HyperedgeIndex) {
+        if let Some(layer_index) = self.hyperedges_layers.swap_remove(&hyperedge_index) {
+            if let Some(layer) = self.layers.get_mut(&layer_index) {
+                layer.hyperedges.swap_remove(&hyperedge_index);
+            }
+        }
+    }
+}
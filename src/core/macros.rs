@@ -0,0 +1,53 @@
+/// Creates a hypergraph from literal vertices and hyperedges, via a
+/// `HypergraphBuilder`. Returns the result of the build.
+///
+/// The vertices are identifiers, either bound to their weights beforehand
+/// or followed by their weights. Within the macro, the identifiers are then
+/// rebound to the indexes of the vertices, to declare the hyperedges as
+/// `weight => [vertices]`.
+///
+/// ```
+/// use hypergraph::{HyperedgeIndex, hypergraph};
+///
+/// let a = 1usize;
+///
+/// let graph = hypergraph! {
+///     vertices: [a, b = 2, c = 3],
+///     hyperedges: {
+///         10usize => [a, b],
+///         20 => [b, c, c],
+///     },
+/// }
+/// .unwrap();
+///
+/// assert_eq!(graph.count_vertices(), 3);
+/// assert_eq!(graph.get_hyperedge_weight(HyperedgeIndex(1)), Ok(&20));
+/// ```
+#[macro_export]
+macro_rules! hypergraph {
+    (@weight $vertex:ident) => {
+        $vertex
+    };
+    (@weight $vertex:ident $weight:expr) => {
+        $weight
+    };
+    (
+        vertices: [$($vertex:ident $(= $weight:expr)?),* $(,)?]
+        $(, hyperedges: {
+            $($hyperedge:expr => [$($hyperedge_vertex:ident),* $(,)?]),* $(,)?
+        })?
+        $(,)?
+    ) => {{
+        let mut builder = $crate::HypergraphBuilder::new();
+
+        $(
+            let $vertex = builder.add_vertex($crate::hypergraph!(@weight $vertex $($weight)?));
+        )*
+
+        $($(
+            builder.add_hyperedge(vec![$($hyperedge_vertex),*], $hyperedge);
+        )*)?
+
+        builder.build()
+    }};
+}
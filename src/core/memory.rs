@@ -0,0 +1,232 @@
+use std::mem::size_of;
+
+use crate::{
+    HyperedgeIndex,
+    HyperedgeKey,
+    HyperedgeTrait,
+    Hypergraph,
+    LayerIndex,
+    Value,
+    VertexIndex,
+    VertexKey,
+    VertexTrait,
+    core::{
+        adjacency_cache::Adjacency,
+        layers::Layer,
+        types::{
+            AIndexMap,
+            AIndexSet,
+            HyperedgeVertices,
+        },
+    },
+};
+
+/// Approximate heap usage of a hypergraph in bytes, broken down by
+/// component. Returned by `estimated_memory_bytes`.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct MemoryUsage {
+    /// Vertices map, including the sets of hyperedges of each vertex.
+    pub vertices: usize,
+    /// Hyperedges and their weights index, including the vectors of vertices
+    /// of each hyperedge.
+    pub hyperedges: usize,
+    /// Bi-directional mappings between the internal and the stable indexes.
+    pub mappings: usize,
+    /// Attributes of the vertices, properties, roles, tags and layers of the
+    /// hyperedges and metadata of the hypergraph.
+    pub attributes: usize,
+    /// Full-text search indexes, if enabled, and similarity index of the
+    /// embeddings of the vertices.
+    pub search_indexes: usize,
+    /// Adjacency cache and distance oracle, if built.
+    pub adjacency_cache: usize,
+}
+
+impl MemoryUsage {
+    /// Returns the total number of bytes of all the components.
+    pub fn total(&self) -> usize {
+        self.vertices
+            + self.hyperedges
+            + self.mappings
+            + self.attributes
+            + self.search_indexes
+            + self.adjacency_cache
+    }
+}
+
+// Private helper to estimate the heap usage of an `IndexMap` - or of an
+// `IndexSet` with a unit value - from its capacity. Entries store the hash
+// along with the key and the value while the hash table stores an index and
+// a control byte per bucket.
+pub(crate) fn index_map_bytes<K, V>(capacity: usize) -> usize {
+    capacity * (2 * size_of::<usize>() + size_of::<K>() + size_of::<V>() + 1)
+}
+
+// Private helper to get the heap usage of the vertices of a hyperedge. With
+// the `smallvec` feature, the inline vertices live within the key itself.
+#[cfg(not(feature = "smallvec"))]
+fn hyperedge_vertices_bytes(vertices: &HyperedgeVertices) -> usize {
+    vertices.capacity() * size_of::<usize>()
+}
+
+#[cfg(feature = "smallvec")]
+fn hyperedge_vertices_bytes(vertices: &HyperedgeVertices) -> usize {
+    if vertices.spilled() {
+        vertices.capacity() * size_of::<usize>()
+    } else {
+        0
+    }
+}
+
+// Private helper to estimate the heap usage of a `HashMap` from its
+// capacity, with a control byte per bucket.
+pub(crate) fn hash_map_bytes<K, V>(capacity: usize) -> usize {
+    capacity * (size_of::<K>() + size_of::<V>() + 1)
+}
+
+// Private helper to estimate the heap usage of the adjacency in one
+// direction.
+fn adjacency_bytes(adjacency: &Adjacency) -> usize {
+    index_map_bytes::<VertexIndex, Vec<(VertexIndex, Vec<HyperedgeIndex>)>>(adjacency.capacity())
+        + adjacency
+            .values()
+            .map(|adjacent_vertices| {
+                adjacent_vertices.capacity() * size_of::<(VertexIndex, Vec<HyperedgeIndex>)>()
+                    + adjacent_vertices
+                        .iter()
+                        .map(|(_, hyperedges)| hyperedges.capacity() * size_of::<HyperedgeIndex>())
+                        .sum::<usize>()
+            })
+            .sum::<usize>()
+}
+
+// Private helper to estimate the heap usage of a map of values.
+fn values_bytes(values: &AIndexMap<String, Value>) -> usize {
+    index_map_bytes::<String, Value>(values.capacity())
+        + values
+            .iter()
+            .map(|(key, value)| {
+                key.capacity()
+                    + match value {
+                        Value::String(value) => value.capacity(),
+                        _ => 0,
+                    }
+            })
+            .sum::<usize>()
+}
+
+// Private helper to estimate the heap usage of a map of attributes.
+fn attributes_bytes<I>(attributes: &AIndexMap<I, AIndexMap<String, Value>>) -> usize {
+    index_map_bytes::<I, AIndexMap<String, Value>>(attributes.capacity())
+        + attributes.values().map(values_bytes).sum::<usize>()
+}
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Estimates the heap usage of the hypergraph by walking its internal
+    /// structures, broken down by component.
+    /// The estimation is based on the capacities of the collections and
+    /// doesn't account for the allocator overhead nor for the heap usage of
+    /// the weights themselves.
+    pub fn estimated_memory_bytes(&self) -> MemoryUsage {
+        let vertices = index_map_bytes::<VertexKey<V>, AIndexSet<usize>>(self.vertices.capacity())
+            + self
+                .vertices
+                .values()
+                .map(|hyperedges| index_map_bytes::<usize, ()>(hyperedges.capacity()))
+                .sum::<usize>();
+
+        let hyperedges = self.hyperedges.capacity() * size_of::<HyperedgeKey<HE>>()
+            + index_map_bytes::<HE, usize>(self.hyperedges_weights.capacity())
+            + self
+                .hyperedges
+                .iter()
+                .map(|HyperedgeKey { vertices, .. }| hyperedge_vertices_bytes(vertices))
+                .sum::<usize>();
+        // The bitmaps are approximated by their serialized size.
+        #[cfg(feature = "roaring")]
+        let hyperedges = hyperedges
+            + self.hyperedges_bitmaps.capacity() * size_of::<roaring::RoaringTreemap>()
+            + self
+                .hyperedges_bitmaps
+                .iter()
+                .map(roaring::RoaringTreemap::serialized_size)
+                .sum::<usize>();
+
+        let mappings =
+            hash_map_bytes::<usize, HyperedgeIndex>(self.hyperedges_mapping.left.capacity())
+                + hash_map_bytes::<HyperedgeIndex, usize>(self.hyperedges_mapping.right.capacity())
+                + hash_map_bytes::<usize, VertexIndex>(self.vertices_mapping.left.capacity())
+                + hash_map_bytes::<VertexIndex, usize>(self.vertices_mapping.right.capacity());
+
+        let attributes = attributes_bytes(&self.vertices_attributes)
+            + attributes_bytes(&self.hyperedges_properties)
+            + values_bytes(&self.metadata)
+            + index_map_bytes::<HyperedgeIndex, Vec<String>>(self.hyperedges_roles.capacity())
+            + index_map_bytes::<VertexIndex, usize>(self.vertices_versions.capacity())
+            + index_map_bytes::<HyperedgeIndex, usize>(self.hyperedges_versions.capacity())
+            + self
+                .hyperedges_roles
+                .values()
+                .map(|roles| {
+                    roles.capacity() * size_of::<String>()
+                        + roles.iter().map(String::capacity).sum::<usize>()
+                })
+                .sum::<usize>()
+            + index_map_bytes::<String, AIndexSet<HyperedgeIndex>>(self.hyperedges_tags.capacity())
+            + self
+                .hyperedges_tags
+                .iter()
+                .map(|(tag, hyperedges)| {
+                    tag.capacity() + index_map_bytes::<HyperedgeIndex, ()>(hyperedges.capacity())
+                })
+                .sum::<usize>()
+            + index_map_bytes::<LayerIndex, Layer>(self.layers.capacity())
+            + self
+                .layers
+                .values()
+                .map(|layer| {
+                    layer.name.capacity()
+                        + index_map_bytes::<HyperedgeIndex, ()>(layer.hyperedges.capacity())
+                })
+                .sum::<usize>()
+            + index_map_bytes::<HyperedgeIndex, LayerIndex>(self.hyperedges_layers.capacity());
+
+        let search_indexes = self
+            .vertices_search_index
+            .as_ref()
+            .map_or(0, |search_index| search_index.estimated_memory_bytes())
+            + self
+                .hyperedges_search_index
+                .as_ref()
+                .map_or(0, |search_index| search_index.estimated_memory_bytes())
+            + self.vertices_similarity_index.estimated_memory_bytes();
+        #[cfg(feature = "bloom")]
+        let search_indexes = search_indexes
+            + self
+                .hyperedges_filter
+                .as_ref()
+                .map_or(0, |filter| filter.estimated_memory_bytes());
+
+        let adjacency_cache = self.adjacency_cache.as_ref().map_or(0, |cache| {
+            adjacency_bytes(&cache.from)
+                + adjacency_bytes(&cache.to)
+                + cache.costs.capacity() * size_of::<usize>()
+        }) + self
+            .distance_oracle
+            .as_ref()
+            .map_or(0, |oracle| oracle.estimated_memory_bytes());
+
+        MemoryUsage {
+            adjacency_cache,
+            vertices,
+            hyperedges,
+            mappings,
+            attributes,
+            search_indexes,
+        }
+    }
+}
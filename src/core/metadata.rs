@@ -0,0 +1,44 @@
+use crate::{
+    HyperedgeTrait,
+    Hypergraph,
+    Value,
+    VertexTrait,
+};
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Sets a metadata entry of the hypergraph itself, e.g. its name, its
+    /// creation time or the dataset it comes from.
+    /// The metadata are preserved by the snapshots and the journals, shown
+    /// by the `Display` summary and kept by `clear`.
+    /// Returns the previous value of the entry, if any.
+    pub fn set_metadata(
+        &mut self,
+        key: impl Into<String>,
+        value: impl Into<Value>,
+    ) -> Option<Value> {
+        self.metadata.insert(key.into(), value.into())
+    }
+
+    /// Gets a metadata entry of the hypergraph.
+    pub fn get_metadata(&self, key: &str) -> Option<&Value> {
+        self.metadata.get(key)
+    }
+
+    /// Removes a metadata entry of the hypergraph.
+    /// Returns the removed value, if any.
+    pub fn remove_metadata(&mut self, key: &str) -> Option<Value> {
+        self.metadata.shift_remove(key)
+    }
+
+    /// Gets an iterator over the metadata entries of the hypergraph, in
+    /// insertion order.
+    pub fn iter_metadata(&self) -> impl Iterator<Item = (&str, &Value)> {
+        self.metadata
+            .iter()
+            .map(|(key, value)| (key.as_str(), value))
+    }
+}
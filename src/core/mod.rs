@@ -1,17 +1,131 @@
+mod adjacency_cache;
+#[doc(hidden)]
+pub mod algebra;
+#[cfg(feature = "arrow")]
+#[doc(hidden)]
+pub mod arrow;
+#[doc(hidden)]
+pub mod betweenness;
 pub(crate) mod bi_hash_map;
 #[doc(hidden)]
+pub mod bipartite;
+#[cfg(feature = "roaring")]
+#[doc(hidden)]
+pub mod bitmaps;
+#[cfg(feature = "bloom")]
+#[doc(hidden)]
+pub mod bloom;
+#[doc(hidden)]
+pub mod builder;
+mod cardinalities;
+#[doc(hidden)]
+pub mod components;
+#[doc(hidden)]
+pub mod constraints;
+#[cfg(feature = "rand")]
+#[doc(hidden)]
+pub mod contagion;
+#[doc(hidden)]
+pub mod content_hash;
+#[doc(hidden)]
+pub mod dijkstra;
+mod display;
+#[doc(hidden)]
+pub mod dot;
+#[doc(hidden)]
+pub mod duplicate_policy;
+#[doc(hidden)]
+pub mod dynamics;
+#[cfg(feature = "rand")]
+#[doc(hidden)]
+pub mod embeddings;
+#[doc(hidden)]
 pub mod errors;
 #[doc(hidden)]
+pub mod frozen;
+#[doc(hidden)]
+pub mod handles;
+#[doc(hidden)]
+pub mod history;
+#[doc(hidden)]
 pub mod hyperedges;
 mod indexes;
 #[doc(hidden)]
 pub mod iterator;
+#[doc(hidden)]
+pub mod journal;
+#[doc(hidden)]
+pub mod k_core;
+#[cfg(any(feature = "python", feature = "wasm"))]
+mod labels;
+#[doc(hidden)]
+pub mod layers;
+mod macros;
+#[doc(hidden)]
+pub mod memory;
+mod metadata;
+#[doc(hidden)]
+pub mod morphism;
+#[doc(hidden)]
+pub mod oracle;
+mod parallel;
+#[doc(hidden)]
+pub mod parts;
+#[doc(hidden)]
+pub mod path_query;
+#[cfg(feature = "petgraph")]
+#[doc(hidden)]
+pub mod petgraph;
+#[doc(hidden)]
+pub mod progress;
+#[cfg(feature = "python")]
+#[doc(hidden)]
+pub mod python;
+#[doc(hidden)]
+pub mod remapping;
+#[doc(hidden)]
+pub mod reversed;
+#[cfg(feature = "rand")]
+#[doc(hidden)]
+pub mod sampling;
+#[doc(hidden)]
+pub mod search;
+#[cfg(feature = "server")]
+#[doc(hidden)]
+pub mod server;
 mod shared;
 #[doc(hidden)]
+pub mod similarity;
+#[doc(hidden)]
+pub mod snapshot;
+#[cfg(feature = "ndarray")]
+#[doc(hidden)]
+pub mod spectral;
+#[doc(hidden)]
+pub mod stats;
+#[doc(hidden)]
+pub mod store;
+#[doc(hidden)]
+pub mod temporal;
+#[cfg(feature = "test-utils")]
+#[doc(hidden)]
+pub mod test_utils;
+#[doc(hidden)]
+pub mod topology;
+#[doc(hidden)]
 mod types;
 mod utils;
 #[doc(hidden)]
+pub mod validation;
+#[doc(hidden)]
+pub mod value;
+#[doc(hidden)]
+pub mod versions;
+#[doc(hidden)]
 pub mod vertices;
+#[cfg(feature = "wasm")]
+#[doc(hidden)]
+pub mod wasm;
 
 use std::{
     fmt::{
@@ -24,46 +138,160 @@ use std::{
     ops::Deref,
 };
 
+use adjacency_cache::AdjacencyCache;
 use bi_hash_map::BiHashMap;
+#[cfg(feature = "bloom")]
+use bloom::HyperedgeFilter;
+use cardinalities::CardinalityHistogram;
+use layers::Layer;
+use oracle::DistanceOracle;
+use search::SearchIndex;
+use similarity::SimilarityIndex;
 use types::{
     AIndexMap,
     AIndexSet,
     ARandomState,
+    HyperedgeVertices,
 };
 
+// Reexport the bipartite graph at this level.
+pub use crate::core::bipartite::{
+    BipartiteGraph,
+    BipartiteIncidence,
+};
+// Reexport the hypergraph builder at this level.
+pub use crate::core::builder::HypergraphBuilder;
+// Reexport the constraints at this level.
+pub use crate::core::constraints::Constraints;
+// Reexport the states and the time series of the contagion models at this
+// level.
+#[cfg(feature = "rand")]
+pub use crate::core::contagion::{
+    ContagionSeries,
+    ContagionState,
+};
+// Reexport the path iterator at this level.
+pub use crate::core::dijkstra::PathIter;
+// Reexport the default color provider of the dot rendering at this level.
+pub use crate::core::dot::palette_color;
+// Reexport the duplicate policy at this level.
+pub use crate::core::duplicate_policy::{
+    DuplicatePolicy,
+    VertexDedupPolicy,
+};
+// Reexport the embeddings options at this level.
+#[cfg(feature = "rand")]
+pub use crate::core::embeddings::EmbeddingsOptions;
+// Reexport the frozen hypergraph at this level.
+pub use crate::core::frozen::{
+    FrozenHypergraph,
+    FrozenReport,
+};
+// Reexport the vertex and hyperedge handles at this level.
+pub use crate::core::handles::{
+    HyperedgeRef,
+    VertexRef,
+};
+// Reexport the time-travel queries over the journal at this level.
+pub use crate::core::history::{
+    HistoricalHypergraph,
+    JournalEntity,
+    JournalEntry,
+    JournalOperation,
+};
 // Reexport indexes at this level.
 pub use crate::core::indexes::{
     HyperedgeIndex,
+    LayerIndex,
     VertexIndex,
 };
+// Reexport the journaled hypergraph at this level.
+pub use crate::core::journal::JournaledHypergraph;
+// Reexport the layer mask at this level.
+pub use crate::core::layers::LayerMask;
+// Reexport the memory usage at this level.
+pub use crate::core::memory::MemoryUsage;
+// Reexport the owned parts at this level.
+pub use crate::core::parts::HypergraphParts;
+// Reexport the path query at this level.
+pub use crate::core::path_query::PathQuery;
+// Reexport the petgraph incidence node at this level.
+#[cfg(feature = "petgraph")]
+pub use crate::core::petgraph::IncidenceNode;
+// Reexport the progress report at this level.
+pub use crate::core::progress::Progress;
+// Reexport the index remapping and the recycled indexes at this level.
+pub use crate::core::remapping::{
+    IndexRemapping,
+    RecycledIndexes,
+};
+// Reexport the reversed view at this level.
+pub use crate::core::reversed::ReversedHypergraph;
+// Reexport the weighted sampler at this level.
+#[cfg(feature = "rand")]
+pub use crate::core::sampling::WeightedSampler;
+// Reexport the HTTP server at this level.
+#[cfg(feature = "server")]
+pub use crate::core::server::HypergraphServer;
+// Reexport the snapshot store at this level.
+pub use crate::core::store::SnapshotStore;
+// Reexport the temporal trait at this level.
+pub use crate::core::temporal::TemporalHyperedgeTrait;
+// Reexport the topology builder at this level.
+pub use crate::core::topology::{
+    CardinalityDistribution,
+    TopologyBuilder,
+};
+// Reexport the validation report at this level.
+pub use crate::core::validation::{
+    Severity,
+    ValidationCode,
+    ValidationIssue,
+    ValidationReport,
+};
+// Reexport the attribute value at this level.
+pub use crate::core::value::Value;
 
 /// Shared Trait for the vertices.
 /// Must be implemented to use the library.
-pub trait VertexTrait: Copy + Debug + Display + Eq + Hash + Send + Sync {}
+/// Only `Clone` is required, hence owned weights such as `String` are
+/// supported. The weights are moved into the hypergraph, the getters return
+/// references to them and they are only cloned when needed, e.g. to build
+/// another hypergraph.
+pub trait VertexTrait: Clone + Debug + Display + Eq + Hash + Send + Sync {}
 
-impl<T> VertexTrait for T where T: Copy + Debug + Display + Eq + Hash + Send + Sync {}
+impl<T> VertexTrait for T where T: Clone + Debug + Display + Eq + Hash + Send + Sync {}
 
 /// Shared Trait for the hyperedges.
 /// Must be implemented to use the library.
+/// The conversion into `usize` gives the cost of the hyperedge. Since it
+/// consumes the weight, the latter is cloned for the computations involving
+/// costs, which should be cheap.
 pub trait HyperedgeTrait: VertexTrait + Into<usize> {}
 
 impl<T> HyperedgeTrait for T where T: VertexTrait + Into<usize> {}
 
 /// A `HyperedgeKey` is a representation of both the vertices and the weight
-/// of a hyperedge, used as a key in the hyperedges set.
-/// In a non-simple hypergraph, since the same vertices can be shared by
-/// different hyperedges, the weight is also included in the key to keep
-/// it unique.
+/// of a hyperedge, stored in the hyperedges vector.
+/// In a non-simple hypergraph, the same vertices can be shared by different
+/// hyperedges, hence the uniqueness is only enforced on the weights via a
+/// separate index. Both members can thus be updated in place.
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub(crate) struct HyperedgeKey<HE> {
-    vertices: Vec<usize>,
+    vertices: HyperedgeVertices,
     weight: HE,
 }
 
 impl<HE> HyperedgeKey<HE> {
     /// Creates a new `HyperedgeKey` from the given vertices and weight.
-    pub(crate) fn new(vertices: Vec<usize>, weight: HE) -> HyperedgeKey<HE> {
-        Self { vertices, weight }
+    pub(crate) fn new<T>(vertices: T, weight: HE) -> HyperedgeKey<HE>
+    where
+        T: Into<HyperedgeVertices>,
+    {
+        Self {
+            vertices: vertices.into(),
+            weight,
+        }
     }
 }
 
@@ -75,17 +303,58 @@ impl<HE> Deref for HyperedgeKey<HE> {
     }
 }
 
+/// A `VertexKey` is a representation of the weight of a vertex, used as a
+/// key in the vertices map.
+/// Vertices added via `add_vertex_allow_duplicate` also include an
+/// auto-generated id in their key so that their weights don't need to be
+/// unique.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub(crate) struct VertexKey<V> {
+    weight: V,
+    id: Option<usize>,
+}
+
+impl<V> VertexKey<V> {
+    /// Creates a new `VertexKey` from the given weight and optional id.
+    pub(crate) fn new(weight: V, id: Option<usize>) -> VertexKey<V> {
+        Self { weight, id }
+    }
+}
+
+impl<V> Deref for VertexKey<V> {
+    type Target = V;
+
+    fn deref(&self) -> &V {
+        &self.weight
+    }
+}
+
 /// A directed hypergraph composed of generic vertices and hyperedges.
+/// Cloning a hypergraph copies it deeply, preserving its stable indexes.
+#[derive(Clone)]
 pub struct Hypergraph<V, HE> {
-    /// Vertices are stored as a map whose unique keys are the weights
-    /// and the values are a set of the hyperedges indexes which include
-    /// the current vertex.
-    vertices: AIndexMap<V, AIndexSet<usize>>,
+    /// Vertices are stored as a map whose unique keys are the weights -
+    /// along with an optional id for duplicated ones - and the values are a
+    /// set of the hyperedges indexes which include the current vertex.
+    vertices: AIndexMap<VertexKey<V>, AIndexSet<usize>>,
 
-    /// Hyperedges are stored as a set whose unique keys are a combination of
-    /// vertices indexes and a weight. Two or more hyperedges can contain
-    /// the exact same vertices (non-simple hypergraph).
-    hyperedges: AIndexSet<HyperedgeKey<HE>>,
+    /// Hyperedges are stored as a vector of vertices indexes and weights,
+    /// the position in the vector being the internal index. Two or more
+    /// hyperedges can contain the exact same vertices (non-simple
+    /// hypergraph).
+    hyperedges: Vec<HyperedgeKey<HE>>,
+
+    /// Unique vertices of each hyperedge as a bitmap of their stable
+    /// indexes, aligned with the hyperedges.
+    #[cfg(feature = "roaring")]
+    hyperedges_bitmaps: Vec<roaring::RoaringTreemap>,
+
+    /// Internal indexes of the hyperedges by weight, used to check the
+    /// uniqueness of the weights in constant time.
+    hyperedges_weights: AIndexMap<HE, usize>,
+
+    /// Number of hyperedges by cardinality.
+    hyperedges_cardinalities: CardinalityHistogram,
 
     /// Bi-directional map for hyperedges.
     hyperedges_mapping: BiHashMap<HyperedgeIndex>,
@@ -98,6 +367,86 @@ pub struct Hypergraph<V, HE> {
 
     /// Stable index generation counter for vertices.
     vertices_count: usize,
+
+    /// Recycled stable indexes of the removed hyperedges, reused before the
+    /// counter and sorted in descending order so that the lowest one comes
+    /// first.
+    hyperedges_recycled: Vec<HyperedgeIndex>,
+
+    /// Recycled stable indexes of the removed vertices, reused before the
+    /// counter and sorted in descending order so that the lowest one comes
+    /// first.
+    vertices_recycled: Vec<VertexIndex>,
+
+    /// Attributes of the vertices, stored by stable index apart from the
+    /// weights.
+    vertices_attributes: AIndexMap<VertexIndex, AIndexMap<String, Value>>,
+
+    /// Properties of the hyperedges, stored by stable index apart from the
+    /// weights.
+    hyperedges_properties: AIndexMap<HyperedgeIndex, AIndexMap<String, Value>>,
+
+    /// Roles of the vertices of the hyperedges, stored by stable index and
+    /// aligned with the positions of the vertices.
+    hyperedges_roles: AIndexMap<HyperedgeIndex, Vec<String>>,
+
+    /// Versions of the vertices, stored by stable index and issued by
+    /// `versions_count` at their creation and updates for the optimistic
+    /// concurrency. A missing entry, e.g. for the entities built from
+    /// parts, is the version zero.
+    vertices_versions: AIndexMap<VertexIndex, usize>,
+
+    /// Versions of the hyperedges, same as the ones of the vertices.
+    hyperedges_versions: AIndexMap<HyperedgeIndex, usize>,
+
+    /// Last version issued to the vertices and the hyperedges. It is never
+    /// reset so that the versions keep increasing at each stable index,
+    /// across the removals and the recycled or remapped indexes.
+    versions_count: usize,
+
+    /// Hyperedges of each tag, the tags being kept in creation order.
+    hyperedges_tags: AIndexMap<String, AIndexSet<HyperedgeIndex>>,
+
+    /// Layers by stable index, along with their hyperedges.
+    layers: AIndexMap<LayerIndex, Layer>,
+
+    /// Layer of each hyperedge assigned to one.
+    hyperedges_layers: AIndexMap<HyperedgeIndex, LayerIndex>,
+
+    /// Stable index generation counter for layers.
+    layers_count: usize,
+
+    /// Metadata of the hypergraph itself, e.g. its name or its provenance.
+    metadata: AIndexMap<String, Value>,
+
+    /// Optional full-text search index of the vertices.
+    vertices_search_index: Option<SearchIndex<VertexIndex, V>>,
+
+    /// Optional full-text search index of the hyperedges.
+    hyperedges_search_index: Option<SearchIndex<HyperedgeIndex, HE>>,
+
+    /// Optional approximate membership filter of the hyperedges.
+    #[cfg(feature = "bloom")]
+    hyperedges_filter: Option<HyperedgeFilter>,
+
+    /// Similarity index of the embeddings of the vertices.
+    vertices_similarity_index: SimilarityIndex,
+
+    /// Optional precomputed adjacency of the vertices, dropped on mutation.
+    adjacency_cache: Option<AdjacencyCache>,
+
+    /// Optional precomputed distances from and to some landmark vertices,
+    /// dropped on mutation.
+    distance_oracle: Option<DistanceOracle>,
+
+    /// Policy enforced on the vertices of the hyperedges.
+    duplicate_policy: DuplicatePolicy,
+
+    /// Deduplication of the vertices of the updated or joined hyperedges.
+    vertex_dedup_policy: VertexDedupPolicy,
+
+    /// Constraints enforced by the mutating methods.
+    constraints: Constraints,
 }
 
 impl<V, HE> Debug for Hypergraph<V, HE>
@@ -113,6 +462,67 @@ where
     }
 }
 
+/// Two hypergraphs are equal when they hold the same vertices and hyperedges
+/// under the same stable indexes, i.e. with the same weights, the same
+/// vertices for each hyperedge and the same attributes, properties, roles,
+/// tags and layers, and when they have the same metadata.
+/// The internal order, the caches, the search and similarity indexes and the
+/// configuration are ignored.
+impl<V, HE> PartialEq for Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.vertices.len() == other.vertices.len()
+            && self.hyperedges.len() == other.hyperedges.len()
+            && self
+                .vertices_mapping
+                .right
+                .iter()
+                .all(|(vertex_index, internal_index)| {
+                    match (
+                        self.vertices.get_index(*internal_index),
+                        other.get_vertex_weight(*vertex_index),
+                    ) {
+                        (Some((key, _)), Ok(weight)) => key.weight == *weight,
+                        _ => false,
+                    }
+                })
+            && self
+                .hyperedges_mapping
+                .right
+                .iter()
+                .all(|(hyperedge_index, internal_index)| {
+                    let stable_vertices = |graph: &Self, internal_index: usize| {
+                        graph.hyperedges.get(internal_index).and_then(|key| {
+                            key.vertices
+                                .iter()
+                                .map(|vertex| graph.get_vertex(*vertex).ok())
+                                .collect::<Option<Vec<VertexIndex>>>()
+                                .map(|vertices| (key.weight.clone(), vertices))
+                        })
+                    };
+
+                    match other.hyperedges_mapping.right.get(hyperedge_index) {
+                        Some(other_internal_index) => {
+                            let hyperedge = stable_vertices(self, *internal_index);
+
+                            hyperedge.is_some()
+                                && hyperedge == stable_vertices(other, *other_internal_index)
+                        }
+                        None => false,
+                    }
+                })
+            && self.vertices_attributes == other.vertices_attributes
+            && self.hyperedges_properties == other.hyperedges_properties
+            && self.hyperedges_roles == other.hyperedges_roles
+            && self.hyperedges_tags == other.hyperedges_tags
+            && self.layers == other.layers
+            && self.metadata == other.metadata
+    }
+}
+
 impl<V, HE> Default for Hypergraph<V, HE>
 where
     V: VertexTrait,
@@ -129,19 +539,71 @@ where
     V: VertexTrait,
     HE: HyperedgeTrait,
 {
-    /// Clears the hypergraph.
+    /// Clears the hypergraph, its metadata aside.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            fields(
+                vertices_count = self.vertices.len(),
+                hyperedges_count = self.hyperedges.len(),
+            )
+        )
+    )]
     pub fn clear(&mut self) {
         // Clear the hyperedges and vertices sets while keeping their capacities.
         self.hyperedges.clear();
+        self.hyperedges_weights.clear();
+        self.hyperedges_cardinalities.clear();
         self.vertices.clear();
+        #[cfg(feature = "roaring")]
+        self.hyperedges_bitmaps.clear();
 
         // Reset the mappings.
         self.hyperedges_mapping = BiHashMap::default();
         self.vertices_mapping = BiHashMap::default();
 
-        // Reset the counters.
+        // Reset the counters and the recycled indexes, except the one of the
+        // versions.
         self.hyperedges_count = 0;
         self.vertices_count = 0;
+        self.hyperedges_recycled.clear();
+        self.vertices_recycled.clear();
+
+        // Clear the attributes, the properties, the roles, the tags and the
+        // layers.
+        self.vertices_attributes.clear();
+        self.hyperedges_properties.clear();
+        self.hyperedges_roles.clear();
+        self.hyperedges_tags.clear();
+        self.vertices_versions.clear();
+        self.hyperedges_versions.clear();
+        self.hyperedges_layers.clear();
+        self.layers.clear();
+        self.layers_count = 0;
+
+        // Drop the adjacency cache and the distance oracle.
+        self.adjacency_cache = None;
+        self.distance_oracle = None;
+
+        // Clear the search indexes while keeping them enabled.
+        if let Some(search_index) = self.vertices_search_index.as_mut() {
+            search_index.clear();
+        }
+
+        // Clear the embeddings of the vertices.
+        self.vertices_similarity_index.clear();
+
+        if let Some(search_index) = self.hyperedges_search_index.as_mut() {
+            search_index.clear();
+        }
+
+        // Clear the approximate membership filter while keeping it enabled.
+        #[cfg(feature = "bloom")]
+        if let Some(filter) = self.hyperedges_filter.as_mut() {
+            filter.clear();
+        }
     }
 
     /// Creates a new hypergraph with no allocation.
@@ -152,12 +614,75 @@ where
     /// Creates a new hypergraph with the specified capacity.
     pub fn with_capacity(vertices: usize, hyperedges: usize) -> Self {
         Hypergraph {
+            adjacency_cache: None,
+            distance_oracle: None,
+            constraints: Constraints::default(),
+            duplicate_policy: DuplicatePolicy::default(),
+            vertex_dedup_policy: VertexDedupPolicy::default(),
+            hyperedges_cardinalities: CardinalityHistogram::default(),
             hyperedges_count: 0,
-            hyperedges_mapping: BiHashMap::default(),
-            hyperedges: AIndexSet::with_capacity_and_hasher(hyperedges, ARandomState::default()),
+            hyperedges_mapping: BiHashMap::with_capacity(hyperedges),
+            hyperedges: Vec::with_capacity(hyperedges),
+            #[cfg(feature = "roaring")]
+            hyperedges_bitmaps: Vec::with_capacity(hyperedges),
+            hyperedges_weights: AIndexMap::with_capacity_and_hasher(
+                hyperedges,
+                ARandomState::default(),
+            ),
+            hyperedges_properties: AIndexMap::default(),
+            hyperedges_recycled: Vec::new(),
+            hyperedges_roles: AIndexMap::default(),
+            hyperedges_search_index: None,
+            hyperedges_versions: AIndexMap::default(),
+            #[cfg(feature = "bloom")]
+            hyperedges_filter: None,
+            hyperedges_layers: AIndexMap::default(),
+            hyperedges_tags: AIndexMap::default(),
+            layers: AIndexMap::default(),
+            layers_count: 0,
+            metadata: AIndexMap::default(),
             vertices_count: 0,
-            vertices_mapping: BiHashMap::default(),
+            vertices_mapping: BiHashMap::with_capacity(vertices),
             vertices: AIndexMap::with_capacity_and_hasher(vertices, ARandomState::default()),
+            vertices_attributes: AIndexMap::default(),
+            vertices_versions: AIndexMap::default(),
+            versions_count: 0,
+            vertices_recycled: Vec::new(),
+            vertices_search_index: None,
+            vertices_similarity_index: SimilarityIndex::default(),
+        }
+    }
+
+    /// Reserves capacity for at least the specified numbers of additional
+    /// vertices and hyperedges, e.g. ahead of a bulk build, to avoid
+    /// repeated reallocations.
+    pub fn reserve(&mut self, vertices: usize, hyperedges: usize) {
+        self.vertices.reserve(vertices);
+        self.vertices_mapping.reserve(vertices);
+        self.hyperedges.reserve(hyperedges);
+        #[cfg(feature = "roaring")]
+        self.hyperedges_bitmaps.reserve(hyperedges);
+        self.hyperedges_weights.reserve(hyperedges);
+        self.hyperedges_mapping.reserve(hyperedges);
+    }
+
+    /// Shrinks the capacity of the hypergraph as much as possible, e.g.
+    /// after a bulk build or after many removals.
+    pub fn shrink_to_fit(&mut self) {
+        self.vertices.shrink_to_fit();
+        self.vertices_mapping.shrink_to_fit();
+        self.hyperedges.shrink_to_fit();
+        #[cfg(feature = "roaring")]
+        self.hyperedges_bitmaps.shrink_to_fit();
+        self.hyperedges_weights.shrink_to_fit();
+        self.hyperedges_mapping.shrink_to_fit();
+
+        for hyperedges in self.vertices.values_mut() {
+            hyperedges.shrink_to_fit();
+        }
+
+        for HyperedgeKey { vertices, .. } in self.hyperedges.iter_mut() {
+            vertices.shrink_to_fit();
         }
     }
 }
@@ -0,0 +1,148 @@
+use crate::{
+    HyperedgeKey,
+    HyperedgeTrait,
+    Hypergraph,
+    VertexKey,
+    VertexTrait,
+    core::types::{
+        AIndexMap,
+        ARandomState,
+    },
+    errors::HypergraphError,
+};
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Creates a new hypergraph with the same topology by mapping the
+    /// weights of the vertices and of the hyperedges, e.g. to convert rich
+    /// structs to compact ids for serving.
+    /// The stable indexes are preserved along with the attributes, the
+    /// properties, the roles, the tags, the layers, the metadata, the
+    /// embeddings and the configuration. The search indexes and the
+    /// hyperedge filter, which depend on the weights, are not.
+    /// Vertices whose mapped weights clash are kept apart like the ones
+    /// added via `add_vertex_allow_duplicate`. Hyperedges whose mapped
+    /// weights clash return an error since their weights must be unique.
+    pub fn map_weights<V2, HE2, FV, FHE>(
+        &self,
+        mut vertex_mapper: FV,
+        mut hyperedge_mapper: FHE,
+    ) -> Result<Hypergraph<V2, HE2>, HypergraphError>
+    where
+        V2: VertexTrait,
+        HE2: HyperedgeTrait,
+        FV: FnMut(&V) -> V2,
+        FHE: FnMut(&HE) -> HE2,
+    {
+        // Map the vertices in internal order, so that the internal indexes
+        // are preserved.
+        let mut vertices =
+            AIndexMap::with_capacity_and_hasher(self.vertices.len(), ARandomState::default());
+
+        for (internal_index, (key, hyperedges)) in self.vertices.iter().enumerate() {
+            let mut mapped = VertexKey::new(vertex_mapper(&key.weight), key.id);
+
+            // Follow `add_vertex_allow_duplicate` for the clashes.
+            if vertices.contains_key(&mapped) {
+                mapped.id = Some(self.get_vertex(internal_index)?.0);
+            }
+
+            vertices.insert(mapped, hyperedges.clone());
+        }
+
+        // Map the hyperedges in internal order as well.
+        let mut hyperedges = Vec::with_capacity(self.hyperedges.len());
+        let mut hyperedges_weights =
+            AIndexMap::with_capacity_and_hasher(self.hyperedges.len(), ARandomState::default());
+
+        for (internal_index, HyperedgeKey { vertices, weight }) in
+            self.hyperedges.iter().enumerate()
+        {
+            let weight = hyperedge_mapper(weight);
+
+            if let Some(other_index) = hyperedges_weights.insert(weight.clone(), internal_index) {
+                return Err(HypergraphError::HyperedgeWeightAlreadyAssigned(
+                    self.get_hyperedge(other_index)?,
+                ));
+            }
+
+            hyperedges.push(HyperedgeKey::new(vertices.clone(), weight));
+        }
+
+        // The adjacency is preserved but the cached costs of the hyperedges
+        // depend on their weights.
+        let adjacency_cache = self.adjacency_cache.clone().map(|mut cache| {
+            cache.costs = hyperedges
+                .iter()
+                .map(|HyperedgeKey { weight, .. }| weight.clone().into())
+                .collect();
+
+            cache
+        });
+
+        Ok(Hypergraph {
+            vertices,
+            hyperedges,
+            #[cfg(feature = "roaring")]
+            hyperedges_bitmaps: self.hyperedges_bitmaps.clone(),
+            hyperedges_weights,
+            hyperedges_cardinalities: self.hyperedges_cardinalities.clone(),
+            hyperedges_mapping: self.hyperedges_mapping.clone(),
+            vertices_mapping: self.vertices_mapping.clone(),
+            hyperedges_count: self.hyperedges_count,
+            vertices_count: self.vertices_count,
+            hyperedges_recycled: self.hyperedges_recycled.clone(),
+            vertices_recycled: self.vertices_recycled.clone(),
+            vertices_attributes: self.vertices_attributes.clone(),
+            hyperedges_properties: self.hyperedges_properties.clone(),
+            hyperedges_roles: self.hyperedges_roles.clone(),
+            vertices_versions: self.vertices_versions.clone(),
+            hyperedges_versions: self.hyperedges_versions.clone(),
+            versions_count: self.versions_count,
+            hyperedges_tags: self.hyperedges_tags.clone(),
+            layers: self.layers.clone(),
+            hyperedges_layers: self.hyperedges_layers.clone(),
+            layers_count: self.layers_count,
+            metadata: self.metadata.clone(),
+            vertices_search_index: None,
+            hyperedges_search_index: None,
+            #[cfg(feature = "bloom")]
+            hyperedges_filter: None,
+            vertices_similarity_index: self.vertices_similarity_index.clone(),
+            adjacency_cache,
+            distance_oracle: None,
+            duplicate_policy: self.duplicate_policy,
+            vertex_dedup_policy: self.vertex_dedup_policy,
+            constraints: self.constraints,
+        })
+    }
+
+    /// Creates a new hypergraph with the same topology by mapping the
+    /// weights of the vertices. See `map_weights` for more details.
+    pub fn map_vertices<V2, F>(
+        &self,
+        vertex_mapper: F,
+    ) -> Result<Hypergraph<V2, HE>, HypergraphError>
+    where
+        V2: VertexTrait,
+        F: FnMut(&V) -> V2,
+    {
+        self.map_weights(vertex_mapper, Clone::clone)
+    }
+
+    /// Creates a new hypergraph with the same topology by mapping the
+    /// weights of the hyperedges. See `map_weights` for more details.
+    pub fn map_hyperedges<HE2, F>(
+        &self,
+        hyperedge_mapper: F,
+    ) -> Result<Hypergraph<V, HE2>, HypergraphError>
+    where
+        HE2: HyperedgeTrait,
+        F: FnMut(&HE) -> HE2,
+    {
+        self.map_weights(Clone::clone, hyperedge_mapper)
+    }
+}
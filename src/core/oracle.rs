@@ -0,0 +1,278 @@
+use std::{
+    cmp::Reverse,
+    collections::{
+        BinaryHeap,
+        HashMap,
+    },
+    mem::size_of,
+};
+
+use crate::{
+    HyperedgeTrait,
+    Hypergraph,
+    VertexIndex,
+    VertexTrait,
+    core::{
+        dijkstra::Direction,
+        memory::hash_map_bytes,
+    },
+    errors::HypergraphError,
+};
+
+/// Distances from and to a set of landmark vertices, used to bound the
+/// distances between any two vertices via the triangle inequality.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct DistanceOracle {
+    /// Landmark vertices, in selection order.
+    landmarks: Vec<VertexIndex>,
+
+    /// Distances from each landmark to the vertices it reaches.
+    from: Vec<HashMap<VertexIndex, usize>>,
+
+    /// Distances to each landmark from the vertices reaching it.
+    to: Vec<HashMap<VertexIndex, usize>>,
+}
+
+impl DistanceOracle {
+    /// Gets the lower and the upper bounds of the distance between two
+    /// vertices, or `None` if the second one is proven to be unreachable
+    /// from the first one.
+    fn get_bounds(&self, from: VertexIndex, to: VertexIndex) -> Option<(usize, Option<usize>)> {
+        if from == to {
+            return Some((0, Some(0)));
+        }
+
+        let mut lower = 0;
+        let mut upper: Option<usize> = None;
+
+        for (distances_from, distances_to) in self.from.iter().zip(self.to.iter()) {
+            // d(L, to) <= d(L, from) + d(from, to).
+            match (distances_from.get(&from), distances_from.get(&to)) {
+                (Some(landmark_from), Some(landmark_to)) => {
+                    lower = lower.max(landmark_to.saturating_sub(*landmark_from));
+                }
+                // The landmark would reach the target via the source.
+                (Some(_), None) => return None,
+                _ => {}
+            }
+
+            // d(from, L) <= d(from, to) + d(to, L).
+            match (distances_to.get(&from), distances_to.get(&to)) {
+                (Some(from_landmark), Some(to_landmark)) => {
+                    lower = lower.max(from_landmark.saturating_sub(*to_landmark));
+                }
+                // The source would reach the landmark via the target.
+                (None, Some(_)) => return None,
+                _ => {}
+            }
+
+            // d(from, to) <= d(from, L) + d(L, to).
+            if let (Some(from_landmark), Some(landmark_to)) =
+                (distances_to.get(&from), distances_from.get(&to))
+            {
+                let distance = from_landmark.saturating_add(*landmark_to);
+
+                upper = Some(upper.map_or(distance, |upper| upper.min(distance)));
+            }
+        }
+
+        Some((lower, upper))
+    }
+
+    /// Estimates the heap usage of the oracle in bytes.
+    pub(crate) fn estimated_memory_bytes(&self) -> usize {
+        self.landmarks.capacity() * size_of::<VertexIndex>()
+            + self
+                .from
+                .iter()
+                .chain(self.to.iter())
+                .map(|distances| hash_map_bytes::<VertexIndex, usize>(distances.capacity()))
+                .sum::<usize>()
+    }
+}
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Precomputes the distances from and to some landmark vertices, i.e.
+    /// an ALT distance oracle, so that the distances between any two
+    /// vertices can be bounded in time proportional to the number of
+    /// landmarks and computed exactly via a goal-directed search.
+    /// The landmarks are selected greedily, the most connected vertex first
+    /// and then the farthest one from the previous landmarks, favoring the
+    /// unreached ones, e.g. in other components.
+    /// The oracle is dropped by any mutation of the hyperedges, the updates
+    /// of their weights included, and must be built again afterwards.
+    /// Returns the landmarks.
+    pub fn build_distance_oracle(
+        &mut self,
+        landmarks: usize,
+    ) -> Result<Vec<VertexIndex>, HypergraphError> {
+        let mut oracle = DistanceOracle::default();
+
+        // Get the most connected vertex first, the earliest inserted one on
+        // ties.
+        let mut next = self
+            .vertices
+            .values()
+            .enumerate()
+            .max_by_key(|(internal_index, hyperedges)| (hyperedges.len(), Reverse(*internal_index)))
+            .map(|(internal_index, _)| internal_index);
+
+        while let Some(internal_index) = next.filter(|_| oracle.landmarks.len() < landmarks) {
+            let landmark = self.get_vertex(internal_index)?;
+
+            oracle.landmarks.push(landmark);
+            oracle.from.push(
+                self.get_shortest_paths(&[landmark], None, Direction::Forward, false, None)?
+                    .distances,
+            );
+            oracle.to.push(
+                self.get_shortest_paths(&[landmark], None, Direction::Backward, false, None)?
+                    .distances,
+            );
+
+            // Get the vertex maximizing its distance to the nearest
+            // landmark, the unreached ones first.
+            next = None;
+
+            let mut farthest = None;
+
+            for internal_index in 0..self.vertices.len() {
+                let vertex_index = self.get_vertex(internal_index)?;
+
+                if oracle.landmarks.contains(&vertex_index) {
+                    continue;
+                }
+
+                let distance = oracle
+                    .from
+                    .iter()
+                    .map(|distances| distances.get(&vertex_index).copied().unwrap_or(usize::MAX))
+                    .min()
+                    .unwrap_or(usize::MAX);
+
+                if farthest.map_or(true, |farthest| distance > farthest) {
+                    farthest = Some(distance);
+                    next = Some(internal_index);
+                }
+            }
+        }
+
+        let landmarks = oracle.landmarks.clone();
+
+        self.distance_oracle = Some(oracle);
+
+        Ok(landmarks)
+    }
+
+    /// Drops the distance oracle.
+    pub fn clear_distance_oracle(&mut self) {
+        self.distance_oracle = None;
+    }
+
+    /// Checks if the distance oracle is built.
+    pub fn has_distance_oracle(&self) -> bool {
+        self.distance_oracle.is_some()
+    }
+
+    /// Gets the lower and the upper bounds of the distance between two
+    /// vertices from the distance oracle, or `None` if the second one is
+    /// proven to be unreachable from the first one. Both bounds are equal
+    /// when the distance is exact, e.g. from a landmark, and the upper one
+    /// is `None` when no landmark connects the vertices.
+    pub fn get_distance_bounds(
+        &self,
+        from: VertexIndex,
+        to: VertexIndex,
+    ) -> Result<Option<(usize, Option<usize>)>, HypergraphError> {
+        let oracle = self
+            .distance_oracle
+            .as_ref()
+            .ok_or(HypergraphError::DistanceOracleNotBuilt)?;
+
+        self.get_internal_vertex(from)?;
+        self.get_internal_vertex(to)?;
+
+        Ok(oracle.get_bounds(from, to))
+    }
+
+    /// Gets the total cost of the cheapest path between two vertices like
+    /// `get_shortest_distance`, via an A* search guided by the lower bounds
+    /// of the distance oracle, which explores fewer vertices.
+    pub fn get_shortest_distance_with_oracle(
+        &self,
+        from: VertexIndex,
+        to: VertexIndex,
+    ) -> Result<Option<usize>, HypergraphError> {
+        let oracle = self
+            .distance_oracle
+            .as_ref()
+            .ok_or(HypergraphError::DistanceOracleNotBuilt)?;
+
+        self.get_internal_vertex(from)?;
+        self.get_internal_vertex(to)?;
+
+        let mut distances = HashMap::new();
+        let mut to_traverse = BinaryHeap::new();
+
+        if let Some((lower, _)) = oracle.get_bounds(from, to) {
+            distances.insert(from, 0);
+            to_traverse.push(Reverse((lower, 0, from)));
+        }
+
+        while let Some(Reverse((_, distance, vertex_index))) = to_traverse.pop() {
+            if vertex_index == to {
+                return Ok(Some(distance));
+            }
+
+            // Skip if a better path has already been found.
+            if distance > distances[&vertex_index] {
+                continue;
+            }
+
+            for (adjacent_index, hyperedges) in self
+                .get_cached_full_adjacent_vertices_from(vertex_index)?
+                .iter()
+            {
+                // Get the lower cost out of all the hyperedges.
+                let mut cost = None;
+
+                for hyperedge_index in hyperedges {
+                    let hyperedge_cost = self.get_hyperedge_cost(*hyperedge_index)?;
+
+                    cost =
+                        Some(cost.map_or(hyperedge_cost, |cost: usize| cost.min(hyperedge_cost)));
+                }
+
+                let Some(cost) = cost else {
+                    continue;
+                };
+
+                let next_distance = distance.saturating_add(cost);
+
+                // Skip the vertices which can't reach the target.
+                let Some((lower, _)) = oracle.get_bounds(*adjacent_index, to) else {
+                    continue;
+                };
+
+                // Relax the adjacent vertex if this is a shorter distance.
+                if distances
+                    .get(adjacent_index)
+                    .map_or(true, |current| next_distance < *current)
+                {
+                    distances.insert(*adjacent_index, next_distance);
+                    to_traverse.push(Reverse((
+                        next_distance.saturating_add(lower),
+                        next_distance,
+                        *adjacent_index,
+                    )));
+                }
+            }
+        }
+
+        Ok(None)
+    }
+}
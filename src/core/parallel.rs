@@ -0,0 +1,249 @@
+//! Parallel iterators, backed by Rayon with the `rayon` feature and by a
+//! sequential fallback mirroring the subset of the Rayon API used by the
+//! crate otherwise, e.g. for single-threaded targets.
+
+#[cfg(feature = "rayon")]
+pub(crate) use rayon::prelude::*;
+
+#[cfg(not(feature = "rayon"))]
+pub(crate) use self::sequential::*;
+
+#[cfg(not(feature = "rayon"))]
+mod sequential {
+    use std::iter::{
+        Once,
+        once,
+    };
+
+    use indexmap::IndexMap;
+
+    /// Sequential counterpart of a Rayon parallel iterator.
+    /// It doesn't implement `Iterator` on purpose so that its methods follow
+    /// the semantics of Rayon, e.g. for `fold` and `reduce`.
+    #[derive(Debug)]
+    pub(crate) struct Seq<I>(I);
+
+    pub(crate) trait IntoParallelIterator: IntoIterator + Sized {
+        fn into_par_iter(self) -> Seq<Self::IntoIter> {
+            Seq(self.into_iter())
+        }
+    }
+
+    impl<T> IntoParallelIterator for T where T: IntoIterator {}
+
+    pub(crate) trait IntoParallelRefIterator<'a> {
+        type Iter: Iterator;
+
+        fn par_iter(&'a self) -> Seq<Self::Iter>;
+    }
+
+    impl<'a, T> IntoParallelRefIterator<'a> for T
+    where
+        T: 'a + ?Sized,
+        &'a T: IntoIterator,
+    {
+        type Iter = <&'a T as IntoIterator>::IntoIter;
+
+        fn par_iter(&'a self) -> Seq<Self::Iter> {
+            Seq(self.into_iter())
+        }
+    }
+
+    pub(crate) trait IntoParallelRefMutIterator<'a> {
+        type Iter: Iterator;
+
+        fn par_iter_mut(&'a mut self) -> Seq<Self::Iter>;
+    }
+
+    impl<'a, T> IntoParallelRefMutIterator<'a> for T
+    where
+        T: 'a + ?Sized,
+        &'a mut T: IntoIterator,
+    {
+        type Iter = <&'a mut T as IntoIterator>::IntoIter;
+
+        fn par_iter_mut(&'a mut self) -> Seq<Self::Iter> {
+            Seq(self.into_iter())
+        }
+    }
+
+    pub(crate) trait ParallelSliceMut<T> {
+        fn par_sort_unstable(&mut self)
+        where
+            T: Ord;
+
+        fn par_sort_unstable_by_key<K, F>(&mut self, f: F)
+        where
+            K: Ord,
+            F: Fn(&T) -> K;
+    }
+
+    impl<T> ParallelSliceMut<T> for [T] {
+        fn par_sort_unstable(&mut self)
+        where
+            T: Ord,
+        {
+            self.sort_unstable();
+        }
+
+        fn par_sort_unstable_by_key<K, F>(&mut self, f: F)
+        where
+            K: Ord,
+            F: Fn(&T) -> K,
+        {
+            self.sort_unstable_by_key(f);
+        }
+    }
+
+    pub(crate) trait ParallelValuesMut<'a, V: 'a> {
+        type Iter: Iterator<Item = &'a mut V>;
+
+        fn par_values_mut(&'a mut self) -> Seq<Self::Iter>;
+    }
+
+    impl<'a, K, V, S> ParallelValuesMut<'a, V> for IndexMap<K, V, S>
+    where
+        K: 'a,
+        V: 'a,
+        S: 'a,
+    {
+        type Iter = indexmap::map::ValuesMut<'a, K, V>;
+
+        fn par_values_mut(&'a mut self) -> Seq<Self::Iter> {
+            Seq(self.values_mut())
+        }
+    }
+
+    impl<I> Seq<I>
+    where
+        I: Iterator,
+    {
+        pub(crate) fn map<B, F>(self, f: F) -> Seq<std::iter::Map<I, F>>
+        where
+            F: FnMut(I::Item) -> B,
+        {
+            Seq(self.0.map(f))
+        }
+
+        pub(crate) fn filter<P>(self, predicate: P) -> Seq<std::iter::Filter<I, P>>
+        where
+            P: FnMut(&I::Item) -> bool,
+        {
+            Seq(self.0.filter(predicate))
+        }
+
+        pub(crate) fn filter_map<B, F>(self, f: F) -> Seq<std::iter::FilterMap<I, F>>
+        where
+            F: FnMut(I::Item) -> Option<B>,
+        {
+            Seq(self.0.filter_map(f))
+        }
+
+        pub(crate) fn flat_map<U, F>(self, f: F) -> Seq<std::iter::FlatMap<I, U, F>>
+        where
+            U: IntoIterator,
+            F: FnMut(I::Item) -> U,
+        {
+            Seq(self.0.flat_map(f))
+        }
+
+        pub(crate) fn flatten(self) -> Seq<std::iter::Flatten<I>>
+        where
+            I::Item: IntoIterator,
+        {
+            Seq(self.0.flatten())
+        }
+
+        pub(crate) fn rev(self) -> Seq<std::iter::Rev<I>>
+        where
+            I: DoubleEndedIterator,
+        {
+            Seq(self.0.rev())
+        }
+
+        pub(crate) fn copied<'a, T>(self) -> Seq<std::iter::Copied<I>>
+        where
+            T: 'a + Copy,
+            I: Iterator<Item = &'a T>,
+        {
+            Seq(self.0.copied())
+        }
+
+        pub(crate) fn cloned<'a, T>(self) -> Seq<std::iter::Cloned<I>>
+        where
+            T: 'a + Clone,
+            I: Iterator<Item = &'a T>,
+        {
+            Seq(self.0.cloned())
+        }
+
+        pub(crate) fn any<P>(mut self, predicate: P) -> bool
+        where
+            P: FnMut(I::Item) -> bool,
+        {
+            self.0.any(predicate)
+        }
+
+        pub(crate) fn for_each<F>(self, f: F)
+        where
+            F: FnMut(I::Item),
+        {
+            self.0.for_each(f);
+        }
+
+        pub(crate) fn collect<C>(self) -> C
+        where
+            C: FromIterator<I::Item>,
+        {
+            self.0.collect()
+        }
+
+        pub(crate) fn fold<T, ID, F>(self, identity: ID, fold_op: F) -> Seq<Once<T>>
+        where
+            ID: Fn() -> T,
+            F: Fn(T, I::Item) -> T,
+        {
+            Seq(once(self.0.fold(identity(), fold_op)))
+        }
+
+        pub(crate) fn fold_with<T, F>(self, init: T, fold_op: F) -> Seq<Once<T>>
+        where
+            F: Fn(T, I::Item) -> T,
+        {
+            Seq(once(self.0.fold(init, fold_op)))
+        }
+
+        pub(crate) fn try_fold<T, E, ID, F>(
+            self,
+            identity: ID,
+            fold_op: F,
+        ) -> Seq<Once<Result<T, E>>>
+        where
+            ID: Fn() -> T,
+            F: Fn(T, I::Item) -> Result<T, E>,
+        {
+            let mut iterator = self.0;
+
+            Seq(once(iterator.try_fold(identity(), fold_op)))
+        }
+
+        pub(crate) fn reduce<ID, F>(self, identity: ID, op: F) -> I::Item
+        where
+            ID: Fn() -> I::Item,
+            F: Fn(I::Item, I::Item) -> I::Item,
+        {
+            self.0.fold(identity(), op)
+        }
+
+        pub(crate) fn try_reduce<T, E, ID, F>(self, identity: ID, op: F) -> Result<T, E>
+        where
+            I: Iterator<Item = Result<T, E>>,
+            ID: Fn() -> T,
+            F: Fn(T, T) -> Result<T, E>,
+        {
+            let mut iterator = self.0;
+
+            iterator.try_fold(identity(), |left, right| op(left, right?))
+        }
+    }
+}
@@ -0,0 +1,175 @@
+use crate::{
+    HyperedgeIndex,
+    HyperedgeKey,
+    HyperedgeTrait,
+    Hypergraph,
+    VertexIndex,
+    VertexKey,
+    VertexTrait,
+    core::types::{
+        AIndexSet,
+        ARandomState,
+    },
+    errors::HypergraphError,
+};
+
+/// Owned parts of a hypergraph, as returned by `into_parts`, e.g. for custom
+/// storage backends or FFI layers.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct HypergraphParts<V, HE> {
+    /// Weights of the vertices, by position.
+    pub vertices: Vec<V>,
+    /// Vertices - as positions in `vertices` - and weights of the
+    /// hyperedges, by position.
+    pub hyperedges: Vec<(Vec<usize>, HE)>,
+    /// Stable index of each vertex, by position.
+    pub vertices_indexes: Vec<VertexIndex>,
+    /// Stable index of each hyperedge, by position.
+    pub hyperedges_indexes: Vec<HyperedgeIndex>,
+}
+
+// Private helper to build the error of invalid parts.
+fn invalid_parts(reason: &str) -> HypergraphError {
+    HypergraphError::PartsInvalid(reason.to_owned())
+}
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Decomposes the hypergraph into its owned parts without cloning the
+    /// weights. The positions are the internal indexes of the vertices and
+    /// of the hyperedges.
+    /// Everything else - e.g. the attributes, the properties, the roles, the
+    /// tags, the layers and the configuration - is dropped.
+    pub fn into_parts(self) -> HypergraphParts<V, HE> {
+        let Hypergraph {
+            vertices,
+            hyperedges,
+            vertices_mapping,
+            hyperedges_mapping,
+            ..
+        } = self;
+
+        HypergraphParts {
+            vertices_indexes: (0..vertices.len())
+                .filter_map(|internal_index| vertices_mapping.left.get(&internal_index).copied())
+                .collect(),
+            hyperedges_indexes: (0..hyperedges.len())
+                .filter_map(|internal_index| hyperedges_mapping.left.get(&internal_index).copied())
+                .collect(),
+            vertices: vertices.into_iter().map(|(key, _)| key.weight).collect(),
+            hyperedges: hyperedges
+                .into_iter()
+                .map(|HyperedgeKey { vertices, weight }| (vertices.into_iter().collect(), weight))
+                .collect(),
+        }
+    }
+
+    /// Creates a new hypergraph from owned parts, e.g. as returned by
+    /// `into_parts`, without cloning the weights.
+    /// The stable indexes are preserved and the counters are set right after
+    /// the highest ones. The vertices sharing the same weight are kept as
+    /// duplicates. The duplicate policy is the default one.
+    pub fn from_parts(parts: HypergraphParts<V, HE>) -> Result<Self, HypergraphError> {
+        let HypergraphParts {
+            vertices,
+            hyperedges,
+            vertices_indexes,
+            hyperedges_indexes,
+        } = parts;
+
+        if vertices.len() != vertices_indexes.len() {
+            return Err(invalid_parts("mismatched vertices indexes"));
+        }
+
+        if hyperedges.len() != hyperedges_indexes.len() {
+            return Err(invalid_parts("mismatched hyperedges indexes"));
+        }
+
+        let mut graph = Hypergraph::with_capacity(vertices.len(), hyperedges.len());
+
+        // Collect the hyperedges of each vertex upfront.
+        let mut vertices_hyperedges =
+            vec![AIndexSet::with_capacity_and_hasher(0, ARandomState::default()); vertices.len()];
+
+        for (internal_index, (hyperedge_vertices, _)) in hyperedges.iter().enumerate() {
+            if hyperedge_vertices.is_empty() {
+                return Err(invalid_parts("hyperedge without vertices"));
+            }
+
+            for vertex in hyperedge_vertices {
+                vertices_hyperedges
+                    .get_mut(*vertex)
+                    .ok_or(HypergraphError::InternalVertexIndexNotFound(*vertex))?
+                    .insert(internal_index);
+            }
+        }
+
+        for (internal_index, (weight, hyperedges)) in
+            vertices.into_iter().zip(vertices_hyperedges).enumerate()
+        {
+            let vertex_index = vertices_indexes[internal_index];
+            let mut key = VertexKey::new(weight, None);
+
+            // Follow `add_vertex_allow_duplicate` for the duplicates.
+            if graph.vertices.contains_key(&key) {
+                key.id = Some(vertex_index.0);
+            }
+
+            graph.vertices.insert(key, hyperedges);
+
+            if graph
+                .vertices_mapping
+                .right
+                .insert(vertex_index, internal_index)
+                .is_some()
+            {
+                return Err(invalid_parts("duplicate vertex index"));
+            }
+
+            graph
+                .vertices_mapping
+                .left
+                .insert(internal_index, vertex_index);
+            graph.vertices_count = graph.vertices_count.max(vertex_index.0 + 1);
+        }
+
+        for (internal_index, (vertices, weight)) in hyperedges.into_iter().enumerate() {
+            let hyperedge_index = hyperedges_indexes[internal_index];
+
+            if let Some(other_index) = graph
+                .hyperedges_weights
+                .insert(weight.clone(), internal_index)
+            {
+                return Err(HypergraphError::HyperedgeWeightAlreadyAssigned(
+                    hyperedges_indexes[other_index],
+                ));
+            }
+
+            if graph
+                .hyperedges_mapping
+                .right
+                .insert(hyperedge_index, internal_index)
+                .is_some()
+            {
+                return Err(invalid_parts("duplicate hyperedge index"));
+            }
+
+            graph.hyperedges_cardinalities.insert(vertices.len());
+            graph.hyperedges.push(HyperedgeKey::new(vertices, weight));
+            graph
+                .hyperedges_mapping
+                .left
+                .insert(internal_index, hyperedge_index);
+            graph.hyperedges_count = graph.hyperedges_count.max(hyperedge_index.0 + 1);
+        }
+
+        // Mirror the vertices of the hyperedges in their bitmaps.
+        #[cfg(feature = "roaring")]
+        graph.rebuild_hyperedges_bitmaps()?;
+
+        Ok(graph)
+    }
+}
@@ -0,0 +1,153 @@
+use std::{
+    fmt::{
+        Debug,
+        Formatter,
+        Result as FmtResult,
+    },
+    iter::once,
+};
+
+use crate::{
+    HyperedgeIndex,
+    HyperedgeTrait,
+    Hypergraph,
+    VertexIndex,
+    VertexTrait,
+    core::{
+        dijkstra::Direction,
+        types::AIndexSet,
+    },
+    errors::HypergraphError,
+};
+
+/// Query of a cheapest path between two vertices under constraints, i.e.
+/// via some waypoints and avoiding some vertices and hyperedges, as
+/// returned by `Hypergraph::path_query`.
+pub struct PathQuery<'a, V, HE> {
+    graph: &'a Hypergraph<V, HE>,
+    from: VertexIndex,
+    to: VertexIndex,
+    waypoints: Vec<VertexIndex>,
+    avoided_vertices: AIndexSet<VertexIndex>,
+    avoided_hyperedges: AIndexSet<HyperedgeIndex>,
+}
+
+impl<V, HE> Debug for PathQuery<'_, V, HE> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("PathQuery")
+            .field("from", &self.from)
+            .field("to", &self.to)
+            .field("waypoints", &self.waypoints)
+            .field("avoided_vertices", &self.avoided_vertices)
+            .field("avoided_hyperedges", &self.avoided_hyperedges)
+            .finish()
+    }
+}
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Starts a query of the cheapest path between two vertices, to
+    /// constrain before finding it.
+    pub fn path_query(&self, from: VertexIndex, to: VertexIndex) -> PathQuery<'_, V, HE> {
+        PathQuery {
+            graph: self,
+            from,
+            to,
+            waypoints: vec![],
+            avoided_vertices: AIndexSet::default(),
+            avoided_hyperedges: AIndexSet::default(),
+        }
+    }
+}
+
+impl<V, HE> PathQuery<'_, V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Adds some waypoints to visit, in order, after the previous ones.
+    pub fn via(mut self, waypoints: &[VertexIndex]) -> Self {
+        self.waypoints.extend_from_slice(waypoints);
+        self
+    }
+
+    /// Adds some vertices to avoid.
+    pub fn avoiding_vertices(mut self, vertices: &[VertexIndex]) -> Self {
+        self.avoided_vertices.extend(vertices.iter().copied());
+        self
+    }
+
+    /// Adds some hyperedges to avoid.
+    pub fn avoiding_hyperedges(mut self, hyperedges: &[HyperedgeIndex]) -> Self {
+        self.avoided_hyperedges.extend(hyperedges.iter().copied());
+        self
+    }
+
+    /// Finds the cheapest path, as a vector of tuples of the form
+    /// `(VertexIndex, Option<HyperedgeIndex>)` like
+    /// `get_dijkstra_connections`.
+    /// The path is the concatenation of the cheapest paths between the
+    /// consecutive stops, i.e. the vertices and the waypoints in order,
+    /// hence it may visit a vertex more than once. It is empty if a stop
+    /// can't be reached or is avoided.
+    pub fn find(&self) -> Result<Vec<(VertexIndex, Option<HyperedgeIndex>)>, HypergraphError> {
+        let graph = self.graph;
+
+        let stops = once(self.from)
+            .chain(self.waypoints.iter().copied())
+            .chain(once(self.to))
+            .collect::<Vec<VertexIndex>>();
+
+        // Check that the constraints refer to existing entities.
+        for vertex_index in stops.iter().chain(self.avoided_vertices.iter()) {
+            graph.get_internal_vertex(*vertex_index)?;
+        }
+
+        for hyperedge_index in self.avoided_hyperedges.iter() {
+            graph.get_internal_hyperedge(*hyperedge_index)?;
+        }
+
+        if stops
+            .iter()
+            .any(|vertex_index| self.avoided_vertices.contains(vertex_index))
+        {
+            return Ok(vec![]);
+        }
+
+        let mut path = vec![(self.from, None)];
+
+        for leg in stops.windows(2) {
+            let shortest_paths = graph.get_filtered_shortest_paths(
+                &[leg[0]],
+                Some(leg[1]),
+                Direction::Forward,
+                true,
+                None,
+                |vertex_index, hyperedge_index| {
+                    !self.avoided_vertices.contains(&vertex_index)
+                        && !self.avoided_hyperedges.contains(&hyperedge_index)
+                },
+            )?;
+
+            if !shortest_paths.distances.contains_key(&leg[1]) {
+                return Ok(vec![]);
+            }
+
+            // Walk the links back from the end of the leg.
+            let mut steps = vec![];
+            let mut current = leg[1];
+
+            while let Some((previous, hyperedge_index)) = shortest_paths.links.get(&current) {
+                steps.push((current, Some(*hyperedge_index)));
+                current = *previous;
+            }
+
+            path.extend(steps.into_iter().rev());
+        }
+
+        Ok(path)
+    }
+}
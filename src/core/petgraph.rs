@@ -0,0 +1,133 @@
+use std::collections::HashMap;
+
+use itertools::Itertools;
+use petgraph::{
+    EdgeType,
+    graph::{
+        DiGraph,
+        Graph,
+        NodeIndex,
+        UnGraph,
+    },
+};
+
+use crate::{
+    HyperedgeIndex,
+    HyperedgeKey,
+    HyperedgeTrait,
+    Hypergraph,
+    VertexIndex,
+    VertexTrait,
+    errors::HypergraphError,
+};
+
+/// Node of the bipartite incidence graph produced by `to_petgraph`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum IncidenceNode<V, HE> {
+    /// A vertex of the hypergraph with its index and its weight.
+    Vertex(VertexIndex, V),
+    /// A hyperedge of the hypergraph with its index and its weight.
+    Hyperedge(HyperedgeIndex, HE),
+}
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Converts the hypergraph to its bipartite incidence graph.
+    /// Both the vertices and the hyperedges become nodes and each hyperedge
+    /// node is connected to its vertices. The weight of an edge is the
+    /// position of the vertex in the hyperedge, which preserves the direction.
+    pub fn to_petgraph(&self) -> Result<DiGraph<IncidenceNode<V, HE>, usize>, HypergraphError> {
+        let mut graph = DiGraph::with_capacity(
+            self.vertices.len() + self.hyperedges.len(),
+            self.hyperedges
+                .iter()
+                .map(|HyperedgeKey { vertices, .. }| vertices.len())
+                .sum(),
+        );
+
+        // Internal vertex indexes map directly to the node indexes since the
+        // vertices are inserted first and in the same order.
+        for (internal_index, (weight, _)) in self.vertices.iter().enumerate() {
+            graph.add_node(IncidenceNode::Vertex(
+                self.get_vertex(internal_index)?,
+                weight.weight.clone(),
+            ));
+        }
+
+        for (internal_index, HyperedgeKey { vertices, weight }) in
+            self.hyperedges.iter().enumerate()
+        {
+            let hyperedge_node = graph.add_node(IncidenceNode::Hyperedge(
+                self.get_hyperedge(internal_index)?,
+                weight.clone(),
+            ));
+
+            for (position, vertex) in vertices.iter().enumerate() {
+                graph.add_edge(hyperedge_node, NodeIndex::new(*vertex), position);
+            }
+        }
+
+        Ok(graph)
+    }
+
+    /// Converts the hypergraph to its weighted 2-section, i.e. the undirected
+    /// graph in which two distinct vertices are connected if they share at
+    /// least one hyperedge. The weight of an edge is the number of shared
+    /// hyperedges.
+    /// Each node holds the index and the weight of its vertex.
+    /// <https://en.wikipedia.org/wiki/Hypergraph#Related_graphs>
+    pub fn to_petgraph_projection(
+        &self,
+    ) -> Result<UnGraph<(VertexIndex, V), usize>, HypergraphError> {
+        let mut graph = UnGraph::with_capacity(self.vertices.len(), 0);
+
+        // Internal vertex indexes map directly to the node indexes since the
+        // vertices are inserted in the same order.
+        for (internal_index, (weight, _)) in self.vertices.iter().enumerate() {
+            graph.add_node((self.get_vertex(internal_index)?, weight.weight.clone()));
+        }
+
+        // Count the number of hyperedges shared by each pair of vertices.
+        let mut pairs = HashMap::<(usize, usize), usize>::new();
+
+        for HyperedgeKey { vertices, .. } in &self.hyperedges {
+            for (a, b) in vertices.iter().sorted().dedup().tuple_combinations() {
+                *pairs.entry((*a, *b)).or_default() += 1;
+            }
+        }
+
+        for ((a, b), count) in pairs.into_iter().sorted() {
+            graph.add_edge(NodeIndex::new(a), NodeIndex::new(b), count);
+        }
+
+        Ok(graph)
+    }
+
+    /// Creates a new hypergraph from an ordinary petgraph graph.
+    /// Each node becomes a vertex and each edge becomes a hyperedge of the
+    /// form `[source, target]`.
+    /// Please note that the weights must be unique as usual.
+    pub fn from_petgraph<Ty: EdgeType>(graph: &Graph<V, HE, Ty>) -> Result<Self, HypergraphError> {
+        let mut hypergraph = Hypergraph::with_capacity(graph.node_count(), graph.edge_count());
+
+        let vertices = graph
+            .node_weights()
+            .map(|weight| hypergraph.add_vertex(weight.clone()))
+            .collect::<Result<Vec<VertexIndex>, HypergraphError>>()?;
+
+        for edge in graph.raw_edges() {
+            hypergraph.add_hyperedge(
+                vec![
+                    vertices[edge.source().index()],
+                    vertices[edge.target().index()],
+                ],
+                edge.weight.clone(),
+            )?;
+        }
+
+        Ok(hypergraph)
+    }
+}
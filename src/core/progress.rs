@@ -0,0 +1,22 @@
+/// Progress of a long-running operation, reported to the callbacks of the
+/// `_with_progress` methods.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Progress {
+    /// Name of the current stage of the operation.
+    pub stage: &'static str,
+    /// Number of completed steps of the current stage.
+    pub completed: usize,
+    /// Total number of steps of the current stage.
+    pub total: usize,
+}
+
+impl Progress {
+    /// Creates a new progress report.
+    pub fn new(stage: &'static str, completed: usize, total: usize) -> Self {
+        Self {
+            stage,
+            completed,
+            total,
+        }
+    }
+}
@@ -0,0 +1,122 @@
+use pyo3::{
+    exceptions::PyValueError,
+    prelude::*,
+};
+
+use crate::{
+    core::labels::LabeledHypergraph,
+    errors::HypergraphError,
+};
+
+/// Directed hypergraph exposed to Python via `PyO3`.
+/// Vertices and hyperedges are weighted by string labels - e.g. serialized
+/// JSON documents - and indexes are exposed as plain integers.
+#[pyclass(name = "Hypergraph", module = "hypergraph")]
+#[derive(Debug, Default)]
+pub struct PyHypergraph(LabeledHypergraph);
+
+// Private helper to convert an error to a Python exception.
+fn to_py_error(error: HypergraphError) -> PyErr {
+    PyValueError::new_err(error.to_string())
+}
+
+#[pymethods]
+impl PyHypergraph {
+    /// Creates a new hypergraph with no allocation.
+    #[new]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a vertex with a label to the hypergraph.
+    /// Returns the index of the vertex.
+    pub fn add_vertex(&mut self, label: String) -> PyResult<usize> {
+        self.0.add_vertex(label).map_err(to_py_error)
+    }
+
+    /// Adds a hyperedge with a label and a cost to the hypergraph.
+    /// Returns the index of the hyperedge.
+    #[pyo3(signature = (vertices, label, cost = 1))]
+    pub fn add_hyperedge(
+        &mut self,
+        vertices: Vec<usize>,
+        label: String,
+        cost: usize,
+    ) -> PyResult<usize> {
+        self.0
+            .add_hyperedge(vertices, label, cost)
+            .map_err(to_py_error)
+    }
+
+    /// Returns the number of vertices in the hypergraph.
+    pub fn count_vertices(&self) -> usize {
+        self.0.count_vertices()
+    }
+
+    /// Returns the number of hyperedges in the hypergraph.
+    pub fn count_hyperedges(&self) -> usize {
+        self.0.count_hyperedges()
+    }
+
+    /// Gets the label of a vertex from its index.
+    pub fn get_vertex_weight(&self, index: usize) -> PyResult<String> {
+        self.0.get_vertex_weight(index).map_err(to_py_error)
+    }
+
+    /// Gets the label and the cost of a hyperedge from its index.
+    pub fn get_hyperedge_weight(&self, index: usize) -> PyResult<(String, usize)> {
+        self.0.get_hyperedge_weight(index).map_err(to_py_error)
+    }
+
+    /// Gets the vertices of a hyperedge.
+    pub fn get_hyperedge_vertices(&self, index: usize) -> PyResult<Vec<usize>> {
+        self.0.get_hyperedge_vertices(index).map_err(to_py_error)
+    }
+
+    /// Gets the list of all vertices connected from a given vertex.
+    pub fn get_adjacent_vertices_from(&self, index: usize) -> PyResult<Vec<usize>> {
+        self.0
+            .get_adjacent_vertices_from(index)
+            .map_err(to_py_error)
+    }
+
+    /// Gets the list of all vertices connected to a given vertex.
+    pub fn get_adjacent_vertices_to(&self, index: usize) -> PyResult<Vec<usize>> {
+        self.0.get_adjacent_vertices_to(index).map_err(to_py_error)
+    }
+
+    /// Gets the cheapest path between two vertices as a list of tuples of
+    /// the form (vertex, traversed hyperedge).
+    /// Returns an empty list if there's no path.
+    pub fn get_dijkstra_connections(
+        &self,
+        from: usize,
+        to: usize,
+    ) -> PyResult<Vec<(usize, Option<usize>)>> {
+        self.0
+            .get_dijkstra_connections(from, to)
+            .map_err(to_py_error)
+    }
+
+    /// Removes a vertex by index.
+    pub fn remove_vertex(&mut self, index: usize) -> PyResult<()> {
+        self.0.remove_vertex(index).map_err(to_py_error)
+    }
+
+    /// Removes a hyperedge by index.
+    pub fn remove_hyperedge(&mut self, index: usize) -> PyResult<()> {
+        self.0.remove_hyperedge(index).map_err(to_py_error)
+    }
+
+    /// Clears the hypergraph.
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+}
+
+/// Python module exposing the hypergraph.
+#[pymodule]
+#[pyo3(name = "hypergraph")]
+pub fn python_module(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyHypergraph>()
+}
@@ -0,0 +1,304 @@
+use std::{
+    collections::{
+        HashMap,
+        HashSet,
+    },
+    fmt::Debug,
+    hash::Hash,
+};
+
+use crate::{
+    HyperedgeIndex,
+    HyperedgeTrait,
+    Hypergraph,
+    VertexIndex,
+    VertexTrait,
+    bi_hash_map::BiHashMap,
+    errors::HypergraphError,
+};
+
+/// Mapping from the previous stable indexes of the vertices and of the
+/// hyperedges to the new ones. Returned by `remap_indexes` and by `append`,
+/// in which case the previous ones are those of the other hypergraph.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct IndexRemapping {
+    /// New stable index of each vertex, by previous stable index.
+    pub vertices: HashMap<VertexIndex, VertexIndex>,
+    /// New stable index of each hyperedge, by previous stable index.
+    pub hyperedges: HashMap<HyperedgeIndex, HyperedgeIndex>,
+}
+
+/// Stable indexes of the removed vertices and hyperedges freed for reuse.
+/// Returned by `recycle_indexes`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct RecycledIndexes {
+    /// Freed stable indexes of the vertices, sorted.
+    pub vertices: Vec<VertexIndex>,
+    /// Freed stable indexes of the hyperedges, sorted.
+    pub hyperedges: Vec<HyperedgeIndex>,
+}
+
+// Private helper to recycle the stable indexes below a counter which are not
+// used anymore. The counter is lowered right after the highest used index
+// and the recycled indexes below it are sorted in descending order.
+// Returns the newly freed indexes, i.e. not already recycled.
+fn recycle<I>(
+    used: &HashMap<I, usize>,
+    count: &mut usize,
+    recycled: &mut Vec<I>,
+    to_index: fn(usize) -> I,
+) -> Vec<I>
+where
+    I: Copy + Eq + Hash,
+{
+    let previously_recycled = recycled.drain(..).collect::<HashSet<I>>();
+    let freed = (0..*count)
+        .filter(|index| !used.contains_key(&to_index(*index)))
+        .collect::<Vec<usize>>();
+
+    *count = (0..*count)
+        .rev()
+        .find(|index| used.contains_key(&to_index(*index)))
+        .map_or(0, |index| index + 1);
+
+    recycled.extend(
+        freed
+            .iter()
+            .rev()
+            .filter(|index| **index < *count)
+            .map(|index| to_index(*index)),
+    );
+
+    freed
+        .into_iter()
+        .map(to_index)
+        .filter(|index| !previously_recycled.contains(index))
+        .collect()
+}
+
+// Private helper to assign compact indexes to some stable indexes while
+// preserving their order.
+fn compact<I>(indexes: impl Iterator<Item = I>, to_index: fn(usize) -> I) -> HashMap<I, I>
+where
+    I: Copy + Eq + Hash + Ord,
+{
+    let mut indexes = indexes.collect::<Vec<I>>();
+
+    indexes.sort_unstable();
+
+    indexes
+        .into_iter()
+        .enumerate()
+        .map(|(position, index)| (index, to_index(position)))
+        .collect()
+}
+
+// Private helper to remap the keys of a bi-directional map.
+fn remap_mapping<I>(mapping: &mut BiHashMap<I>, remapping: &HashMap<I, I>)
+where
+    I: Copy + Debug + Eq + Hash,
+{
+    let mut remapped = BiHashMap::with_capacity(mapping.left.len());
+
+    for (internal_index, index) in mapping.left.drain() {
+        let index = remapping[&index];
+
+        remapped.left.insert(internal_index, index);
+        remapped.right.insert(index, internal_index);
+    }
+
+    *mapping = remapped;
+}
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Reassigns compact stable indexes to the vertices and the hyperedges,
+    /// i.e. from zero without any gap left by the removals, while preserving
+    /// their relative order. The counters are reset accordingly.
+    /// The stable indexes are otherwise never reused and preserved by the
+    /// snapshots, so that they can be stored by downstream systems. The
+    /// returned mapping allows to update such references.
+    /// The indexes of the layers are left untouched while the adjacency
+    /// cache and the distance oracle are dropped.
+    pub fn remap_indexes(&mut self) -> Result<IndexRemapping, HypergraphError> {
+        let vertices = compact(self.vertices_mapping.right.keys().copied(), VertexIndex);
+        let hyperedges = compact(
+            self.hyperedges_mapping.right.keys().copied(),
+            HyperedgeIndex,
+        );
+
+        // Drop the adjacency cache and the distance oracle.
+        self.adjacency_cache = None;
+        self.distance_oracle = None;
+
+        remap_mapping(&mut self.vertices_mapping, &vertices);
+
+        // The ids of the vertices added via `add_vertex_allow_duplicate` are
+        // their stable indexes, remap them as well so that they don't clash
+        // with the upcoming ones.
+        if self.vertices.keys().any(|key| key.id.is_some()) {
+            let vertices_mapping = &self.vertices_mapping;
+
+            self.vertices = self
+                .vertices
+                .drain(..)
+                .enumerate()
+                .map(|(internal_index, (mut key, hyperedges))| {
+                    if key.id.is_some() {
+                        key.id = vertices_mapping
+                            .left
+                            .get(&internal_index)
+                            .map(|vertex_index| vertex_index.0);
+                    }
+
+                    (key, hyperedges)
+                })
+                .collect();
+        }
+
+        self.vertices_count = vertices.len();
+        self.hyperedges_count = hyperedges.len();
+
+        // Remap everything stored by stable index.
+        self.vertices_attributes = self
+            .vertices_attributes
+            .drain(..)
+            .map(|(index, attributes)| (vertices[&index], attributes))
+            .collect();
+        self.vertices_versions = self
+            .vertices_versions
+            .drain(..)
+            .map(|(index, version)| (vertices[&index], version))
+            .collect();
+        self.remap_hyperedges(&hyperedges);
+
+        // Issue a new version to the moved entities, since their new indexes
+        // may have belonged to removed ones.
+        if vertices.iter().any(|(previous, index)| previous != index)
+            || hyperedges.iter().any(|(previous, index)| previous != index)
+        {
+            let version = self.next_version();
+
+            for (_, index) in vertices
+                .iter()
+                .filter(|(previous, index)| previous != index)
+            {
+                self.vertices_versions.insert(*index, version);
+            }
+
+            for (_, index) in hyperedges
+                .iter()
+                .filter(|(previous, index)| previous != index)
+            {
+                self.hyperedges_versions.insert(*index, version);
+            }
+        }
+
+        if let Some(search_index) = self.vertices_search_index.as_mut() {
+            search_index.remap(|index| vertices[&index]);
+        }
+
+        self.vertices_similarity_index
+            .remap(|index| vertices[&index]);
+
+        // The bitmaps of the hyperedges hold the stable indexes of the
+        // vertices.
+        #[cfg(feature = "roaring")]
+        for bitmap in self.hyperedges_bitmaps.iter_mut() {
+            *bitmap = bitmap
+                .iter()
+                .map(|index| vertices[&VertexIndex(index as usize)].0 as u64)
+                .collect();
+        }
+
+        // The signatures depend on the stable indexes of the vertices.
+        #[cfg(feature = "bloom")]
+        if self.hyperedges_filter.is_some() {
+            self.rebuild_hyperedge_filter()?;
+        }
+
+        // The indexes are compact, there's nothing left to recycle.
+        self.vertices_recycled.clear();
+        self.hyperedges_recycled.clear();
+
+        Ok(IndexRemapping {
+            vertices,
+            hyperedges,
+        })
+    }
+
+    /// Frees the stable indexes of the removed vertices and hyperedges so
+    /// that they get reused - the lowest one first - by the upcoming
+    /// insertions, e.g. to bound the downstream arrays sized by the highest
+    /// index under churn. The counters are lowered accordingly.
+    /// Unlike `remap_indexes`, the indexes in use are left untouched. The
+    /// stable indexes are otherwise never reused, even after a removal, so
+    /// the references to the removed entities held by downstream systems
+    /// must be dropped first.
+    /// Returns the newly freed indexes.
+    pub fn recycle_indexes(&mut self) -> RecycledIndexes {
+        RecycledIndexes {
+            vertices: recycle(
+                &self.vertices_mapping.right,
+                &mut self.vertices_count,
+                &mut self.vertices_recycled,
+                VertexIndex,
+            ),
+            hyperedges: recycle(
+                &self.hyperedges_mapping.right,
+                &mut self.hyperedges_count,
+                &mut self.hyperedges_recycled,
+                HyperedgeIndex,
+            ),
+        }
+    }
+
+    // Private method to remap the stable indexes of the hyperedges along
+    // with everything stored by stable index.
+    pub(crate) fn remap_hyperedges(
+        &mut self,
+        hyperedges: &HashMap<HyperedgeIndex, HyperedgeIndex>,
+    ) {
+        remap_mapping(&mut self.hyperedges_mapping, hyperedges);
+
+        self.hyperedges_properties = self
+            .hyperedges_properties
+            .drain(..)
+            .map(|(index, properties)| (hyperedges[&index], properties))
+            .collect();
+        self.hyperedges_roles = self
+            .hyperedges_roles
+            .drain(..)
+            .map(|(index, roles)| (hyperedges[&index], roles))
+            .collect();
+        self.hyperedges_versions = self
+            .hyperedges_versions
+            .drain(..)
+            .map(|(index, version)| (hyperedges[&index], version))
+            .collect();
+        self.hyperedges_layers = self
+            .hyperedges_layers
+            .drain(..)
+            .map(|(index, layer_index)| (hyperedges[&index], layer_index))
+            .collect();
+
+        for tagged in self.hyperedges_tags.values_mut() {
+            *tagged = tagged.drain(..).map(|index| hyperedges[&index]).collect();
+        }
+
+        for layer in self.layers.values_mut() {
+            layer.hyperedges = layer
+                .hyperedges
+                .drain(..)
+                .map(|index| hyperedges[&index])
+                .collect();
+        }
+
+        if let Some(search_index) = self.hyperedges_search_index.as_mut() {
+            search_index.remap(|index| hyperedges[&index]);
+        }
+    }
+}
@@ -0,0 +1,143 @@
+use crate::{
+    HyperedgeIndex,
+    HyperedgeTrait,
+    Hypergraph,
+    VertexIndex,
+    VertexTrait,
+    errors::HypergraphError,
+};
+
+/// Read-only view of a hypergraph with the direction of all the hyperedges
+/// inverted, as returned by `Hypergraph::reversed`.
+/// The queries are answered by the underlying hypergraph without copying
+/// nor mutating it, e.g. to find what depends on a vertex.
+#[derive(Clone, Copy)]
+pub struct ReversedHypergraph<'a, V, HE> {
+    graph: &'a Hypergraph<V, HE>,
+}
+
+impl<V, HE> std::fmt::Debug for ReversedHypergraph<'_, V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ReversedHypergraph")
+            .field("graph", self.graph)
+            .finish()
+    }
+}
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Gets a read-only view of the hypergraph with the direction of all
+    /// the hyperedges inverted.
+    pub fn reversed(&self) -> ReversedHypergraph<'_, V, HE> {
+        ReversedHypergraph { graph: self }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+impl<'a, V, HE> ReversedHypergraph<'a, V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Gets the underlying hypergraph.
+    pub fn inner(&self) -> &'a Hypergraph<V, HE> {
+        self.graph
+    }
+
+    /// Gets the vertices of a reversed hyperedge.
+    pub fn get_hyperedge_vertices(
+        &self,
+        hyperedge_index: HyperedgeIndex,
+    ) -> Result<Vec<VertexIndex>, HypergraphError> {
+        let mut vertices = self.graph.get_hyperedge_vertices(hyperedge_index)?;
+
+        vertices.reverse();
+
+        Ok(vertices)
+    }
+
+    /// Gets the list of all vertices connected from a given vertex.
+    pub fn get_adjacent_vertices_from(
+        &self,
+        from: VertexIndex,
+    ) -> Result<Vec<VertexIndex>, HypergraphError> {
+        self.graph.get_adjacent_vertices_to(from)
+    }
+
+    /// Gets the list of all vertices connected to a given vertex.
+    pub fn get_adjacent_vertices_to(
+        &self,
+        to: VertexIndex,
+    ) -> Result<Vec<VertexIndex>, HypergraphError> {
+        self.graph.get_adjacent_vertices_from(to)
+    }
+
+    /// Gets the hyperedges directed from a vertex.
+    pub fn get_hyperedges_from(
+        &self,
+        vertex_index: VertexIndex,
+    ) -> Result<Vec<HyperedgeIndex>, HypergraphError> {
+        self.graph.get_hyperedges_to(vertex_index)
+    }
+
+    /// Gets the hyperedges directed to a vertex.
+    pub fn get_hyperedges_to(
+        &self,
+        vertex_index: VertexIndex,
+    ) -> Result<Vec<HyperedgeIndex>, HypergraphError> {
+        self.graph.get_hyperedges_from(vertex_index)
+    }
+
+    /// Gets the hyperedges directly connecting a vertex to another.
+    pub fn get_hyperedges_connecting(
+        &self,
+        from: VertexIndex,
+        to: VertexIndex,
+    ) -> Result<Vec<HyperedgeIndex>, HypergraphError> {
+        self.graph.get_hyperedges_connecting(to, from)
+    }
+
+    /// Gets the in-degree of a vertex.
+    pub fn get_vertex_degree_in(&self, to: VertexIndex) -> Result<usize, HypergraphError> {
+        self.graph.get_vertex_degree_out(to)
+    }
+
+    /// Gets the out-degree of a vertex.
+    pub fn get_vertex_degree_out(&self, from: VertexIndex) -> Result<usize, HypergraphError> {
+        self.graph.get_vertex_degree_in(from)
+    }
+
+    /// Gets a list of the cheapest path of vertices between two vertices,
+    /// following the reversed hyperedges. See
+    /// `Hypergraph::get_dijkstra_connections` for the format.
+    pub fn get_dijkstra_connections(
+        &self,
+        from: VertexIndex,
+        to: VertexIndex,
+    ) -> Result<Vec<(VertexIndex, Option<HyperedgeIndex>)>, HypergraphError> {
+        let path = self.graph.get_dijkstra_connections(to, from)?;
+
+        // Walk the path backwards, each vertex being reached via the
+        // hyperedge which was leading to its successor.
+        let hyperedges = path
+            .iter()
+            .skip(1)
+            .map(|(_, hyperedge_index)| *hyperedge_index)
+            .rev()
+            .collect::<Vec<Option<HyperedgeIndex>>>();
+
+        Ok(path
+            .iter()
+            .rev()
+            .zip(std::iter::once(None).chain(hyperedges))
+            .map(|((vertex_index, _), hyperedge_index)| (*vertex_index, hyperedge_index))
+            .collect())
+    }
+}
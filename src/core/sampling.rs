@@ -0,0 +1,287 @@
+use rand::{
+    Rng,
+    seq::{
+        IteratorRandom,
+        SliceRandom,
+    },
+};
+
+use crate::{
+    HyperedgeIndex,
+    HyperedgeKey,
+    HyperedgeTrait,
+    Hypergraph,
+    VertexIndex,
+    VertexTrait,
+    errors::HypergraphError,
+};
+
+/// Sampler picking indexes with probabilities proportional to their
+/// weights, based on the prefix sums of the weights. Each draw is thus done
+/// in O(log n) via a binary search.
+/// The sampler is a snapshot: it must be rebuilt after mutating the
+/// hypergraph for the changes to be taken into account.
+#[derive(Clone, Debug, PartialEq)]
+pub struct WeightedSampler<I> {
+    cumulative_weights: Vec<f64>,
+    indexes: Vec<I>,
+}
+
+impl<I> WeightedSampler<I>
+where
+    I: Copy,
+{
+    // Private method to build a sampler from some indexes and their weights.
+    // The weights must be finite and positive, the ones equal to zero being
+    // skipped since they can't be drawn.
+    fn new<E>(
+        weights: impl Iterator<Item = Result<(I, f64), HypergraphError>>,
+        error: E,
+    ) -> Result<Self, HypergraphError>
+    where
+        E: Fn(I) -> HypergraphError,
+    {
+        let mut cumulative_weights = vec![];
+        let mut indexes = vec![];
+        let mut total = 0.;
+
+        for item in weights {
+            let (index, weight) = item?;
+
+            if !weight.is_finite() || weight < 0. {
+                return Err(error(index));
+            }
+
+            if weight > 0. {
+                total += weight;
+
+                // The total must stay finite too.
+                if !total.is_finite() {
+                    return Err(error(index));
+                }
+
+                cumulative_weights.push(total);
+                indexes.push(index);
+            }
+        }
+
+        Ok(Self {
+            cumulative_weights,
+            indexes,
+        })
+    }
+
+    /// Draws an index with a probability proportional to its weight.
+    /// Returns `None` if all the weights are equal to zero.
+    pub fn sample<R>(&self, rng: &mut R) -> Option<I>
+    where
+        R: Rng + ?Sized,
+    {
+        let total = *self.cumulative_weights.last()?;
+        let target = rng.gen_range(0. ..total);
+        let position = self
+            .cumulative_weights
+            .partition_point(|cumulative_weight| *cumulative_weight <= target);
+
+        self.indexes.get(position).copied()
+    }
+
+    /// Returns the number of indexes with a weight greater than zero.
+    pub fn len(&self) -> usize {
+        self.indexes.len()
+    }
+
+    /// Returns true if no index has a weight greater than zero.
+    pub fn is_empty(&self) -> bool {
+        self.indexes.is_empty()
+    }
+}
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    // Private method to perform a random walk of at most `length` internal
+    // vertices from an internal vertex.
+    pub(crate) fn get_internal_random_walk<R>(
+        &self,
+        start: usize,
+        length: usize,
+        rng: &mut R,
+    ) -> Result<Vec<usize>, HypergraphError>
+    where
+        R: Rng + ?Sized,
+    {
+        let mut current = start;
+        let mut walk = Vec::with_capacity(length);
+
+        if length == 0 {
+            return Ok(walk);
+        }
+
+        walk.push(current);
+
+        while walk.len() < length {
+            let (_, hyperedges) = self
+                .vertices
+                .get_index(current)
+                .ok_or(HypergraphError::InternalVertexIndexNotFound(current))?;
+
+            let Some(hyperedge) = hyperedges.iter().choose(rng) else {
+                break;
+            };
+
+            let HyperedgeKey { vertices, .. } = self
+                .hyperedges
+                .get(*hyperedge)
+                .ok_or(HypergraphError::InternalHyperedgeIndexNotFound(*hyperedge))?;
+
+            // A hyperedge always contains at least one vertex.
+            if let Some(vertex) = vertices.choose(rng) {
+                current = *vertex;
+
+                walk.push(current);
+            }
+        }
+
+        Ok(walk)
+    }
+
+    /// Performs a random walk of at most `length` vertices from a vertex.
+    /// Each step picks uniformly one of the hyperedges of the current vertex
+    /// and then one of the vertices of that hyperedge, regardless of their
+    /// direction. The walk stops early on a vertex without hyperedges.
+    /// Returns the visited vertices, starting vertex included.
+    pub fn random_walk<R>(
+        &self,
+        start: VertexIndex,
+        length: usize,
+        rng: &mut R,
+    ) -> Result<Vec<VertexIndex>, HypergraphError>
+    where
+        R: Rng + ?Sized,
+    {
+        let internal_index = self.get_internal_vertex(start)?;
+
+        self.get_vertices(&self.get_internal_random_walk(internal_index, length, rng)?)
+    }
+
+    /// Samples uniformly `amount` distinct vertices of the hypergraph.
+    /// Returns all the vertices in random order if `amount` exceeds their
+    /// number.
+    pub fn sample_vertices<R>(
+        &self,
+        amount: usize,
+        rng: &mut R,
+    ) -> Result<Vec<VertexIndex>, HypergraphError>
+    where
+        R: Rng + ?Sized,
+    {
+        let length = self.vertices.len();
+
+        rand::seq::index::sample(rng, length, amount.min(length))
+            .into_iter()
+            .map(|internal_index| self.get_vertex(internal_index))
+            .collect()
+    }
+
+    /// Samples uniformly `amount` distinct hyperedges of the hypergraph.
+    /// Returns all the hyperedges in random order if `amount` exceeds their
+    /// number.
+    pub fn sample_hyperedges<R>(
+        &self,
+        amount: usize,
+        rng: &mut R,
+    ) -> Result<Vec<HyperedgeIndex>, HypergraphError>
+    where
+        R: Rng + ?Sized,
+    {
+        let length = self.hyperedges.len();
+
+        rand::seq::index::sample(rng, length, amount.min(length))
+            .into_iter()
+            .map(|internal_index| self.get_hyperedge(internal_index))
+            .collect()
+    }
+
+    /// Builds a sampler of the vertices, each vertex being picked with a
+    /// probability proportional to the weight returned by the provided
+    /// function, which must be finite and positive.
+    /// See `WeightedSampler` for more details.
+    pub fn get_vertices_weighted_sampler<F>(
+        &self,
+        mut weight: F,
+    ) -> Result<WeightedSampler<VertexIndex>, HypergraphError>
+    where
+        F: FnMut(VertexIndex, &V) -> f64,
+    {
+        WeightedSampler::new(
+            self.vertices
+                .keys()
+                .enumerate()
+                .map(|(internal_index, key)| {
+                    let vertex_index = self.get_vertex(internal_index)?;
+
+                    Ok((vertex_index, weight(vertex_index, &key.weight)))
+                }),
+            HypergraphError::VertexSamplingInvalidWeight,
+        )
+    }
+
+    /// Builds a sampler of the hyperedges, each hyperedge being picked with a
+    /// probability proportional to the weight returned by the provided
+    /// function, which must be finite and positive.
+    /// See `WeightedSampler` for more details.
+    pub fn get_hyperedges_weighted_sampler<F>(
+        &self,
+        mut weight: F,
+    ) -> Result<WeightedSampler<HyperedgeIndex>, HypergraphError>
+    where
+        F: FnMut(HyperedgeIndex, &HE) -> f64,
+    {
+        WeightedSampler::new(
+            self.hyperedges
+                .iter()
+                .enumerate()
+                .map(|(internal_index, key)| {
+                    let hyperedge_index = self.get_hyperedge(internal_index)?;
+
+                    Ok((hyperedge_index, weight(hyperedge_index, &key.weight)))
+                }),
+            HypergraphError::HyperedgeSamplingInvalidWeight,
+        )
+    }
+
+    /// Samples a vertex with a probability proportional to the weight
+    /// returned by the provided function. Returns `None` if all the weights
+    /// are equal to zero.
+    /// Prefer `get_vertices_weighted_sampler` to draw repeatedly.
+    pub fn sample_vertex_weighted<R, F>(
+        &self,
+        rng: &mut R,
+        weight: F,
+    ) -> Result<Option<VertexIndex>, HypergraphError>
+    where
+        R: Rng + ?Sized,
+        F: FnMut(VertexIndex, &V) -> f64,
+    {
+        Ok(self.get_vertices_weighted_sampler(weight)?.sample(rng))
+    }
+
+    /// Samples a hyperedge with a probability proportional to the weight
+    /// returned by the provided function. Returns `None` if all the weights
+    /// are equal to zero.
+    /// Prefer `get_hyperedges_weighted_sampler` to draw repeatedly.
+    pub fn sample_hyperedge_weighted<R, F>(
+        &self,
+        rng: &mut R,
+        weight: F,
+    ) -> Result<Option<HyperedgeIndex>, HypergraphError>
+    where
+        R: Rng + ?Sized,
+        F: FnMut(HyperedgeIndex, &HE) -> f64,
+    {
+        Ok(self.get_hyperedges_weighted_sampler(weight)?.sample(rng))
+    }
+}
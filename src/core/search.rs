@@ -0,0 +1,264 @@
+use std::{
+    collections::BTreeMap,
+    hash::Hash,
+    mem::size_of,
+};
+
+use crate::{
+    HyperedgeIndex,
+    HyperedgeKey,
+    HyperedgeTrait,
+    Hypergraph,
+    VertexIndex,
+    VertexTrait,
+    core::{
+        memory::index_map_bytes,
+        types::{
+            AIndexMap,
+            AIndexSet,
+        },
+    },
+    errors::HypergraphError,
+};
+
+/// Score of a query token matching an indexed token exactly.
+const EXACT_MATCH_SCORE: usize = 2;
+
+/// Score of a query token matching the beginning of an indexed token.
+const PREFIX_MATCH_SCORE: usize = 1;
+
+// Private helper to split a text into distinct lowercase alphanumeric tokens.
+fn tokenize(text: &str) -> AIndexSet<String> {
+    text.split(|character: char| !character.is_alphanumeric())
+        .filter(|token| !token.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// Inverted index mapping the tokens extracted from the weights to the
+/// indexes of the vertices or the hyperedges.
+#[derive(Clone, Debug)]
+pub(crate) struct SearchIndex<I, W> {
+    /// Extractor of the text to tokenize from a weight.
+    extractor: fn(&W) -> String,
+
+    /// Tokens of each indexed entry, used for removal.
+    documents: AIndexMap<I, AIndexSet<String>>,
+
+    /// Entries of each token, sorted by token to allow prefix scans.
+    postings: BTreeMap<String, AIndexSet<I>>,
+}
+
+impl<I, W> SearchIndex<I, W>
+where
+    I: Copy + Eq + Hash + Ord,
+{
+    /// Creates a new empty search index with a given extractor.
+    fn new(extractor: fn(&W) -> String) -> Self {
+        Self {
+            extractor,
+            documents: AIndexMap::default(),
+            postings: BTreeMap::new(),
+        }
+    }
+
+    /// Indexes an entry, replacing its previous tokens if any.
+    pub(crate) fn insert(&mut self, index: I, weight: &W) {
+        self.remove(index);
+
+        let tokens = tokenize(&(self.extractor)(weight));
+
+        for token in &tokens {
+            self.postings
+                .entry(token.clone())
+                .or_default()
+                .insert(index);
+        }
+
+        self.documents.insert(index, tokens);
+    }
+
+    /// Removes an entry from the index.
+    pub(crate) fn remove(&mut self, index: I) {
+        if let Some(tokens) = self.documents.swap_remove(&index) {
+            for token in tokens {
+                if let Some(entries) = self.postings.get_mut(&token) {
+                    entries.swap_remove(&index);
+
+                    if entries.is_empty() {
+                        self.postings.remove(&token);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Remaps the indexes of the entries, e.g. once the stable indexes have
+    /// been reassigned.
+    pub(crate) fn remap<F>(&mut self, remapping: F)
+    where
+        F: Fn(I) -> I,
+    {
+        self.documents = self
+            .documents
+            .drain(..)
+            .map(|(index, tokens)| (remapping(index), tokens))
+            .collect();
+
+        for entries in self.postings.values_mut() {
+            *entries = entries.drain(..).map(&remapping).collect();
+        }
+    }
+
+    /// Clears the index while keeping its extractor.
+    pub(crate) fn clear(&mut self) {
+        self.documents.clear();
+        self.postings.clear();
+    }
+
+    /// Estimates the heap usage of the index in bytes.
+    /// Each node of the postings tree is approximated by its key and value.
+    pub(crate) fn estimated_memory_bytes(&self) -> usize {
+        index_map_bytes::<I, AIndexSet<String>>(self.documents.capacity())
+            + self
+                .documents
+                .values()
+                .map(|tokens| {
+                    index_map_bytes::<String, ()>(tokens.capacity())
+                        + tokens.iter().map(String::capacity).sum::<usize>()
+                })
+                .sum::<usize>()
+            + self
+                .postings
+                .iter()
+                .map(|(token, entries)| {
+                    size_of::<String>()
+                        + size_of::<AIndexSet<I>>()
+                        + token.capacity()
+                        + index_map_bytes::<I, ()>(entries.capacity())
+                })
+                .sum::<usize>()
+    }
+
+    /// Searches the entries matching a query, ranked by decreasing score.
+    fn search(&self, query: &str) -> Vec<I> {
+        let mut scores = AIndexMap::<I, usize>::default();
+
+        for query_token in tokenize(query) {
+            // Only keep the best score of each entry for a given query token.
+            let mut token_scores = AIndexMap::<I, usize>::default();
+
+            for (token, entries) in self
+                .postings
+                .range(query_token.clone()..)
+                .take_while(|(token, _)| token.starts_with(&query_token))
+            {
+                let score = if *token == query_token {
+                    EXACT_MATCH_SCORE
+                } else {
+                    PREFIX_MATCH_SCORE
+                };
+
+                for entry in entries {
+                    let current = token_scores.entry(*entry).or_default();
+
+                    *current = (*current).max(score);
+                }
+            }
+
+            for (entry, score) in token_scores {
+                *scores.entry(entry).or_default() += score;
+            }
+        }
+
+        let mut results = scores.into_iter().collect::<Vec<(I, usize)>>();
+
+        results.sort_unstable_by(|(left_index, left_score), (right_index, right_score)| {
+            right_score
+                .cmp(left_score)
+                .then_with(|| left_index.cmp(right_index))
+        });
+
+        results.into_iter().map(|(index, _)| index).collect()
+    }
+}
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Enables the full-text search over the vertices by tokenizing the
+    /// `Display` output of their weights.
+    /// The index is kept up to date by the mutations of the hypergraph.
+    pub fn enable_vertex_search(&mut self) {
+        self.enable_vertex_search_with(ToString::to_string);
+    }
+
+    /// Enables the full-text search over the vertices by tokenizing the text
+    /// extracted from their weights.
+    /// The index is kept up to date by the mutations of the hypergraph.
+    pub fn enable_vertex_search_with(&mut self, extractor: fn(&V) -> String) {
+        let mut search_index = SearchIndex::new(extractor);
+
+        for (vertex_index, internal_index) in &self.vertices_mapping.right {
+            if let Some((weight, _)) = self.vertices.get_index(*internal_index) {
+                search_index.insert(*vertex_index, &**weight);
+            }
+        }
+
+        self.vertices_search_index = Some(search_index);
+    }
+
+    /// Disables the full-text search over the vertices and drops its index.
+    pub fn disable_vertex_search(&mut self) {
+        self.vertices_search_index = None;
+    }
+
+    /// Searches the vertices whose tokens match the ones of a query.
+    /// Tokens are lowercase alphanumeric words. A query token matching an
+    /// indexed token exactly scores higher than one only matching its
+    /// beginning. Returns the indexes ranked by decreasing score.
+    pub fn search_vertices(&self, query: &str) -> Result<Vec<VertexIndex>, HypergraphError> {
+        self.vertices_search_index
+            .as_ref()
+            .map(|search_index| search_index.search(query))
+            .ok_or(HypergraphError::SearchIndexNotEnabled)
+    }
+
+    /// Enables the full-text search over the hyperedges by tokenizing the
+    /// `Display` output of their weights.
+    /// The index is kept up to date by the mutations of the hypergraph.
+    pub fn enable_hyperedge_search(&mut self) {
+        self.enable_hyperedge_search_with(ToString::to_string);
+    }
+
+    /// Enables the full-text search over the hyperedges by tokenizing the
+    /// text extracted from their weights.
+    /// The index is kept up to date by the mutations of the hypergraph.
+    pub fn enable_hyperedge_search_with(&mut self, extractor: fn(&HE) -> String) {
+        let mut search_index = SearchIndex::new(extractor);
+
+        for (hyperedge_index, internal_index) in &self.hyperedges_mapping.right {
+            if let Some(HyperedgeKey { weight, .. }) = self.hyperedges.get(*internal_index) {
+                search_index.insert(*hyperedge_index, weight);
+            }
+        }
+
+        self.hyperedges_search_index = Some(search_index);
+    }
+
+    /// Disables the full-text search over the hyperedges and drops its index.
+    pub fn disable_hyperedge_search(&mut self) {
+        self.hyperedges_search_index = None;
+    }
+
+    /// Searches the hyperedges whose tokens match the ones of a query.
+    /// See `search_vertices` for the details of the ranking.
+    pub fn search_hyperedges(&self, query: &str) -> Result<Vec<HyperedgeIndex>, HypergraphError> {
+        self.hyperedges_search_index
+            .as_ref()
+            .map(|search_index| search_index.search(query))
+            .ok_or(HypergraphError::SearchIndexNotEnabled)
+    }
+}
@@ -0,0 +1,567 @@
+use std::{
+    fmt::{
+        self,
+        Debug,
+        Formatter,
+    },
+    net::ToSocketAddrs,
+    str::FromStr,
+    sync::{
+        Arc,
+        Mutex,
+    },
+};
+
+use axum::{
+    Json,
+    Router,
+    extract::{
+        Path,
+        State,
+    },
+    http::StatusCode,
+    response::{
+        IntoResponse,
+        Response,
+    },
+    routing::{
+        get,
+        post,
+    },
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+use crate::{
+    HyperedgeIndex,
+    HyperedgeTrait,
+    Hypergraph,
+    SnapshotStore,
+    VertexIndex,
+    VertexTrait,
+    errors::HypergraphError,
+};
+
+/// Store shared by the handlers.
+type SharedStore<V, HE> = Arc<Mutex<SnapshotStore<V, HE>>>;
+
+/// Error of a handler, rendered as a JSON body with a status code.
+struct ServerError(HypergraphError);
+
+impl From<HypergraphError> for ServerError {
+    fn from(error: HypergraphError) -> Self {
+        ServerError(error)
+    }
+}
+
+impl IntoResponse for ServerError {
+    fn into_response(self) -> Response {
+        let status = match self.0 {
+            HypergraphError::VertexIndexNotFound(_)
+            | HypergraphError::HyperedgeIndexNotFound(_)
+            | HypergraphError::StoreNamespaceNotFound(_) => StatusCode::NOT_FOUND,
+            HypergraphError::VertexWeightAlreadyAssigned(_)
+            | HypergraphError::HyperedgeWeightAlreadyAssigned(_)
+            | HypergraphError::HyperedgeVerticesAlreadyAssigned(_)
+            | HypergraphError::VertexVersionConflict { .. }
+            | HypergraphError::HyperedgeVersionConflict { .. }
+            | HypergraphError::StoreNamespaceConflict(_) => StatusCode::CONFLICT,
+            HypergraphError::StoreIo(_)
+            | HypergraphError::SnapshotIo(_)
+            | HypergraphError::ServerIo(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            _ => StatusCode::BAD_REQUEST,
+        };
+
+        (
+            status,
+            Json(ErrorBody {
+                error: self.0.to_string(),
+            }),
+        )
+            .into_response()
+    }
+}
+
+/// Body of an error.
+#[derive(Serialize)]
+struct ErrorBody {
+    error: String,
+}
+
+/// Body of the counts of a namespace.
+#[derive(Serialize)]
+struct NamespaceBody {
+    vertices: usize,
+    hyperedges: usize,
+}
+
+/// Body of a vertex.
+#[derive(Serialize)]
+struct VertexBody {
+    index: VertexIndex,
+    weight: String,
+    version: usize,
+    hyperedges: Vec<HyperedgeIndex>,
+}
+
+/// Body of a hyperedge.
+#[derive(Serialize)]
+struct HyperedgeBody {
+    index: HyperedgeIndex,
+    weight: String,
+    version: usize,
+    vertices: Vec<VertexIndex>,
+}
+
+/// Body of a created or updated entity.
+#[derive(Serialize)]
+struct VersionBody<I> {
+    index: I,
+    version: usize,
+}
+
+/// Body of a step of a path.
+#[derive(Serialize)]
+struct StepBody {
+    vertex: VertexIndex,
+    hyperedge: Option<HyperedgeIndex>,
+}
+
+/// Body of a new vertex or of an update of a vertex.
+#[derive(Deserialize)]
+struct VertexInput {
+    weight: String,
+    #[serde(default)]
+    expected_version: Option<usize>,
+}
+
+/// Body of a new hyperedge.
+#[derive(Deserialize)]
+struct HyperedgeInput {
+    vertices: Vec<VertexIndex>,
+    weight: String,
+}
+
+/// Body of an update of a hyperedge, the weight and the vertices being
+/// optional.
+#[derive(Deserialize)]
+struct HyperedgeUpdateInput {
+    #[serde(default)]
+    vertices: Option<Vec<VertexIndex>>,
+    #[serde(default)]
+    weight: Option<String>,
+    #[serde(default)]
+    expected_version: Option<usize>,
+}
+
+// Private helper to parse a weight.
+fn parse_weight<W>(weight: &str) -> Result<W, HypergraphError>
+where
+    W: FromStr,
+{
+    weight
+        .parse()
+        .map_err(|_| HypergraphError::ServerInvalidWeight(weight.to_owned()))
+}
+
+// Private helper to run a query against the hypergraph of a namespace.
+fn query<V, HE, T, F>(
+    store: &SharedStore<V, HE>,
+    namespace: &str,
+    query: F,
+) -> Result<T, ServerError>
+where
+    V: VertexTrait + FromStr,
+    HE: HyperedgeTrait + FromStr,
+    F: FnOnce(&Hypergraph<V, HE>) -> Result<T, HypergraphError>,
+{
+    let mut store = store
+        .lock()
+        .map_err(|_| HypergraphError::ServerIo("poisoned store".to_owned()))?;
+
+    Ok(query(store.get(namespace)?)?)
+}
+
+// Private helper to apply a mutation to the hypergraph of a namespace and
+// to write it through to the snapshot. The namespace is evicted if the
+// mutation fails midway or if the snapshot can't be written, e.g. on a
+// conflict with another writer, so that the next request reloads it.
+fn mutate<V, HE, T, F>(
+    store: &SharedStore<V, HE>,
+    namespace: &str,
+    mutation: F,
+) -> Result<T, ServerError>
+where
+    V: VertexTrait + FromStr,
+    HE: HyperedgeTrait + FromStr,
+    F: FnOnce(&mut Hypergraph<V, HE>) -> Result<T, HypergraphError>,
+{
+    let mut store = store
+        .lock()
+        .map_err(|_| HypergraphError::ServerIo("poisoned store".to_owned()))?;
+
+    let output = store
+        .update(namespace, mutation)
+        .and_then(|output| store.flush(namespace).map(|()| output));
+
+    if output.is_err() {
+        store.evict(namespace);
+    }
+
+    Ok(output?)
+}
+
+async fn list_namespaces<V, HE>(
+    State(store): State<SharedStore<V, HE>>,
+) -> Result<Json<Vec<String>>, ServerError>
+where
+    V: VertexTrait + FromStr,
+    HE: HyperedgeTrait + FromStr,
+{
+    let store = store
+        .lock()
+        .map_err(|_| HypergraphError::ServerIo("poisoned store".to_owned()))?;
+
+    Ok(Json(store.list_namespaces()?))
+}
+
+async fn get_namespace<V, HE>(
+    State(store): State<SharedStore<V, HE>>,
+    Path(namespace): Path<String>,
+) -> Result<Json<NamespaceBody>, ServerError>
+where
+    V: VertexTrait + FromStr,
+    HE: HyperedgeTrait + FromStr,
+{
+    query(&store, &namespace, |graph| {
+        Ok(Json(NamespaceBody {
+            vertices: graph.count_vertices(),
+            hyperedges: graph.count_hyperedges(),
+        }))
+    })
+}
+
+async fn drop_namespace<V, HE>(
+    State(store): State<SharedStore<V, HE>>,
+    Path(namespace): Path<String>,
+) -> Result<StatusCode, ServerError>
+where
+    V: VertexTrait + FromStr,
+    HE: HyperedgeTrait + FromStr,
+{
+    let mut store = store
+        .lock()
+        .map_err(|_| HypergraphError::ServerIo("poisoned store".to_owned()))?;
+
+    store.drop_namespace(&namespace)?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn add_vertex<V, HE>(
+    State(store): State<SharedStore<V, HE>>,
+    Path(namespace): Path<String>,
+    Json(input): Json<VertexInput>,
+) -> Result<(StatusCode, Json<VersionBody<VertexIndex>>), ServerError>
+where
+    V: VertexTrait + FromStr,
+    HE: HyperedgeTrait + FromStr,
+{
+    let weight = parse_weight(&input.weight)?;
+    let (index, version) = mutate(&store, &namespace, |graph| {
+        let index = graph.add_vertex(weight)?;
+
+        Ok((index, graph.get_vertex_version(index)?))
+    })?;
+
+    Ok((StatusCode::CREATED, Json(VersionBody { index, version })))
+}
+
+async fn get_vertex<V, HE>(
+    State(store): State<SharedStore<V, HE>>,
+    Path((namespace, index)): Path<(String, usize)>,
+) -> Result<Json<VertexBody>, ServerError>
+where
+    V: VertexTrait + FromStr,
+    HE: HyperedgeTrait + FromStr,
+{
+    let index = VertexIndex(index);
+
+    query(&store, &namespace, |graph| {
+        Ok(Json(VertexBody {
+            index,
+            weight: graph.get_vertex_weight(index)?.to_string(),
+            version: graph.get_vertex_version(index)?,
+            hyperedges: graph.get_vertex_hyperedges(index)?,
+        }))
+    })
+}
+
+async fn update_vertex<V, HE>(
+    State(store): State<SharedStore<V, HE>>,
+    Path((namespace, index)): Path<(String, usize)>,
+    Json(input): Json<VertexInput>,
+) -> Result<Json<VersionBody<VertexIndex>>, ServerError>
+where
+    V: VertexTrait + FromStr,
+    HE: HyperedgeTrait + FromStr,
+{
+    let index = VertexIndex(index);
+    let weight = parse_weight(&input.weight)?;
+
+    mutate(&store, &namespace, |graph| {
+        let expected = match input.expected_version {
+            Some(expected) => expected,
+            None => graph.get_vertex_version(index)?,
+        };
+        let version = graph.update_vertex_weight_if_version(index, weight, expected)?;
+
+        Ok(Json(VersionBody { index, version }))
+    })
+}
+
+async fn remove_vertex<V, HE>(
+    State(store): State<SharedStore<V, HE>>,
+    Path((namespace, index)): Path<(String, usize)>,
+) -> Result<StatusCode, ServerError>
+where
+    V: VertexTrait + FromStr,
+    HE: HyperedgeTrait + FromStr,
+{
+    mutate(&store, &namespace, |graph| {
+        graph.remove_vertex(VertexIndex(index))
+    })?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn get_adjacent_vertices<V, HE>(
+    State(store): State<SharedStore<V, HE>>,
+    Path((namespace, index)): Path<(String, usize)>,
+) -> Result<Json<Vec<VertexIndex>>, ServerError>
+where
+    V: VertexTrait + FromStr,
+    HE: HyperedgeTrait + FromStr,
+{
+    query(&store, &namespace, |graph| {
+        graph
+            .get_adjacent_vertices_from(VertexIndex(index))
+            .map(Json)
+    })
+}
+
+async fn add_hyperedge<V, HE>(
+    State(store): State<SharedStore<V, HE>>,
+    Path(namespace): Path<String>,
+    Json(input): Json<HyperedgeInput>,
+) -> Result<(StatusCode, Json<VersionBody<HyperedgeIndex>>), ServerError>
+where
+    V: VertexTrait + FromStr,
+    HE: HyperedgeTrait + FromStr,
+{
+    let weight = parse_weight(&input.weight)?;
+    let (index, version) = mutate(&store, &namespace, |graph| {
+        let index = graph.add_hyperedge(input.vertices, weight)?;
+
+        Ok((index, graph.get_hyperedge_version(index)?))
+    })?;
+
+    Ok((StatusCode::CREATED, Json(VersionBody { index, version })))
+}
+
+async fn get_hyperedge<V, HE>(
+    State(store): State<SharedStore<V, HE>>,
+    Path((namespace, index)): Path<(String, usize)>,
+) -> Result<Json<HyperedgeBody>, ServerError>
+where
+    V: VertexTrait + FromStr,
+    HE: HyperedgeTrait + FromStr,
+{
+    let index = HyperedgeIndex(index);
+
+    query(&store, &namespace, |graph| {
+        Ok(Json(HyperedgeBody {
+            index,
+            weight: graph.get_hyperedge_weight(index)?.to_string(),
+            version: graph.get_hyperedge_version(index)?,
+            vertices: graph.get_hyperedge_vertices(index)?,
+        }))
+    })
+}
+
+async fn update_hyperedge<V, HE>(
+    State(store): State<SharedStore<V, HE>>,
+    Path((namespace, index)): Path<(String, usize)>,
+    Json(input): Json<HyperedgeUpdateInput>,
+) -> Result<Json<VersionBody<HyperedgeIndex>>, ServerError>
+where
+    V: VertexTrait + FromStr,
+    HE: HyperedgeTrait + FromStr,
+{
+    let index = HyperedgeIndex(index);
+    let weight = input
+        .weight
+        .as_deref()
+        .map(parse_weight::<HE>)
+        .transpose()?;
+
+    mutate(&store, &namespace, |graph| {
+        let mut version = match input.expected_version {
+            Some(expected) => expected,
+            None => graph.get_hyperedge_version(index)?,
+        };
+
+        // Each update expects the version left by the previous one.
+        if let Some(vertices) = input.vertices {
+            version = graph.update_hyperedge_vertices_if_version(index, vertices, version)?;
+        }
+
+        if let Some(weight) = weight {
+            version = graph.update_hyperedge_weight_if_version(index, weight, version)?;
+        }
+
+        Ok(Json(VersionBody { index, version }))
+    })
+}
+
+async fn remove_hyperedge<V, HE>(
+    State(store): State<SharedStore<V, HE>>,
+    Path((namespace, index)): Path<(String, usize)>,
+) -> Result<StatusCode, ServerError>
+where
+    V: VertexTrait + FromStr,
+    HE: HyperedgeTrait + FromStr,
+{
+    mutate(&store, &namespace, |graph| {
+        graph.remove_hyperedge(HyperedgeIndex(index))
+    })?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn get_path<V, HE>(
+    State(store): State<SharedStore<V, HE>>,
+    Path((namespace, from, to)): Path<(String, usize, usize)>,
+) -> Result<Json<Vec<StepBody>>, ServerError>
+where
+    V: VertexTrait + FromStr,
+    HE: HyperedgeTrait + FromStr,
+{
+    query(&store, &namespace, |graph| {
+        let path = graph.get_dijkstra_connections(VertexIndex(from), VertexIndex(to))?;
+
+        Ok(Json(
+            path.into_iter()
+                .map(|(vertex, hyperedge)| StepBody { vertex, hyperedge })
+                .collect(),
+        ))
+    })
+}
+
+/// HTTP service exposing the namespaces of a snapshot store as a JSON API,
+/// so that non-Rust clients can use it without bindings.
+/// Weights are exchanged as strings via their `Display` and `FromStr`
+/// implementations, and the indexes as plain integers. Every mutation is
+/// written through to the snapshot of its namespace, a conflict with another
+/// writer being reported with the `409` status code. The updates accept an
+/// optional `expected_version`, see `update_vertex_weight_if_version`.
+///
+/// - `GET /namespaces`
+/// - `GET` and `DELETE /namespaces/{namespace}`
+/// - `POST /namespaces/{namespace}/vertices` with a `weight`
+/// - `GET`, `PUT` and `DELETE /namespaces/{namespace}/vertices/{index}`
+/// - `GET /namespaces/{namespace}/vertices/{index}/adjacent`
+/// - `POST /namespaces/{namespace}/hyperedges` with `vertices` and a `weight`
+/// - `GET`, `PUT` and `DELETE /namespaces/{namespace}/hyperedges/{index}`
+/// - `GET /namespaces/{namespace}/paths/{from}/{to}`
+pub struct HypergraphServer<V, HE> {
+    store: SharedStore<V, HE>,
+}
+
+impl<V, HE> Debug for HypergraphServer<V, HE> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HypergraphServer")
+            .field("store", &self.store)
+            .finish()
+    }
+}
+
+impl<V, HE> HypergraphServer<V, HE>
+where
+    V: VertexTrait + FromStr + 'static,
+    HE: HyperedgeTrait + FromStr + 'static,
+{
+    /// Creates a new server over a snapshot store.
+    pub fn new(store: SnapshotStore<V, HE>) -> Self {
+        Self {
+            store: Arc::new(Mutex::new(store)),
+        }
+    }
+
+    /// Converts the server into a router, e.g. to nest it into an existing
+    /// application.
+    pub fn into_router(self) -> Router {
+        Router::new()
+            .route("/namespaces", get(list_namespaces::<V, HE>))
+            .route(
+                "/namespaces/{namespace}",
+                get(get_namespace::<V, HE>).delete(drop_namespace::<V, HE>),
+            )
+            .route(
+                "/namespaces/{namespace}/vertices",
+                post(add_vertex::<V, HE>),
+            )
+            .route(
+                "/namespaces/{namespace}/vertices/{index}",
+                get(get_vertex::<V, HE>)
+                    .put(update_vertex::<V, HE>)
+                    .delete(remove_vertex::<V, HE>),
+            )
+            .route(
+                "/namespaces/{namespace}/vertices/{index}/adjacent",
+                get(get_adjacent_vertices::<V, HE>),
+            )
+            .route(
+                "/namespaces/{namespace}/hyperedges",
+                post(add_hyperedge::<V, HE>),
+            )
+            .route(
+                "/namespaces/{namespace}/hyperedges/{index}",
+                get(get_hyperedge::<V, HE>)
+                    .put(update_hyperedge::<V, HE>)
+                    .delete(remove_hyperedge::<V, HE>),
+            )
+            .route(
+                "/namespaces/{namespace}/paths/{from}/{to}",
+                get(get_path::<V, HE>),
+            )
+            .with_state(self.store)
+    }
+
+    /// Runs the server on an address until it fails, blocking the current
+    /// thread.
+    pub fn run<A>(self, address: A) -> Result<(), HypergraphError>
+    where
+        A: ToSocketAddrs,
+    {
+        let io_error = |error: std::io::Error| HypergraphError::ServerIo(error.to_string());
+        let listener = std::net::TcpListener::bind(address).map_err(io_error)?;
+
+        listener.set_nonblocking(true).map_err(io_error)?;
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_io()
+            .build()
+            .map_err(io_error)?;
+
+        runtime.block_on(async move {
+            let listener = tokio::net::TcpListener::from_std(listener).map_err(io_error)?;
+
+            axum::serve(listener, self.into_router())
+                .await
+                .map_err(io_error)
+        })
+    }
+}
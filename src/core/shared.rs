@@ -1,12 +1,13 @@
 use itertools::Itertools;
-use rayon::prelude::*;
 
 use crate::{
     HyperedgeIndex,
+    HyperedgeKey,
     HyperedgeTrait,
     Hypergraph,
     VertexIndex,
     VertexTrait,
+    core::parallel::*,
     errors::HypergraphError,
 };
 
@@ -33,7 +34,7 @@ where
     pub(crate) fn get_connections(
         &self,
         connections: &Connection,
-    ) -> Result<Connections, HypergraphError<V, HE>> {
+    ) -> Result<Connections, HypergraphError> {
         let internal_index = self.get_internal_vertex(match connections {
             Connection::InAndOut(vertex_index, _)
             | Connection::In(vertex_index)
@@ -45,70 +46,57 @@ where
             .get_index(internal_index)
             .ok_or(HypergraphError::InternalVertexIndexNotFound(internal_index))?;
 
-        let hyperedges =
-            self.get_hyperedges(&hyperedges_index_set.clone().into_iter().collect_vec())?;
+        let mut results = Connections::new();
 
-        let hyperedges_with_vertices = hyperedges
-            .into_par_iter()
-            .map(|hyperedge_index| {
-                self.get_hyperedge_vertices(hyperedge_index)
-                    .map(|vertices| (hyperedge_index, vertices))
-            })
-            .collect::<Result<Vec<(HyperedgeIndex, Vec<VertexIndex>)>, HypergraphError<V, HE>>>()?;
+        for internal_hyperedge in hyperedges_index_set {
+            let hyperedge_index = self.get_hyperedge(*internal_hyperedge)?;
 
-        let capacity = hyperedges_with_vertices.len();
+            // Iterate lazily over the windows of vertices to avoid allocating
+            // the vertices of each hyperedge.
+            for (window_from, window_to) in self
+                .get_hyperedge_vertices_iter(hyperedge_index)?
+                .tuple_windows::<(_, _)>()
+            {
+                match connections {
+                    // Inject the index of the hyperedge and the vertex index
+                    // if the current window is a match.
+                    Connection::In(from) if window_from == *from => {
+                        results.push((hyperedge_index, Some(window_to)));
+                    }
+                    Connection::Out(to) if window_to == *to => {
+                        results.push((hyperedge_index, Some(window_from)));
+                    }
+                    // Inject only the index of the hyperedge if the current
+                    // window is a match.
+                    Connection::InAndOut(from, to) if window_from == *from && window_to == *to => {
+                        results.push((hyperedge_index, None));
+                    }
+                    _ => {}
+                }
+            }
+        }
 
-        let results = hyperedges_with_vertices
-            .into_par_iter()
-            .fold_with(
-                Vec::with_capacity(capacity),
-                |acc, (hyperedge_index, vertices)| {
-                    vertices.iter().tuple_windows::<(_, _)>().fold(
-                        acc,
-                        |index_acc, (window_from, window_to)| {
-                            match connections {
-                                Connection::In(from) => {
-                                    // Inject the index of the hyperedge and the
-                                    // vertex index if the current window is a
-                                    // match.
-                                    if *window_from == *from {
-                                        return index_acc
-                                            .into_iter()
-                                            .chain(vec![(hyperedge_index, Some(*window_to))])
-                                            .collect_vec();
-                                    }
-                                }
-                                Connection::Out(to) => {
-                                    // Inject the index of the hyperedge and the
-                                    // vertex index if the current window is a
-                                    // match.
-                                    if *window_to == *to {
-                                        return index_acc
-                                            .into_iter()
-                                            .chain(vec![(hyperedge_index, Some(*window_from))])
-                                            .collect_vec();
-                                    }
-                                }
-                                Connection::InAndOut(from, to) => {
-                                    // Inject only the index of the hyperedge
-                                    // if the current window is a match.
-                                    if *window_from == *from && *window_to == *to {
-                                        return index_acc
-                                            .into_iter()
-                                            .chain(vec![(hyperedge_index, None)])
-                                            .collect_vec();
-                                    }
-                                }
-                            }
+        Ok(results)
+    }
 
-                            index_acc
-                        },
-                    )
-                },
-            )
-            .flatten()
-            .collect::<Connections>();
+    // Private method to get the deduplicated internal vertices adjacent from
+    // each internal vertex, following the direction of the hyperedges.
+    pub(crate) fn get_internal_adjacency(&self) -> Vec<Vec<usize>> {
+        let mut adjacency = vec![vec![]; self.vertices.len()];
 
-        Ok(results)
+        for HyperedgeKey { vertices, .. } in self.hyperedges.iter() {
+            for pair in vertices.windows(2) {
+                if pair[0] != pair[1] {
+                    adjacency[pair[0]].push(pair[1]);
+                }
+            }
+        }
+
+        adjacency.par_iter_mut().for_each(|adjacent_vertices| {
+            adjacent_vertices.sort_unstable();
+            adjacent_vertices.dedup();
+        });
+
+        adjacency
     }
 }
@@ -0,0 +1,361 @@
+use std::{
+    cmp::Ordering,
+    mem::size_of,
+};
+
+use crate::{
+    HyperedgeTrait,
+    Hypergraph,
+    VertexIndex,
+    VertexTrait,
+    core::{
+        memory::index_map_bytes,
+        types::{
+            AIndexMap,
+            AIndexSet,
+        },
+    },
+    errors::HypergraphError,
+};
+
+/// Minimum number of embeddings before partitioning them.
+/// Below it, the queries are exact and scan all the embeddings.
+const MIN_PARTITIONED_EMBEDDINGS: usize = 64;
+
+/// Number of iterations of the k-means clustering of the partitions.
+const KMEANS_ITERATIONS: usize = 5;
+
+// Private helper to compute the dot product of two vectors.
+fn dot(left: &[f32], right: &[f32]) -> f32 {
+    left.iter()
+        .zip(right)
+        .map(|(left, right)| left * right)
+        .sum()
+}
+
+// Private helper to scale a vector to a unit length, leaving the null vector
+// untouched.
+fn normalize(mut vector: Vec<f32>) -> Vec<f32> {
+    let norm = dot(&vector, &vector).sqrt();
+
+    if norm > 0. {
+        vector.iter_mut().for_each(|value| *value /= norm);
+    }
+
+    vector
+}
+
+/// Inverted file index of the embeddings of the vertices, used to find the
+/// most similar vertices by cosine similarity.
+/// The embeddings are normalized and partitioned by k-means around
+/// centroids once numerous enough. A query only scans the partitions whose
+/// centroids are the closest to it.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct SimilarityIndex {
+    /// Number of dimensions of the embeddings, set by the first one.
+    dimensions: usize,
+
+    /// Normalized embeddings of the vertices.
+    embeddings: AIndexMap<VertexIndex, Vec<f32>>,
+
+    /// Centroids of the partitions, empty until partitioned.
+    centroids: Vec<Vec<f32>>,
+
+    /// Vertices of each partition.
+    partitions: Vec<AIndexSet<VertexIndex>>,
+
+    /// Partition of each vertex.
+    assignments: AIndexMap<VertexIndex, usize>,
+
+    /// Number of embeddings at the time of the last partitioning.
+    partitioned_len: usize,
+}
+
+impl SimilarityIndex {
+    /// Gets the normalized embedding of a vertex.
+    pub(crate) fn get(&self, index: VertexIndex) -> Option<&[f32]> {
+        self.embeddings.get(&index).map(Vec::as_slice)
+    }
+
+    /// Indexes the embedding of a vertex, replacing the previous one if
+    /// any. The dimensions must match the ones of the other embeddings.
+    pub(crate) fn insert(
+        &mut self,
+        index: VertexIndex,
+        embedding: Vec<f32>,
+    ) -> Result<(), HypergraphError> {
+        if self.embeddings.is_empty() {
+            self.dimensions = embedding.len();
+        } else if embedding.len() != self.dimensions {
+            return Err(HypergraphError::VertexEmbeddingInvalidDimensions {
+                index,
+                expected: self.dimensions,
+                actual: embedding.len(),
+            });
+        }
+
+        self.remove(index);
+
+        let embedding = normalize(embedding);
+
+        if !self.centroids.is_empty() {
+            let partition = self.get_closest_partitions(&embedding)[0];
+
+            self.partitions[partition].insert(index);
+            self.assignments.insert(index, partition);
+        }
+
+        self.embeddings.insert(index, embedding);
+
+        // Partition again once the number of embeddings has doubled.
+        if self.embeddings.len() >= MIN_PARTITIONED_EMBEDDINGS
+            && self.embeddings.len() >= 2 * self.partitioned_len
+        {
+            self.partition();
+        }
+
+        Ok(())
+    }
+
+    /// Removes the embedding of a vertex from the index.
+    pub(crate) fn remove(&mut self, index: VertexIndex) {
+        self.embeddings.swap_remove(&index);
+
+        if let Some(partition) = self.assignments.swap_remove(&index) {
+            self.partitions[partition].swap_remove(&index);
+        }
+    }
+
+    /// Remaps the indexes of the vertices, e.g. once the stable indexes have
+    /// been reassigned.
+    pub(crate) fn remap<F>(&mut self, remapping: F)
+    where
+        F: Fn(VertexIndex) -> VertexIndex,
+    {
+        self.embeddings = self
+            .embeddings
+            .drain(..)
+            .map(|(index, embedding)| (remapping(index), embedding))
+            .collect();
+
+        for partition in self.partitions.iter_mut() {
+            *partition = partition.drain(..).map(&remapping).collect();
+        }
+
+        self.assignments = self
+            .assignments
+            .drain(..)
+            .map(|(index, partition)| (remapping(index), partition))
+            .collect();
+    }
+
+    /// Clears the index.
+    pub(crate) fn clear(&mut self) {
+        *self = SimilarityIndex::default();
+    }
+
+    /// Estimates the heap usage of the index in bytes.
+    pub(crate) fn estimated_memory_bytes(&self) -> usize {
+        let vectors = (self.embeddings.len() + self.centroids.len()) * self.dimensions;
+
+        index_map_bytes::<VertexIndex, Vec<f32>>(self.embeddings.capacity())
+            + self.centroids.capacity() * size_of::<Vec<f32>>()
+            + vectors * size_of::<f32>()
+            + self
+                .partitions
+                .iter()
+                .map(|partition| index_map_bytes::<VertexIndex, ()>(partition.capacity()))
+                .sum::<usize>()
+            + index_map_bytes::<VertexIndex, usize>(self.assignments.capacity())
+    }
+
+    // Private method to rank the partitions by decreasing similarity of
+    // their centroids with a normalized vector.
+    fn get_closest_partitions(&self, vector: &[f32]) -> Vec<usize> {
+        let mut partitions = (0..self.centroids.len()).collect::<Vec<usize>>();
+
+        partitions.sort_by(|left, right| {
+            dot(vector, &self.centroids[*right])
+                .partial_cmp(&dot(vector, &self.centroids[*left]))
+                .unwrap_or(Ordering::Equal)
+        });
+
+        partitions
+    }
+
+    // Private method to partition the embeddings around about the square
+    // root of their number of centroids, via a k-means clustering seeded
+    // deterministically with evenly spaced embeddings.
+    fn partition(&mut self) {
+        let count = self.embeddings.len();
+        let partitions = (count as f64).sqrt().ceil() as usize;
+
+        self.centroids = (0..partitions)
+            .map(|position| self.embeddings[position * count / partitions].clone())
+            .collect();
+
+        let mut assignments = vec![0; count];
+
+        for _ in 0..KMEANS_ITERATIONS {
+            for (assignment, embedding) in assignments.iter_mut().zip(self.embeddings.values()) {
+                *assignment = self.get_closest_partitions(embedding)[0];
+            }
+
+            let mut sums = vec![vec![0.; self.dimensions]; partitions];
+
+            for (assignment, embedding) in assignments.iter().zip(self.embeddings.values()) {
+                for (sum, value) in sums[*assignment].iter_mut().zip(embedding) {
+                    *sum += value;
+                }
+            }
+
+            // Keep the previous centroid of an empty partition.
+            for (centroid, sum) in self.centroids.iter_mut().zip(sums) {
+                if sum.iter().any(|value| *value != 0.) {
+                    *centroid = normalize(sum);
+                }
+            }
+        }
+
+        self.partitions = vec![AIndexSet::default(); partitions];
+        self.assignments.clear();
+
+        for (index, embedding) in self.embeddings.iter() {
+            let partition = self.get_closest_partitions(embedding)[0];
+
+            self.partitions[partition].insert(*index);
+            self.assignments.insert(*index, partition);
+        }
+
+        self.partitioned_len = count;
+    }
+
+    /// Finds the `k` indexed vertices the most similar to a vector, by
+    /// decreasing cosine similarity, the excluded vertex aside.
+    /// The closest partitions are scanned until a quarter of them - at least
+    /// one - have been and `k` candidates have been found.
+    pub(crate) fn search(
+        &self,
+        vector: &[f32],
+        k: usize,
+        excluded: VertexIndex,
+    ) -> Vec<(VertexIndex, f32)> {
+        let vector = normalize(vector.to_vec());
+
+        let mut results = if self.centroids.is_empty() {
+            self.embeddings
+                .iter()
+                .filter(|(index, _)| **index != excluded)
+                .map(|(index, embedding)| (*index, dot(&vector, embedding)))
+                .collect::<Vec<(VertexIndex, f32)>>()
+        } else {
+            let probes = (self.partitions.len() + 3) / 4;
+            let mut results = vec![];
+
+            for (probed, partition) in self.get_closest_partitions(&vector).into_iter().enumerate()
+            {
+                if probed >= probes && results.len() >= k {
+                    break;
+                }
+
+                results.extend(
+                    self.partitions[partition]
+                        .iter()
+                        .filter(|index| **index != excluded)
+                        .filter_map(|index| {
+                            self.embeddings
+                                .get(index)
+                                .map(|embedding| (*index, dot(&vector, embedding)))
+                        }),
+                );
+            }
+
+            results
+        };
+
+        results.sort_by(|(left_index, left), (right_index, right)| {
+            right
+                .partial_cmp(left)
+                .unwrap_or(Ordering::Equal)
+                .then_with(|| left_index.cmp(right_index))
+        });
+        results.truncate(k);
+
+        results
+    }
+}
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Sets the embedding of a vertex, e.g. as generated by
+    /// `get_vertex_embeddings` or supplied by the user, for the similarity
+    /// search. All the embeddings must have the same dimensions.
+    /// The embedding is dropped along with the vertex.
+    pub fn set_vertex_embedding(
+        &mut self,
+        vertex_index: VertexIndex,
+        embedding: Vec<f32>,
+    ) -> Result<(), HypergraphError> {
+        self.get_internal_vertex(vertex_index)?;
+
+        self.vertices_similarity_index
+            .insert(vertex_index, embedding)
+    }
+
+    /// Sets the embeddings of many vertices at once.
+    /// See `set_vertex_embedding`.
+    pub fn set_vertex_embeddings<I>(&mut self, embeddings: I) -> Result<(), HypergraphError>
+    where
+        I: IntoIterator<Item = (VertexIndex, Vec<f32>)>,
+    {
+        embeddings
+            .into_iter()
+            .try_for_each(|(vertex_index, embedding)| {
+                self.set_vertex_embedding(vertex_index, embedding)
+            })
+    }
+
+    /// Gets the normalized embedding of a vertex, if any.
+    pub fn get_vertex_embedding(
+        &self,
+        vertex_index: VertexIndex,
+    ) -> Result<Option<&[f32]>, HypergraphError> {
+        self.get_internal_vertex(vertex_index)?;
+
+        Ok(self.vertices_similarity_index.get(vertex_index))
+    }
+
+    /// Removes the embedding of a vertex, if any.
+    pub fn remove_vertex_embedding(
+        &mut self,
+        vertex_index: VertexIndex,
+    ) -> Result<(), HypergraphError> {
+        self.get_internal_vertex(vertex_index)?;
+
+        self.vertices_similarity_index.remove(vertex_index);
+
+        Ok(())
+    }
+
+    /// Finds the `k` vertices the most similar to a given one, by
+    /// decreasing cosine similarity of their embeddings, as a vector of
+    /// `(VertexIndex, similarity)`.
+    /// The search is exact for a few embeddings and approximate otherwise,
+    /// only the closest partitions of embeddings being scanned.
+    pub fn find_similar_vertices(
+        &self,
+        vertex_index: VertexIndex,
+        k: usize,
+    ) -> Result<Vec<(VertexIndex, f32)>, HypergraphError> {
+        let embedding = self
+            .get_vertex_embedding(vertex_index)?
+            .ok_or(HypergraphError::VertexEmbeddingNotFound(vertex_index))?;
+
+        Ok(self
+            .vertices_similarity_index
+            .search(embedding, k, vertex_index))
+    }
+}
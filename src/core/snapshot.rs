@@ -0,0 +1,852 @@
+use std::{
+    fs::File,
+    io::{
+        BufReader,
+        BufWriter,
+        Read,
+        Write,
+    },
+    path::Path,
+    str::FromStr,
+};
+
+use crate::{
+    Constraints,
+    DuplicatePolicy,
+    HyperedgeIndex,
+    HyperedgeKey,
+    HyperedgeTrait,
+    Hypergraph,
+    LayerIndex,
+    Severity,
+    Value,
+    VertexDedupPolicy,
+    VertexIndex,
+    VertexKey,
+    VertexTrait,
+    core::{
+        layers::Layer,
+        types::{
+            AIndexMap,
+            AIndexSet,
+            ARandomState,
+        },
+    },
+    errors::HypergraphError,
+};
+
+/// Magic bytes at the start of every snapshot.
+const MAGIC: &[u8; 8] = b"HGSNAPSH";
+
+/// Version of the snapshot format written by this release. It must be bumped
+/// on any change of the layout below.
+pub const SNAPSHOT_VERSION: u32 = 1;
+
+// Private helper to convert an I/O error.
+fn io_error(error: std::io::Error) -> HypergraphError {
+    HypergraphError::SnapshotIo(error.to_string())
+}
+
+// Private helper to write an unsigned integer in little-endian order.
+pub(crate) fn write_u64(buffer: &mut Vec<u8>, value: usize) {
+    buffer.extend_from_slice(&(value as u64).to_le_bytes());
+}
+
+// Private helper to write a string prefixed by its length.
+pub(crate) fn write_string(buffer: &mut Vec<u8>, value: &str) {
+    write_u64(buffer, value.len());
+    buffer.extend_from_slice(value.as_bytes());
+}
+
+// Private helper to write an optional unsigned integer prefixed by a flag.
+fn write_optional(buffer: &mut Vec<u8>, value: Option<usize>) {
+    match value {
+        Some(value) => {
+            buffer.push(1);
+            write_u64(buffer, value);
+        }
+        None => buffer.push(0),
+    }
+}
+
+// Private helper to write a value prefixed by its type tag.
+pub(crate) fn write_value(buffer: &mut Vec<u8>, value: &Value) {
+    match value {
+        Value::Boolean(value) => {
+            buffer.push(0);
+            buffer.push(u8::from(*value));
+        }
+        Value::Float(value) => {
+            buffer.push(1);
+            buffer.extend_from_slice(&value.to_bits().to_le_bytes());
+        }
+        Value::Integer(value) => {
+            buffer.push(2);
+            buffer.extend_from_slice(&value.to_le_bytes());
+        }
+        Value::String(value) => {
+            buffer.push(3);
+            write_string(buffer, value);
+        }
+    }
+}
+
+// Private helper to write a map of attributes keyed by stable indexes.
+fn write_attributes<I>(
+    buffer: &mut Vec<u8>,
+    attributes: &AIndexMap<I, AIndexMap<String, Value>>,
+    index: impl Fn(&I) -> usize,
+) {
+    write_u64(buffer, attributes.len());
+
+    for (key, values) in attributes {
+        write_u64(buffer, index(key));
+        write_u64(buffer, values.len());
+
+        for (name, value) in values {
+            write_string(buffer, name);
+            write_value(buffer, value);
+        }
+    }
+}
+
+// Private cursor over the bytes of a snapshot or of a journal. Reading past
+// the end returns an explicit error.
+pub(crate) struct SnapshotReader<'a> {
+    pub(crate) bytes: &'a [u8],
+    pub(crate) position: usize,
+}
+
+impl<'a> SnapshotReader<'a> {
+    pub(crate) fn read_bytes(&mut self, length: usize) -> Result<&'a [u8], HypergraphError> {
+        let end = self
+            .position
+            .checked_add(length)
+            .filter(|end| *end <= self.bytes.len())
+            .ok_or_else(|| HypergraphError::SnapshotCorrupted("unexpected end".to_owned()))?;
+        let bytes = &self.bytes[self.position..end];
+
+        self.position = end;
+
+        Ok(bytes)
+    }
+
+    pub(crate) fn read_u8(&mut self) -> Result<u8, HypergraphError> {
+        Ok(self.read_bytes(1)?[0])
+    }
+
+    pub(crate) fn read_u32(&mut self) -> Result<u32, HypergraphError> {
+        let mut bytes = [0; 4];
+
+        bytes.copy_from_slice(self.read_bytes(4)?);
+
+        Ok(u32::from_le_bytes(bytes))
+    }
+
+    pub(crate) fn read_u64(&mut self) -> Result<u64, HypergraphError> {
+        let mut bytes = [0; 8];
+
+        bytes.copy_from_slice(self.read_bytes(8)?);
+
+        Ok(u64::from_le_bytes(bytes))
+    }
+
+    pub(crate) fn read_usize(&mut self) -> Result<usize, HypergraphError> {
+        let value = self.read_u64()?;
+
+        usize::try_from(value)
+            .map_err(|_| HypergraphError::SnapshotCorrupted(format!("invalid size {value}")))
+    }
+
+    // Reads a length which can't exceed the remaining bytes, given that each
+    // item takes at least one byte. This avoids huge allocations on
+    // corrupted inputs.
+    pub(crate) fn read_length(&mut self) -> Result<usize, HypergraphError> {
+        let length = self.read_usize()?;
+
+        if length > self.bytes.len() - self.position {
+            return Err(HypergraphError::SnapshotCorrupted(format!(
+                "invalid length {length}"
+            )));
+        }
+
+        Ok(length)
+    }
+
+    pub(crate) fn read_string(&mut self) -> Result<String, HypergraphError> {
+        let length = self.read_length()?;
+
+        String::from_utf8(self.read_bytes(length)?.to_vec())
+            .map_err(|_| HypergraphError::SnapshotCorrupted("invalid string".to_owned()))
+    }
+
+    pub(crate) fn read_weight<T>(&mut self) -> Result<T, HypergraphError>
+    where
+        T: FromStr,
+    {
+        let weight = self.read_string()?;
+
+        weight
+            .parse()
+            .map_err(|_| HypergraphError::SnapshotInvalidWeight(weight))
+    }
+
+    pub(crate) fn read_optional(&mut self) -> Result<Option<usize>, HypergraphError> {
+        match self.read_u8()? {
+            0 => Ok(None),
+            _ => self.read_usize().map(Some),
+        }
+    }
+
+    pub(crate) fn read_value(&mut self) -> Result<Value, HypergraphError> {
+        match self.read_u8()? {
+            0 => Ok(Value::Boolean(self.read_u8()? != 0)),
+            1 => Ok(Value::Float(f64::from_bits(self.read_u64()?))),
+            2 => {
+                let mut bytes = [0; 8];
+
+                bytes.copy_from_slice(self.read_bytes(8)?);
+
+                Ok(Value::Integer(i64::from_le_bytes(bytes)))
+            }
+            3 => Ok(Value::String(self.read_string()?)),
+            tag => Err(HypergraphError::SnapshotCorrupted(format!(
+                "invalid value tag {tag}"
+            ))),
+        }
+    }
+
+    fn read_attributes<I>(
+        &mut self,
+        index: impl Fn(usize) -> I,
+    ) -> Result<AIndexMap<I, AIndexMap<String, Value>>, HypergraphError>
+    where
+        I: Eq + std::hash::Hash,
+    {
+        let mut attributes = AIndexMap::default();
+
+        for _ in 0..self.read_length()? {
+            let key = index(self.read_usize()?);
+            let mut values = AIndexMap::default();
+
+            for _ in 0..self.read_length()? {
+                let name = self.read_string()?;
+
+                values.insert(name, self.read_value()?);
+            }
+
+            attributes.insert(key, values);
+        }
+
+        Ok(attributes)
+    }
+
+    // Reads an internal index which must be lower than a bound.
+    fn read_internal_index(&mut self, bound: usize) -> Result<usize, HypergraphError> {
+        let index = self.read_usize()?;
+
+        if index >= bound {
+            return Err(HypergraphError::SnapshotCorrupted(format!(
+                "invalid internal index {index}"
+            )));
+        }
+
+        Ok(index)
+    }
+}
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Writes a versioned binary snapshot of the hypergraph.
+    /// The snapshot starts with magic bytes and the version of the format,
+    /// followed by the duplicate policy, the constraints, the vertices, the
+    /// hyperedges, the attributes, the properties, the roles, the metadata,
+    /// the tags, the layers, the vertex dedup policy, the recycled stable
+    /// indexes and the versions of the vertices and of the hyperedges along
+    /// with their counter. The stable indexes are preserved while the search
+    /// indexes, the adjacency cache and the distance oracle are not.
+    /// Weights are stored as strings via their `Display` implementation.
+    pub fn write_snapshot<W>(&self, mut writer: W) -> Result<(), HypergraphError>
+    where
+        W: Write,
+    {
+        let mut buffer = Vec::new();
+
+        buffer.extend_from_slice(MAGIC);
+        buffer.extend_from_slice(&SNAPSHOT_VERSION.to_le_bytes());
+
+        // Counters of the stable indexes.
+        write_u64(&mut buffer, self.vertices_count);
+        write_u64(&mut buffer, self.hyperedges_count);
+
+        buffer.push(match self.duplicate_policy {
+            DuplicatePolicy::AllowDuplicateVertices => 0,
+            DuplicatePolicy::RejectDuplicateVertexLists => 1,
+            DuplicatePolicy::RejectDuplicateVertexSets => 2,
+        });
+
+        // Constraints, checked again against the hypergraph once read.
+        write_optional(&mut buffer, self.constraints.max_hyperedge_cardinality);
+        write_optional(&mut buffer, self.constraints.max_vertex_degree);
+        buffer.push(u8::from(self.constraints.forbid_self_loops));
+        write_optional(&mut buffer, self.constraints.max_vertices);
+        write_optional(&mut buffer, self.constraints.max_hyperedges);
+
+        // Vertices in internal order, along with their hyperedges.
+        write_u64(&mut buffer, self.vertices.len());
+
+        for (internal_index, (key, hyperedges)) in self.vertices.iter().enumerate() {
+            write_u64(&mut buffer, self.get_vertex(internal_index)?.0);
+
+            match key.id {
+                Some(id) => {
+                    buffer.push(1);
+                    write_u64(&mut buffer, id);
+                }
+                None => buffer.push(0),
+            }
+
+            write_string(&mut buffer, &key.weight.to_string());
+            write_u64(&mut buffer, hyperedges.len());
+
+            for hyperedge in hyperedges {
+                write_u64(&mut buffer, *hyperedge);
+            }
+        }
+
+        // Hyperedges in internal order.
+        write_u64(&mut buffer, self.hyperedges.len());
+
+        for (internal_index, HyperedgeKey { vertices, weight }) in
+            self.hyperedges.iter().enumerate()
+        {
+            write_u64(&mut buffer, self.get_hyperedge(internal_index)?.0);
+            write_string(&mut buffer, &weight.to_string());
+            write_u64(&mut buffer, vertices.len());
+
+            for vertex in vertices {
+                write_u64(&mut buffer, *vertex);
+            }
+        }
+
+        write_attributes(&mut buffer, &self.vertices_attributes, |index| index.0);
+        write_attributes(&mut buffer, &self.hyperedges_properties, |index| index.0);
+
+        write_u64(&mut buffer, self.hyperedges_roles.len());
+
+        for (hyperedge_index, roles) in self.hyperedges_roles.iter() {
+            write_u64(&mut buffer, hyperedge_index.0);
+            write_u64(&mut buffer, roles.len());
+
+            for role in roles {
+                write_string(&mut buffer, role);
+            }
+        }
+
+        write_u64(&mut buffer, self.metadata.len());
+
+        for (key, value) in self.metadata.iter() {
+            write_string(&mut buffer, key);
+            write_value(&mut buffer, value);
+        }
+
+        write_u64(&mut buffer, self.hyperedges_tags.len());
+
+        for (tag, hyperedges) in self.hyperedges_tags.iter() {
+            write_string(&mut buffer, tag);
+            write_u64(&mut buffer, hyperedges.len());
+
+            for hyperedge_index in hyperedges {
+                write_u64(&mut buffer, hyperedge_index.0);
+            }
+        }
+
+        write_u64(&mut buffer, self.layers_count);
+        write_u64(&mut buffer, self.layers.len());
+
+        for (layer_index, layer) in self.layers.iter() {
+            write_u64(&mut buffer, layer_index.0);
+            write_string(&mut buffer, &layer.name);
+            write_u64(&mut buffer, layer.hyperedges.len());
+
+            for hyperedge_index in layer.hyperedges.iter() {
+                write_u64(&mut buffer, hyperedge_index.0);
+            }
+        }
+
+        buffer.push(match self.vertex_dedup_policy {
+            VertexDedupPolicy::KeepDuplicates => 0,
+            VertexDedupPolicy::DedupConsecutive => 1,
+            VertexDedupPolicy::DedupAll => 2,
+        });
+
+        // Recycled stable indexes, in reuse order.
+        write_u64(&mut buffer, self.vertices_recycled.len());
+
+        for vertex_index in self.vertices_recycled.iter() {
+            write_u64(&mut buffer, vertex_index.0);
+        }
+
+        write_u64(&mut buffer, self.hyperedges_recycled.len());
+
+        for hyperedge_index in self.hyperedges_recycled.iter() {
+            write_u64(&mut buffer, hyperedge_index.0);
+        }
+
+        // Versions of the vertices and of the hyperedges, by stable index.
+        write_u64(&mut buffer, self.vertices_versions.len());
+
+        for (vertex_index, version) in self.vertices_versions.iter() {
+            write_u64(&mut buffer, vertex_index.0);
+            write_u64(&mut buffer, *version);
+        }
+
+        write_u64(&mut buffer, self.hyperedges_versions.len());
+
+        for (hyperedge_index, version) in self.hyperedges_versions.iter() {
+            write_u64(&mut buffer, hyperedge_index.0);
+            write_u64(&mut buffer, *version);
+        }
+
+        write_u64(&mut buffer, self.versions_count);
+
+        writer.write_all(&buffer).map_err(io_error)?;
+        writer.flush().map_err(io_error)
+    }
+
+    /// Reads a hypergraph from a snapshot written by `write_snapshot`.
+    /// Weights are parsed back via their `FromStr` implementation.
+    /// Snapshots written by another version of the format are rejected with
+    /// an explicit error, and the inconsistent ones, e.g. referencing missing
+    /// vertices or hyperedges, as corrupted. The hypergraphs breaking their
+    /// constraints are rejected with the error of the first broken one.
+    pub fn read_snapshot<R>(mut reader: R) -> Result<Self, HypergraphError>
+    where
+        R: Read,
+        V: FromStr,
+        HE: FromStr,
+    {
+        let mut bytes = Vec::new();
+
+        reader.read_to_end(&mut bytes).map_err(io_error)?;
+
+        let mut reader = SnapshotReader {
+            bytes: &bytes,
+            position: 0,
+        };
+
+        if reader.read_bytes(MAGIC.len()).ok() != Some(&MAGIC[..]) {
+            return Err(HypergraphError::SnapshotInvalidHeader);
+        }
+
+        let version = reader.read_u32()?;
+
+        if version != SNAPSHOT_VERSION {
+            return Err(HypergraphError::SnapshotUnsupportedVersion(version));
+        }
+
+        let vertices_count = reader.read_usize()?;
+        let hyperedges_count = reader.read_usize()?;
+
+        let duplicate_policy = match reader.read_u8()? {
+            0 => DuplicatePolicy::AllowDuplicateVertices,
+            1 => DuplicatePolicy::RejectDuplicateVertexLists,
+            2 => DuplicatePolicy::RejectDuplicateVertexSets,
+            policy => {
+                return Err(HypergraphError::SnapshotCorrupted(format!(
+                    "invalid duplicate policy {policy}"
+                )));
+            }
+        };
+
+        let constraints = Constraints {
+            max_hyperedge_cardinality: reader.read_optional()?,
+            max_vertex_degree: reader.read_optional()?,
+            forbid_self_loops: reader.read_u8()? != 0,
+            max_vertices: reader.read_optional()?,
+            max_hyperedges: reader.read_optional()?,
+        };
+
+        let mut graph = Hypergraph::with_duplicate_policy(duplicate_policy);
+
+        graph.constraints = constraints;
+
+        // Since the hyperedges are read afterwards, the hyperedges of the
+        // vertices are validated at the end along with the other references.
+        let vertices = reader.read_length()?;
+
+        for internal_index in 0..vertices {
+            let vertex_index = VertexIndex(reader.read_usize()?);
+
+            let id = match reader.read_u8()? {
+                0 => None,
+                _ => Some(reader.read_usize()?),
+            };
+
+            let weight = reader.read_weight::<V>()?;
+
+            let mut hyperedges = AIndexSet::with_capacity_and_hasher(0, ARandomState::default());
+
+            for _ in 0..reader.read_length()? {
+                hyperedges.insert(reader.read_usize()?);
+            }
+
+            if graph
+                .vertices
+                .insert(VertexKey::new(weight, id), hyperedges)
+                .is_some()
+            {
+                return Err(HypergraphError::SnapshotCorrupted(format!(
+                    "duplicate vertex {vertex_index}"
+                )));
+            }
+
+            graph
+                .vertices_mapping
+                .left
+                .insert(internal_index, vertex_index);
+            graph
+                .vertices_mapping
+                .right
+                .insert(vertex_index, internal_index);
+        }
+
+        let hyperedges = reader.read_length()?;
+
+        for internal_index in 0..hyperedges {
+            let hyperedge_index = HyperedgeIndex(reader.read_usize()?);
+            let weight = reader.read_weight::<HE>()?;
+
+            let vertices = (0..reader.read_length()?)
+                .map(|_| reader.read_internal_index(graph.vertices.len()))
+                .collect::<Result<Vec<usize>, HypergraphError>>()?;
+
+            if vertices.is_empty() {
+                return Err(HypergraphError::SnapshotCorrupted(format!(
+                    "empty hyperedge {hyperedge_index}"
+                )));
+            }
+
+            if graph
+                .hyperedges_weights
+                .insert(weight.clone(), internal_index)
+                .is_some()
+            {
+                return Err(HypergraphError::SnapshotCorrupted(format!(
+                    "duplicate hyperedge {hyperedge_index}"
+                )));
+            }
+
+            graph.hyperedges_cardinalities.insert(vertices.len());
+            graph.hyperedges.push(HyperedgeKey::new(vertices, weight));
+            graph
+                .hyperedges_mapping
+                .left
+                .insert(internal_index, hyperedge_index);
+            graph
+                .hyperedges_mapping
+                .right
+                .insert(hyperedge_index, internal_index);
+        }
+
+        // Check that the stable indexes are unique and below the counters.
+        if graph.vertices_mapping.right.len() != vertices
+            || graph.hyperedges_mapping.right.len() != hyperedges
+            || graph
+                .vertices_mapping
+                .right
+                .keys()
+                .any(|VertexIndex(index)| *index >= vertices_count)
+            || graph
+                .hyperedges_mapping
+                .right
+                .keys()
+                .any(|HyperedgeIndex(index)| *index >= hyperedges_count)
+        {
+            return Err(HypergraphError::SnapshotCorrupted(
+                "invalid stable indexes".to_owned(),
+            ));
+        }
+
+        graph.vertices_count = vertices_count;
+        graph.hyperedges_count = hyperedges_count;
+
+        graph.vertices_attributes = reader.read_attributes(VertexIndex)?;
+        graph.hyperedges_properties = reader.read_attributes(HyperedgeIndex)?;
+
+        for _ in 0..reader.read_length()? {
+            let hyperedge_index = HyperedgeIndex(reader.read_usize()?);
+            let roles = (0..reader.read_length()?)
+                .map(|_| reader.read_string())
+                .collect::<Result<Vec<String>, HypergraphError>>()?;
+
+            graph.hyperedges_roles.insert(hyperedge_index, roles);
+        }
+
+        for _ in 0..reader.read_length()? {
+            let key = reader.read_string()?;
+
+            graph.metadata.insert(key, reader.read_value()?);
+        }
+
+        for _ in 0..reader.read_length()? {
+            let tag = reader.read_string()?;
+            let hyperedges = (0..reader.read_length()?)
+                .map(|_| reader.read_usize().map(HyperedgeIndex))
+                .collect::<Result<AIndexSet<HyperedgeIndex>, HypergraphError>>()?;
+
+            graph.hyperedges_tags.insert(tag, hyperedges);
+        }
+
+        graph.layers_count = reader.read_usize()?;
+
+        for _ in 0..reader.read_length()? {
+            let layer_index = LayerIndex(reader.read_usize()?);
+            let name = reader.read_string()?;
+            let hyperedges = (0..reader.read_length()?)
+                .map(|_| reader.read_usize().map(HyperedgeIndex))
+                .collect::<Result<AIndexSet<HyperedgeIndex>, HypergraphError>>()?;
+
+            // Check that the layers are below the counter, uniquely named and
+            // don't share any hyperedge.
+            if layer_index.0 >= graph.layers_count
+                || graph.layers.contains_key(&layer_index)
+                || graph.get_layer_by_name(&name).is_some()
+            {
+                return Err(HypergraphError::SnapshotCorrupted(format!(
+                    "invalid layer {layer_index}"
+                )));
+            }
+
+            for hyperedge_index in hyperedges.iter() {
+                if graph
+                    .hyperedges_layers
+                    .insert(*hyperedge_index, layer_index)
+                    .is_some()
+                {
+                    return Err(HypergraphError::SnapshotCorrupted(format!(
+                        "hyperedge {hyperedge_index} in several layers"
+                    )));
+                }
+            }
+
+            graph.layers.insert(layer_index, Layer { name, hyperedges });
+        }
+
+        graph.vertex_dedup_policy = match reader.read_u8()? {
+            0 => VertexDedupPolicy::KeepDuplicates,
+            1 => VertexDedupPolicy::DedupConsecutive,
+            2 => VertexDedupPolicy::DedupAll,
+            policy => {
+                return Err(HypergraphError::SnapshotCorrupted(format!(
+                    "invalid vertex dedup policy {policy}"
+                )));
+            }
+        };
+
+        graph.vertices_recycled = (0..reader.read_length()?)
+            .map(|_| reader.read_usize().map(VertexIndex))
+            .collect::<Result<Vec<VertexIndex>, HypergraphError>>()?;
+        graph.hyperedges_recycled = (0..reader.read_length()?)
+            .map(|_| reader.read_usize().map(HyperedgeIndex))
+            .collect::<Result<Vec<HyperedgeIndex>, HypergraphError>>()?;
+
+        // Check that the recycled indexes are unused, unique and below
+        // the counters.
+        if graph.vertices_recycled.iter().any(|vertex_index| {
+            vertex_index.0 >= vertices_count
+                || graph.vertices_mapping.right.contains_key(vertex_index)
+        }) || graph.hyperedges_recycled.iter().any(|hyperedge_index| {
+            hyperedge_index.0 >= hyperedges_count
+                || graph.hyperedges_mapping.right.contains_key(hyperedge_index)
+        }) || !graph
+            .vertices_recycled
+            .windows(2)
+            .all(|pair| pair[0] > pair[1])
+            || !graph
+                .hyperedges_recycled
+                .windows(2)
+                .all(|pair| pair[0] > pair[1])
+        {
+            return Err(HypergraphError::SnapshotCorrupted(
+                "invalid recycled indexes".to_owned(),
+            ));
+        }
+
+        for _ in 0..reader.read_length()? {
+            let vertex_index = VertexIndex(reader.read_usize()?);
+
+            graph
+                .vertices_versions
+                .insert(vertex_index, reader.read_usize()?);
+        }
+
+        for _ in 0..reader.read_length()? {
+            let hyperedge_index = HyperedgeIndex(reader.read_usize()?);
+
+            graph
+                .hyperedges_versions
+                .insert(hyperedge_index, reader.read_usize()?);
+        }
+
+        graph.versions_count = reader.read_usize()?;
+
+        // Check that the versions belong to existing entities and have been
+        // issued by the counter.
+        if graph
+            .vertices_versions
+            .keys()
+            .any(|vertex_index| !graph.vertices_mapping.right.contains_key(vertex_index))
+            || graph.hyperedges_versions.keys().any(|hyperedge_index| {
+                !graph.hyperedges_mapping.right.contains_key(hyperedge_index)
+            })
+            || graph
+                .vertices_versions
+                .values()
+                .chain(graph.hyperedges_versions.values())
+                .any(|version| *version > graph.versions_count)
+        {
+            return Err(HypergraphError::SnapshotCorrupted(
+                "invalid versions".to_owned(),
+            ));
+        }
+
+        // Check that the vertices and the hyperedges reference each other and
+        // that the attributes, the properties, the roles, the tags and the
+        // layers belong to existing entities.
+        if let Some(issue) = graph
+            .validate()
+            .issues
+            .into_iter()
+            .find(|issue| issue.severity != Severity::Info)
+        {
+            return Err(HypergraphError::SnapshotCorrupted(issue.message));
+        }
+
+        // Check that the hypergraph complies with its constraints.
+        graph.check_all_constraints()?;
+
+        // Mirror the vertices of the hyperedges in their bitmaps.
+        #[cfg(feature = "roaring")]
+        graph.rebuild_hyperedges_bitmaps()?;
+
+        if reader.position != bytes.len() {
+            return Err(HypergraphError::SnapshotCorrupted(
+                "trailing bytes".to_owned(),
+            ));
+        }
+
+        Ok(graph)
+    }
+
+    /// Saves a versioned binary snapshot of the hypergraph to a file.
+    /// See `write_snapshot` for more details.
+    pub fn save_snapshot<P>(&self, path: P) -> Result<(), HypergraphError>
+    where
+        P: AsRef<Path>,
+    {
+        self.write_snapshot(BufWriter::new(File::create(path).map_err(io_error)?))
+    }
+
+    /// Loads a hypergraph from a snapshot file.
+    /// See `read_snapshot` for more details.
+    pub fn load_snapshot<P>(path: P) -> Result<Self, HypergraphError>
+    where
+        P: AsRef<Path>,
+        V: FromStr,
+        HE: FromStr,
+    {
+        Self::read_snapshot(BufReader::new(File::open(path).map_err(io_error)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Private helper to write a snapshot and read it back.
+    fn round_trip(
+        graph: &Hypergraph<usize, usize>,
+    ) -> Result<Hypergraph<usize, usize>, HypergraphError> {
+        let mut bytes = vec![];
+
+        graph.write_snapshot(&mut bytes).unwrap();
+
+        Hypergraph::read_snapshot(&bytes[..])
+    }
+
+    #[test]
+    fn check_references() {
+        let mut graph = Hypergraph::<usize, usize>::new();
+
+        let a = graph.add_vertex(1).unwrap();
+        let b = graph.add_vertex(2).unwrap();
+        let alpha = graph
+            .add_hyperedge_with_roles(vec![(a, "input"), (b, "output")], 10)
+            .unwrap();
+        let layer = graph.add_layer("friends").unwrap();
+
+        graph.set_hyperedge_layer(alpha, Some(layer)).unwrap();
+        graph.tag_hyperedge(alpha, "family").unwrap();
+
+        assert_eq!(round_trip(&graph).as_ref(), Ok(&graph));
+
+        let corruptions: Vec<fn(&mut Hypergraph<usize, usize>)> = vec![
+            |graph| {
+                graph
+                    .vertices_attributes
+                    .insert(VertexIndex(9), AIndexMap::default());
+            },
+            |graph| {
+                graph
+                    .hyperedges_properties
+                    .insert(HyperedgeIndex(9), AIndexMap::default());
+            },
+            |graph| {
+                graph
+                    .hyperedges_roles
+                    .insert(HyperedgeIndex(0), vec!["input".to_owned()]);
+            },
+            |graph| {
+                graph.hyperedges_tags.insert(
+                    "ghost".to_owned(),
+                    [HyperedgeIndex(9)].into_iter().collect(),
+                );
+            },
+            |graph| graph.layers_count = 0,
+            |graph| {
+                graph.layers.insert(
+                    LayerIndex(1),
+                    Layer {
+                        name: "colleagues".to_owned(),
+                        hyperedges: [HyperedgeIndex(0)].into_iter().collect(),
+                    },
+                );
+                graph.layers_count = 2;
+            },
+            |graph| {
+                graph.vertices.get_index_mut(1).unwrap().1.clear();
+            },
+            |graph| {
+                graph.vertices.get_index_mut(0).unwrap().1.insert(1);
+            },
+        ];
+
+        for corrupt in corruptions {
+            let mut corrupted = graph.clone();
+
+            corrupt(&mut corrupted);
+
+            assert!(
+                matches!(
+                    round_trip(&corrupted),
+                    Err(HypergraphError::SnapshotCorrupted(_))
+                ),
+                "should reject a corrupted snapshot"
+            );
+        }
+    }
+}
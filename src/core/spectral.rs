@@ -0,0 +1,124 @@
+use ndarray::Array2;
+
+use crate::{
+    HyperedgeKey,
+    HyperedgeTrait,
+    Hypergraph,
+    VertexIndex,
+    VertexTrait,
+    core::types::AIndexSet,
+    errors::HypergraphError,
+};
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    // Private method to get the vertices indexing the rows and the columns of
+    // the matrices, i.e. the vertices ordered by their internal indexes.
+    fn get_matrix_vertices(&self) -> Result<Vec<VertexIndex>, HypergraphError> {
+        (0..self.vertices.len())
+            .map(|internal_index| self.get_vertex(internal_index))
+            .collect()
+    }
+
+    // Private method to get the distinct internal vertices of each hyperedge.
+    fn get_distinct_hyperedges_vertices(&self) -> Vec<(AIndexSet<usize>, HE)> {
+        self.hyperedges
+            .iter()
+            .map(|HyperedgeKey { vertices, weight }| {
+                (vertices.iter().copied().collect(), weight.clone())
+            })
+            .collect()
+    }
+
+    /// Gets the incidence matrix of the hypergraph along with the vertices
+    /// indexing its rows. Columns follow the order of the hyperedges.
+    /// An entry is one if the vertex belongs to the hyperedge, zero otherwise.
+    pub fn to_incidence_matrix(&self) -> Result<(Vec<VertexIndex>, Array2<f64>), HypergraphError> {
+        let mut matrix = Array2::zeros((self.vertices.len(), self.hyperedges.len()));
+
+        for (hyperedge, HyperedgeKey { vertices, .. }) in self.hyperedges.iter().enumerate() {
+            for vertex in vertices {
+                matrix[[*vertex, hyperedge]] = 1.;
+            }
+        }
+
+        Ok((self.get_matrix_vertices()?, matrix))
+    }
+
+    /// Gets the adjacency matrix of the clique expansion of the hypergraph
+    /// along with the vertices indexing its rows and columns.
+    /// An entry is the number of hyperedges shared by two distinct vertices.
+    /// <https://en.wikipedia.org/wiki/Hypergraph#Related_graphs>
+    pub fn to_clique_expansion_adjacency_matrix(
+        &self,
+    ) -> Result<(Vec<VertexIndex>, Array2<f64>), HypergraphError> {
+        let mut matrix = Array2::zeros((self.vertices.len(), self.vertices.len()));
+
+        for (vertices, _) in self.get_distinct_hyperedges_vertices() {
+            for from in &vertices {
+                for to in &vertices {
+                    if from != to {
+                        matrix[[*from, *to]] += 1.;
+                    }
+                }
+            }
+        }
+
+        Ok((self.get_matrix_vertices()?, matrix))
+    }
+
+    /// Gets the normalized Laplacian matrix of the hypergraph along with the
+    /// vertices indexing its rows and columns.
+    /// Follows the formulation of Zhou et al. where the cost of a hyperedge
+    /// is used as its weight:
+    /// `L = I - Dv^-1/2 H W De^-1 H^T Dv^-1/2`.
+    /// Isolated vertices are left with a diagonal entry of one.
+    /// <https://papers.nips.cc/paper/3128-learning-with-hypergraphs-clustering-classification-and-embedding>
+    pub fn to_normalized_laplacian_matrix(
+        &self,
+    ) -> Result<(Vec<VertexIndex>, Array2<f64>), HypergraphError> {
+        let hyperedges = self.get_distinct_hyperedges_vertices();
+
+        // Compute the weighted degrees of the vertices.
+        let mut degrees = vec![0.; self.vertices.len()];
+
+        for (vertices, weight) in &hyperedges {
+            let weight = weight.clone().into() as f64;
+
+            for vertex in vertices {
+                degrees[*vertex] += weight;
+            }
+        }
+
+        let inverse_square_roots = degrees
+            .into_iter()
+            .map(|degree: f64| {
+                if degree > 0. {
+                    degree.sqrt().recip()
+                } else {
+                    0.
+                }
+            })
+            .collect::<Vec<f64>>();
+
+        let mut matrix = Array2::eye(self.vertices.len());
+
+        // Subtract the normalized contribution of each hyperedge, which only
+        // involves the pairs of vertices it contains.
+        for (vertices, weight) in &hyperedges {
+            let contribution = weight.clone().into() as f64 / vertices.len() as f64;
+
+            for from in vertices {
+                for to in vertices {
+                    matrix[[*from, *to]] -=
+                        contribution * inverse_square_roots[*from] * inverse_square_roots[*to];
+                }
+            }
+        }
+
+        Ok((self.get_matrix_vertices()?, matrix))
+    }
+}
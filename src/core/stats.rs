@@ -0,0 +1,237 @@
+use std::collections::HashMap;
+
+use crate::{
+    HyperedgeKey,
+    HyperedgeTrait,
+    Hypergraph,
+    VertexIndex,
+    VertexTrait,
+    core::{
+        parallel::*,
+        types::AIndexSet,
+    },
+    errors::HypergraphError,
+};
+
+// Private helper to compute the Pearson correlation coefficient of pairs of
+// values, undefined without any variance.
+fn get_pearson_correlation<I>(pairs: I) -> Option<f64>
+where
+    I: IntoIterator<Item = (f64, f64)>,
+{
+    let (mut count, mut sum_x, mut sum_y, mut sum_xx, mut sum_yy, mut sum_xy) =
+        (0., 0., 0., 0., 0., 0.);
+
+    for (x, y) in pairs {
+        count += 1.;
+        sum_x += x;
+        sum_y += y;
+        sum_xx += x * x;
+        sum_yy += y * y;
+        sum_xy += x * y;
+    }
+
+    let covariance = count * sum_xy - sum_x * sum_y;
+    let variance = (count * sum_xx - sum_x * sum_x) * (count * sum_yy - sum_y * sum_y);
+
+    (variance > 0.).then(|| covariance / variance.sqrt())
+}
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    // Private method to get the internal vertices sharing at least one
+    // hyperedge with a vertex, the direction being ignored.
+    fn get_internal_neighbors(&self, vertex: usize) -> Result<AIndexSet<usize>, HypergraphError> {
+        let (_, hyperedges) = self
+            .vertices
+            .get_index(vertex)
+            .ok_or(HypergraphError::InternalVertexIndexNotFound(vertex))?;
+
+        let mut neighbors = AIndexSet::default();
+
+        for hyperedge in hyperedges {
+            let vertices = &self
+                .hyperedges
+                .get(*hyperedge)
+                .ok_or(HypergraphError::InternalHyperedgeIndexNotFound(*hyperedge))?
+                .vertices;
+
+            neighbors.extend(vertices.iter().copied().filter(|other| *other != vertex));
+        }
+
+        Ok(neighbors)
+    }
+
+    // Private method to get the internal hyperedges shared by two vertices.
+    fn get_internal_shared_hyperedges(
+        &self,
+        left: usize,
+        right: usize,
+    ) -> Result<AIndexSet<usize>, HypergraphError> {
+        let get_hyperedges = |vertex: usize| {
+            self.vertices
+                .get_index(vertex)
+                .map(|(_, hyperedges)| hyperedges)
+                .ok_or(HypergraphError::InternalVertexIndexNotFound(vertex))
+        };
+
+        Ok(get_hyperedges(left)?
+            .intersection(get_hyperedges(right)?)
+            .copied()
+            .collect())
+    }
+
+    // Private method to compute the clustering coefficient of a vertex.
+    fn get_internal_clustering_coefficient(&self, vertex: usize) -> Result<f64, HypergraphError> {
+        let neighbors = self
+            .get_internal_neighbors(vertex)?
+            .into_iter()
+            .map(|neighbor| {
+                Ok((
+                    neighbor,
+                    self.get_internal_shared_hyperedges(vertex, neighbor)?,
+                ))
+            })
+            .collect::<Result<Vec<(usize, AIndexSet<usize>)>, HypergraphError>>()?;
+
+        let mut triples = 0;
+        let mut triangles = 0;
+
+        for (position, (left, left_shared)) in neighbors.iter().enumerate() {
+            for (right, right_shared) in neighbors.iter().skip(position + 1) {
+                // The two neighbors must be reached via distinct hyperedges.
+                if left_shared.union(right_shared).nth(1).is_none() {
+                    continue;
+                }
+
+                triples += 1;
+
+                // The three pairs of vertices must be connected via three
+                // distinct hyperedges, i.e. the shared hyperedges must admit
+                // a system of distinct representatives.
+                let between = self.get_internal_shared_hyperedges(*left, *right)?;
+
+                if !between.is_empty()
+                    && between.union(left_shared).nth(1).is_some()
+                    && between.union(right_shared).nth(1).is_some()
+                    && between
+                        .iter()
+                        .chain(left_shared)
+                        .chain(right_shared)
+                        .collect::<AIndexSet<&usize>>()
+                        .len()
+                        >= 3
+                {
+                    triangles += 1;
+                }
+            }
+        }
+
+        Ok(match triples {
+            0 => 0.,
+            triples => triangles as f64 / triples as f64,
+        })
+    }
+
+    /// Computes the clustering coefficient of each vertex, following the
+    /// definition of Estrada and Rodríguez-Velázquez for hypergraphs, the
+    /// direction of the hyperedges being ignored.
+    /// It is the ratio between the number of hyper-triangles containing the
+    /// vertex, i.e. three vertices pairwise connected via three distinct
+    /// hyperedges, and the number of pairs of its neighbors reached via two
+    /// distinct hyperedges. A single hyperedge doesn't form any triangle, in
+    /// contrast with the clustering coefficient of the clique expansion.
+    /// The coefficient is zero for a vertex without such pair of neighbors.
+    /// <https://arxiv.org/abs/physics/0505137>
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            err,
+            fields(
+                vertices_count = self.vertices.len(),
+                hyperedges_count = self.hyperedges.len(),
+            )
+        )
+    )]
+    pub fn clustering_coefficients(&self) -> Result<HashMap<VertexIndex, f64>, HypergraphError> {
+        (0..self.vertices.len())
+            .into_par_iter()
+            .map(|internal_index| {
+                Ok((
+                    self.get_vertex(internal_index)?,
+                    self.get_internal_clustering_coefficient(internal_index)?,
+                ))
+            })
+            .collect()
+    }
+
+    /// Computes the average clustering coefficient of the vertices, zero for
+    /// an empty hypergraph.
+    /// See `clustering_coefficients` for more details.
+    pub fn average_clustering_coefficient(&self) -> Result<f64, HypergraphError> {
+        let coefficients = self.clustering_coefficients()?;
+
+        Ok(match coefficients.len() {
+            0 => 0.,
+            count => coefficients.values().sum::<f64>() / count as f64,
+        })
+    }
+
+    /// Computes the degree assortativity of the hypergraph over its
+    /// 2-section, i.e. the undirected graph in which two vertices are
+    /// adjacent when they share at least one hyperedge.
+    /// It is the Pearson correlation coefficient between the degrees, in the
+    /// 2-section, of the two ends of its edges. Positive values indicate
+    /// that vertices tend to be adjacent to vertices with a similar degree.
+    /// It is undefined, i.e. `None`, when all the ends have the same degree.
+    /// <https://en.wikipedia.org/wiki/Assortativity>
+    pub fn degree_assortativity(&self) -> Result<Option<f64>, HypergraphError> {
+        let neighbors = (0..self.vertices.len())
+            .into_par_iter()
+            .map(|internal_index| self.get_internal_neighbors(internal_index))
+            .collect::<Result<Vec<AIndexSet<usize>>, HypergraphError>>()?;
+
+        // Each edge is counted in both directions to be symmetric.
+        Ok(get_pearson_correlation(neighbors.iter().flat_map(
+            |vertex_neighbors| {
+                vertex_neighbors.iter().map(|neighbor| {
+                    (
+                        vertex_neighbors.len() as f64,
+                        neighbors[*neighbor].len() as f64,
+                    )
+                })
+            },
+        )))
+    }
+
+    /// Computes the correlation between the cardinalities of the hyperedges
+    /// and the degrees of their vertices, i.e. the number of hyperedges they
+    /// belong to.
+    /// It is the Pearson correlation coefficient over the pairs of a
+    /// hyperedge and of one of its distinct vertices. Positive values
+    /// indicate that the vertices of the larger hyperedges tend to belong to
+    /// more hyperedges.
+    /// It is undefined, i.e. `None`, without any variance of either the
+    /// cardinalities or the degrees.
+    pub fn cardinality_degree_correlation(&self) -> Result<Option<f64>, HypergraphError> {
+        let mut pairs = vec![];
+
+        for HyperedgeKey { vertices, .. } in self.hyperedges.iter() {
+            for vertex in vertices.iter().collect::<AIndexSet<&usize>>() {
+                let (_, hyperedges) = self
+                    .vertices
+                    .get_index(*vertex)
+                    .ok_or(HypergraphError::InternalVertexIndexNotFound(*vertex))?;
+
+                pairs.push((vertices.len() as f64, hyperedges.len() as f64));
+            }
+        }
+
+        Ok(get_pearson_correlation(pairs))
+    }
+}
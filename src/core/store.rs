@@ -0,0 +1,432 @@
+use std::{
+    collections::hash_map::RandomState,
+    fs::{
+        self,
+        File,
+        Metadata,
+    },
+    hash::{
+        BuildHasher,
+        Hasher,
+    },
+    io::{
+        ErrorKind,
+        Read,
+        Write,
+    },
+    path::{
+        Path,
+        PathBuf,
+    },
+    process,
+    str::FromStr,
+    time::{
+        Duration,
+        SystemTime,
+    },
+};
+
+use crate::{
+    Constraints,
+    HyperedgeTrait,
+    Hypergraph,
+    VertexTrait,
+    core::{
+        content_hash::FnvHasher,
+        types::AIndexMap,
+    },
+    errors::HypergraphError,
+};
+
+/// Extension of the snapshot files of the namespaces.
+const EXTENSION: &str = "snapshot";
+
+/// Age under which the modification time of a snapshot file can't tell two
+/// writes apart, given the coarse timestamps of the file systems.
+const RACY_WINDOW: Duration = Duration::from_secs(2);
+
+// Private helper to convert an I/O error.
+fn io_error(error: std::io::Error) -> HypergraphError {
+    HypergraphError::StoreIo(error.to_string())
+}
+
+// Private etag of a snapshot file, i.e. the digest of its bytes, along with
+// the length and the modification time of the file which are compared
+// first to avoid reading it again. The metadata are only trusted once the
+// file is older than the racy window.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct Etag {
+    digest: u64,
+    length: u64,
+    modified: Option<SystemTime>,
+    trusted: bool,
+}
+
+impl Etag {
+    // Private helper to get the etag of the bytes of a snapshot.
+    fn new(bytes: &[u8], metadata: &Metadata) -> Self {
+        let mut hasher = FnvHasher::default();
+
+        hasher.write(bytes);
+
+        let modified = metadata.modified().ok();
+
+        Self {
+            digest: hasher.finish(),
+            length: metadata.len(),
+            modified,
+            trusted: matches!(
+                modified.and_then(|modified| SystemTime::now().duration_since(modified).ok()),
+                Some(age) if age >= RACY_WINDOW
+            ),
+        }
+    }
+
+    // Private helper to check whether a file still matches the etag
+    // according to its metadata.
+    fn matches(&self, metadata: &Metadata) -> bool {
+        self.trusted && self.length == metadata.len() && self.modified == metadata.modified().ok()
+    }
+}
+
+// Private helper to read a snapshot file along with its etag, if it exists.
+// The metadata are read before the bytes so that a concurrent change is
+// caught by the next comparison.
+fn read_snapshot_file(path: &Path) -> Result<Option<(Vec<u8>, Etag)>, HypergraphError> {
+    let mut file = match File::open(path) {
+        Ok(file) => file,
+        Err(error) if error.kind() == ErrorKind::NotFound => return Ok(None),
+        Err(error) => return Err(io_error(error)),
+    };
+    let metadata = file.metadata().map_err(io_error)?;
+    let mut bytes = Vec::new();
+
+    file.read_to_end(&mut bytes).map_err(io_error)?;
+
+    let etag = Etag::new(&bytes, &metadata);
+
+    Ok(Some((bytes, etag)))
+}
+
+// Private helper to get the current etag of a snapshot file, if it exists.
+// The file is only read and hashed again if its metadata differ from the
+// cached etag.
+fn read_etag(path: &Path, cached: Option<Etag>) -> Result<Option<Etag>, HypergraphError> {
+    match (fs::metadata(path), cached) {
+        (Ok(metadata), Some(etag)) if etag.matches(&metadata) => Ok(Some(etag)),
+        (Ok(_), _) => Ok(read_snapshot_file(path)?.map(|(_, etag)| etag)),
+        (Err(error), _) if error.kind() == ErrorKind::NotFound => Ok(None),
+        (Err(error), _) => Err(io_error(error)),
+    }
+}
+
+// Private helper to get the path of a temporary file next to a snapshot,
+// unique to the writer via the process id and a random suffix so that
+// concurrent writers never write to the same temporary file.
+fn get_temporary_path(path: &Path) -> PathBuf {
+    let mut temporary = path.as_os_str().to_owned();
+
+    temporary.push(format!(
+        ".{}-{:016x}.tmp",
+        process::id(),
+        RandomState::new().build_hasher().finish()
+    ));
+
+    PathBuf::from(temporary)
+}
+
+// Private helper to replace a snapshot file atomically, by writing a
+// temporary file in the same directory and renaming it over the snapshot.
+// The temporary file is removed on failure.
+// Returns the etag of the new snapshot.
+fn write_snapshot_file(path: &Path, bytes: &[u8]) -> Result<Etag, HypergraphError> {
+    let temporary = get_temporary_path(path);
+
+    let written = File::create(&temporary)
+        .and_then(|mut file| {
+            file.write_all(bytes)?;
+            file.sync_all()
+        })
+        .and_then(|()| fs::rename(&temporary, path));
+
+    if let Err(error) = written {
+        // Best effort, the temporary file being ignored by the store.
+        let _ = fs::remove_file(&temporary);
+
+        return Err(io_error(error));
+    }
+
+    Ok(Etag::new(bytes, &fs::metadata(path).map_err(io_error)?))
+}
+
+// Private helper to check that a namespace can safely be used as a file
+// name.
+fn is_valid_namespace(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|character| character.is_ascii_alphanumeric() || matches!(character, '-' | '_'))
+}
+
+/// Store of named hypergraphs, i.e. namespaces, under one root directory,
+/// e.g. to host one hypergraph per customer within a single service.
+/// Each namespace is persisted as a snapshot file and cached in memory once
+/// accessed, independently of the other namespaces.
+/// The names are made of ASCII alphanumeric characters, dashes and
+/// underscores.
+/// Each cached namespace keeps track of the etag of its snapshot so that
+/// flushing it over the changes of another writer - e.g. another store on
+/// the same root - fails with an explicit error instead of silently
+/// overwriting them. The entities can then be reloaded and updated
+/// conditionally on their versions, see `update_vertex_weight_if_version`.
+/// The check is best-effort since the files are not locked: it isn't atomic
+/// with the replacement of the snapshot, so that a writer flushing between
+/// the check and the replacement of another one gets its changes silently
+/// overwritten.
+/// The constraints of the hypergraphs are persisted in their snapshots, and
+/// can be enforced for every namespace via `open_with_constraints`, e.g. to
+/// limit the size of each tenant.
+pub struct SnapshotStore<V, HE> {
+    root: PathBuf,
+    constraints: Option<Constraints>,
+    graphs: AIndexMap<String, Hypergraph<V, HE>>,
+    etags: AIndexMap<String, Option<Etag>>,
+}
+
+impl<V, HE> std::fmt::Debug for SnapshotStore<V, HE> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SnapshotStore")
+            .field("root", &self.root)
+            .field("constraints", &self.constraints)
+            .field("cached", &self.graphs.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl<V, HE> SnapshotStore<V, HE>
+where
+    V: VertexTrait + FromStr,
+    HE: HyperedgeTrait + FromStr,
+{
+    /// Opens a store at a root directory, creating the directory if needed.
+    /// The hypergraphs keep the constraints persisted in their snapshots.
+    pub fn open<P>(root: P) -> Result<Self, HypergraphError>
+    where
+        P: AsRef<Path>,
+    {
+        Self::open_inner(root, None)
+    }
+
+    /// Opens a store at a root directory, creating the directory if needed.
+    /// The constraints are enforced for every namespace, either created or
+    /// loaded, overriding the ones persisted in the snapshots. Loading a
+    /// snapshot which doesn't comply with them fails.
+    pub fn open_with_constraints<P>(
+        root: P,
+        constraints: Constraints,
+    ) -> Result<Self, HypergraphError>
+    where
+        P: AsRef<Path>,
+    {
+        Self::open_inner(root, Some(constraints))
+    }
+
+    // Private method to open a store with optional constraints.
+    fn open_inner<P>(root: P, constraints: Option<Constraints>) -> Result<Self, HypergraphError>
+    where
+        P: AsRef<Path>,
+    {
+        fs::create_dir_all(&root).map_err(io_error)?;
+
+        Ok(Self {
+            root: root.as_ref().to_path_buf(),
+            constraints,
+            graphs: AIndexMap::default(),
+            etags: AIndexMap::default(),
+        })
+    }
+
+    /// Gets the constraints enforced for every namespace, if any.
+    pub fn get_constraints(&self) -> Option<Constraints> {
+        self.constraints
+    }
+
+    /// Gets the root directory of the store.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Lists the namespaces, either persisted or only cached, sorted by
+    /// name.
+    pub fn list_namespaces(&self) -> Result<Vec<String>, HypergraphError> {
+        let mut namespaces = self.graphs.keys().cloned().collect::<Vec<String>>();
+
+        for entry in fs::read_dir(&self.root).map_err(io_error)? {
+            let path = entry.map_err(io_error)?.path();
+
+            if path.extension().and_then(|extension| extension.to_str()) != Some(EXTENSION) {
+                continue;
+            }
+
+            if let Some(name) = path.file_stem().and_then(|name| name.to_str()) {
+                if is_valid_namespace(name) {
+                    namespaces.push(name.to_owned());
+                }
+            }
+        }
+
+        namespaces.sort_unstable();
+        namespaces.dedup();
+
+        Ok(namespaces)
+    }
+
+    /// Gets the hypergraph of a namespace, loading it in the cache if
+    /// needed.
+    pub fn get(&mut self, name: &str) -> Result<&Hypergraph<V, HE>, HypergraphError> {
+        self.load(name, false).map(|graph| &*graph)
+    }
+
+    /// Updates the hypergraph of a namespace via a mutation, loading it in
+    /// the cache if needed or creating an empty one if the namespace doesn't
+    /// exist. Returns the output of the mutation.
+    /// The constraints of the store, if any, are applied again once the
+    /// mutation is done, e.g. if it replaced the hypergraph. If the
+    /// hypergraph then breaks them, the namespace is evicted - discarding the
+    /// changes which haven't been flushed - and the error is returned.
+    /// The changes are persisted via `flush` or `flush_all`.
+    pub fn update<T, F>(&mut self, name: &str, mutation: F) -> Result<T, HypergraphError>
+    where
+        F: FnOnce(&mut Hypergraph<V, HE>) -> Result<T, HypergraphError>,
+    {
+        let constraints = self.constraints;
+        let graph = self.load(name, true)?;
+        let output = mutation(graph);
+
+        if let Some(constraints) = constraints {
+            graph.constraints = constraints;
+
+            if let Err(error) = graph.check_all_constraints() {
+                self.evict(name);
+
+                return Err(error);
+            }
+        }
+
+        output
+    }
+
+    /// Persists the cached hypergraph of a namespace.
+    /// The snapshot is written to a temporary file which then replaces it
+    /// atomically, so that a failed write leaves the previous one intact.
+    /// Returns an error if its snapshot has been written by another writer
+    /// since it was loaded or last flushed, in which case it must be evicted
+    /// and the changes applied again, or if the hypergraph breaks its
+    /// constraints.
+    pub fn flush(&mut self, name: &str) -> Result<(), HypergraphError> {
+        let path = self.get_path(name)?;
+        let graph = self
+            .graphs
+            .get(name)
+            .ok_or_else(|| HypergraphError::StoreNamespaceNotFound(name.to_owned()))?;
+
+        graph.check_all_constraints()?;
+
+        let cached = self.etags.get(name).copied().flatten();
+
+        if read_etag(&path, cached)?.map(|etag| etag.digest) != cached.map(|etag| etag.digest) {
+            return Err(HypergraphError::StoreNamespaceConflict(name.to_owned()));
+        }
+
+        let mut bytes = Vec::new();
+
+        graph.write_snapshot(&mut bytes)?;
+
+        // Never truncate the snapshot in place, so that a failed write
+        // leaves it intact. Another writer can still replace it from now on
+        // until the rename, see the documentation of the store.
+        let etag = write_snapshot_file(&path, &bytes)?;
+
+        self.etags.insert(name.to_owned(), Some(etag));
+
+        Ok(())
+    }
+
+    /// Persists the cached hypergraphs of all the namespaces.
+    pub fn flush_all(&mut self) -> Result<(), HypergraphError> {
+        let names = self.graphs.keys().cloned().collect::<Vec<String>>();
+
+        names.iter().try_for_each(|name| self.flush(name))
+    }
+
+    /// Evicts the hypergraph of a namespace from the cache, discarding the
+    /// changes which haven't been flushed.
+    pub fn evict(&mut self, name: &str) {
+        self.graphs.swap_remove(name);
+        self.etags.swap_remove(name);
+    }
+
+    /// Drops a namespace, both from the cache and from the disk.
+    pub fn drop_namespace(&mut self, name: &str) -> Result<(), HypergraphError> {
+        let path = self.get_path(name)?;
+        let cached = self.graphs.swap_remove(name).is_some();
+
+        self.etags.swap_remove(name);
+
+        match fs::remove_file(path) {
+            Ok(()) => Ok(()),
+            Err(error) if error.kind() == ErrorKind::NotFound => {
+                if cached {
+                    Ok(())
+                } else {
+                    Err(HypergraphError::StoreNamespaceNotFound(name.to_owned()))
+                }
+            }
+            Err(error) => Err(io_error(error)),
+        }
+    }
+
+    // Private method to get the path of the snapshot of a namespace.
+    fn get_path(&self, name: &str) -> Result<PathBuf, HypergraphError> {
+        if !is_valid_namespace(name) {
+            return Err(HypergraphError::StoreInvalidNamespace(name.to_owned()));
+        }
+
+        Ok(self.root.join(format!("{}.{}", name, EXTENSION)))
+    }
+
+    // Private method to get the cached hypergraph of a namespace, loading it
+    // first if needed, and optionally creating it.
+    fn load(
+        &mut self,
+        name: &str,
+        create: bool,
+    ) -> Result<&mut Hypergraph<V, HE>, HypergraphError> {
+        let path = self.get_path(name)?;
+
+        if !self.graphs.contains_key(name) {
+            // Read the snapshot once to get both its content and its etag.
+            let (mut graph, etag) = match read_snapshot_file(&path)? {
+                Some((bytes, etag)) => (Hypergraph::read_snapshot(&bytes[..])?, Some(etag)),
+                None if create => (Hypergraph::new(), None),
+                None => {
+                    return Err(HypergraphError::StoreNamespaceNotFound(name.to_owned()));
+                }
+            };
+
+            // Enforce the constraints of the store, which the snapshot may
+            // not comply with.
+            if let Some(constraints) = self.constraints {
+                graph.constraints = constraints;
+                graph.check_all_constraints()?;
+            }
+
+            self.graphs.insert(name.to_owned(), graph);
+            self.etags.insert(name.to_owned(), etag);
+        }
+
+        self.graphs
+            .get_mut(name)
+            .ok_or_else(|| HypergraphError::StoreNamespaceNotFound(name.to_owned()))
+    }
+}
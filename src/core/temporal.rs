@@ -0,0 +1,125 @@
+use crate::{
+    HyperedgeIndex,
+    HyperedgeKey,
+    HyperedgeTrait,
+    Hypergraph,
+    VertexIndex,
+    VertexTrait,
+    core::{
+        parallel::*,
+        shared::Connection,
+    },
+    errors::HypergraphError,
+};
+
+/// Shared Trait for the hyperedges carrying a timestamp - or any other
+/// ordering key.
+/// Must be implemented to use the temporal queries.
+pub trait TemporalHyperedgeTrait: HyperedgeTrait {
+    /// Type of the timestamp.
+    type Timestamp: Ord;
+
+    /// Returns the timestamp of the hyperedge, if any.
+    /// Hyperedges without timestamp are excluded from the temporal queries.
+    fn timestamp(&self) -> Option<Self::Timestamp>;
+}
+
+// Private helper to check if a hyperedge belongs to an inclusive window.
+fn is_within<HE>(weight: &HE, start: &HE::Timestamp, end: &HE::Timestamp) -> bool
+where
+    HE: TemporalHyperedgeTrait,
+{
+    weight
+        .timestamp()
+        .map_or(false, |timestamp| *start <= timestamp && timestamp <= *end)
+}
+
+#[allow(clippy::type_complexity)]
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: TemporalHyperedgeTrait,
+{
+    /// Gets the hyperedges whose timestamps are within the inclusive window
+    /// from `start` to `end`.
+    pub fn hyperedges_between(
+        &self,
+        start: HE::Timestamp,
+        end: HE::Timestamp,
+    ) -> Result<Vec<HyperedgeIndex>, HypergraphError> {
+        self.hyperedges
+            .iter()
+            .enumerate()
+            .filter(|(_, HyperedgeKey { weight, .. })| is_within(weight, &start, &end))
+            .map(|(internal_index, _)| self.get_hyperedge(internal_index))
+            .collect()
+    }
+
+    // Private method to get the adjacent vertices of a vertex via the
+    // hyperedges within a window.
+    fn get_adjacent_vertices_between(
+        &self,
+        connection: &Connection,
+        start: &HE::Timestamp,
+        end: &HE::Timestamp,
+    ) -> Result<Vec<VertexIndex>, HypergraphError> {
+        let mut results = self
+            .get_connections(connection)?
+            .into_iter()
+            .filter_map(|(hyperedge_index, vertex_index)| {
+                match self.get_hyperedge_weight(hyperedge_index) {
+                    Ok(weight) if is_within(weight, start, end) => vertex_index.map(Ok),
+                    Ok(_) => None,
+                    Err(error) => Some(Err(error)),
+                }
+            })
+            .collect::<Result<Vec<VertexIndex>, HypergraphError>>()?;
+
+        results.par_sort_unstable();
+        results.dedup();
+
+        Ok(results)
+    }
+
+    /// Gets the list of all vertices connected from a given vertex via the
+    /// hyperedges whose timestamps are within the inclusive window from
+    /// `start` to `end`.
+    pub fn get_adjacent_vertices_from_between(
+        &self,
+        from: VertexIndex,
+        start: HE::Timestamp,
+        end: HE::Timestamp,
+    ) -> Result<Vec<VertexIndex>, HypergraphError> {
+        self.get_adjacent_vertices_between(&Connection::In(from), &start, &end)
+    }
+
+    /// Gets the list of all vertices connected to a given vertex via the
+    /// hyperedges whose timestamps are within the inclusive window from
+    /// `start` to `end`.
+    pub fn get_adjacent_vertices_to_between(
+        &self,
+        to: VertexIndex,
+        start: HE::Timestamp,
+        end: HE::Timestamp,
+    ) -> Result<Vec<VertexIndex>, HypergraphError> {
+        self.get_adjacent_vertices_between(&Connection::Out(to), &start, &end)
+    }
+
+    /// Gets the cheapest path between two vertices like
+    /// `get_dijkstra_connections` while only traversing the hyperedges whose
+    /// timestamps are within the inclusive window from `start` to `end`.
+    pub fn get_dijkstra_connections_between(
+        &self,
+        from: VertexIndex,
+        to: VertexIndex,
+        start: HE::Timestamp,
+        end: HE::Timestamp,
+    ) -> Result<Vec<(VertexIndex, Option<HyperedgeIndex>)>, HypergraphError> {
+        self.get_filtered_dijkstra_connections(
+            from,
+            to,
+            |_, weight| is_within(weight, &start, &end),
+            None,
+        )
+    }
+}
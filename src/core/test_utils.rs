@@ -0,0 +1,213 @@
+use proptest::{
+    collection::vec,
+    prelude::*,
+    sample::Index,
+};
+
+use crate::{
+    HyperedgeIndex,
+    HyperedgeTrait,
+    Hypergraph,
+    VertexIndex,
+    VertexTrait,
+    core::types::AIndexSet,
+    errors::HypergraphError,
+};
+
+/// Maximum number of vertices of the generated hyperedges.
+pub const MAX_CARDINALITY: usize = 4;
+
+/// Mutation of a hypergraph, as generated by `mutations`.
+/// The existing vertices and hyperedges are selected via an `Index`
+/// resolved against their current number when the mutation is applied.
+#[derive(Clone, Debug)]
+pub enum Mutation<V, HE> {
+    /// Adds a vertex.
+    AddVertex(V),
+
+    /// Removes a vertex.
+    RemoveVertex(Index),
+
+    /// Updates the weight of a vertex.
+    UpdateVertexWeight(Index, V),
+
+    /// Adds a hyperedge.
+    AddHyperedge(Vec<Index>, HE),
+
+    /// Removes a hyperedge.
+    RemoveHyperedge(Index),
+
+    /// Updates the weight of a hyperedge.
+    UpdateHyperedgeWeight(Index, HE),
+
+    /// Updates the vertices of a hyperedge.
+    UpdateHyperedgeVertices(Index, Vec<Index>),
+}
+
+// Private helper to select an existing vertex.
+fn select_vertex<V, HE>(graph: &Hypergraph<V, HE>, index: &Index) -> Option<VertexIndex>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    match graph.count_vertices() {
+        0 => None,
+        count => graph.get_vertex(index.index(count)).ok(),
+    }
+}
+
+// Private helper to select existing vertices.
+fn select_vertices<V, HE>(graph: &Hypergraph<V, HE>, indexes: &[Index]) -> Option<Vec<VertexIndex>>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    indexes
+        .iter()
+        .map(|index| select_vertex(graph, index))
+        .collect()
+}
+
+// Private helper to select an existing hyperedge.
+fn select_hyperedge<V, HE>(graph: &Hypergraph<V, HE>, index: &Index) -> Option<HyperedgeIndex>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    match graph.count_hyperedges() {
+        0 => None,
+        count => graph.get_hyperedge(index.index(count)).ok(),
+    }
+}
+
+impl<V, HE> Mutation<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Applies the mutation to a hypergraph, via its public methods.
+    /// The mutation is skipped if there is no element to select. The errors
+    /// of the hypergraph, e.g. on a duplicated weight, are returned as is.
+    pub fn apply(&self, graph: &mut Hypergraph<V, HE>) -> Result<(), HypergraphError> {
+        match self {
+            Mutation::AddVertex(weight) => graph.add_vertex(weight.clone()).map(|_| ()),
+            Mutation::RemoveVertex(index) => match select_vertex(graph, index) {
+                Some(vertex_index) => graph.remove_vertex(vertex_index),
+                None => Ok(()),
+            },
+            Mutation::UpdateVertexWeight(index, weight) => match select_vertex(graph, index) {
+                Some(vertex_index) => graph.update_vertex_weight(vertex_index, weight.clone()),
+                None => Ok(()),
+            },
+            Mutation::AddHyperedge(indexes, weight) => match select_vertices(graph, indexes) {
+                Some(vertices) => graph.add_hyperedge(vertices, weight.clone()).map(|_| ()),
+                None => Ok(()),
+            },
+            Mutation::RemoveHyperedge(index) => match select_hyperedge(graph, index) {
+                Some(hyperedge_index) => graph.remove_hyperedge(hyperedge_index),
+                None => Ok(()),
+            },
+            Mutation::UpdateHyperedgeWeight(index, weight) => {
+                match select_hyperedge(graph, index) {
+                    Some(hyperedge_index) => {
+                        graph.update_hyperedge_weight(hyperedge_index, weight.clone())
+                    }
+                    None => Ok(()),
+                }
+            }
+            Mutation::UpdateHyperedgeVertices(index, indexes) => {
+                match (
+                    select_hyperedge(graph, index),
+                    select_vertices(graph, indexes),
+                ) {
+                    (Some(hyperedge_index), Some(vertices)) => {
+                        graph.update_hyperedge_vertices(hyperedge_index, vertices)
+                    }
+                    _ => Ok(()),
+                }
+            }
+        }
+    }
+}
+
+/// Generates hypergraphs of up to `max_vertices` vertices and
+/// `max_hyperedges` hyperedges, with weights drawn from the given
+/// strategies.
+/// The invariants of the crate hold: the weights are deduplicated and the
+/// hyperedges have between one and `MAX_CARDINALITY` vertices.
+pub fn hypergraphs<V, HE, SV, SHE>(
+    vertices_weights: SV,
+    hyperedges_weights: SHE,
+    max_vertices: usize,
+    max_hyperedges: usize,
+) -> impl Strategy<Value = Hypergraph<V, HE>>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+    SV: Strategy<Value = V>,
+    SHE: Strategy<Value = HE>,
+{
+    (
+        vec(vertices_weights, 0..=max_vertices),
+        vec(
+            (vec(any::<Index>(), 1..=MAX_CARDINALITY), hyperedges_weights),
+            0..=max_hyperedges,
+        ),
+    )
+        .prop_map(|(vertices_weights, hyperedges)| {
+            let mut graph = Hypergraph::new();
+
+            for weight in vertices_weights.into_iter().collect::<AIndexSet<V>>() {
+                graph
+                    .add_vertex(weight)
+                    .expect("deduplicated vertex weight");
+            }
+
+            let mut hyperedges_weights = AIndexSet::default();
+
+            for (indexes, weight) in hyperedges {
+                if let Some(vertices) = select_vertices(&graph, &indexes) {
+                    if hyperedges_weights.insert(weight.clone()) {
+                        graph
+                            .add_hyperedge(vertices, weight)
+                            .expect("deduplicated hyperedge weight");
+                    }
+                }
+            }
+
+            graph
+        })
+}
+
+/// Generates sequences of up to `max_len` mutations, with weights drawn
+/// from the given strategies. See `Mutation::apply`.
+pub fn mutations<V, HE, SV, SHE>(
+    vertices_weights: SV,
+    hyperedges_weights: SHE,
+    max_len: usize,
+) -> impl Strategy<Value = Vec<Mutation<V, HE>>>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+    SV: Strategy<Value = V> + Clone + 'static,
+    SHE: Strategy<Value = HE> + Clone + 'static,
+{
+    let mutation = prop_oneof![
+        vertices_weights.clone().prop_map(Mutation::AddVertex),
+        any::<Index>().prop_map(Mutation::RemoveVertex),
+        (any::<Index>(), vertices_weights)
+            .prop_map(|(index, weight)| Mutation::UpdateVertexWeight(index, weight)),
+        (
+            vec(any::<Index>(), 1..=MAX_CARDINALITY),
+            hyperedges_weights.clone()
+        )
+            .prop_map(|(indexes, weight)| Mutation::AddHyperedge(indexes, weight)),
+        any::<Index>().prop_map(Mutation::RemoveHyperedge),
+        (any::<Index>(), hyperedges_weights)
+            .prop_map(|(index, weight)| Mutation::UpdateHyperedgeWeight(index, weight)),
+        (any::<Index>(), vec(any::<Index>(), 1..=MAX_CARDINALITY))
+            .prop_map(|(index, indexes)| Mutation::UpdateHyperedgeVertices(index, indexes)),
+    ];
+
+    vec(mutation, 0..=max_len)
+}
@@ -0,0 +1,194 @@
+use crate::{
+    HyperedgeTrait,
+    Hypergraph,
+    VertexIndex,
+    VertexTrait,
+    errors::HypergraphError,
+};
+
+/// Distribution of the number of vertices of the hyperedges generated by a
+/// `TopologyBuilder`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CardinalityDistribution {
+    /// Every hyperedge has the same number of vertices.
+    Constant(usize),
+    /// Uniform distribution within an inclusive range.
+    Uniform {
+        /// Minimum number of vertices.
+        min: usize,
+        /// Maximum number of vertices.
+        max: usize,
+    },
+    /// Power-law distribution within an inclusive range, the probability of
+    /// a cardinality `k` being proportional to `k^-exponent`.
+    PowerLaw {
+        /// Minimum number of vertices.
+        min: usize,
+        /// Maximum number of vertices.
+        max: usize,
+        /// Exponent of the distribution.
+        exponent: f64,
+    },
+}
+
+/// Builder of synthetic hypergraphs with a configurable topology, e.g. to
+/// write representative benchmarks.
+/// The generation is deterministic for a given seed.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TopologyBuilder {
+    cardinality: CardinalityDistribution,
+    hyperedges: usize,
+    overlap: f64,
+    seed: u64,
+    vertices: usize,
+}
+
+// Private SplitMix64 generator, which is enough for synthetic topologies and
+// avoids depending on the rand feature.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+
+        let mut value = self.0;
+
+        value = (value ^ (value >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        value = (value ^ (value >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+
+        value ^ (value >> 31)
+    }
+
+    // Returns a float in [0, 1).
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    // Returns an integer in [0, bound).
+    fn next_below(&mut self, bound: usize) -> usize {
+        (self.next_u64() % bound as u64) as usize
+    }
+}
+
+impl TopologyBuilder {
+    /// Creates a new builder of a hypergraph with the given numbers of
+    /// vertices and hyperedges.
+    /// Defaults to hyperedges of two vertices, no overlap and a seed of 0.
+    pub fn new(vertices: usize, hyperedges: usize) -> Self {
+        Self {
+            cardinality: CardinalityDistribution::Constant(2),
+            hyperedges,
+            overlap: 0.,
+            seed: 0,
+            vertices,
+        }
+    }
+
+    /// Sets the distribution of the number of vertices of the hyperedges.
+    /// Cardinalities are at least one.
+    pub fn cardinality(mut self, cardinality: CardinalityDistribution) -> Self {
+        self.cardinality = cardinality;
+
+        self
+    }
+
+    /// Sets the overlap factor, clamped between 0 and 1.
+    /// It is the probability for each vertex of a hyperedge to be picked
+    /// among the vertices of the previous hyperedge instead of uniformly
+    /// among all the vertices.
+    pub fn overlap(mut self, overlap: f64) -> Self {
+        self.overlap = overlap.clamp(0., 1.);
+
+        self
+    }
+
+    /// Sets the seed of the generation.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+
+        self
+    }
+
+    /// Builds the hypergraph, the weights being created from the positions
+    /// of the vertices and of the hyperedges in the generation order.
+    /// The weights must be unique as usual.
+    pub fn build<V, HE, FV, FHE>(
+        &self,
+        mut vertex_weight: FV,
+        mut hyperedge_weight: FHE,
+    ) -> Result<Hypergraph<V, HE>, HypergraphError>
+    where
+        V: VertexTrait,
+        HE: HyperedgeTrait,
+        FV: FnMut(usize) -> V,
+        FHE: FnMut(usize) -> HE,
+    {
+        let mut rng = SplitMix64(self.seed);
+        let mut graph = Hypergraph::with_capacity(self.vertices, self.hyperedges);
+
+        let vertices = (0..self.vertices)
+            .map(|position| graph.add_vertex(vertex_weight(position)))
+            .collect::<Result<Vec<VertexIndex>, HypergraphError>>()?;
+
+        // Precompute the cumulative weights of the power-law distribution.
+        let cumulative_weights = match self.cardinality {
+            CardinalityDistribution::PowerLaw { min, max, exponent } => {
+                let (min, max) = (min.min(max).max(1), max.max(min).max(1));
+
+                (min..=max)
+                    .scan(0., |total, cardinality| {
+                        *total += (cardinality as f64).powf(-exponent);
+
+                        Some((cardinality, *total))
+                    })
+                    .collect::<Vec<(usize, f64)>>()
+            }
+            _ => vec![],
+        };
+
+        let mut previous: Vec<VertexIndex> = vec![];
+
+        for position in 0..self.hyperedges {
+            let cardinality = match self.cardinality {
+                CardinalityDistribution::Constant(cardinality) => cardinality,
+                CardinalityDistribution::Uniform { min, max } => {
+                    let (min, max) = (min.min(max), max.max(min));
+
+                    min + rng.next_below(max - min + 1)
+                }
+                CardinalityDistribution::PowerLaw { .. } => {
+                    let total = cumulative_weights.last().map_or(0., |(_, total)| *total);
+                    let target = rng.next_f64() * total;
+                    let index = cumulative_weights
+                        .partition_point(|(_, cumulative)| *cumulative <= target)
+                        .min(cumulative_weights.len() - 1);
+
+                    cumulative_weights[index].0
+                }
+            }
+            .max(1);
+
+            let mut current = Vec::with_capacity(cardinality);
+
+            // Without any vertex, the hyperedge stays empty and its creation
+            // returns an explicit error.
+            if !vertices.is_empty() {
+                for _ in 0..cardinality {
+                    let vertex = if !previous.is_empty() && rng.next_f64() < self.overlap {
+                        previous[rng.next_below(previous.len())]
+                    } else {
+                        vertices[rng.next_below(vertices.len())]
+                    };
+
+                    current.push(vertex);
+                }
+            }
+
+            graph.add_hyperedge(current.clone(), hyperedge_weight(position))?;
+
+            previous = current;
+        }
+
+        Ok(graph)
+    }
+}
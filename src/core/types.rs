@@ -12,3 +12,17 @@ pub(crate) type AIndexSet<T> = IndexSet<T, RandomState>;
 
 /// Type alias for the `AHash` hasher factory.
 pub(crate) type ARandomState = RandomState;
+
+/// Type alias for the internal vertices of a hyperedge.
+#[cfg(not(feature = "smallvec"))]
+pub(crate) type HyperedgeVertices = Vec<usize>;
+
+/// Number of vertices of a hyperedge stored inline, i.e. without any heap
+/// allocation, with the `smallvec` feature.
+#[cfg(feature = "smallvec")]
+pub(crate) const INLINE_HYPEREDGE_VERTICES: usize = 4;
+
+/// Type alias for the internal vertices of a hyperedge, stored inline up to
+/// `INLINE_HYPEREDGE_VERTICES` vertices.
+#[cfg(feature = "smallvec")]
+pub(crate) type HyperedgeVertices = smallvec::SmallVec<[usize; INLINE_HYPEREDGE_VERTICES]>;
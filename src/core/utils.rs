@@ -1,5 +1,12 @@
+use std::{
+    cmp::Reverse,
+    collections::BinaryHeap,
+};
+
 use itertools::Itertools;
 
+use crate::errors::HypergraphError;
+
 pub(crate) fn are_slices_equal(a: &[usize], b: &[usize]) -> bool {
     // Early guard if lengths are different.
     if a.len() != b.len() {
@@ -9,6 +16,34 @@ pub(crate) fn are_slices_equal(a: &[usize], b: &[usize]) -> bool {
     a.iter().zip_eq(b).fold(true, |acc, (a, b)| acc && a == b)
 }
 
+// Gets the positions of the k greatest keys in O(n log k) via a bounded
+// min-heap, sorted by descending key, the first position winning the ties.
+pub(crate) fn get_top_k<K, I>(keys: I, k: usize) -> Result<Vec<usize>, HypergraphError>
+where
+    K: Ord,
+    I: Iterator<Item = Result<K, HypergraphError>>,
+{
+    if k == 0 {
+        return Ok(vec![]);
+    }
+
+    let mut heap = BinaryHeap::new();
+
+    for (position, key) in keys.enumerate() {
+        heap.push(Reverse((key?, Reverse(position))));
+
+        if heap.len() > k {
+            heap.pop();
+        }
+    }
+
+    Ok(heap
+        .into_sorted_vec()
+        .into_iter()
+        .map(|Reverse((_, Reverse(position)))| position)
+        .collect())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -27,4 +62,15 @@ mod tests {
         assert!(!are_slices_equal(&[1, 2, 3], &[1, 2, 4]));
         assert!(!are_slices_equal(&[1, 2, 3], &[1, 2, 3, 4]));
     }
+    #[test]
+    fn check_top_k() {
+        let keys = [3, 1, 4, 1, 5, 9, 2, 6, 5];
+
+        assert_eq!(get_top_k(keys.iter().map(Ok), 4), Ok(vec![5, 7, 4, 8]));
+        assert_eq!(get_top_k(keys.iter().map(Ok), 0), Ok(vec![]));
+        assert_eq!(
+            get_top_k(keys.iter().map(Ok), 20).map(|top| top.len()),
+            Ok(9)
+        );
+    }
 }
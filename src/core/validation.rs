@@ -0,0 +1,459 @@
+use std::{
+    cmp::Reverse,
+    fmt::{
+        Display,
+        Formatter,
+        Result as FmtResult,
+    },
+};
+
+use crate::{
+    HyperedgeIndex,
+    HyperedgeKey,
+    HyperedgeTrait,
+    Hypergraph,
+    VertexIndex,
+    VertexTrait,
+    core::cardinalities::CardinalityHistogram,
+    errors::HypergraphError,
+};
+
+/// Severity of a validation issue.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum Severity {
+    /// Notable but expected state, e.g. an isolated vertex.
+    Info,
+    /// Inconsistency which doesn't affect the vertices and the hyperedges.
+    Warning,
+    /// Corruption of the vertices or of the hyperedges.
+    Error,
+}
+
+/// Kind of a validation issue.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum ValidationCode {
+    /// Internal index without a stable index, or the other way around.
+    UnmappedIndex,
+    /// Stable index not below its generation counter.
+    IndexBeyondCounter,
+    /// Hyperedge without any vertex.
+    EmptyHyperedge,
+    /// Vertex listing a hyperedge which doesn't contain it.
+    DanglingVertexReference,
+    /// Hyperedge containing a vertex which is missing or doesn't list it.
+    DanglingHyperedgeReference,
+    /// Index of the weights of the hyperedges not matching them.
+    WeightsIndexMismatch,
+    /// Histogram of the cardinalities not matching the hyperedges.
+    CardinalitiesMismatch,
+    /// Attributes, properties, roles, versions, tags or layer of a missing
+    /// entity.
+    OrphanedReference,
+    /// Roles not aligned with the vertices of their hyperedge.
+    RolesMismatch,
+    /// Vertex not belonging to any hyperedge.
+    IsolatedVertex,
+}
+
+/// Issue found by `Hypergraph::validate`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ValidationIssue {
+    /// Severity of the issue.
+    pub severity: Severity,
+    /// Kind of the issue.
+    pub code: ValidationCode,
+    /// Human-readable description of the issue.
+    pub message: String,
+    /// Vertices involved in the issue, if any.
+    pub vertices: Vec<VertexIndex>,
+    /// Hyperedges involved in the issue, if any.
+    pub hyperedges: Vec<HyperedgeIndex>,
+}
+
+impl Display for ValidationIssue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{:?} {:?}: {}", self.severity, self.code, self.message)
+    }
+}
+
+/// Report of the consistency check of a hypergraph, as returned by
+/// `Hypergraph::validate`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ValidationReport {
+    /// Issues found, the most severe first.
+    pub issues: Vec<ValidationIssue>,
+}
+
+impl ValidationReport {
+    /// Checks whether no error has been found.
+    pub fn is_valid(&self) -> bool {
+        self.count(Severity::Error) == 0
+    }
+
+    /// Counts the issues of a severity.
+    pub fn count(&self, severity: Severity) -> usize {
+        self.issues
+            .iter()
+            .filter(|issue| issue.severity == severity)
+            .count()
+    }
+
+    /// Converts the report into an error holding its first issue if it
+    /// isn't valid, e.g. to fail a build.
+    pub fn into_result(self) -> Result<Self, HypergraphError> {
+        match self
+            .issues
+            .iter()
+            .find(|issue| issue.severity == Severity::Error)
+        {
+            Some(issue) => Err(HypergraphError::ValidationFailed(Box::new(issue.clone()))),
+            None => Ok(self),
+        }
+    }
+
+    // Private method to record an issue.
+    fn push(
+        &mut self,
+        severity: Severity,
+        code: ValidationCode,
+        message: String,
+        vertices: Vec<VertexIndex>,
+        hyperedges: Vec<HyperedgeIndex>,
+    ) {
+        self.issues.push(ValidationIssue {
+            severity,
+            code,
+            message,
+            vertices,
+            hyperedges,
+        });
+    }
+}
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Checks the consistency of the hypergraph, i.e. of its internal
+    /// structures, and reports the issues found along with their severities
+    /// and the vertices and the hyperedges involved, e.g. to fail a build on
+    /// corruption via `ValidationReport::into_result`.
+    pub fn validate(&self) -> ValidationReport {
+        let mut report = ValidationReport::default();
+
+        // Check the mappings between the internal and the stable indexes.
+        for internal_index in 0..self.vertices.len() {
+            match self.vertices_mapping.left.get(&internal_index) {
+                Some(vertex_index) if vertex_index.0 >= self.vertices_count => report.push(
+                    Severity::Error,
+                    ValidationCode::IndexBeyondCounter,
+                    format!("vertex {vertex_index} is beyond the counter"),
+                    vec![*vertex_index],
+                    vec![],
+                ),
+                Some(_) => {}
+                None => report.push(
+                    Severity::Error,
+                    ValidationCode::UnmappedIndex,
+                    format!("internal vertex {internal_index} has no stable index"),
+                    vec![],
+                    vec![],
+                ),
+            }
+        }
+
+        for internal_index in 0..self.hyperedges.len() {
+            match self.hyperedges_mapping.left.get(&internal_index) {
+                Some(hyperedge_index) if hyperedge_index.0 >= self.hyperedges_count => report.push(
+                    Severity::Error,
+                    ValidationCode::IndexBeyondCounter,
+                    format!("hyperedge {hyperedge_index} is beyond the counter"),
+                    vec![],
+                    vec![*hyperedge_index],
+                ),
+                Some(_) => {}
+                None => report.push(
+                    Severity::Error,
+                    ValidationCode::UnmappedIndex,
+                    format!("internal hyperedge {internal_index} has no stable index"),
+                    vec![],
+                    vec![],
+                ),
+            }
+        }
+
+        if self.vertices_mapping.left.len() != self.vertices.len()
+            || self.vertices_mapping.right.len() != self.vertices.len()
+        {
+            report.push(
+                Severity::Error,
+                ValidationCode::UnmappedIndex,
+                "the mapping of the vertices doesn't match them".to_string(),
+                vec![],
+                vec![],
+            );
+        }
+
+        if self.hyperedges_mapping.left.len() != self.hyperedges.len()
+            || self.hyperedges_mapping.right.len() != self.hyperedges.len()
+        {
+            report.push(
+                Severity::Error,
+                ValidationCode::UnmappedIndex,
+                "the mapping of the hyperedges doesn't match them".to_string(),
+                vec![],
+                vec![],
+            );
+        }
+
+        let vertex = |internal_index: usize| self.vertices_mapping.left.get(&internal_index);
+        let hyperedge = |internal_index: usize| self.hyperedges_mapping.left.get(&internal_index);
+
+        // Check the references between the vertices and the hyperedges.
+        let mut cardinalities = CardinalityHistogram::default();
+
+        for (internal_index, HyperedgeKey { vertices, weight }) in
+            self.hyperedges.iter().enumerate()
+        {
+            let hyperedges = hyperedge(internal_index).copied().into_iter().collect();
+
+            cardinalities.insert(vertices.len());
+
+            if vertices.is_empty() {
+                report.push(
+                    Severity::Error,
+                    ValidationCode::EmptyHyperedge,
+                    format!("hyperedge {weight} has no vertex"),
+                    vec![],
+                    hyperedges,
+                );
+
+                continue;
+            }
+
+            for internal_vertex in vertices.iter() {
+                let listed = self
+                    .vertices
+                    .get_index(*internal_vertex)
+                    .map_or(false, |(_, vertex_hyperedges)| {
+                        vertex_hyperedges.contains(&internal_index)
+                    });
+
+                if !listed {
+                    report.push(
+                        Severity::Error,
+                        ValidationCode::DanglingHyperedgeReference,
+                        format!(
+                            "hyperedge {weight} contains the internal vertex {internal_vertex} \
+                             which doesn't list it"
+                        ),
+                        vertex(*internal_vertex).copied().into_iter().collect(),
+                        hyperedges.clone(),
+                    );
+                }
+            }
+
+            if self.hyperedges_weights.get(weight) != Some(&internal_index) {
+                report.push(
+                    Severity::Error,
+                    ValidationCode::WeightsIndexMismatch,
+                    format!("hyperedge {weight} is not indexed by its weight"),
+                    vec![],
+                    hyperedges,
+                );
+            }
+        }
+
+        if self.hyperedges_weights.len() != self.hyperedges.len() {
+            report.push(
+                Severity::Error,
+                ValidationCode::WeightsIndexMismatch,
+                "the index of the weights doesn't match the hyperedges".to_string(),
+                vec![],
+                vec![],
+            );
+        }
+
+        if cardinalities != self.hyperedges_cardinalities {
+            report.push(
+                Severity::Warning,
+                ValidationCode::CardinalitiesMismatch,
+                "the histogram of the cardinalities doesn't match the hyperedges".to_string(),
+                vec![],
+                vec![],
+            );
+        }
+
+        for (internal_index, (key, vertex_hyperedges)) in self.vertices.iter().enumerate() {
+            let vertices = vertex(internal_index)
+                .copied()
+                .into_iter()
+                .collect::<Vec<_>>();
+
+            if vertex_hyperedges.is_empty() {
+                report.push(
+                    Severity::Info,
+                    ValidationCode::IsolatedVertex,
+                    format!("vertex {} doesn't belong to any hyperedge", key.weight),
+                    vertices.clone(),
+                    vec![],
+                );
+            }
+
+            for internal_hyperedge in vertex_hyperedges.iter() {
+                let contained = self
+                    .hyperedges
+                    .get(*internal_hyperedge)
+                    .map_or(false, |HyperedgeKey { vertices, .. }| {
+                        vertices.contains(&internal_index)
+                    });
+
+                if !contained {
+                    report.push(
+                        Severity::Error,
+                        ValidationCode::DanglingVertexReference,
+                        format!(
+                            "vertex {} lists the internal hyperedge {internal_hyperedge} which \
+                             doesn't contain it",
+                            key.weight
+                        ),
+                        vertices.clone(),
+                        hyperedge(*internal_hyperedge)
+                            .copied()
+                            .into_iter()
+                            .collect(),
+                    );
+                }
+            }
+        }
+
+        // Check the entities referenced by stable index.
+        let mut orphaned_vertices = self
+            .vertices_attributes
+            .keys()
+            .chain(self.vertices_versions.keys())
+            .filter(|vertex_index| !self.vertices_mapping.right.contains_key(vertex_index))
+            .copied()
+            .collect::<Vec<VertexIndex>>();
+
+        orphaned_vertices.sort_unstable();
+        orphaned_vertices.dedup();
+
+        if !orphaned_vertices.is_empty() {
+            report.push(
+                Severity::Warning,
+                ValidationCode::OrphanedReference,
+                "some attributes or versions belong to missing vertices".to_string(),
+                orphaned_vertices,
+                vec![],
+            );
+        }
+
+        let mut orphaned_hyperedges = self
+            .hyperedges_properties
+            .keys()
+            .chain(self.hyperedges_roles.keys())
+            .chain(self.hyperedges_versions.keys())
+            .chain(self.hyperedges_layers.keys())
+            .chain(self.hyperedges_tags.values().flatten())
+            .chain(
+                self.layers
+                    .values()
+                    .flat_map(|layer| layer.hyperedges.iter()),
+            )
+            .filter(|hyperedge_index| !self.hyperedges_mapping.right.contains_key(hyperedge_index))
+            .copied()
+            .collect::<Vec<HyperedgeIndex>>();
+
+        orphaned_hyperedges.sort_unstable();
+        orphaned_hyperedges.dedup();
+
+        if !orphaned_hyperedges.is_empty() {
+            report.push(
+                Severity::Warning,
+                ValidationCode::OrphanedReference,
+                "some properties, roles, versions, tags or layers belong to missing hyperedges"
+                    .to_string(),
+                vec![],
+                orphaned_hyperedges,
+            );
+        }
+
+        for (hyperedge_index, roles) in self.hyperedges_roles.iter() {
+            let cardinality = self
+                .hyperedges_mapping
+                .right
+                .get(hyperedge_index)
+                .and_then(|internal_index| self.hyperedges.get(*internal_index))
+                .map(|HyperedgeKey { vertices, .. }| vertices.len());
+
+            if cardinality.map_or(false, |cardinality| cardinality != roles.len()) {
+                report.push(
+                    Severity::Warning,
+                    ValidationCode::RolesMismatch,
+                    format!("the roles of hyperedge {hyperedge_index} don't match its vertices"),
+                    vec![],
+                    vec![*hyperedge_index],
+                );
+            }
+        }
+
+        // The most severe issues come first.
+        report.issues.sort_by_key(|issue| Reverse(issue.severity));
+
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_corruption() {
+        let mut graph = Hypergraph::<usize, usize>::new();
+
+        let a = graph.add_vertex(1).unwrap();
+        let b = graph.add_vertex(2).unwrap();
+
+        graph.add_hyperedge(vec![a, b], 10).unwrap();
+
+        assert!(graph.validate().issues.is_empty());
+
+        // Drop the hyperedge from the second vertex only.
+        graph.vertices.get_index_mut(1).unwrap().1.clear();
+
+        let report = graph.validate();
+
+        assert!(!report.is_valid());
+        assert_eq!(
+            report.issues[0].code,
+            ValidationCode::DanglingHyperedgeReference
+        );
+        assert_eq!(report.issues[0].vertices, vec![b]);
+        assert_eq!(report.issues[0].hyperedges, vec![HyperedgeIndex(0)]);
+        assert_eq!(
+            report.issues.last().unwrap().code,
+            ValidationCode::IsolatedVertex
+        );
+        assert_eq!(
+            report.clone().into_result(),
+            Err(HypergraphError::ValidationFailed(Box::new(
+                report.issues[0].clone()
+            )))
+        );
+    }
+}
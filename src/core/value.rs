@@ -0,0 +1,60 @@
+use std::fmt::{
+    Display,
+    Formatter,
+    Result,
+};
+
+/// Value of an attribute or a property.
+/// Unlike the weights, values don't need to be unique nor hashable.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    /// A boolean value.
+    Boolean(bool),
+    /// A floating point value.
+    Float(f64),
+    /// An integer value.
+    Integer(i64),
+    /// A string value.
+    String(String),
+}
+
+impl Display for Value {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result {
+        match self {
+            Value::Boolean(value) => write!(formatter, "{value}"),
+            Value::Float(value) => write!(formatter, "{value}"),
+            Value::Integer(value) => write!(formatter, "{value}"),
+            Value::String(value) => write!(formatter, "{value}"),
+        }
+    }
+}
+
+impl From<bool> for Value {
+    fn from(value: bool) -> Self {
+        Value::Boolean(value)
+    }
+}
+
+impl From<f64> for Value {
+    fn from(value: f64) -> Self {
+        Value::Float(value)
+    }
+}
+
+impl From<i64> for Value {
+    fn from(value: i64) -> Self {
+        Value::Integer(value)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(value: &str) -> Self {
+        Value::String(value.to_owned())
+    }
+}
+
+impl From<String> for Value {
+    fn from(value: String) -> Self {
+        Value::String(value)
+    }
+}
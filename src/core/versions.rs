@@ -0,0 +1,140 @@
+use crate::{
+    HyperedgeIndex,
+    HyperedgeTrait,
+    Hypergraph,
+    VertexIndex,
+    VertexTrait,
+    errors::HypergraphError,
+};
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Gets the version of a vertex for the optimistic concurrency.
+    /// The versions are issued by a counter shared by the vertices and the
+    /// hyperedges, at their creation and at each update of their weight -
+    /// or of their vertices for the hyperedges. They keep increasing at
+    /// each stable index, even across removals and recycled or remapped
+    /// indexes, so that a stale version never matches a new entity.
+    /// The versions are preserved by the snapshots but are not part of the
+    /// equality.
+    pub fn get_vertex_version(&self, vertex_index: VertexIndex) -> Result<usize, HypergraphError> {
+        // Check that the vertex exists.
+        self.get_internal_vertex(vertex_index)?;
+
+        Ok(self
+            .vertices_versions
+            .get(&vertex_index)
+            .copied()
+            .unwrap_or_default())
+    }
+
+    /// Gets the version of a hyperedge for the optimistic concurrency. See
+    /// `get_vertex_version`.
+    pub fn get_hyperedge_version(
+        &self,
+        hyperedge_index: HyperedgeIndex,
+    ) -> Result<usize, HypergraphError> {
+        // Check that the hyperedge exists.
+        self.get_internal_hyperedge(hyperedge_index)?;
+
+        Ok(self
+            .hyperedges_versions
+            .get(&hyperedge_index)
+            .copied()
+            .unwrap_or_default())
+    }
+
+    /// Updates the weight of a vertex only if its version is the expected
+    /// one, e.g. the one read before computing the new weight, so that
+    /// concurrent writers don't silently overwrite each other.
+    /// Returns the new version of the vertex.
+    pub fn update_vertex_weight_if_version(
+        &mut self,
+        vertex_index: VertexIndex,
+        weight: V,
+        expected: usize,
+    ) -> Result<usize, HypergraphError> {
+        self.check_vertex_version(vertex_index, expected)?;
+        self.update_vertex_weight(vertex_index, weight)?;
+
+        self.get_vertex_version(vertex_index)
+    }
+
+    /// Updates the weight of a hyperedge only if its version is the expected
+    /// one. See `update_vertex_weight_if_version`.
+    /// Returns the new version of the hyperedge.
+    pub fn update_hyperedge_weight_if_version(
+        &mut self,
+        hyperedge_index: HyperedgeIndex,
+        weight: HE,
+        expected: usize,
+    ) -> Result<usize, HypergraphError> {
+        self.check_hyperedge_version(hyperedge_index, expected)?;
+        self.update_hyperedge_weight(hyperedge_index, weight)?;
+
+        self.get_hyperedge_version(hyperedge_index)
+    }
+
+    /// Updates the vertices of a hyperedge only if its version is the
+    /// expected one. See `update_vertex_weight_if_version`.
+    /// Returns the new version of the hyperedge.
+    pub fn update_hyperedge_vertices_if_version(
+        &mut self,
+        hyperedge_index: HyperedgeIndex,
+        vertices: Vec<VertexIndex>,
+        expected: usize,
+    ) -> Result<usize, HypergraphError> {
+        self.check_hyperedge_version(hyperedge_index, expected)?;
+        self.update_hyperedge_vertices(hyperedge_index, vertices)?;
+
+        self.get_hyperedge_version(hyperedge_index)
+    }
+
+    // Private method to issue a new version.
+    pub(crate) fn next_version(&mut self) -> usize {
+        self.versions_count += 1;
+
+        self.versions_count
+    }
+
+    // Private method to check the version of a vertex.
+    fn check_vertex_version(
+        &self,
+        vertex_index: VertexIndex,
+        expected: usize,
+    ) -> Result<(), HypergraphError> {
+        let actual = self.get_vertex_version(vertex_index)?;
+
+        if actual != expected {
+            return Err(HypergraphError::VertexVersionConflict {
+                index: vertex_index,
+                expected,
+                actual,
+            });
+        }
+
+        Ok(())
+    }
+
+    // Private method to check the version of a hyperedge.
+    fn check_hyperedge_version(
+        &self,
+        hyperedge_index: HyperedgeIndex,
+        expected: usize,
+    ) -> Result<(), HypergraphError> {
+        let actual = self.get_hyperedge_version(hyperedge_index)?;
+
+        if actual != expected {
+            return Err(HypergraphError::HyperedgeVersionConflict {
+                index: hyperedge_index,
+                expected,
+                actual,
+            });
+        }
+
+        Ok(())
+    }
+}
@@ -2,6 +2,7 @@ use crate::{
     HyperedgeTrait,
     Hypergraph,
     VertexIndex,
+    VertexKey,
     VertexTrait,
     core::types::{
         AIndexSet,
@@ -17,26 +18,92 @@ where
 {
     /// Adds a vertex with a custom weight to the hypergraph.
     /// Returns the index of the vertex.
-    pub fn add_vertex(&mut self, weight: V) -> Result<VertexIndex, HypergraphError<V, HE>> {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            err,
+            fields(
+                vertices_count = self.vertices.len(),
+                hyperedges_count = self.hyperedges.len(),
+            )
+        )
+    )]
+    pub fn add_vertex(&mut self, weight: V) -> Result<VertexIndex, HypergraphError> {
+        let key = VertexKey::new(weight, None);
+
         // Return an error if the weight is already assigned to another vertex.
-        if self.vertices.contains_key(&weight) {
-            return Err(HypergraphError::VertexWeightAlreadyAssigned(weight));
+        if let Some(internal_index) = self.vertices.get_index_of(&key) {
+            return Err(HypergraphError::VertexWeightAlreadyAssigned(
+                self.get_vertex(internal_index)?,
+            ));
         }
 
-        self.vertices
-            .entry(weight)
-            .or_insert(AIndexSet::with_capacity_and_hasher(
-                0,
-                ARandomState::default(),
-            ));
+        // Return an error if the maximum number of vertices is reached.
+        self.check_vertices_count()?;
+
+        // Since the weight is not in the map, it is inserted at the end.
+        let (internal_index, _) = self.vertices.insert_full(
+            key,
+            AIndexSet::with_capacity_and_hasher(0, ARandomState::default()),
+        );
 
-        let internal_index = self
-            .vertices
-            .get_index_of(&weight)
-            // This safe-check should always pass since the weight has been
-            // inserted upfront.
-            .ok_or(HypergraphError::VertexWeightNotFound(weight))?;
+        let vertex_index = self.add_vertex_index(internal_index);
 
-        Ok(self.add_vertex_index(internal_index))
+        // Index the weight for the full-text search.
+        self.index_vertex_weight(vertex_index, internal_index);
+
+        Ok(vertex_index)
+    }
+
+    /// Adds a vertex with a custom weight to the hypergraph, skipping the
+    /// uniqueness check of the weight.
+    /// The vertex is keyed internally by an auto-generated id, hence its
+    /// weight can be shared by other vertices. Please note that `add_vertex`
+    /// only checks the uniqueness against the vertices it has added itself.
+    /// Returns the index of the vertex.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            err,
+            fields(
+                vertices_count = self.vertices.len(),
+                hyperedges_count = self.hyperedges.len(),
+            )
+        )
+    )]
+    pub fn add_vertex_allow_duplicate(
+        &mut self,
+        weight: V,
+    ) -> Result<VertexIndex, HypergraphError> {
+        // Return an error if the maximum number of vertices is reached.
+        self.check_vertices_count()?;
+
+        // The upcoming stable index is unique and can be used as id.
+        let (internal_index, _) = self.vertices.insert_full(
+            VertexKey::new(weight, Some(self.next_vertex_index().0)),
+            AIndexSet::with_capacity_and_hasher(0, ARandomState::default()),
+        );
+
+        let vertex_index = self.add_vertex_index(internal_index);
+
+        // Index the weight for the full-text search.
+        self.index_vertex_weight(vertex_index, internal_index);
+
+        Ok(vertex_index)
+    }
+
+    // Private method to index the weight of a vertex, owned by the map of
+    // the vertices, for the full-text search.
+    pub(crate) fn index_vertex_weight(&mut self, vertex_index: VertexIndex, internal_index: usize) {
+        if let (Some(search_index), Some((key, _))) = (
+            self.vertices_search_index.as_mut(),
+            self.vertices.get_index(internal_index),
+        ) {
+            search_index.insert(vertex_index, &key.weight);
+        }
     }
 }
@@ -16,7 +16,7 @@ where
         if let Some(vertex_index) = self.vertices_mapping.left.get(&internal_index) {
             *vertex_index
         } else {
-            let vertex_index = VertexIndex(self.vertices_count);
+            let vertex_index = self.next_vertex_index();
 
             if self
                 .vertices_mapping
@@ -24,15 +24,34 @@ where
                 .insert(internal_index, vertex_index)
                 .is_none()
             {
-                // Update the counter only for the first insertion.
-                self.vertices_count += 1;
+                // Consume the recycled index or update the counter only for
+                // the first insertion.
+                if self.vertices_recycled.last() == Some(&vertex_index) {
+                    self.vertices_recycled.pop();
+                } else {
+                    self.vertices_count += 1;
+                }
             }
 
             self.vertices_mapping
                 .right
                 .insert(vertex_index, internal_index);
 
+            // Issue a new version, e.g. in case the index is reused.
+            let version = self.next_version();
+
+            self.vertices_versions.insert(vertex_index, version);
+
             vertex_index
         }
     }
+
+    // Private method to get the upcoming stable index of a vertex, i.e. the
+    // lowest recycled one if any or the next one of the counter.
+    pub(crate) fn next_vertex_index(&self) -> VertexIndex {
+        self.vertices_recycled
+            .last()
+            .copied()
+            .unwrap_or(VertexIndex(self.vertices_count))
+    }
 }
@@ -0,0 +1,154 @@
+use std::hash::Hash;
+
+use itertools::Itertools;
+
+use crate::{
+    HyperedgeTrait,
+    Hypergraph,
+    VertexIndex,
+    VertexTrait,
+    core::types::{
+        AIndexMap,
+        AIndexSet,
+    },
+    errors::HypergraphError,
+};
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Contracts the vertices matching a predicate into representative
+    /// targets across all the hyperedges, e.g. to deduplicate entities.
+    /// The predicate is called with the index and the weight of each vertex
+    /// and returns the key of its group, if any, e.g. a normalized name. The
+    /// target selector picks the representative of each group of at least
+    /// two vertices, sorted by index, among them.
+    /// Like `contract_hyperedge_vertices`, the contracted vertices are
+    /// replaced by their target in every hyperedge, the consecutive
+    /// duplicates being merged. The contracted vertices are then removed.
+    /// Returns the targets along with their contracted vertices, sorted by
+    /// index.
+    #[allow(clippy::type_complexity)]
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            err,
+            fields(
+                vertices_count = self.vertices.len(),
+                hyperedges_count = self.hyperedges.len(),
+            )
+        )
+    )]
+    pub fn contract_vertices_where<K, P, S>(
+        &mut self,
+        mut predicate: P,
+        mut target_selector: S,
+    ) -> Result<Vec<(VertexIndex, Vec<VertexIndex>)>, HypergraphError>
+    where
+        K: Eq + Hash,
+        P: FnMut(VertexIndex, &V) -> Option<K>,
+        S: FnMut(&[VertexIndex]) -> VertexIndex,
+    {
+        // Group the matching vertices by key.
+        let mut groups = AIndexMap::<K, Vec<VertexIndex>>::default();
+
+        for (internal_index, (key, _)) in self.vertices.iter().enumerate() {
+            let vertex_index = self.get_vertex(internal_index)?;
+
+            if let Some(group) = predicate(vertex_index, &key.weight) {
+                groups.entry(group).or_default().push(vertex_index);
+            }
+        }
+
+        // Select the targets and check them upfront, before any mutation.
+        let mut contractions = vec![];
+        let mut remapping = AIndexMap::<VertexIndex, VertexIndex>::default();
+        let mut all_hyperedges = AIndexSet::<usize>::default();
+
+        for (_, mut vertices) in groups {
+            if vertices.len() < 2 {
+                continue;
+            }
+
+            vertices.sort_unstable();
+
+            let target = target_selector(&vertices);
+
+            if vertices.binary_search(&target).is_err() {
+                return Err(HypergraphError::VerticesInvalidContraction { target, vertices });
+            }
+
+            // Check that the target doesn't exceed the maximum degree once
+            // part of all the hyperedges of its group.
+            let mut group_hyperedges = AIndexSet::<usize>::default();
+
+            for vertex_index in vertices.iter() {
+                let internal_index = self.get_internal_vertex(*vertex_index)?;
+                let (_, hyperedges) = self
+                    .vertices
+                    .get_index(internal_index)
+                    .ok_or(HypergraphError::InternalVertexIndexNotFound(internal_index))?;
+
+                group_hyperedges.extend(hyperedges.iter().copied());
+            }
+
+            self.check_vertex_degree(self.get_internal_vertex(target)?, group_hyperedges.len())?;
+
+            all_hyperedges.extend(group_hyperedges);
+
+            vertices.retain(|vertex_index| *vertex_index != target);
+
+            for vertex_index in vertices.iter() {
+                remapping.insert(*vertex_index, target);
+            }
+
+            contractions.push((target, vertices));
+        }
+
+        // Early exit if there's nothing to contract.
+        if contractions.is_empty() {
+            return Ok(contractions);
+        }
+
+        // Get the stable indexes of the hyperedges upfront since their
+        // internal indexes are stable during the updates.
+        let all_hyperedges = all_hyperedges
+            .into_iter()
+            .sorted()
+            .map(|internal_index| self.get_hyperedge(internal_index))
+            .collect::<Result<Vec<_>, HypergraphError>>()?;
+
+        for hyperedge_index in all_hyperedges {
+            let hyperedge_vertices = self.get_hyperedge_vertices(hyperedge_index)?;
+
+            // Remap each vertex to itself or to its target and dedupe the
+            // resulting vector.
+            let contraction = hyperedge_vertices
+                .iter()
+                .map(|vertex_index| *remapping.get(vertex_index).unwrap_or(vertex_index))
+                .dedup()
+                .collect_vec();
+
+            // Only update the hyperedge if necessary.
+            if contraction != hyperedge_vertices {
+                self.update_hyperedge_vertices(hyperedge_index, contraction)?;
+            }
+        }
+
+        // Remove the contracted vertices, which are now isolated.
+        let contracted = remapping
+            .keys()
+            .map(|vertex_index| self.get_internal_vertex(*vertex_index))
+            .collect::<Result<AIndexSet<usize>, HypergraphError>>()?;
+
+        self.retain_vertices(|internal_index, _, _| !contracted.contains(&internal_index))?;
+
+        contractions.sort_unstable();
+
+        Ok(contractions)
+    }
+}
@@ -1,11 +1,12 @@
-use rayon::prelude::*;
-
 use crate::{
     HyperedgeTrait,
     Hypergraph,
     VertexIndex,
     VertexTrait,
-    core::shared::Connection,
+    core::{
+        parallel::*,
+        shared::Connection,
+    },
     errors::HypergraphError,
 };
 
@@ -18,7 +19,7 @@ where
     pub fn get_adjacent_vertices_from(
         &self,
         from: VertexIndex,
-    ) -> Result<Vec<VertexIndex>, HypergraphError<V, HE>> {
+    ) -> Result<Vec<VertexIndex>, HypergraphError> {
         let mut results = self
             .get_connections(&Connection::In(from))?
             .into_par_iter()
@@ -0,0 +1,103 @@
+use std::collections::HashMap;
+
+use crate::{
+    HyperedgeKey,
+    HyperedgeTrait,
+    Hypergraph,
+    VertexIndex,
+    VertexTrait,
+    core::parallel::*,
+    errors::HypergraphError,
+};
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Gets the lists of all vertices connected from each of the given
+    /// vertices, in the same order, in a single pass.
+    /// Each hyperedge shared by some of the vertices is only traversed once
+    /// and the hyperedges are traversed in parallel. Each list matches the
+    /// output of `get_adjacent_vertices_from`.
+    pub fn get_adjacent_vertices_from_many(
+        &self,
+        from: &[VertexIndex],
+    ) -> Result<Vec<Vec<VertexIndex>>, HypergraphError> {
+        let internal_vertices = self.get_internal_vertices(from.to_vec())?;
+
+        // Map each distinct internal vertex to its slot in the results.
+        let mut slots = HashMap::with_capacity(internal_vertices.len());
+
+        for internal_index in internal_vertices.iter() {
+            let next_slot = slots.len();
+
+            slots.entry(*internal_index).or_insert(next_slot);
+        }
+
+        // Gather the hyperedges of all the vertices, deduplicated.
+        let mut hyperedges = vec![];
+
+        for internal_index in slots.keys() {
+            let (_, index_set) = self.vertices.get_index(*internal_index).ok_or(
+                HypergraphError::InternalVertexIndexNotFound(*internal_index),
+            )?;
+
+            hyperedges.extend(index_set.iter().copied());
+        }
+
+        hyperedges.par_sort_unstable();
+        hyperedges.dedup();
+
+        let count = slots.len();
+
+        let internal_results = hyperedges
+            .par_iter()
+            .map(|hyperedge| {
+                self.hyperedges
+                    .get(*hyperedge)
+                    .ok_or(HypergraphError::InternalHyperedgeIndexNotFound(*hyperedge))
+            })
+            .try_fold(
+                || vec![vec![]; count],
+                |mut results, hyperedge_key| {
+                    let HyperedgeKey { vertices, .. } = hyperedge_key?;
+
+                    for pair in vertices.windows(2) {
+                        if let Some(slot) = slots.get(&pair[0]) {
+                            results[*slot].push(pair[1]);
+                        }
+                    }
+
+                    Ok::<_, HypergraphError>(results)
+                },
+            )
+            .try_reduce(
+                || vec![vec![]; count],
+                |mut left, right| {
+                    for (results, other) in left.iter_mut().zip(right) {
+                        results.extend(other);
+                    }
+
+                    Ok(left)
+                },
+            )?;
+
+        let results = internal_results
+            .into_par_iter()
+            .map(|internal_results| {
+                let mut results = self.get_vertices(&internal_results)?;
+
+                results.sort_unstable();
+                results.dedup();
+
+                Ok(results)
+            })
+            .collect::<Result<Vec<Vec<VertexIndex>>, HypergraphError>>()?;
+
+        Ok(internal_vertices
+            .iter()
+            .map(|internal_index| results[slots[internal_index]].clone())
+            .collect())
+    }
+}
@@ -0,0 +1,80 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    HyperedgeIndex,
+    HyperedgeKey,
+    HyperedgeTrait,
+    Hypergraph,
+    VertexIndex,
+    VertexTrait,
+    core::dijkstra::Direction,
+    errors::HypergraphError,
+};
+
+#[allow(clippy::type_complexity)]
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Gets the vertices connected from a given vertex like
+    /// `get_adjacent_vertices_from`, explaining why they are connected, i.e.
+    /// along with each hyperedge connecting them and the positions of the
+    /// source and of the target vertices in each of its windows of two
+    /// consecutive vertices producing the adjacency.
+    /// The results are sorted by vertex and then by hyperedge.
+    pub fn get_adjacent_vertices_from_with_positions(
+        &self,
+        from: VertexIndex,
+    ) -> Result<Vec<(VertexIndex, HyperedgeIndex, Vec<(usize, usize)>)>, HypergraphError> {
+        self.get_adjacent_vertices_with_positions(from, Direction::Forward)
+    }
+
+    // Private method to get the vertices connected from or to a given
+    // vertex, depending on the direction, along with the connecting
+    // hyperedges and the positions of the source and of the target
+    // vertices.
+    pub(crate) fn get_adjacent_vertices_with_positions(
+        &self,
+        vertex_index: VertexIndex,
+        direction: Direction,
+    ) -> Result<Vec<(VertexIndex, HyperedgeIndex, Vec<(usize, usize)>)>, HypergraphError> {
+        let internal_index = self.get_internal_vertex(vertex_index)?;
+
+        let (_, hyperedges) = self
+            .vertices
+            .get_index(internal_index)
+            .ok_or(HypergraphError::InternalVertexIndexNotFound(internal_index))?;
+
+        let mut results = BTreeMap::<(VertexIndex, HyperedgeIndex), Vec<(usize, usize)>>::new();
+
+        for internal_hyperedge in hyperedges {
+            let HyperedgeKey { vertices, .. } = self.hyperedges.get(*internal_hyperedge).ok_or(
+                HypergraphError::InternalHyperedgeIndexNotFound(*internal_hyperedge),
+            )?;
+
+            for (position, window) in vertices.windows(2).enumerate() {
+                let adjacent = match direction {
+                    Direction::Forward if window[0] == internal_index => window[1],
+                    Direction::Backward if window[1] == internal_index => window[0],
+                    _ => continue,
+                };
+
+                results
+                    .entry((
+                        self.get_vertex(adjacent)?,
+                        self.get_hyperedge(*internal_hyperedge)?,
+                    ))
+                    .or_default()
+                    .push((position, position + 1));
+            }
+        }
+
+        Ok(results
+            .into_iter()
+            .map(|((vertex_index, hyperedge_index), positions)| {
+                (vertex_index, hyperedge_index, positions)
+            })
+            .collect())
+    }
+}
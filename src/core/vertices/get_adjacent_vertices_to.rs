@@ -1,11 +1,12 @@
-use rayon::prelude::*;
-
 use crate::{
     HyperedgeTrait,
     Hypergraph,
     VertexIndex,
     VertexTrait,
-    core::shared::Connection,
+    core::{
+        parallel::*,
+        shared::Connection,
+    },
     errors::HypergraphError,
 };
 
@@ -15,10 +16,27 @@ where
     HE: HyperedgeTrait,
 {
     /// Gets the list of all vertices connected to a given vertex.
+    /// Uses the adjacency cache if built.
     pub fn get_adjacent_vertices_to(
         &self,
         to: VertexIndex,
-    ) -> Result<Vec<VertexIndex>, HypergraphError<V, HE>> {
+    ) -> Result<Vec<VertexIndex>, HypergraphError> {
+        // The cached vertices are already deduplicated.
+        if let Some(adjacent_vertices) = self
+            .adjacency_cache
+            .as_ref()
+            .and_then(|cache| cache.to.get(&to))
+        {
+            let mut results = adjacent_vertices
+                .iter()
+                .map(|(vertex_index, _)| *vertex_index)
+                .collect::<Vec<VertexIndex>>();
+
+            results.par_sort_unstable();
+
+            return Ok(results);
+        }
+
         let mut results = self
             .get_connections(&Connection::Out(to))?
             .into_par_iter()
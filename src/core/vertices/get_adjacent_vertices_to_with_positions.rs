@@ -0,0 +1,29 @@
+use crate::{
+    HyperedgeIndex,
+    HyperedgeTrait,
+    Hypergraph,
+    VertexIndex,
+    VertexTrait,
+    core::dijkstra::Direction,
+    errors::HypergraphError,
+};
+
+#[allow(clippy::type_complexity)]
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Gets the vertices connected to a given vertex like
+    /// `get_adjacent_vertices_to`, explaining why they are connected, i.e.
+    /// along with each hyperedge connecting them and the positions of the
+    /// source and of the target vertices in each of its windows of two
+    /// consecutive vertices producing the adjacency.
+    /// The results are sorted by vertex and then by hyperedge.
+    pub fn get_adjacent_vertices_to_with_positions(
+        &self,
+        to: VertexIndex,
+    ) -> Result<Vec<(VertexIndex, HyperedgeIndex, Vec<(usize, usize)>)>, HypergraphError> {
+        self.get_adjacent_vertices_with_positions(to, Direction::Backward)
+    }
+}
@@ -0,0 +1,65 @@
+use crate::{
+    HyperedgeKey,
+    HyperedgeTrait,
+    Hypergraph,
+    VertexIndex,
+    VertexTrait,
+    core::parallel::*,
+    errors::HypergraphError,
+};
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Gets the in-degree and the out-degree of all the vertices as a vector
+    /// of `(VertexIndex, in-degree, out-degree)`, sorted by `VertexIndex`.
+    /// The degrees are computed in a single parallel pass over the
+    /// hyperedges and match the ones of `get_vertex_degree_in` and
+    /// `get_vertex_degree_out`.
+    /// <https://en.wikipedia.org/wiki/Directed_graph#Indegree_and_outdegree>
+    pub fn get_all_vertex_degrees(
+        &self,
+    ) -> Result<Vec<(VertexIndex, usize, usize)>, HypergraphError> {
+        let count = self.vertices.len();
+
+        let degrees = self
+            .hyperedges
+            .par_iter()
+            .fold(
+                || vec![(0, 0); count],
+                |mut degrees, HyperedgeKey { vertices, .. }| {
+                    for pair in vertices.windows(2) {
+                        degrees[pair[0]].1 += 1;
+                        degrees[pair[1]].0 += 1;
+                    }
+
+                    degrees
+                },
+            )
+            .reduce(
+                || vec![(0, 0); count],
+                |mut left, right| {
+                    for (left, right) in left.iter_mut().zip(right) {
+                        left.0 += right.0;
+                        left.1 += right.1;
+                    }
+
+                    left
+                },
+            );
+
+        let mut results = degrees
+            .into_iter()
+            .enumerate()
+            .map(|(internal_index, (degree_in, degree_out))| {
+                Ok((self.get_vertex(internal_index)?, degree_in, degree_out))
+            })
+            .collect::<Result<Vec<(VertexIndex, usize, usize)>, HypergraphError>>()?;
+
+        results.par_sort_unstable_by_key(|(vertex_index, _, _)| *vertex_index);
+
+        Ok(results)
+    }
+}
@@ -5,16 +5,19 @@ use std::{
         HashMap,
     },
     fmt::Debug,
+    sync::atomic::{
+        AtomicBool,
+        Ordering as AtomicOrdering,
+    },
 };
 
-use rayon::prelude::*;
-
 use crate::{
     HyperedgeIndex,
     HyperedgeTrait,
     Hypergraph,
     VertexIndex,
     VertexTrait,
+    core::parallel::*,
     errors::HypergraphError,
 };
 
@@ -64,7 +67,51 @@ where
         &self,
         from: VertexIndex,
         to: VertexIndex,
-    ) -> Result<Vec<(VertexIndex, Option<HyperedgeIndex>)>, HypergraphError<V, HE>> {
+    ) -> Result<Vec<(VertexIndex, Option<HyperedgeIndex>)>, HypergraphError> {
+        self.get_filtered_dijkstra_connections(from, to, |_, _| true, None)
+    }
+
+    /// Gets the cheapest path between two vertices like
+    /// `get_dijkstra_connections` while checking a cancellation flag before
+    /// visiting each vertex. Setting the flag from another thread aborts the
+    /// query with a `Cancelled` error.
+    pub fn get_dijkstra_connections_cancellable(
+        &self,
+        from: VertexIndex,
+        to: VertexIndex,
+        cancelled: &AtomicBool,
+    ) -> Result<Vec<(VertexIndex, Option<HyperedgeIndex>)>, HypergraphError> {
+        self.get_filtered_dijkstra_connections(from, to, |_, _| true, Some(cancelled))
+    }
+
+    // Private method to get the cheapest path of vertices between two
+    // vertices while only traversing the hyperedges matching a predicate.
+    // The traversal is aborted as soon as the optional cancellation flag is
+    // set.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            err,
+            fields(
+                ?from,
+                ?to,
+                vertices_count = self.vertices.len(),
+                hyperedges_count = self.hyperedges.len(),
+            )
+        )
+    )]
+    pub(crate) fn get_filtered_dijkstra_connections<F>(
+        &self,
+        from: VertexIndex,
+        to: VertexIndex,
+        predicate: F,
+        cancelled: Option<&AtomicBool>,
+    ) -> Result<Vec<(VertexIndex, Option<HyperedgeIndex>)>, HypergraphError>
+    where
+        F: Fn(HyperedgeIndex, &HE) -> bool,
+    {
         // Get the internal indexes of the vertices.
         let internal_from = self.get_internal_vertex(from)?;
         let internal_to = self.get_internal_vertex(to)?;
@@ -87,6 +134,11 @@ where
         let mut path = Vec::<VertexIndex>::new();
 
         while let Some(Visitor { distance, index }) = to_traverse.pop() {
+            // Abort the traversal if it has been cancelled.
+            if cancelled.map_or(false, |cancelled| cancelled.load(AtomicOrdering::Relaxed)) {
+                return Err(HypergraphError::Cancelled);
+            }
+
             // End of the traversal.
             if index == internal_to {
                 // Inject the target vertex.
@@ -114,22 +166,27 @@ where
             // Proceed by finding all the adjacent vertices as a hashmap whose
             // keys are VertexIndex and values are a vector of HyperedgeIndex.
             let mapped_index = self.get_vertex(index)?;
-            let indexes = self.get_full_adjacent_vertices_from(mapped_index)?;
+            let indexes = self.get_cached_full_adjacent_vertices_from(mapped_index)?;
 
             // For every connected vertex, try to find the lowest distance.
-            for (vertex_index, hyperedge_indexes) in indexes {
-                let internal_vertex_index = self.get_internal_vertex(vertex_index)?;
+            for (vertex_index, hyperedge_indexes) in indexes.iter() {
+                let internal_vertex_index = self.get_internal_vertex(*vertex_index)?;
 
                 let mut min_cost = usize::MAX;
                 let mut best_hyperedge: Option<HyperedgeIndex> = None;
 
                 // Get the lower cost out of all the hyperedges.
-                for hyperedge_index in hyperedge_indexes {
+                for &hyperedge_index in hyperedge_indexes {
                     let hyperedge_weight = self.get_hyperedge_weight(hyperedge_index)?;
 
+                    // Skip the hyperedges which can't be traversed.
+                    if !predicate(hyperedge_index, hyperedge_weight) {
+                        continue;
+                    }
+
                     // Use the trait implementation to get the associated cost
-                    // of the hyperedge.
-                    let cost = hyperedge_weight.to_owned().into();
+                    // of the hyperedge, unless cached.
+                    let cost = self.get_hyperedge_cost(hyperedge_index)?;
 
                     if cost < min_cost {
                         min_cost = cost;
@@ -139,6 +196,11 @@ where
                     }
                 }
 
+                // Skip the vertex if none of the hyperedges can be traversed.
+                if best_hyperedge.is_none() {
+                    continue;
+                }
+
                 // Prepare the next visitor.
                 let next = Visitor::new(distance + min_cost, internal_vertex_index);
 
@@ -149,7 +211,7 @@ where
 
                 // If so, add it to the frontier and continue.
                 if is_shorter {
-                    maybe_traversed_hyperedge_by_vertex.insert(vertex_index, best_hyperedge);
+                    maybe_traversed_hyperedge_by_vertex.insert(*vertex_index, best_hyperedge);
 
                     // Update the path traversal accordingly.
                     // Keep vertex indexes unique.
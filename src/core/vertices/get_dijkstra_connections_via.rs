@@ -0,0 +1,80 @@
+use crate::{
+    HyperedgeIndex,
+    HyperedgeTrait,
+    Hypergraph,
+    VertexIndex,
+    VertexTrait,
+    core::types::AIndexSet,
+    errors::HypergraphError,
+};
+
+#[allow(clippy::type_complexity)]
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Gets the cheapest path between two vertices like
+    /// `get_dijkstra_connections` while only traversing the allowed
+    /// hyperedges.
+    pub fn get_dijkstra_connections_via(
+        &self,
+        from: VertexIndex,
+        to: VertexIndex,
+        allowed: &[HyperedgeIndex],
+    ) -> Result<Vec<(VertexIndex, Option<HyperedgeIndex>)>, HypergraphError> {
+        // Check that the allowed hyperedges exist.
+        self.get_internal_hyperedges(allowed)?;
+
+        let allowed = allowed
+            .iter()
+            .copied()
+            .collect::<AIndexSet<HyperedgeIndex>>();
+
+        self.get_filtered_dijkstra_connections(
+            from,
+            to,
+            |hyperedge_index, _| allowed.contains(&hyperedge_index),
+            None,
+        )
+    }
+
+    /// Gets the cheapest path between two vertices like
+    /// `get_dijkstra_connections` while only traversing the hyperedges
+    /// matching a predicate called with their indexes and weights.
+    pub fn get_dijkstra_connections_where<F>(
+        &self,
+        from: VertexIndex,
+        to: VertexIndex,
+        predicate: F,
+    ) -> Result<Vec<(VertexIndex, Option<HyperedgeIndex>)>, HypergraphError>
+    where
+        F: Fn(HyperedgeIndex, &HE) -> bool,
+    {
+        self.get_filtered_dijkstra_connections(from, to, predicate, None)
+    }
+
+    /// Gets the cheapest path between two vertices like
+    /// `get_dijkstra_connections` while only traversing the hyperedges
+    /// having at least one of the given tags, e.g. the ones of some layers.
+    pub fn get_dijkstra_connections_with_tags(
+        &self,
+        from: VertexIndex,
+        to: VertexIndex,
+        tags: &[&str],
+    ) -> Result<Vec<(VertexIndex, Option<HyperedgeIndex>)>, HypergraphError> {
+        let allowed = tags
+            .iter()
+            .filter_map(|tag| self.hyperedges_tags.get(*tag))
+            .flatten()
+            .copied()
+            .collect::<AIndexSet<HyperedgeIndex>>();
+
+        self.get_filtered_dijkstra_connections(
+            from,
+            to,
+            |hyperedge_index, _| allowed.contains(&hyperedge_index),
+            None,
+        )
+    }
+}
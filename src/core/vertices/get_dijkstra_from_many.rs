@@ -0,0 +1,62 @@
+use std::collections::HashMap;
+
+use crate::{
+    HyperedgeTrait,
+    Hypergraph,
+    VertexIndex,
+    VertexTrait,
+    core::dijkstra::Direction,
+    errors::HypergraphError,
+};
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Gets the nearest source of every reachable vertex along with the
+    /// total cost of the cheapest path from it, as a map whose values are
+    /// tuples of the form `(VertexIndex, usize)`, in a single Dijkstra pass
+    /// seeded with all the sources, e.g. for facility location analyses.
+    /// The sources are their own nearest source.
+    pub fn get_dijkstra_from_many(
+        &self,
+        sources: &[VertexIndex],
+    ) -> Result<HashMap<VertexIndex, (VertexIndex, usize)>, HypergraphError> {
+        let shortest_paths =
+            self.get_shortest_paths(sources, None, Direction::Forward, true, None)?;
+
+        let mut nearest_sources = HashMap::with_capacity(shortest_paths.distances.len());
+
+        for vertex_index in shortest_paths.distances.keys() {
+            // Walk back to the first vertex whose nearest source is known.
+            let mut walked = vec![];
+            let mut current = *vertex_index;
+
+            let source = loop {
+                if let Some(source) = nearest_sources.get(&current) {
+                    break *source;
+                }
+
+                match shortest_paths.links.get(&current) {
+                    Some((previous, _)) => {
+                        walked.push(current);
+                        current = *previous;
+                    }
+                    None => break current,
+                }
+            };
+
+            nearest_sources.insert(current, source);
+            nearest_sources.extend(walked.into_iter().map(|walked| (walked, source)));
+        }
+
+        Ok(shortest_paths
+            .distances
+            .into_iter()
+            .map(|(vertex_index, distance)| {
+                (vertex_index, (nearest_sources[&vertex_index], distance))
+            })
+            .collect())
+    }
+}
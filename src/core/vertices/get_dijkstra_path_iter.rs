@@ -0,0 +1,33 @@
+use crate::{
+    HyperedgeTrait,
+    Hypergraph,
+    PathIter,
+    VertexIndex,
+    VertexTrait,
+    core::dijkstra::Direction,
+    errors::HypergraphError,
+};
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Gets the cheapest path of vertices between two vertices as a lazy
+    /// iterator of tuples of the form `(VertexIndex, Option<HyperedgeIndex>)`,
+    /// following `get_dijkstra_connections`.
+    /// The search runs backwards from the target so that the steps are then
+    /// yielded from the source by following the successors of each vertex,
+    /// without materializing the path. The iterator is empty if there's no
+    /// path.
+    pub fn get_dijkstra_path_iter(
+        &self,
+        from: VertexIndex,
+        to: VertexIndex,
+    ) -> Result<PathIter, HypergraphError> {
+        let shortest_paths =
+            self.get_shortest_paths(&[to], Some(from), Direction::Backward, true, None)?;
+
+        Ok(PathIter::new(shortest_paths.links, from, to))
+    }
+}
@@ -1,3 +1,5 @@
+use std::borrow::Cow;
+
 use indexmap::IndexMap;
 use itertools::{
     Itertools,
@@ -22,10 +24,21 @@ where
 {
     /// Gets the list of all vertices connected to a given vertex as tuples of
     /// the form (`VertexIndex`, Vec<HyperedgeIndex>).
+    /// Uses the adjacency cache if built.
     pub fn get_full_adjacent_vertices_to(
         &self,
         to: VertexIndex,
-    ) -> Result<Vec<(VertexIndex, Vec<HyperedgeIndex>)>, HypergraphError<V, HE>> {
+    ) -> Result<Vec<(VertexIndex, Vec<HyperedgeIndex>)>, HypergraphError> {
+        self.get_cached_full_adjacent_vertices_to(to)
+            .map(Cow::into_owned)
+    }
+
+    // Private method to compute the list of all vertices connected to a
+    // given vertex, bypassing the adjacency cache.
+    pub(crate) fn get_uncached_full_adjacent_vertices_to(
+        &self,
+        to: VertexIndex,
+    ) -> Result<Vec<(VertexIndex, Vec<HyperedgeIndex>)>, HypergraphError> {
         let results = self.get_connections(&Connection::Out(to))?;
 
         Ok(fold(
@@ -1,10 +1,9 @@
-use rayon::prelude::*;
-
 use crate::{
     HyperedgeTrait,
     Hypergraph,
     VertexIndex,
     VertexTrait,
+    core::parallel::*,
     errors::HypergraphError,
 };
 
@@ -17,7 +16,7 @@ where
     pub fn get_full_vertex_hyperedges(
         &self,
         vertex_index: VertexIndex,
-    ) -> Result<Vec<Vec<VertexIndex>>, HypergraphError<V, HE>> {
+    ) -> Result<Vec<Vec<VertexIndex>>, HypergraphError> {
         self.get_vertex_hyperedges(vertex_index).map(|hyperedges| {
             hyperedges
                 .into_par_iter()
@@ -0,0 +1,46 @@
+use crate::{
+    HyperedgeIndex,
+    HyperedgeKey,
+    HyperedgeTrait,
+    Hypergraph,
+    VertexIndex,
+    VertexTrait,
+    errors::HypergraphError,
+};
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Gets the hyperedges leaving a vertex as a vector of `HyperedgeIndex`,
+    /// i.e. the hyperedges in which the vertex appears at least once in a
+    /// position other than the last one.
+    pub fn get_hyperedges_from(
+        &self,
+        vertex_index: VertexIndex,
+    ) -> Result<Vec<HyperedgeIndex>, HypergraphError> {
+        let internal_index = self.get_internal_vertex(vertex_index)?;
+
+        let (_, hyperedges_index_set) = self
+            .vertices
+            .get_index(internal_index)
+            .ok_or(HypergraphError::InternalVertexIndexNotFound(internal_index))?;
+
+        let mut hyperedges = vec![];
+
+        for hyperedge in hyperedges_index_set {
+            let HyperedgeKey { vertices, .. } = self
+                .hyperedges
+                .get(*hyperedge)
+                .ok_or(HypergraphError::InternalHyperedgeIndexNotFound(*hyperedge))?;
+
+            // Keep the hyperedge if the vertex appears before the last position.
+            if vertices[..vertices.len() - 1].contains(&internal_index) {
+                hyperedges.push(*hyperedge);
+            }
+        }
+
+        self.get_hyperedges(&hyperedges)
+    }
+}
@@ -15,7 +15,7 @@ where
     pub(crate) fn get_internal_vertex(
         &self,
         vertex_index: VertexIndex,
-    ) -> Result<usize, HypergraphError<V, HE>> {
+    ) -> Result<usize, HypergraphError> {
         match self.vertices_mapping.right.get(&vertex_index) {
             Some(index) => Ok(*index),
             None => Err(HypergraphError::VertexIndexNotFound(vertex_index)),
@@ -1,10 +1,9 @@
-use rayon::prelude::*;
-
 use crate::{
     HyperedgeTrait,
     Hypergraph,
     VertexIndex,
     VertexTrait,
+    core::parallel::*,
     errors::HypergraphError,
 };
 
@@ -17,7 +16,7 @@ where
     pub(crate) fn get_internal_vertices<R: AsRef<Vec<VertexIndex>>>(
         &self,
         vertices: R,
-    ) -> Result<Vec<usize>, HypergraphError<V, HE>> {
+    ) -> Result<Vec<usize>, HypergraphError> {
         vertices
             .as_ref()
             .par_iter()
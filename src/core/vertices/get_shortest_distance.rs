@@ -0,0 +1,28 @@
+use crate::{
+    HyperedgeTrait,
+    Hypergraph,
+    VertexIndex,
+    VertexTrait,
+    core::dijkstra::Direction,
+    errors::HypergraphError,
+};
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Gets the total cost of the cheapest path between two vertices, if
+    /// any, without reconstructing the path. This is cheaper than
+    /// `get_dijkstra_connections` for existence or cost queries.
+    pub fn get_shortest_distance(
+        &self,
+        from: VertexIndex,
+        to: VertexIndex,
+    ) -> Result<Option<usize>, HypergraphError> {
+        let shortest_paths =
+            self.get_shortest_paths(&[from], Some(to), Direction::Forward, false, None)?;
+
+        Ok(shortest_paths.distances.get(&to).copied())
+    }
+}
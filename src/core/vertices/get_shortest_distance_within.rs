@@ -0,0 +1,29 @@
+use crate::{
+    HyperedgeTrait,
+    Hypergraph,
+    VertexIndex,
+    VertexTrait,
+    core::dijkstra::Direction,
+    errors::HypergraphError,
+};
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Gets the total cost of the cheapest path between two vertices like
+    /// `get_shortest_distance`, as long as it doesn't exceed a maximum cost.
+    /// The exploration is aborted beyond this budget.
+    pub fn get_shortest_distance_within(
+        &self,
+        from: VertexIndex,
+        to: VertexIndex,
+        max_cost: usize,
+    ) -> Result<Option<usize>, HypergraphError> {
+        let shortest_paths =
+            self.get_shortest_paths(&[from], Some(to), Direction::Forward, false, Some(max_cost))?;
+
+        Ok(shortest_paths.distances.get(&to).copied())
+    }
+}
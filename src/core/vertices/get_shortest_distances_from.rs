@@ -0,0 +1,32 @@
+use std::collections::HashMap;
+
+use crate::{
+    HyperedgeTrait,
+    Hypergraph,
+    VertexIndex,
+    VertexTrait,
+    core::dijkstra::Direction,
+    errors::HypergraphError,
+};
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Gets the total cost of the cheapest path from a vertex to every
+    /// reachable vertex, including itself, e.g. for heat maps. No path is
+    /// reconstructed.
+    /// With a maximum cost, the exploration is aborted beyond this budget
+    /// and only the vertices within it are returned.
+    pub fn get_shortest_distances_from(
+        &self,
+        from: VertexIndex,
+        max_cost: Option<usize>,
+    ) -> Result<HashMap<VertexIndex, usize>, HypergraphError> {
+        let shortest_paths =
+            self.get_shortest_paths(&[from], None, Direction::Forward, false, max_cost)?;
+
+        Ok(shortest_paths.distances)
+    }
+}
@@ -0,0 +1,29 @@
+use crate::{
+    HyperedgeTrait,
+    Hypergraph,
+    Value,
+    VertexIndex,
+    VertexTrait,
+    errors::HypergraphError,
+};
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Gets an attribute of a vertex.
+    pub fn get_vertex_attr(
+        &self,
+        vertex_index: VertexIndex,
+        key: &str,
+    ) -> Result<Option<&Value>, HypergraphError> {
+        // Check that the vertex exists.
+        self.get_internal_vertex(vertex_index)?;
+
+        Ok(self
+            .vertices_attributes
+            .get(&vertex_index)
+            .and_then(|attributes| attributes.get(key)))
+    }
+}
@@ -14,7 +14,7 @@ where
 {
     /// Gets the in-degree of a vertex.
     /// <https://en.wikipedia.org/wiki/Directed_graph#Indegree_and_outdegree>
-    pub fn get_vertex_degree_in(&self, to: VertexIndex) -> Result<usize, HypergraphError<V, HE>> {
+    pub fn get_vertex_degree_in(&self, to: VertexIndex) -> Result<usize, HypergraphError> {
         let results = self.get_connections(&Connection::Out(to))?;
 
         Ok(results.len())
@@ -14,10 +14,7 @@ where
 {
     /// Gets the out-degree of a vertex.
     /// <https://en.wikipedia.org/wiki/Directed_graph#Indegree_and_outdegree>
-    pub fn get_vertex_degree_out(
-        &self,
-        from: VertexIndex,
-    ) -> Result<usize, HypergraphError<V, HE>> {
+    pub fn get_vertex_degree_out(&self, from: VertexIndex) -> Result<usize, HypergraphError> {
         let results = self.get_connections(&Connection::In(from))?;
 
         Ok(results.len())
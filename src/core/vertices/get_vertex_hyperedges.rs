@@ -18,7 +18,7 @@ where
     pub fn get_vertex_hyperedges(
         &self,
         vertex_index: VertexIndex,
-    ) -> Result<Vec<HyperedgeIndex>, HypergraphError<V, HE>> {
+    ) -> Result<Vec<HyperedgeIndex>, HypergraphError> {
         let internal_index = self.get_internal_vertex(vertex_index)?;
 
         let (_, hyperedges_index_set) = self
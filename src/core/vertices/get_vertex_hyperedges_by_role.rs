@@ -0,0 +1,36 @@
+use crate::{
+    HyperedgeIndex,
+    HyperedgeTrait,
+    Hypergraph,
+    VertexIndex,
+    VertexTrait,
+    errors::HypergraphError,
+};
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Gets the hyperedges in which a vertex has a given role as a vector of
+    /// `HyperedgeIndex`.
+    pub fn get_vertex_hyperedges_by_role(
+        &self,
+        vertex_index: VertexIndex,
+        role: &str,
+    ) -> Result<Vec<HyperedgeIndex>, HypergraphError> {
+        let mut hyperedges = vec![];
+
+        for hyperedge_index in self.get_vertex_hyperedges(vertex_index)? {
+            if self.get_hyperedge_roles(hyperedge_index)?.into_iter().any(
+                |(current_vertex, current_role)| {
+                    current_vertex == vertex_index && current_role == Some(role)
+                },
+            ) {
+                hyperedges.push(hyperedge_index);
+            }
+        }
+
+        Ok(hyperedges)
+    }
+}
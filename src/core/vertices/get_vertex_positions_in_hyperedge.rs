@@ -0,0 +1,50 @@
+use crate::{
+    HyperedgeIndex,
+    HyperedgeKey,
+    HyperedgeTrait,
+    Hypergraph,
+    VertexIndex,
+    VertexTrait,
+    errors::HypergraphError,
+};
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    // Private method to get the internal vertices of a hyperedge along with
+    // the internal index of a vertex.
+    pub(crate) fn get_internal_vertex_and_hyperedge_vertices(
+        &self,
+        vertex_index: VertexIndex,
+        hyperedge_index: HyperedgeIndex,
+    ) -> Result<(usize, &[usize]), HypergraphError> {
+        let internal_vertex = self.get_internal_vertex(vertex_index)?;
+        let internal_hyperedge = self.get_internal_hyperedge(hyperedge_index)?;
+
+        let HyperedgeKey { vertices, .. } = self.hyperedges.get(internal_hyperedge).ok_or(
+            HypergraphError::InternalHyperedgeIndexNotFound(internal_hyperedge),
+        )?;
+
+        Ok((internal_vertex, vertices))
+    }
+
+    /// Gets the positions of a vertex in a hyperedge.
+    /// Returns an empty vector if the vertex is not part of the hyperedge.
+    pub fn get_vertex_positions_in_hyperedge(
+        &self,
+        vertex_index: VertexIndex,
+        hyperedge_index: HyperedgeIndex,
+    ) -> Result<Vec<usize>, HypergraphError> {
+        let (internal_vertex, vertices) =
+            self.get_internal_vertex_and_hyperedge_vertices(vertex_index, hyperedge_index)?;
+
+        Ok(vertices
+            .iter()
+            .enumerate()
+            .filter(|(_, vertex)| **vertex == internal_vertex)
+            .map(|(position, _)| position)
+            .collect())
+    }
+}
@@ -0,0 +1,22 @@
+use crate::{
+    HyperedgeTrait,
+    Hypergraph,
+    VertexIndex,
+    VertexTrait,
+    errors::HypergraphError,
+};
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Gets the weights of many vertices at once from their indexes, in the
+    /// same order, as references without copying them.
+    pub fn get_vertex_weights(&self, vertices: &[VertexIndex]) -> Result<Vec<&V>, HypergraphError> {
+        vertices
+            .iter()
+            .map(|vertex_index| self.get_vertex_weight(*vertex_index))
+            .collect()
+    }
+}
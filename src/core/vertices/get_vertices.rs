@@ -1,10 +1,9 @@
-use rayon::prelude::*;
-
 use crate::{
     HyperedgeTrait,
     Hypergraph,
     VertexIndex,
     VertexTrait,
+    core::parallel::*,
     errors::HypergraphError,
 };
 
@@ -17,7 +16,7 @@ where
     pub(crate) fn get_vertices(
         &self,
         vertices: &[usize],
-    ) -> Result<Vec<VertexIndex>, HypergraphError<V, HE>> {
+    ) -> Result<Vec<VertexIndex>, HypergraphError> {
         vertices
             .par_iter()
             .map(|vertex_index| self.get_vertex(*vertex_index))
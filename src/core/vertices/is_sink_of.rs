@@ -0,0 +1,26 @@
+use crate::{
+    HyperedgeIndex,
+    HyperedgeTrait,
+    Hypergraph,
+    VertexIndex,
+    VertexTrait,
+    errors::HypergraphError,
+};
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Checks if a vertex is the sink of a hyperedge, i.e. its last vertex.
+    pub fn is_sink_of(
+        &self,
+        vertex_index: VertexIndex,
+        hyperedge_index: HyperedgeIndex,
+    ) -> Result<bool, HypergraphError> {
+        let (internal_vertex, vertices) =
+            self.get_internal_vertex_and_hyperedge_vertices(vertex_index, hyperedge_index)?;
+
+        Ok(vertices.last() == Some(&internal_vertex))
+    }
+}
@@ -0,0 +1,102 @@
+use std::{
+    cmp::Reverse,
+    collections::BinaryHeap,
+};
+
+use crate::{
+    HyperedgeKey,
+    HyperedgeTrait,
+    Hypergraph,
+    VertexIndex,
+    VertexTrait,
+    core::types::AIndexSet,
+    errors::HypergraphError,
+};
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Gets an approximation of a minimum hitting set, i.e. a smallest set of
+    /// vertices intersecting every hyperedge.
+    /// Vertices are greedily picked by decreasing number of hyperedges not
+    /// yet hit, which is a logarithmic approximation of the optimum.
+    /// Returns the vertices by ascending index.
+    /// <https://en.wikipedia.org/wiki/Vertex_cover_in_hypergraphs>
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            err,
+            fields(
+                vertices_count = self.vertices.len(),
+                hyperedges_count = self.hyperedges.len(),
+            )
+        )
+    )]
+    pub fn minimum_hitting_set(&self) -> Result<Vec<VertexIndex>, HypergraphError> {
+        // Number of hyperedges not yet hit by each vertex.
+        let mut counts = self
+            .vertices
+            .values()
+            .map(AIndexSet::len)
+            .collect::<Vec<usize>>();
+        let mut hit = vec![false; self.hyperedges.len()];
+
+        // Ties are broken by the lowest internal index.
+        let mut heap = counts
+            .iter()
+            .enumerate()
+            .map(|(vertex, count)| (*count, Reverse(vertex)))
+            .collect::<BinaryHeap<(usize, Reverse<usize>)>>();
+
+        let mut selected = Vec::new();
+
+        while let Some((count, Reverse(vertex))) = heap.pop() {
+            // All the hyperedges have been hit.
+            if count == 0 {
+                break;
+            }
+
+            // Skip the outdated entries.
+            if count != counts[vertex] {
+                continue;
+            }
+
+            selected.push(vertex);
+
+            let (_, hyperedges) = self
+                .vertices
+                .get_index(vertex)
+                .ok_or(HypergraphError::InternalVertexIndexNotFound(vertex))?;
+
+            for hyperedge in hyperedges {
+                if hit[*hyperedge] {
+                    continue;
+                }
+
+                hit[*hyperedge] = true;
+
+                let HyperedgeKey { vertices, .. } = self
+                    .hyperedges
+                    .get(*hyperedge)
+                    .ok_or(HypergraphError::InternalHyperedgeIndexNotFound(*hyperedge))?;
+
+                // Vertices can be repeated within a hyperedge.
+                for other in vertices.iter().collect::<AIndexSet<&usize>>() {
+                    counts[*other] -= 1;
+
+                    if *other != vertex {
+                        heap.push((counts[*other], Reverse(*other)));
+                    }
+                }
+            }
+        }
+
+        selected.sort_unstable();
+
+        self.get_vertices(&selected)
+    }
+}
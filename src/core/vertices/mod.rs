@@ -3,18 +3,43 @@ pub(crate) mod get_internal_vertex;
 pub(crate) mod get_internal_vertices;
 pub(crate) mod get_vertex;
 pub(crate) mod get_vertices;
+pub(crate) mod retain_vertices;
 
 pub mod add_vertex;
+pub mod contract_vertices_where;
 pub mod count_vertices;
 pub mod get_adjacent_vertices_from;
+pub mod get_adjacent_vertices_from_many;
+pub mod get_adjacent_vertices_from_with_positions;
 pub mod get_adjacent_vertices_to;
+pub mod get_adjacent_vertices_to_with_positions;
+pub mod get_all_vertex_degrees;
 pub mod get_dijkstra_connections;
+pub mod get_dijkstra_connections_via;
+pub mod get_dijkstra_from_many;
+pub mod get_dijkstra_path_iter;
 pub mod get_full_adjacent_vertices_from;
 pub mod get_full_adjacent_vertices_to;
 pub mod get_full_vertex_hyperedges;
+pub mod get_hyperedges_from;
+pub mod get_hyperedges_to;
+pub mod get_shortest_distance;
+pub mod get_shortest_distance_within;
+pub mod get_shortest_distances_from;
+pub mod get_vertex_attr;
 pub mod get_vertex_degree_in;
 pub mod get_vertex_degree_out;
 pub mod get_vertex_hyperedges;
+pub mod get_vertex_hyperedges_by_role;
+pub mod get_vertex_positions_in_hyperedge;
 pub mod get_vertex_weight;
+pub mod get_vertex_weights;
+pub mod is_sink_of;
+pub mod is_source_of;
+pub mod minimum_hitting_set;
+pub mod prune_isolated_vertices;
 pub mod remove_vertex;
+pub mod remove_vertex_attr;
+pub mod set_vertex_attr;
+pub mod top_k_vertices_by_degree;
 pub mod update_vertex_weight;
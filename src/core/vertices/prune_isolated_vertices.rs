@@ -0,0 +1,19 @@
+use crate::{
+    HyperedgeTrait,
+    Hypergraph,
+    VertexTrait,
+    errors::HypergraphError,
+};
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Removes all the vertices which don't belong to any hyperedge in one
+    /// single pass.
+    /// Returns the number of removed vertices.
+    pub fn prune_isolated_vertices(&mut self) -> Result<usize, HypergraphError> {
+        self.retain_vertices(|_, _, hyperedges| !hyperedges.is_empty())
+    }
+}
@@ -1,11 +1,10 @@
-use rayon::prelude::*;
-
 use crate::{
     HyperedgeKey,
     HyperedgeTrait,
     Hypergraph,
     VertexIndex,
     VertexTrait,
+    core::parallel::*,
     errors::HypergraphError,
 };
 
@@ -15,21 +14,38 @@ where
     HE: HyperedgeTrait,
 {
     /// Removes a vertex by index.
-    pub fn remove_vertex(
-        &mut self,
-        vertex_index: VertexIndex,
-    ) -> Result<(), HypergraphError<V, HE>> {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            err,
+            fields(
+                ?vertex_index,
+                vertices_count = self.vertices.len(),
+                hyperedges_count = self.hyperedges.len(),
+            )
+        )
+    )]
+    pub fn remove_vertex(&mut self, vertex_index: VertexIndex) -> Result<(), HypergraphError> {
         let internal_index = self.get_internal_vertex(vertex_index)?;
 
         // Get the hyperedges of the vertex.
         let hyperedges =
             self.get_internal_hyperedges(&self.get_vertex_hyperedges(vertex_index)?)?;
 
+        // Check upfront that the removal doesn't make some hyperedges clash
+        // according to the duplicate policy, before any mutation.
+        self.check_duplicate_vertices_on_removal(internal_index, &hyperedges)?;
+
+        // Same for the constraints.
+        self.check_constraints_on_removal(internal_index, &hyperedges)?;
+
         // Remove the vertex from the hyperedges which contain it.
         for hyperedge in hyperedges {
             let HyperedgeKey { vertices, .. } = self
                 .hyperedges
-                .get_index(hyperedge)
+                .get(hyperedge)
                 .cloned()
                 .ok_or(HypergraphError::InternalHyperedgeIndexNotFound(hyperedge))?;
 
@@ -48,18 +64,38 @@ where
             if unique_vertices.len() == 1 {
                 self.remove_hyperedge(hyperedge_index)?;
             } else {
+                // Keep track of the roles of the other vertices, dropped by
+                // the update.
+                let roles = self.hyperedges_roles.get(&hyperedge_index).map(|roles| {
+                    vertices
+                        .iter()
+                        .zip(roles)
+                        .filter(|(vertex, _)| **vertex != internal_index)
+                        .map(|(_, role)| role.clone())
+                        .collect::<Vec<String>>()
+                });
+
                 // Otherwise update the hyperedge with the updated vertices.
                 let updated_vertices = self.get_vertices(
                     &vertices
-                        .into_par_iter()
+                        .par_iter()
+                        .copied()
                         .filter(|vertex| *vertex != internal_index)
                         .collect::<Vec<usize>>(),
                 )?;
 
                 self.update_hyperedge_vertices(hyperedge_index, updated_vertices)?;
+
+                if let Some(roles) = roles {
+                    self.hyperedges_roles.insert(hyperedge_index, roles);
+                }
             }
         }
 
+        // Drop the adjacency cache and the distance oracle.
+        self.adjacency_cache = None;
+        self.distance_oracle = None;
+
         // Find the last index.
         let last_index = self.vertices.len() - 1;
 
@@ -70,6 +106,18 @@ where
         self.vertices_mapping.left.remove(&internal_index);
         self.vertices_mapping.right.remove(&vertex_index);
 
+        // Remove the attributes and the version of the vertex.
+        self.vertices_attributes.swap_remove(&vertex_index);
+        self.vertices_versions.swap_remove(&vertex_index);
+
+        // Remove the vertex from the full-text search index.
+        if let Some(search_index) = self.vertices_search_index.as_mut() {
+            search_index.remove(vertex_index);
+        }
+
+        // Remove the embedding of the vertex.
+        self.vertices_similarity_index.remove(vertex_index);
+
         // If the index to remove wasn't the last one, the last vertex has
         // been swapped in place of the removed one. See the remove_hyperedge
         // method for more details about the internals.
@@ -91,33 +139,17 @@ where
 
             // Update the impacted hyperedges accordingly.
             for hyperedge in stale_hyperedges {
-                let HyperedgeKey { vertices, weight } = self
+                let HyperedgeKey { vertices, .. } = self
                     .hyperedges
-                    .get_index(hyperedge)
+                    .get_mut(hyperedge)
                     .ok_or(HypergraphError::InternalHyperedgeIndexNotFound(hyperedge))?;
 
-                let updated_vertices = vertices
-                    .into_par_iter()
-                    .map(|vertex| {
-                        // Remap the vertex if this is the swapped one.
-                        if vertex == &last_index {
-                            internal_index
-                        } else {
-                            *vertex
-                        }
-                    })
-                    .collect();
-
-                // Insert the new entry with the updated vertices.
-                // Since we are not altering the weight, we can safely perform
-                // the operation without checking its output.
-                self.hyperedges
-                    .insert(HyperedgeKey::new(updated_vertices, *weight));
-
-                // Swap and remove by index.
-                // Since we know that the hyperedge index is correct, we can
-                // safely perform the operation without checking its output.
-                self.hyperedges.swap_remove_index(hyperedge);
+                // Remap the swapped vertex in place.
+                vertices.par_iter_mut().for_each(|vertex| {
+                    if *vertex == last_index {
+                        *vertex = internal_index;
+                    }
+                });
             }
         }
 
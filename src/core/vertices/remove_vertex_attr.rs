@@ -0,0 +1,30 @@
+use crate::{
+    HyperedgeTrait,
+    Hypergraph,
+    Value,
+    VertexIndex,
+    VertexTrait,
+    errors::HypergraphError,
+};
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Removes an attribute of a vertex.
+    /// Returns the removed value, if any.
+    pub fn remove_vertex_attr(
+        &mut self,
+        vertex_index: VertexIndex,
+        key: &str,
+    ) -> Result<Option<Value>, HypergraphError> {
+        // Check that the vertex exists.
+        self.get_internal_vertex(vertex_index)?;
+
+        Ok(self
+            .vertices_attributes
+            .get_mut(&vertex_index)
+            .and_then(|attributes| attributes.shift_remove(key)))
+    }
+}
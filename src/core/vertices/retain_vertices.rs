@@ -0,0 +1,124 @@
+use crate::{
+    HyperedgeKey,
+    HyperedgeTrait,
+    Hypergraph,
+    VertexTrait,
+    bi_hash_map::BiHashMap,
+    core::types::AIndexSet,
+    errors::HypergraphError,
+};
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    // Private method to retain only the vertices matching a predicate in one
+    // single pass. The predicate is called with the internal index of the
+    // vertex, its weight and its set of internal hyperedges.
+    // Unlike repeated calls to `remove_vertex`, the internal indexes are
+    // shifted only once and all the references are remapped at the end.
+    // Please note that the caller is responsible for only removing vertices
+    // which are not included in any hyperedge.
+    // Returns the number of removed vertices.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            err,
+            fields(
+                vertices_count = self.vertices.len(),
+                hyperedges_count = self.hyperedges.len(),
+            )
+        )
+    )]
+    pub(crate) fn retain_vertices<F>(&mut self, mut predicate: F) -> Result<usize, HypergraphError>
+    where
+        F: FnMut(usize, &V, &AIndexSet<usize>) -> bool,
+    {
+        // Compute the new internal indexes of the vertices to keep upfront.
+        let mut next_index = 0;
+        let remapping = self
+            .vertices
+            .iter()
+            .enumerate()
+            .map(|(internal_index, (key, hyperedges))| {
+                if predicate(internal_index, &key.weight, hyperedges) {
+                    next_index += 1;
+
+                    Some(next_index - 1)
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<Option<usize>>>();
+
+        let removed = self.vertices.len() - next_index;
+
+        // Early exit if there's nothing to remove.
+        if removed == 0 {
+            return Ok(0);
+        }
+
+        // Drop the adjacency cache and the distance oracle.
+        self.adjacency_cache = None;
+        self.distance_oracle = None;
+
+        // Rebuild the vertices while preserving their relative order.
+        let mut cursor = 0;
+
+        self.vertices.retain(|_, _| {
+            cursor += 1;
+
+            remapping[cursor - 1].is_some()
+        });
+
+        // Remap the stable indexes.
+        let mut vertices_mapping = BiHashMap::default();
+
+        for (internal_index, vertex_index) in self.vertices_mapping.left.drain() {
+            if let Some(Some(updated_index)) = remapping.get(internal_index) {
+                vertices_mapping.left.insert(*updated_index, vertex_index);
+                vertices_mapping.right.insert(vertex_index, *updated_index);
+            } else {
+                // Remove the attributes and the version of the removed
+                // vertex.
+                self.vertices_attributes.swap_remove(&vertex_index);
+                self.vertices_versions.swap_remove(&vertex_index);
+
+                // Remove the vertex from the full-text search index.
+                if let Some(search_index) = self.vertices_search_index.as_mut() {
+                    search_index.remove(vertex_index);
+                }
+
+                // Remove the embedding of the removed vertex.
+                self.vertices_similarity_index.remove(vertex_index);
+            }
+        }
+
+        self.vertices_mapping = vertices_mapping;
+
+        // Remap the vertices of the hyperedges. The order of the hyperedges
+        // is preserved so their internal indexes are left untouched.
+        self.hyperedges = self
+            .hyperedges
+            .drain(..)
+            .map(|HyperedgeKey { vertices, weight }| {
+                vertices
+                    .into_iter()
+                    .map(|vertex| {
+                        remapping
+                            .get(vertex)
+                            .copied()
+                            .flatten()
+                            .ok_or(HypergraphError::InternalVertexIndexNotFound(vertex))
+                    })
+                    .collect::<Result<Vec<usize>, HypergraphError>>()
+                    .map(|vertices| HyperedgeKey::new(vertices, weight))
+            })
+            .collect::<Result<Vec<HyperedgeKey<HE>>, HypergraphError>>()?;
+
+        Ok(removed)
+    }
+}
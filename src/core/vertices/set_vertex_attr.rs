@@ -0,0 +1,34 @@
+use crate::{
+    HyperedgeTrait,
+    Hypergraph,
+    Value,
+    VertexIndex,
+    VertexTrait,
+    errors::HypergraphError,
+};
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Sets an attribute of a vertex.
+    /// Attributes are stored apart from the weight which identifies the
+    /// vertex, hence they don't need to be unique.
+    /// Returns the previous value of the attribute, if any.
+    pub fn set_vertex_attr(
+        &mut self,
+        vertex_index: VertexIndex,
+        key: impl Into<String>,
+        value: impl Into<Value>,
+    ) -> Result<Option<Value>, HypergraphError> {
+        // Check that the vertex exists.
+        self.get_internal_vertex(vertex_index)?;
+
+        Ok(self
+            .vertices_attributes
+            .entry(vertex_index)
+            .or_default()
+            .insert(key.into(), value.into()))
+    }
+}
@@ -0,0 +1,41 @@
+use crate::{
+    HyperedgeTrait,
+    Hypergraph,
+    VertexIndex,
+    VertexTrait,
+    core::utils::get_top_k,
+    errors::HypergraphError,
+};
+
+impl<V, HE> Hypergraph<V, HE>
+where
+    V: VertexTrait,
+    HE: HyperedgeTrait,
+{
+    /// Gets the k most connected vertices along with their degrees, i.e.
+    /// the number of hyperedges they belong to regardless of the direction,
+    /// sorted by descending degree, the earliest inserted one winning the
+    /// ties.
+    /// Only k vertices are kept at a time, which avoids a full sort.
+    pub fn top_k_vertices_by_degree(
+        &self,
+        k: usize,
+    ) -> Result<Vec<(VertexIndex, usize)>, HypergraphError> {
+        let degrees = self
+            .vertices
+            .values()
+            .map(|hyperedges| Ok(hyperedges.len()));
+
+        get_top_k(degrees, k)?
+            .into_iter()
+            .map(|internal_index| {
+                let (_, hyperedges) = self
+                    .vertices
+                    .get_index(internal_index)
+                    .ok_or(HypergraphError::InternalVertexIndexNotFound(internal_index))?;
+
+                Ok((self.get_vertex(internal_index)?, hyperedges.len()))
+            })
+            .collect()
+    }
+}
@@ -2,6 +2,7 @@ use crate::{
     HyperedgeTrait,
     Hypergraph,
     VertexIndex,
+    VertexKey,
     VertexTrait,
     errors::HypergraphError,
 };
@@ -12,48 +13,109 @@ where
     HE: HyperedgeTrait,
 {
     /// Updates the weight of a vertex by index.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            err,
+            fields(
+                ?vertex_index,
+                vertices_count = self.vertices.len(),
+                hyperedges_count = self.hyperedges.len(),
+            )
+        )
+    )]
     pub fn update_vertex_weight(
         &mut self,
         vertex_index: VertexIndex,
         weight: V,
-    ) -> Result<(), HypergraphError<V, HE>> {
+    ) -> Result<(), HypergraphError> {
         let internal_index = self.get_internal_vertex(vertex_index)?;
 
-        let (previous_weight, index_set) = self
+        let (previous_key, index_set) = self
             .vertices
             .get_index(internal_index)
-            .map(|(previous_weight, index_set)| (previous_weight.to_owned(), index_set.clone()))
+            .map(|(previous_key, index_set)| (previous_key.to_owned(), index_set.clone()))
             .ok_or(HypergraphError::InternalVertexIndexNotFound(internal_index))?;
 
         // Return an error if the new weight is the same as the previous one.
-        if weight == previous_weight {
-            return Err(HypergraphError::VertexWeightUnchanged {
-                index: vertex_index,
-                weight,
-            });
+        if weight == *previous_key {
+            return Err(HypergraphError::VertexWeightUnchanged(vertex_index));
         }
 
+        // Keep the id of the vertex, if any, so that duplicated vertices
+        // stay duplicated.
+        let key = VertexKey::new(weight, previous_key.id);
+
         // Return an error if the new weight is already assigned to another
         // vertex.
-        if self.vertices.contains_key(&weight) {
-            return Err(HypergraphError::VertexWeightAlreadyAssigned(weight));
+        if let Some(internal_index) = self.vertices.get_index_of(&key) {
+            return Err(HypergraphError::VertexWeightAlreadyAssigned(
+                self.get_vertex(internal_index)?,
+            ));
         }
 
+        // IndexMap doesn't allow holes by design, see:
+        // https://github.com/bluss/indexmap/issues/90#issuecomment-455381877
+        //
+        // As a consequence, we have two options. Either we use shift_remove
+        // and it will result in an expensive regeneration of all the indexes
+        // in the map/set or we use swap_remove methods and deal with the fact
+        // that the last element will be swapped in place of the removed one
+        // and will thus get a new index.
+        //
+        // In our case, since we are inserting an entry upfront, it circumvents
+        // the aforementioned issue.
+        //
+        // First case: index alteration is avoided.
+        //
+        // Entry to remove
+        //  |              1.Insert new entry
+        //  |                     |
+        //  v                     v
+        // [a, b, c] -> [a, b, c, d] -> [d, b, c, _]
+        //                               ^        ^
+        //                               |        |
+        //                               +--------+
+        //                         2.Swap and remove
+        //
+        // -----------------------------------------
+        //
+        // Second case: no index alteration.
+        //
+        // Entry to remove
+        //        |        1.Insert new entry
+        //        |               |
+        //        v               v
+        // [a, b, c] -> [a, b, c, d] -> [a, b, d, _]
+        //                                     ^  ^
+        //                                     |  |
+        //                                     +--+
+        //                         2.Swap and remove
+        //
         // We can't directly replace the value in the map.
         // First, we need to insert the new weight, it will end up
         // being at the last position.
         // Since we have already checked that the new weight is not in the
         // map, we can safely perform the operation without checking its output.
-        self.vertices.insert(weight, index_set);
+        self.vertices.insert(key, index_set);
 
         // Then we use swap and remove. This will remove the previous weight
         // and insert the new one at the index position of the former.
         // This doesn't alter the indexing.
-        // See the update_hyperedge_weight method for more detailed explanation.
         // Since we know that the internal index is correct, we can safely
         // perform the operation without checking its output.
         self.vertices.swap_remove_index(internal_index);
 
+        // Update the full-text search index.
+        self.index_vertex_weight(vertex_index, internal_index);
+
+        // Bump the version of the vertex.
+        let version = self.next_version();
+
+        self.vertices_versions.insert(vertex_index, version);
+
         // Return a unit.
         Ok(())
     }
@@ -0,0 +1,124 @@
+use wasm_bindgen::prelude::*;
+
+use crate::{
+    core::labels::LabeledHypergraph,
+    errors::HypergraphError,
+};
+
+/// Directed hypergraph exposed to JavaScript via `wasm-bindgen`.
+/// Vertices and hyperedges are weighted by string labels and indexes are
+/// exposed as plain numbers.
+#[wasm_bindgen]
+#[derive(Debug, Default)]
+pub struct JsHypergraph(LabeledHypergraph);
+
+// Private helper to convert an error to a JavaScript one. The weights held
+// by the errors are already resolved to their labels.
+fn to_js_error(error: HypergraphError) -> JsError {
+    JsError::new(&error.to_string())
+}
+
+#[wasm_bindgen]
+impl JsHypergraph {
+    /// Creates a new hypergraph with no allocation.
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a vertex with a label to the hypergraph.
+    /// Returns the index of the vertex.
+    #[wasm_bindgen(js_name = addVertex)]
+    pub fn add_vertex(&mut self, label: String) -> Result<usize, JsError> {
+        self.0.add_vertex(label).map_err(to_js_error)
+    }
+
+    /// Adds a hyperedge with a label and a cost to the hypergraph.
+    /// The labels of the hyperedges are unique, regardless of their costs.
+    /// Returns the index of the hyperedge.
+    #[wasm_bindgen(js_name = addHyperedge)]
+    pub fn add_hyperedge(
+        &mut self,
+        vertices: Vec<usize>,
+        label: String,
+        cost: usize,
+    ) -> Result<usize, JsError> {
+        self.0
+            .add_hyperedge(vertices, label, cost)
+            .map_err(to_js_error)
+    }
+
+    /// Returns the number of vertices in the hypergraph.
+    #[wasm_bindgen(js_name = countVertices)]
+    pub fn count_vertices(&self) -> usize {
+        self.0.count_vertices()
+    }
+
+    /// Returns the number of hyperedges in the hypergraph.
+    #[wasm_bindgen(js_name = countHyperedges)]
+    pub fn count_hyperedges(&self) -> usize {
+        self.0.count_hyperedges()
+    }
+
+    /// Gets the label of a vertex from its index.
+    #[wasm_bindgen(js_name = getVertexWeight)]
+    pub fn get_vertex_weight(&self, index: usize) -> Result<String, JsError> {
+        self.0.get_vertex_weight(index).map_err(to_js_error)
+    }
+
+    /// Gets the label of a hyperedge from its index.
+    #[wasm_bindgen(js_name = getHyperedgeWeight)]
+    pub fn get_hyperedge_weight(&self, index: usize) -> Result<String, JsError> {
+        self.0
+            .get_hyperedge_weight(index)
+            .map(|(label, _)| label)
+            .map_err(to_js_error)
+    }
+
+    /// Gets the vertices of a hyperedge.
+    #[wasm_bindgen(js_name = getHyperedgeVertices)]
+    pub fn get_hyperedge_vertices(&self, index: usize) -> Result<Vec<usize>, JsError> {
+        self.0.get_hyperedge_vertices(index).map_err(to_js_error)
+    }
+
+    /// Gets the list of all vertices connected from a given vertex.
+    #[wasm_bindgen(js_name = getAdjacentVerticesFrom)]
+    pub fn get_adjacent_vertices_from(&self, index: usize) -> Result<Vec<usize>, JsError> {
+        self.0
+            .get_adjacent_vertices_from(index)
+            .map_err(to_js_error)
+    }
+
+    /// Gets the list of all vertices connected to a given vertex.
+    #[wasm_bindgen(js_name = getAdjacentVerticesTo)]
+    pub fn get_adjacent_vertices_to(&self, index: usize) -> Result<Vec<usize>, JsError> {
+        self.0.get_adjacent_vertices_to(index).map_err(to_js_error)
+    }
+
+    /// Gets the cheapest path of vertices between two vertices.
+    /// Returns an empty array if there's no path.
+    #[wasm_bindgen(js_name = getDijkstraConnections)]
+    pub fn get_dijkstra_connections(&self, from: usize, to: usize) -> Result<Vec<usize>, JsError> {
+        self.0
+            .get_dijkstra_connections(from, to)
+            .map(|path| path.into_iter().map(|(vertex, _)| vertex).collect())
+            .map_err(to_js_error)
+    }
+
+    /// Removes a vertex by index.
+    #[wasm_bindgen(js_name = removeVertex)]
+    pub fn remove_vertex(&mut self, index: usize) -> Result<(), JsError> {
+        self.0.remove_vertex(index).map_err(to_js_error)
+    }
+
+    /// Removes a hyperedge by index.
+    #[wasm_bindgen(js_name = removeHyperedge)]
+    pub fn remove_hyperedge(&mut self, index: usize) -> Result<(), JsError> {
+        self.0.remove_hyperedge(index).map_err(to_js_error)
+    }
+
+    /// Clears the hypergraph.
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+}
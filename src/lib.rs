@@ -20,6 +20,8 @@
 //!
 //! Please notice that the hyperedges and the vertices must implement the
 //! [`HyperedgeTrait`](crate::HyperedgeTrait) and the [`VertexTrait`](crate::VertexTrait) respectively.
+//! Only `Clone` is required, hence owned weights - e.g. `String` - are
+//! supported as well as `Copy` ones.
 //!
 //! ```
 //! use hypergraph::{HyperedgeIndex, Hypergraph, VertexIndex};
@@ -0,0 +1,120 @@
+//! Integration tests.
+
+mod common;
+
+use common::{
+    Hyperedge,
+    Vertex,
+};
+use hypergraph::Hypergraph;
+
+#[test]
+fn integration_adjacency_cache() {
+    // Create a new hypergraph.
+    let mut graph = Hypergraph::<Vertex, Hyperedge>::new();
+
+    // Create some vertices.
+    let a = graph.add_vertex(Vertex::new("one")).unwrap();
+    let b = graph.add_vertex(Vertex::new("two")).unwrap();
+    let c = graph.add_vertex(Vertex::new("three")).unwrap();
+
+    // Add some hyperedges.
+    let alpha = graph
+        .add_hyperedge(vec![a, b, c], Hyperedge::new("one", 10))
+        .unwrap();
+    let beta = graph
+        .add_hyperedge(vec![a, c], Hyperedge::new("two", 1))
+        .unwrap();
+
+    let adjacent_vertices = graph.get_full_adjacent_vertices_from(a).unwrap();
+    let incoming_vertices = graph.get_full_adjacent_vertices_to(c).unwrap();
+    let path = graph.get_dijkstra_connections(a, c).unwrap();
+    let backward_path = graph
+        .get_dijkstra_path_iter(a, c)
+        .unwrap()
+        .collect::<Vec<_>>();
+
+    assert!(!graph.has_adjacency_cache(), "should not have a cache");
+
+    graph.build_adjacency_cache().unwrap();
+
+    assert!(graph.has_adjacency_cache(), "should have a cache");
+    assert!(
+        graph.estimated_memory_bytes().adjacency_cache > 0,
+        "should account for the cache"
+    );
+    assert_eq!(
+        graph.get_full_adjacent_vertices_from(a),
+        Ok(adjacent_vertices),
+        "should get the same adjacent vertices from the cache"
+    );
+    assert_eq!(
+        graph.get_dijkstra_connections(a, c),
+        Ok(path),
+        "should get the same path from the cache"
+    );
+    assert_eq!(
+        graph.get_full_adjacent_vertices_to(c),
+        Ok(incoming_vertices),
+        "should get the same incoming vertices from the cache"
+    );
+    assert_eq!(graph.get_adjacent_vertices_to(c), Ok(vec![a, b]));
+    assert_eq!(graph.get_adjacent_vertices_to(a), Ok(vec![]));
+    assert_eq!(
+        graph
+            .get_dijkstra_path_iter(a, c)
+            .unwrap()
+            .collect::<Vec<_>>(),
+        backward_path,
+        "should get the same backward path from the cache"
+    );
+
+    // Updating the weight of a hyperedge keeps the cache along with the
+    // cached costs up to date.
+    graph
+        .update_hyperedge_weight(beta, Hyperedge::new("two", 100))
+        .unwrap();
+
+    assert!(graph.has_adjacency_cache(), "should keep the cache");
+    assert_eq!(
+        graph.get_dijkstra_connections(a, c),
+        Ok(vec![(a, None), (b, Some(alpha)), (c, Some(alpha))]),
+        "should use the updated cost"
+    );
+    assert_eq!(graph.get_shortest_distance(a, c), Ok(Some(20)));
+
+    // Any mutation of the hyperedges drops the cache.
+    graph.remove_hyperedge(beta).unwrap();
+
+    assert!(!graph.has_adjacency_cache(), "should drop the cache");
+    assert_eq!(
+        graph.get_dijkstra_connections(a, c),
+        Ok(vec![(a, None), (b, Some(alpha)), (c, Some(alpha))]),
+        "should not use a stale cache"
+    );
+    assert_eq!(
+        graph.get_full_adjacent_vertices_to(c),
+        Ok(vec![(b, vec![alpha])]),
+        "should not use a stale cache"
+    );
+
+    // Adding a vertex doesn't change the adjacency of the others.
+    graph.build_adjacency_cache().unwrap();
+
+    let d = graph.add_vertex(Vertex::new("four")).unwrap();
+
+    assert_eq!(
+        graph.get_full_adjacent_vertices_from(d),
+        Ok(vec![]),
+        "should compute the adjacency of a vertex missing from the cache"
+    );
+    assert_eq!(
+        graph.get_adjacent_vertices_to(d),
+        Ok(vec![]),
+        "should compute the adjacency of a vertex missing from the cache"
+    );
+
+    graph.clear_adjacency_cache();
+
+    assert!(!graph.has_adjacency_cache(), "should clear the cache");
+}
@@ -0,0 +1,86 @@
+//! Integration tests.
+
+mod common;
+
+use common::{
+    Hyperedge,
+    Vertex,
+};
+use hypergraph::{
+    Hypergraph,
+    VertexIndex,
+    errors::HypergraphError,
+};
+
+#[test]
+fn integration_adjacency_explanation() {
+    // Create a new hypergraph.
+    let mut graph = Hypergraph::<Vertex, Hyperedge>::new();
+
+    let a = graph.add_vertex(Vertex::new("a")).unwrap();
+    let b = graph.add_vertex(Vertex::new("b")).unwrap();
+    let c = graph.add_vertex(Vertex::new("c")).unwrap();
+
+    let alpha = graph
+        .add_hyperedge(vec![a, b, c, a, b], Hyperedge::new("alpha", 1))
+        .unwrap();
+    let beta = graph
+        .add_hyperedge(vec![c, a, c], Hyperedge::new("beta", 1))
+        .unwrap();
+    let gamma = graph
+        .add_hyperedge(vec![b], Hyperedge::new("gamma", 1))
+        .unwrap();
+
+    // Each adjacency comes along with the windows producing it.
+    assert_eq!(
+        graph.get_adjacent_vertices_from_with_positions(a),
+        Ok(vec![
+            (b, alpha, vec![(0, 1), (3, 4)]),
+            (c, beta, vec![(1, 2)])
+        ])
+    );
+    assert_eq!(
+        graph.get_adjacent_vertices_to_with_positions(a),
+        Ok(vec![(c, alpha, vec![(2, 3)]), (c, beta, vec![(0, 1)])])
+    );
+    assert_eq!(
+        graph
+            .get_adjacent_vertices_from_with_positions(c)
+            .unwrap()
+            .into_iter()
+            .map(|(vertex_index, _, _)| vertex_index)
+            .collect::<Vec<_>>(),
+        vec![a, a],
+        "should explain each hyperedge separately"
+    );
+
+    // The vertices match the plain queries.
+    for vertex_index in [a, b, c].iter() {
+        let mut adjacent = graph
+            .get_adjacent_vertices_from_with_positions(*vertex_index)
+            .unwrap()
+            .into_iter()
+            .map(|(vertex_index, _, _)| vertex_index)
+            .collect::<Vec<_>>();
+
+        adjacent.dedup();
+
+        assert_eq!(
+            Ok(adjacent),
+            graph.get_adjacent_vertices_from(*vertex_index)
+        );
+    }
+
+    // The unaries don't produce any adjacency.
+    assert!(graph.get_vertex_hyperedges(b).unwrap().contains(&gamma));
+    assert_eq!(
+        graph.get_adjacent_vertices_to_with_positions(b),
+        Ok(vec![(a, alpha, vec![(0, 1), (3, 4)])])
+    );
+
+    assert_eq!(
+        graph.get_adjacent_vertices_from_with_positions(VertexIndex(3)),
+        Err(HypergraphError::VertexIndexNotFound(VertexIndex(3))),
+        "should return an explicit error"
+    );
+}
@@ -0,0 +1,173 @@
+//! Integration tests.
+
+mod common;
+
+use common::{
+    Hyperedge,
+    Vertex,
+};
+use hypergraph::{
+    HyperedgeIndex,
+    Hypergraph,
+    VertexIndex,
+    errors::HypergraphError,
+};
+
+#[test]
+fn integration_algebra() {
+    // Create a first snapshot.
+    let mut last_week = Hypergraph::<Vertex, Hyperedge>::new();
+
+    let a = last_week.add_vertex(Vertex::new("a")).unwrap();
+    let b = last_week.add_vertex(Vertex::new("b")).unwrap();
+    let c = last_week.add_vertex(Vertex::new("c")).unwrap();
+
+    last_week
+        .add_hyperedge(vec![a, b], Hyperedge::new("ab", 1))
+        .unwrap();
+    last_week
+        .add_hyperedge(vec![b, c], Hyperedge::new("bc", 1))
+        .unwrap();
+
+    // Create a second snapshot with a different insertion order.
+    let mut this_week = Hypergraph::<Vertex, Hyperedge>::new();
+
+    let d = this_week.add_vertex(Vertex::new("d")).unwrap();
+    let c = this_week.add_vertex(Vertex::new("c")).unwrap();
+    let b = this_week.add_vertex(Vertex::new("b")).unwrap();
+    let a = this_week.add_vertex(Vertex::new("a")).unwrap();
+
+    this_week
+        .add_hyperedge(vec![a, b], Hyperedge::new("ab", 1))
+        .unwrap();
+    // Same weight but reversed vertices.
+    this_week
+        .add_hyperedge(vec![c, b], Hyperedge::new("bc", 1))
+        .unwrap();
+    this_week
+        .add_hyperedge(vec![c, d], Hyperedge::new("cd", 1))
+        .unwrap();
+
+    // Intersection.
+    let intersection = this_week.intersection(&last_week).unwrap();
+
+    assert_eq!(intersection.count_vertices(), 3);
+    assert_eq!(intersection.count_hyperedges(), 1);
+    assert_eq!(
+        (0..3)
+            .map(|index| intersection.get_vertex_weight(VertexIndex(index)))
+            .collect::<Vec<_>>(),
+        vec![
+            Ok(&Vertex::new("c")),
+            Ok(&Vertex::new("b")),
+            Ok(&Vertex::new("a"))
+        ],
+        "should keep the common vertices in order"
+    );
+    assert_eq!(
+        intersection.get_hyperedge_weight(HyperedgeIndex(0)),
+        Ok(&Hyperedge::new("ab", 1)),
+        "should keep the common hyperedge"
+    );
+    assert_eq!(
+        intersection.get_hyperedge_vertices(HyperedgeIndex(0)),
+        Ok(vec![VertexIndex(2), VertexIndex(1)]),
+        "should remap the vertices of the common hyperedge"
+    );
+    assert_eq!(
+        intersection.content_hash(),
+        last_week.intersection(&this_week).unwrap().content_hash(),
+        "should be commutative regardless of the order"
+    );
+
+    // Difference.
+    let difference = this_week.difference(&last_week).unwrap();
+
+    assert_eq!(
+        (0..3)
+            .map(|index| difference.get_vertex_weight(VertexIndex(index)))
+            .collect::<Vec<_>>(),
+        vec![
+            Ok(&Vertex::new("d")),
+            Ok(&Vertex::new("c")),
+            Ok(&Vertex::new("b"))
+        ],
+        "should keep the new vertices and the ones of the new hyperedges"
+    );
+    assert_eq!(difference.count_hyperedges(), 2);
+    assert_eq!(
+        difference.get_hyperedge_weight(HyperedgeIndex(0)),
+        Ok(&Hyperedge::new("bc", 1)),
+        "should keep the updated hyperedge"
+    );
+    assert_eq!(
+        difference.get_hyperedge_vertices(HyperedgeIndex(1)),
+        Ok(vec![VertexIndex(1), VertexIndex(0)]),
+        "should keep the new hyperedge"
+    );
+    assert_eq!(
+        this_week.difference(&this_week).unwrap().count_vertices(),
+        0,
+        "should be empty"
+    );
+}
+
+#[test]
+fn integration_algebra_append() {
+    let mut graph = Hypergraph::<usize, usize>::new();
+
+    let a = graph.add_vertex(1).unwrap();
+    let b = graph.add_vertex(2).unwrap();
+
+    graph.add_hyperedge(vec![a, b], 10).unwrap();
+
+    let mut other = Hypergraph::<usize, usize>::new();
+
+    let c = other.add_vertex(3).unwrap();
+    let d = other.add_vertex(2).unwrap();
+    let e = other.add_vertex_allow_duplicate(1).unwrap();
+
+    let alpha = other.add_hyperedge(vec![c, d], 10).unwrap();
+    let beta = other.add_hyperedge(vec![d, e], 20).unwrap();
+
+    // The clashing weights are relabeled and both copies are preserved.
+    let remapping = graph
+        .append(&other, |weight| weight + 100, |weight| weight + 1000)
+        .unwrap();
+
+    assert_eq!(remapping.vertices[&c], VertexIndex(2));
+    assert_eq!(remapping.vertices[&d], VertexIndex(3));
+    assert_eq!(remapping.vertices[&e], VertexIndex(4));
+    assert_eq!(remapping.hyperedges[&alpha], HyperedgeIndex(1));
+    assert_eq!(remapping.hyperedges[&beta], HyperedgeIndex(2));
+    assert_eq!(graph.get_vertex_weight(VertexIndex(2)), Ok(&3));
+    assert_eq!(graph.get_vertex_weight(VertexIndex(3)), Ok(&102));
+    assert_eq!(graph.get_vertex_weight(VertexIndex(4)), Ok(&1));
+    assert_eq!(graph.get_hyperedge_weight(HyperedgeIndex(1)), Ok(&1010));
+    assert_eq!(
+        graph.get_hyperedge_vertices(HyperedgeIndex(1)),
+        Ok(vec![VertexIndex(2), VertexIndex(3)])
+    );
+    assert_eq!(graph.get_hyperedge_weight(HyperedgeIndex(2)), Ok(&20));
+    assert_eq!(graph.count_vertices(), 5);
+    assert_eq!(graph.count_hyperedges(), 3);
+
+    // The appended entities are removed on error.
+    let before = graph.clone();
+
+    assert_eq!(
+        graph.append(
+            &other,
+            |weight| if *weight == 3 { 103 } else { *weight },
+            |weight| weight + 2000
+        ),
+        Err(HypergraphError::VertexWeightAlreadyAssigned(VertexIndex(1))),
+        "should return an explicit error"
+    );
+    assert_eq!(graph.count_vertices(), before.count_vertices());
+    assert_eq!(graph.count_hyperedges(), before.count_hyperedges());
+    assert_eq!(
+        graph.get_vertex_weight(VertexIndex(5)),
+        Err(HypergraphError::VertexIndexNotFound(VertexIndex(5)))
+    );
+}
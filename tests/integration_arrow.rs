@@ -0,0 +1,117 @@
+//! Integration tests.
+
+#![cfg(feature = "arrow")]
+
+use arrow_array::{
+    RecordBatch,
+    UInt64Array,
+};
+use hypergraph::{
+    Hypergraph,
+    Progress,
+    errors::HypergraphError,
+};
+
+#[test]
+fn integration_arrow() {
+    // Create a new hypergraph.
+    let mut graph = Hypergraph::<usize, usize>::new();
+
+    // Create some vertices.
+    let a = graph.add_vertex(1).unwrap();
+    let b = graph.add_vertex(2).unwrap();
+    let c = graph.add_vertex(3).unwrap();
+    let d = graph.add_vertex(4).unwrap();
+
+    // Create some hyperedges.
+    graph.add_hyperedge(vec![a, b, c], 10).unwrap();
+    graph.add_hyperedge(vec![d, d], 20).unwrap();
+
+    // Remove a vertex to get a hole in the stable indexes.
+    graph.remove_vertex(a).unwrap();
+
+    let (vertices, hyperedges) = graph.to_arrow().unwrap();
+
+    assert_eq!(vertices.num_rows(), 3, "should export three vertices");
+    assert_eq!(hyperedges.num_rows(), 2, "should export two hyperedges");
+    assert_eq!(
+        vertices
+            .column_by_name("index")
+            .unwrap()
+            .as_any()
+            .downcast_ref::<UInt64Array>()
+            .unwrap()
+            .values()
+            .to_vec(),
+        vec![3, 1, 2],
+        "should export the stable indexes in the internal order"
+    );
+
+    // Round-trip.
+    let imported = Hypergraph::<usize, usize>::from_arrow(&vertices, &hyperedges).unwrap();
+
+    assert_eq!(imported.count_vertices(), 3, "should import three vertices");
+    assert_eq!(
+        imported.count_hyperedges(),
+        2,
+        "should import two hyperedges"
+    );
+    assert_eq!(
+        imported.into_iter().collect::<Vec<(usize, Vec<usize>)>>(),
+        vec![(10, vec![2, 3]), (20, vec![4, 4])],
+        "should preserve the weights and the vertices of the hyperedges"
+    );
+
+    // Round-trip with progress reports.
+    let mut reports = vec![];
+
+    Hypergraph::<usize, usize>::from_arrow_with_progress(&vertices, &hyperedges, |progress| {
+        reports.push(progress)
+    })
+    .unwrap();
+
+    assert_eq!(
+        reports,
+        vec![
+            Progress {
+                stage: "vertices",
+                completed: 1,
+                total: 3
+            },
+            Progress {
+                stage: "vertices",
+                completed: 2,
+                total: 3
+            },
+            Progress {
+                stage: "vertices",
+                completed: 3,
+                total: 3
+            },
+            Progress {
+                stage: "hyperedges",
+                completed: 1,
+                total: 2
+            },
+            Progress {
+                stage: "hyperedges",
+                completed: 2,
+                total: 2
+            },
+        ],
+        "should report the progress of each row"
+    );
+
+    // Invalid batches.
+    assert_eq!(
+        Hypergraph::<usize, usize>::from_arrow(
+            &RecordBatch::new_empty(hyperedges.schema()),
+            &vertices
+        )
+        .map(|graph| graph.count_vertices()),
+        Err(HypergraphError::ArrowInvalidRecordBatch(
+            "missing list column vertices".to_owned()
+        )),
+        "should return an explicit error"
+    );
+}
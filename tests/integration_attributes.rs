@@ -0,0 +1,105 @@
+//! Integration tests.
+
+mod common;
+
+use common::{
+    Hyperedge,
+    Vertex,
+};
+use hypergraph::{
+    Hypergraph,
+    Value,
+    VertexIndex,
+    errors::HypergraphError,
+};
+
+#[test]
+fn integration_attributes() {
+    // Create a new hypergraph.
+    let mut graph = Hypergraph::<Vertex, Hyperedge>::new();
+
+    // Create some vertices.
+    let a = graph.add_vertex(Vertex::new("one")).unwrap();
+    let b = graph.add_vertex(Vertex::new("two")).unwrap();
+    let c = graph.add_vertex(Vertex::new("three")).unwrap();
+
+    graph
+        .add_hyperedge(vec![a, b], Hyperedge::new("one", 10))
+        .unwrap();
+
+    // Attributes don't need to be unique.
+    assert_eq!(
+        graph.set_vertex_attr(a, "name", "Anna"),
+        Ok(None),
+        "should set a new attribute"
+    );
+    assert_eq!(
+        graph.set_vertex_attr(b, "name", "Anna"),
+        Ok(None),
+        "should set the same attribute value on another vertex"
+    );
+    assert_eq!(
+        graph.set_vertex_attr(a, "age", 42),
+        Ok(None),
+        "should set another attribute"
+    );
+    assert_eq!(
+        graph.set_vertex_attr(a, "age", 43),
+        Ok(Some(Value::Integer(42))),
+        "should return the previous value"
+    );
+    assert_eq!(
+        graph.get_vertex_attr(a, "name"),
+        Ok(Some(&Value::String("Anna".to_owned()))),
+        "should get the attribute"
+    );
+    assert_eq!(
+        graph.get_vertex_attr(c, "name"),
+        Ok(None),
+        "should get no attribute"
+    );
+    assert_eq!(
+        graph.set_vertex_attr(VertexIndex(9), "name", true),
+        Err(HypergraphError::VertexIndexNotFound(VertexIndex(9))),
+        "should return an explicit error"
+    );
+    assert_eq!(
+        graph.remove_vertex_attr(a, "age"),
+        Ok(Some(Value::Integer(43))),
+        "should remove the attribute"
+    );
+    assert_eq!(
+        graph.get_vertex_attr(a, "age"),
+        Ok(None),
+        "should get no attribute"
+    );
+
+    // Attributes follow the stable indexes.
+    graph.set_vertex_attr(c, "score", 0.5).unwrap();
+    graph.remove_vertex(a).unwrap();
+
+    assert_eq!(
+        graph.get_vertex_attr(b, "name"),
+        Ok(Some(&Value::from("Anna"))),
+        "should keep the attributes of the other vertices"
+    );
+    assert_eq!(
+        graph.get_vertex_attr(c, "score"),
+        Ok(Some(&Value::Float(0.5))),
+        "should keep the attributes of the swapped vertex"
+    );
+
+    // Pruning also drops the attributes.
+    graph.prune_isolated_vertices().unwrap();
+
+    assert_eq!(
+        graph.get_vertex_attr(c, "score"),
+        Err(HypergraphError::VertexIndexNotFound(c)),
+        "should remove the pruned vertex"
+    );
+    assert_eq!(
+        graph.get_vertex_attr(b, "name"),
+        Ok(Some(&Value::from("Anna"))),
+        "should keep the attributes of the remaining vertices"
+    );
+}
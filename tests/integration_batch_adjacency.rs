@@ -0,0 +1,67 @@
+//! Integration tests.
+
+use hypergraph::{
+    Hypergraph,
+    VertexIndex,
+    errors::HypergraphError,
+};
+
+#[test]
+fn integration_batch_adjacency() {
+    // Create a new hypergraph.
+    let mut graph = Hypergraph::<usize, usize>::new();
+
+    // Create some vertices.
+    let vertices = (0..8)
+        .map(|weight| graph.add_vertex(weight).unwrap())
+        .collect::<Vec<VertexIndex>>();
+
+    // Create some hyperedges, with shared vertices, repetitions and
+    // self-loops.
+    graph
+        .add_hyperedge(vec![vertices[0], vertices[1], vertices[2]], 0)
+        .unwrap();
+    graph
+        .add_hyperedge(vec![vertices[1], vertices[0], vertices[1], vertices[3]], 1)
+        .unwrap();
+    graph
+        .add_hyperedge(vec![vertices[2], vertices[2], vertices[4]], 2)
+        .unwrap();
+    graph
+        .add_hyperedge(vec![vertices[5], vertices[0], vertices[6]], 3)
+        .unwrap();
+    graph.add_hyperedge(vec![vertices[6]], 4).unwrap();
+
+    // Remove a vertex to get holes in the stable indexes.
+    graph.remove_vertex(vertices[3]).unwrap();
+
+    let from = vec![
+        vertices[0],
+        vertices[1],
+        vertices[2],
+        vertices[6],
+        vertices[7],
+        vertices[0],
+    ];
+
+    assert_eq!(
+        graph.get_adjacent_vertices_from_many(&from),
+        from.iter()
+            .map(|vertex_index| graph.get_adjacent_vertices_from(*vertex_index))
+            .collect::<Result<Vec<Vec<VertexIndex>>, HypergraphError>>(),
+        "should match the single-vertex variant, duplicates included"
+    );
+    assert_eq!(
+        graph.get_adjacent_vertices_from_many(&[vertices[0], vertices[2]]),
+        Ok(vec![
+            vec![vertices[1], vertices[6]],
+            vec![vertices[2], vertices[4]]
+        ])
+    );
+    assert_eq!(graph.get_adjacent_vertices_from_many(&[]), Ok(vec![]));
+    assert_eq!(
+        graph.get_adjacent_vertices_from_many(&[vertices[0], vertices[3]]),
+        Err(HypergraphError::VertexIndexNotFound(vertices[3])),
+        "should return an explicit error"
+    );
+}
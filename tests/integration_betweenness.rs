@@ -0,0 +1,96 @@
+//! Integration tests.
+
+mod common;
+
+use common::{
+    Hyperedge,
+    Vertex,
+};
+use hypergraph::{
+    Hypergraph,
+    VertexIndex,
+};
+
+// Private helper creating a diamond followed by a tail, i.e. a -> b -> d,
+// a -> c -> d and d -> e.
+fn create_graph() -> Hypergraph<Vertex<'static>, Hyperedge<'static>> {
+    let mut graph = Hypergraph::<Vertex, Hyperedge>::new();
+
+    let a = graph.add_vertex(Vertex::new("a")).unwrap();
+    let b = graph.add_vertex(Vertex::new("b")).unwrap();
+    let c = graph.add_vertex(Vertex::new("c")).unwrap();
+    let d = graph.add_vertex(Vertex::new("d")).unwrap();
+    let e = graph.add_vertex(Vertex::new("e")).unwrap();
+
+    graph
+        .add_hyperedge(vec![a, b, d], Hyperedge::new("one", 1))
+        .unwrap();
+    graph
+        .add_hyperedge(vec![a, c, d, e], Hyperedge::new("two", 1))
+        .unwrap();
+    // Parallel hyperedges and self-loops don't alter the shortest paths.
+    graph
+        .add_hyperedge(vec![a, b, b], Hyperedge::new("three", 1))
+        .unwrap();
+
+    graph
+}
+
+#[test]
+fn integration_betweenness() {
+    let graph = create_graph();
+
+    let betweenness = graph.betweenness_centrality().unwrap();
+
+    assert_eq!(
+        (0..5)
+            .map(|index| betweenness[&VertexIndex(index)])
+            .collect::<Vec<f64>>(),
+        vec![0., 1., 1., 3., 0.],
+        "should share the paths of the diamond between b and c"
+    );
+    assert!(
+        Hypergraph::<Vertex, Hyperedge>::new()
+            .betweenness_centrality()
+            .unwrap()
+            .is_empty(),
+        "should be empty"
+    );
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn integration_approximate_betweenness() {
+    use rand::{
+        SeedableRng,
+        rngs::StdRng,
+    };
+
+    let graph = create_graph();
+
+    let mut rng = StdRng::seed_from_u64(42);
+
+    assert_eq!(
+        graph.approximate_betweenness_centrality(10, &mut rng),
+        graph.betweenness_centrality(),
+        "should be exact with as many pivots as vertices"
+    );
+
+    let first = graph
+        .approximate_betweenness_centrality(2, &mut StdRng::seed_from_u64(7))
+        .unwrap();
+    let second = graph
+        .approximate_betweenness_centrality(2, &mut StdRng::seed_from_u64(7))
+        .unwrap();
+
+    assert_eq!(first, second, "should be reproducible with the same seed");
+    assert_eq!(first.len(), 5, "should cover all the vertices");
+    assert!(
+        graph
+            .approximate_betweenness_centrality(0, &mut rng)
+            .unwrap()
+            .values()
+            .all(|value| *value == 0.),
+        "should be null without pivots"
+    );
+}
@@ -0,0 +1,82 @@
+//! Integration tests.
+
+use hypergraph::{
+    BipartiteGraph,
+    BipartiteIncidence,
+    Hypergraph,
+};
+
+#[test]
+fn integration_bipartite() {
+    // Create a new hypergraph.
+    let mut graph = Hypergraph::<usize, usize>::new();
+
+    assert_eq!(
+        graph.to_bipartite(),
+        Ok(BipartiteGraph {
+            vertices: vec![],
+            hyperedges: vec![],
+            incidences: vec![],
+        }),
+        "should convert an empty hypergraph"
+    );
+
+    // Create some vertices.
+    let a = graph.add_vertex(1).unwrap();
+    let b = graph.add_vertex(2).unwrap();
+    let c = graph.add_vertex(3).unwrap();
+    let d = graph.add_vertex(4).unwrap();
+
+    // Create some hyperedges.
+    let alpha = graph.add_hyperedge(vec![a, b, a], 10).unwrap();
+    let beta = graph.add_hyperedge(vec![c, b], 20).unwrap();
+
+    // Remove a vertex to get an internal order differing from the stable
+    // one.
+    graph.remove_vertex(a).unwrap();
+
+    let bipartite = graph.to_bipartite().unwrap();
+
+    assert_eq!(
+        bipartite.vertices,
+        vec![(d, 4), (b, 2), (c, 3)],
+        "should keep the isolated vertices"
+    );
+    assert_eq!(bipartite.hyperedges, vec![(alpha, 10), (beta, 20)]);
+    assert_eq!(
+        bipartite.incidences,
+        vec![
+            BipartiteIncidence {
+                hyperedge: 0,
+                vertex: 1,
+                position: 0,
+            },
+            BipartiteIncidence {
+                hyperedge: 1,
+                vertex: 2,
+                position: 0,
+            },
+            BipartiteIncidence {
+                hyperedge: 1,
+                vertex: 1,
+                position: 1,
+            },
+        ]
+    );
+
+    // Each incidence matches the vertices of the hyperedges.
+    for BipartiteIncidence {
+        hyperedge,
+        vertex,
+        position,
+    } in bipartite.incidences
+    {
+        let (hyperedge_index, _) = bipartite.hyperedges[hyperedge];
+        let (vertex_index, _) = bipartite.vertices[vertex];
+
+        assert_eq!(
+            graph.get_hyperedge_vertices(hyperedge_index).unwrap()[position],
+            vertex_index
+        );
+    }
+}
@@ -0,0 +1,96 @@
+//! Integration tests.
+
+#![cfg(feature = "roaring")]
+
+use hypergraph::{
+    HyperedgeIndex,
+    Hypergraph,
+    VertexIndex,
+    errors::HypergraphError,
+};
+use roaring::RoaringTreemap;
+
+// Private helper to build a bitmap from some vertices.
+fn bitmap(vertices: &[VertexIndex]) -> RoaringTreemap {
+    vertices.iter().map(|vertex| vertex.0 as u64).collect()
+}
+
+#[test]
+fn integration_bitmaps() {
+    let mut graph = Hypergraph::<usize, usize>::new();
+
+    let a = graph.add_vertex(1).unwrap();
+    let b = graph.add_vertex(2).unwrap();
+    let c = graph.add_vertex(3).unwrap();
+    let d = graph.add_vertex(4).unwrap();
+
+    let alpha = graph.add_hyperedge(vec![a, b, a, c], 10).unwrap();
+    let beta = graph.add_hyperedge(vec![c, b], 20).unwrap();
+    let gamma = graph.add_hyperedge(vec![d, c], 30).unwrap();
+
+    // The bitmaps only hold the unique vertices.
+    assert_eq!(graph.get_hyperedge_bitmap(alpha), Ok(&bitmap(&[a, b, c])));
+    assert_eq!(
+        graph.get_hyperedges_bitmap_intersection(&[alpha, beta, gamma]),
+        Ok(bitmap(&[c]))
+    );
+    assert_eq!(
+        graph.get_hyperedges_bitmap_union(&[beta, gamma]),
+        Ok(bitmap(&[b, c, d]))
+    );
+    assert_eq!(graph.get_hyperedges_bitmap_union(&[]), Ok(bitmap(&[])));
+    assert_eq!(
+        graph.get_hyperedges_bitmap_intersection(&[]),
+        Err(HypergraphError::HyperedgesInvalidIntersections),
+        "should return an explicit error"
+    );
+    assert_eq!(
+        graph.get_hyperedge_bitmap(HyperedgeIndex(3)),
+        Err(HypergraphError::HyperedgeIndexNotFound(HyperedgeIndex(3))),
+        "should return an explicit error"
+    );
+
+    // The direction is ignored by the subset tests.
+    assert_eq!(graph.is_hyperedge_subset(beta, alpha), Ok(true));
+    assert_eq!(graph.is_hyperedge_subset(alpha, beta), Ok(false));
+    assert_eq!(graph.is_hyperedge_subset(gamma, alpha), Ok(false));
+
+    // The bitmaps are kept in sync by the mutations.
+    graph
+        .update_hyperedge_vertices(gamma, vec![c, d, b])
+        .unwrap();
+
+    assert_eq!(graph.is_hyperedge_subset(beta, gamma), Ok(true));
+
+    graph.remove_hyperedge(alpha).unwrap();
+    graph.remove_vertex(b).unwrap();
+
+    assert_eq!(graph.get_hyperedge_bitmap(beta), Ok(&bitmap(&[c])));
+    assert_eq!(graph.get_hyperedge_bitmap(gamma), Ok(&bitmap(&[c, d])));
+
+    graph
+        .sort_hyperedges_by(|hyperedge| *hyperedge.weight(), false)
+        .unwrap();
+    graph.remap_indexes().unwrap();
+
+    assert_eq!(
+        graph.get_hyperedge_bitmap(HyperedgeIndex(1)),
+        Ok(&bitmap(&[VertexIndex(1), VertexIndex(2)]))
+    );
+
+    // Same after a round-trip via a snapshot.
+    let mut bytes = vec![];
+
+    graph.write_snapshot(&mut bytes).unwrap();
+
+    let loaded = Hypergraph::<usize, usize>::read_snapshot(&bytes[..]).unwrap();
+
+    assert_eq!(
+        loaded.get_hyperedge_bitmap(HyperedgeIndex(1)),
+        Ok(&bitmap(&[VertexIndex(1), VertexIndex(2)]))
+    );
+    assert_eq!(
+        loaded.get_hyperedges_bitmap_intersection(&[HyperedgeIndex(0), HyperedgeIndex(1)]),
+        Ok(bitmap(&[VertexIndex(1)]))
+    );
+}
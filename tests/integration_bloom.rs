@@ -0,0 +1,81 @@
+//! Integration tests.
+
+#![cfg(feature = "bloom")]
+
+use hypergraph::{
+    Hypergraph,
+    VertexIndex,
+    errors::HypergraphError,
+};
+
+#[test]
+fn integration_bloom() {
+    let mut graph = Hypergraph::<usize, usize>::new();
+
+    let a = graph.add_vertex(1).unwrap();
+    let b = graph.add_vertex(2).unwrap();
+    let c = graph.add_vertex(3).unwrap();
+
+    let alpha = graph.add_hyperedge(vec![a, b], 10).unwrap();
+
+    assert!(!graph.has_hyperedge_filter());
+    assert_eq!(
+        graph.maybe_contains_hyperedge(&[a, b], &10),
+        Err(HypergraphError::HyperedgeFilterNotEnabled),
+        "should return an explicit error"
+    );
+    assert_eq!(
+        graph.rebuild_hyperedge_filter(),
+        Err(HypergraphError::HyperedgeFilterNotEnabled),
+        "should return an explicit error"
+    );
+
+    // The existing hyperedges are inserted when enabling the filter.
+    graph.enable_hyperedge_filter(1_000, 0.001).unwrap();
+
+    assert!(graph.has_hyperedge_filter());
+    assert_eq!(graph.maybe_contains_hyperedge(&[a, b], &10), Ok(true));
+    assert_eq!(
+        graph.maybe_contains_hyperedge(&[b, a, b], &10),
+        Ok(true),
+        "should ignore the order and the repetitions of the vertices"
+    );
+
+    // The filter is maintained on insert and on update.
+    let beta = graph.add_hyperedge(vec![b, c], 20).unwrap();
+
+    assert_eq!(graph.maybe_contains_hyperedge(&[c, b], &20), Ok(true));
+
+    graph.update_hyperedge_weight(beta, 30).unwrap();
+
+    assert_eq!(graph.maybe_contains_hyperedge(&[b, c], &30), Ok(true));
+
+    // No false negatives nor, with such a rate, false positives here.
+    assert_eq!(graph.maybe_contains_hyperedge(&[a, c], &10), Ok(false));
+    assert_eq!(graph.maybe_contains_hyperedge(&[a, b], &20), Ok(false));
+    assert_eq!(
+        graph.maybe_contains_hyperedge(&[a, b, VertexIndex(42)], &10),
+        Ok(false)
+    );
+
+    // The removed hyperedges stay in the filter until it is rebuilt.
+    graph.remove_hyperedge(alpha).unwrap();
+
+    assert_eq!(graph.maybe_contains_hyperedge(&[a, b], &10), Ok(true));
+    assert_eq!(graph.maybe_contains_hyperedge(&[b, c], &20), Ok(true));
+
+    graph.rebuild_hyperedge_filter().unwrap();
+
+    assert_eq!(graph.maybe_contains_hyperedge(&[a, b], &10), Ok(false));
+    assert_eq!(graph.maybe_contains_hyperedge(&[b, c], &20), Ok(false));
+    assert_eq!(graph.maybe_contains_hyperedge(&[b, c], &30), Ok(true));
+
+    // Clearing the hyperedges keeps the filter enabled but empty.
+    graph.clear_hyperedges().unwrap();
+
+    assert_eq!(graph.maybe_contains_hyperedge(&[b, c], &30), Ok(false));
+
+    graph.disable_hyperedge_filter();
+
+    assert!(!graph.has_hyperedge_filter());
+}
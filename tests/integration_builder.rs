@@ -0,0 +1,80 @@
+//! Integration tests.
+
+use hypergraph::{
+    Constraints,
+    DuplicatePolicy,
+    HyperedgeIndex,
+    HypergraphBuilder,
+    VertexIndex,
+    errors::HypergraphError,
+};
+
+#[test]
+fn integration_builder() {
+    // Record some vertices and hyperedges.
+    let mut builder = HypergraphBuilder::<usize, usize>::with_capacity(3, 2);
+
+    let a = builder.add_vertex(1);
+    let b = builder.add_vertex(2);
+    let c = builder.add_vertex(3);
+
+    assert_eq!(builder.add_hyperedge(vec![a, b], 10), HyperedgeIndex(0));
+    assert_eq!(builder.add_hyperedge(vec![b, c, a], 20), HyperedgeIndex(1));
+
+    // The handles match the indexes of the built hypergraph.
+    let graph = builder.build().unwrap();
+
+    assert_eq!(graph.count_vertices(), 3);
+    assert_eq!(graph.count_hyperedges(), 2);
+    assert_eq!(graph.get_vertex_weight(c), Ok(&3));
+    assert_eq!(
+        graph.get_hyperedge_vertices(HyperedgeIndex(1)),
+        Ok(vec![b, c, a])
+    );
+
+    // Chain the calls.
+    let graph = HypergraphBuilder::<usize, usize>::new()
+        .vertex(1)
+        .vertex(2)
+        .hyperedge(vec![VertexIndex(0), VertexIndex(1)], 10)
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        graph.get_hyperedge_vertices(HyperedgeIndex(0)),
+        Ok(vec![VertexIndex(0), VertexIndex(1)])
+    );
+
+    // The validation is deferred to the build.
+    let mut builder = HypergraphBuilder::<usize, usize>::new();
+
+    let a = builder.add_vertex(1);
+
+    builder.add_hyperedge(vec![a, VertexIndex(1)], 10);
+
+    assert_eq!(
+        builder.build().err(),
+        Some(HypergraphError::VertexIndexNotFound(VertexIndex(1)))
+    );
+
+    // The duplicate policy and the constraints are applied.
+    let builder = HypergraphBuilder::<usize, usize>::new()
+        .duplicate_policy(DuplicatePolicy::RejectDuplicateVertexLists)
+        .vertex(1)
+        .vertex(2)
+        .hyperedge(vec![VertexIndex(0), VertexIndex(1)], 10)
+        .hyperedge(vec![VertexIndex(0), VertexIndex(1)], 20);
+
+    assert!(builder.build().is_err());
+
+    let graph = HypergraphBuilder::<usize, usize>::new()
+        .constraints(Constraints {
+            forbid_self_loops: true,
+            ..Constraints::default()
+        })
+        .vertex(1)
+        .build()
+        .unwrap();
+
+    assert!(graph.get_constraints().forbid_self_loops);
+}
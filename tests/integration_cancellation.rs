@@ -0,0 +1,62 @@
+//! Integration tests.
+
+mod common;
+
+use std::sync::atomic::{
+    AtomicBool,
+    Ordering,
+};
+
+use common::{
+    Hyperedge,
+    Vertex,
+};
+use hypergraph::{
+    Hypergraph,
+    errors::HypergraphError,
+};
+
+#[test]
+fn integration_cancellation() {
+    // Create a new hypergraph.
+    let mut graph = Hypergraph::<Vertex, Hyperedge>::new();
+
+    // Create some vertices.
+    let a = graph.add_vertex(Vertex::new("one")).unwrap();
+    let b = graph.add_vertex(Vertex::new("two")).unwrap();
+    let c = graph.add_vertex(Vertex::new("three")).unwrap();
+
+    // Add some hyperedges.
+    let alpha = graph
+        .add_hyperedge(vec![a, b, c], Hyperedge::new("one", 1))
+        .unwrap();
+    let beta = graph
+        .add_hyperedge(vec![b, c], Hyperedge::new("two", 1))
+        .unwrap();
+
+    let cancelled = AtomicBool::new(false);
+
+    assert_eq!(
+        graph.get_dijkstra_connections_cancellable(a, c, &cancelled),
+        graph.get_dijkstra_connections(a, c),
+        "should behave like the regular query when not cancelled"
+    );
+    assert_eq!(
+        graph.get_hyperedges_intersections_cancellable(vec![alpha, beta], &cancelled),
+        Ok(vec![b, c]),
+        "should get the intersections when not cancelled"
+    );
+
+    cancelled.store(true, Ordering::Relaxed);
+
+    assert_eq!(
+        graph.get_dijkstra_connections_cancellable(a, c, &cancelled),
+        Err(HypergraphError::Cancelled),
+        "should abort the Dijkstra query"
+    );
+    assert_eq!(
+        graph.get_hyperedges_intersections_cancellable(vec![alpha, beta], &cancelled),
+        Err(HypergraphError::Cancelled),
+        "should abort the intersections query"
+    );
+}
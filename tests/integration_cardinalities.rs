@@ -0,0 +1,84 @@
+//! Integration tests.
+
+use hypergraph::{
+    HyperedgeIndex,
+    Hypergraph,
+};
+
+#[test]
+fn integration_cardinalities() {
+    // Create a new hypergraph.
+    let mut graph = Hypergraph::<usize, usize>::new();
+
+    // Create some vertices.
+    let a = graph.add_vertex(1).unwrap();
+    let b = graph.add_vertex(2).unwrap();
+    let c = graph.add_vertex(3).unwrap();
+    let d = graph.add_vertex(4).unwrap();
+
+    // Create some hyperedges.
+    let alpha = graph.add_hyperedge(vec![a, b], 10).unwrap();
+    let beta = graph.add_hyperedge(vec![b, c, d], 20).unwrap();
+    let gamma = graph.add_hyperedge(vec![c, d], 30).unwrap();
+    let delta = graph.add_hyperedge(vec![a, a, b, c], 40).unwrap();
+
+    assert_eq!(graph.count_hyperedges_with_cardinality(1), 0);
+    assert_eq!(graph.count_hyperedges_with_cardinality(2), 2);
+    assert_eq!(graph.count_hyperedges_with_cardinality(3), 1);
+    assert_eq!(graph.count_hyperedges_with_cardinality(4), 1);
+    assert_eq!(
+        graph
+            .iter_hyperedges_with_cardinality(2..=2)
+            .collect::<Vec<_>>(),
+        vec![alpha, gamma]
+    );
+    assert_eq!(
+        graph
+            .iter_hyperedges_with_cardinality(3..)
+            .collect::<Vec<_>>(),
+        vec![beta, delta]
+    );
+    assert_eq!(graph.iter_hyperedges_with_cardinality(5..).count(), 0);
+
+    // Update some vertices.
+    graph
+        .update_hyperedge_vertices(alpha, vec![a, b, c])
+        .unwrap();
+
+    assert_eq!(graph.count_hyperedges_with_cardinality(2), 1);
+    assert_eq!(graph.count_hyperedges_with_cardinality(3), 2);
+
+    // Remove a vertex, which shrinks the hyperedges containing it.
+    graph.remove_vertex(a).unwrap();
+
+    assert_eq!(graph.count_hyperedges_with_cardinality(2), 3);
+    assert_eq!(graph.count_hyperedges_with_cardinality(3), 1);
+    assert_eq!(graph.count_hyperedges_with_cardinality(4), 0);
+
+    // Remove a hyperedge.
+    graph.remove_hyperedge(gamma).unwrap();
+
+    assert_eq!(graph.count_hyperedges_with_cardinality(2), 2);
+
+    // Prune the hyperedges.
+    graph.prune_hyperedges_by_cardinality(3, 3).unwrap();
+
+    assert_eq!(graph.count_hyperedges_with_cardinality(2), 0);
+    assert_eq!(
+        graph
+            .iter_hyperedges_with_cardinality(..)
+            .collect::<Vec<_>>(),
+        vec![beta]
+    );
+
+    // Clear the hyperedges.
+    graph.clear_hyperedges().unwrap();
+
+    assert_eq!(graph.count_hyperedges_with_cardinality(3), 0);
+    assert_eq!(
+        graph
+            .iter_hyperedges_with_cardinality(..)
+            .collect::<Vec<HyperedgeIndex>>(),
+        vec![]
+    );
+}
@@ -0,0 +1,81 @@
+//! Integration tests.
+
+mod common;
+
+use common::{
+    Hyperedge,
+    Vertex,
+};
+use hypergraph::{
+    HyperedgeIndex,
+    Hypergraph,
+    VertexIndex,
+};
+
+#[test]
+fn integration_components() {
+    // Create a new hypergraph.
+    let mut graph = Hypergraph::<Vertex, Hyperedge>::new();
+
+    // Create some vertices.
+    let a = graph.add_vertex(Vertex::new("a")).unwrap();
+    let b = graph.add_vertex(Vertex::new("b")).unwrap();
+    let c = graph.add_vertex(Vertex::new("c")).unwrap();
+    let d = graph.add_vertex(Vertex::new("d")).unwrap();
+    let e = graph.add_vertex(Vertex::new("e")).unwrap();
+    let f = graph.add_vertex(Vertex::new("f")).unwrap();
+
+    // Two cycles linked together and an isolated vertex.
+    graph
+        .add_hyperedge(vec![a, b, a], Hyperedge::new("ab", 1))
+        .unwrap();
+    graph
+        .add_hyperedge(vec![b, c, d], Hyperedge::new("bcd", 1))
+        .unwrap();
+    graph
+        .add_hyperedge(vec![d, c], Hyperedge::new("dc", 1))
+        .unwrap();
+    graph
+        .add_hyperedge(vec![a, e, e], Hyperedge::new("ae", 1))
+        .unwrap();
+
+    assert_eq!(
+        graph.strongly_connected_components(),
+        Ok(vec![vec![a, b], vec![c, d], vec![e], vec![f]]),
+        "should get the strongly connected components"
+    );
+
+    let (condensation, components) = graph.condense().unwrap();
+
+    assert_eq!(components, vec![vec![a, b], vec![c, d], vec![e], vec![f]]);
+    assert_eq!(condensation.count_vertices(), 4);
+    assert_eq!(
+        condensation.get_vertex_weight(VertexIndex(1)),
+        Ok(&Vertex::new("c")),
+        "should weight the component with its first vertex"
+    );
+    assert_eq!(
+        condensation.count_hyperedges(),
+        2,
+        "should drop the hyperedges within a single component"
+    );
+    assert_eq!(
+        condensation.get_hyperedge_weight(HyperedgeIndex(0)),
+        Ok(&Hyperedge::new("bcd", 1))
+    );
+    assert_eq!(
+        condensation.get_hyperedge_vertices(HyperedgeIndex(0)),
+        Ok(vec![VertexIndex(0), VertexIndex(1)]),
+        "should drop the steps within a component"
+    );
+    assert_eq!(
+        condensation.get_hyperedge_vertices(HyperedgeIndex(1)),
+        Ok(vec![VertexIndex(0), VertexIndex(2)]),
+        "should remap the vertices to the components"
+    );
+    assert_eq!(
+        condensation.strongly_connected_components().unwrap().len(),
+        4,
+        "should be acyclic"
+    );
+}
@@ -0,0 +1,264 @@
+//! Integration tests.
+
+use hypergraph::{
+    Constraints,
+    Hypergraph,
+    HypergraphBuilder,
+    errors::HypergraphError,
+};
+
+#[test]
+fn integration_constraints() {
+    // Create a new hypergraph with some constraints.
+    let constraints = Constraints {
+        max_hyperedge_cardinality: Some(3),
+        max_vertex_degree: Some(2),
+        forbid_self_loops: true,
+        ..Constraints::default()
+    };
+    let mut graph = Hypergraph::<usize, usize>::with_constraints(constraints);
+
+    assert_eq!(graph.get_constraints(), constraints);
+    assert_eq!(
+        Hypergraph::<usize, usize>::new().get_constraints(),
+        Constraints::default(),
+        "should enforce no constraint by default"
+    );
+
+    // Create some vertices.
+    let a = graph.add_vertex(1).unwrap();
+    let b = graph.add_vertex(2).unwrap();
+    let c = graph.add_vertex(3).unwrap();
+    let d = graph.add_vertex(4).unwrap();
+
+    // Maximum cardinality.
+    assert_eq!(
+        graph.add_hyperedge(vec![a, b, c, d], 10),
+        Err(HypergraphError::HyperedgeCardinalityExceeded {
+            cardinality: 4,
+            max: 3
+        })
+    );
+
+    // Self-loops.
+    assert_eq!(
+        graph.add_hyperedge(vec![a, b, b], 10),
+        Err(HypergraphError::HyperedgeSelfLoopForbidden(b))
+    );
+
+    let alpha = graph.add_hyperedge(vec![a, b, a], 10).unwrap();
+    let beta = graph.add_hyperedge(vec![b, c], 20).unwrap();
+
+    assert_eq!(
+        graph.update_hyperedge_vertices(beta, vec![c, c]),
+        Err(HypergraphError::HyperedgeSelfLoopForbidden(c))
+    );
+
+    // Removing a vertex surrounded by the same one would create a self-loop.
+    assert_eq!(
+        graph.remove_vertex(b),
+        Err(HypergraphError::HyperedgeSelfLoopForbidden(a))
+    );
+    assert_eq!(
+        graph.get_hyperedge_vertices(beta),
+        Ok(vec![b, c]),
+        "should not mutate the hypergraph"
+    );
+
+    // Maximum degree.
+    assert_eq!(
+        graph.add_hyperedge(vec![d, b], 30),
+        Err(HypergraphError::VertexDegreeExceeded { index: b, max: 2 })
+    );
+    assert_eq!(
+        graph.update_hyperedge_vertices(beta, vec![b, c, d]),
+        Ok(()),
+        "should not count the updated hyperedge twice"
+    );
+
+    let gamma = graph.add_hyperedge(vec![c, d], 30).unwrap();
+
+    // Joining doesn't count the joined hyperedges twice.
+    assert_eq!(
+        graph.join_hyperedges(&[alpha, beta]),
+        Err(HypergraphError::HyperedgeCardinalityExceeded {
+            cardinality: 6,
+            max: 3
+        })
+    );
+    assert_eq!(
+        graph.count_hyperedges(),
+        3,
+        "should not mutate the hypergraph"
+    );
+
+    graph.remove_hyperedge(alpha).unwrap();
+
+    assert_eq!(graph.update_hyperedge_vertices(beta, vec![b, d]), Ok(()));
+    assert_eq!(
+        graph.join_hyperedges(&[beta, gamma]),
+        Err(HypergraphError::HyperedgeCardinalityExceeded {
+            cardinality: 4,
+            max: 3
+        })
+    );
+
+    graph.update_hyperedge_vertices(beta, vec![d]).unwrap();
+    graph.update_hyperedge_vertices(gamma, vec![c]).unwrap();
+
+    assert_eq!(graph.join_hyperedges(&[beta, gamma]), Ok(()));
+    assert_eq!(graph.get_hyperedge_vertices(beta), Ok(vec![d, c]));
+    assert_eq!(graph.get_vertex_hyperedges(c), Ok(vec![beta]));
+
+    // Contracting can't exceed the maximum degree of the target.
+    let delta = graph.add_hyperedge(vec![a, b], 40).unwrap();
+    let epsilon = graph.add_hyperedge(vec![a, c], 50).unwrap();
+
+    assert_eq!(
+        graph.contract_hyperedge_vertices(epsilon, vec![a, c], a),
+        Err(HypergraphError::VertexDegreeExceeded { index: a, max: 2 })
+    );
+    assert_eq!(
+        graph.get_hyperedge_vertices(beta),
+        Ok(vec![d, c]),
+        "should not mutate the hypergraph"
+    );
+    assert_eq!(
+        graph.contract_hyperedge_vertices(delta, vec![a, b], a),
+        Ok(vec![a])
+    );
+
+    // The constraints are carried over by the algebra.
+    assert_eq!(
+        graph
+            .difference(&Hypergraph::new())
+            .unwrap()
+            .get_constraints(),
+        constraints
+    );
+}
+
+#[test]
+fn integration_constraints_counts() {
+    let mut graph = Hypergraph::<usize, usize>::with_constraints(Constraints {
+        max_vertices: Some(2),
+        max_hyperedges: Some(1),
+        ..Constraints::default()
+    });
+
+    let a = graph.add_vertex(1).unwrap();
+    let b = graph.add_vertex_allow_duplicate(1).unwrap();
+
+    assert_eq!(
+        graph.add_vertex(3),
+        Err(HypergraphError::VerticesCountExceeded { max: 2 })
+    );
+    assert_eq!(
+        graph.add_vertex_allow_duplicate(3),
+        Err(HypergraphError::VerticesCountExceeded { max: 2 })
+    );
+    assert_eq!(
+        graph.add_vertex(1),
+        Err(HypergraphError::VertexWeightAlreadyAssigned(a)),
+        "should report the clashing weight first"
+    );
+
+    let alpha = graph.add_hyperedge(vec![a, b], 10).unwrap();
+
+    assert_eq!(
+        graph.add_hyperedge(vec![b, a], 20),
+        Err(HypergraphError::HyperedgesCountExceeded { max: 1 })
+    );
+    assert_eq!(graph.count_vertices(), 2);
+    assert_eq!(graph.count_hyperedges(), 1);
+
+    // The removals free some room.
+    graph.remove_hyperedge(alpha).unwrap();
+    graph.remove_vertex(b).unwrap();
+
+    assert!(graph.add_vertex(3).is_ok());
+    assert!(graph.add_hyperedge(vec![a], 20).is_ok());
+
+    // Same via the builder.
+    let builder = HypergraphBuilder::<usize, usize>::new()
+        .constraints(Constraints {
+            max_vertices: Some(1),
+            ..Constraints::default()
+        })
+        .vertex(1)
+        .vertex(2);
+
+    assert_eq!(
+        builder.build().err(),
+        Some(HypergraphError::VerticesCountExceeded { max: 1 })
+    );
+}
+
+#[test]
+fn integration_constraints_snapshot() {
+    let constraints = Constraints {
+        max_hyperedge_cardinality: Some(3),
+        max_vertex_degree: Some(2),
+        forbid_self_loops: true,
+        max_vertices: Some(2),
+        max_hyperedges: None,
+    };
+    let mut graph = Hypergraph::<usize, usize>::with_constraints(constraints);
+
+    let a = graph.add_vertex(1).unwrap();
+    let b = graph.add_vertex(2).unwrap();
+
+    // Round-trip via a snapshot.
+    let mut bytes = vec![];
+
+    graph.write_snapshot(&mut bytes).unwrap();
+
+    let mut loaded = Hypergraph::<usize, usize>::read_snapshot(&bytes[..]).unwrap();
+
+    assert_eq!(
+        loaded.get_constraints(),
+        constraints,
+        "should restore the constraints"
+    );
+    assert_eq!(
+        loaded.add_vertex(3),
+        Err(HypergraphError::VerticesCountExceeded { max: 2 })
+    );
+    assert_eq!(
+        loaded.add_hyperedge(vec![a, a], 10),
+        Err(HypergraphError::HyperedgeSelfLoopForbidden(a))
+    );
+    assert_eq!(
+        loaded.add_hyperedge(vec![a, b, a, b], 10),
+        Err(HypergraphError::HyperedgeCardinalityExceeded {
+            cardinality: 4,
+            max: 3
+        })
+    );
+
+    // A snapshot whose hypergraph breaks its constraints is rejected.
+    let mut graph = Hypergraph::<usize, usize>::new();
+
+    let a = graph.add_vertex(1).unwrap();
+
+    graph.add_hyperedge(vec![a, a], 10).unwrap();
+
+    let mut bytes = vec![];
+
+    graph.write_snapshot(&mut bytes).unwrap();
+
+    assert!(
+        Hypergraph::<usize, usize>::read_snapshot(&bytes[..]).is_ok(),
+        "should accept the hypergraph without constraints"
+    );
+
+    // Forbid the self-loops, right after the header, the counters, the
+    // duplicate policy and the first two optional constraints.
+    bytes[8 + 4 + 8 + 8 + 1 + 1 + 1] = 1;
+
+    assert_eq!(
+        Hypergraph::<usize, usize>::read_snapshot(&bytes[..]),
+        Err(HypergraphError::HyperedgeSelfLoopForbidden(a)),
+        "should reject the self-loop"
+    );
+}
@@ -0,0 +1,143 @@
+//! Integration tests.
+
+#![cfg(feature = "rand")]
+
+use hypergraph::{
+    ContagionSeries,
+    ContagionState,
+    Hypergraph,
+    VertexIndex,
+    errors::HypergraphError,
+};
+use rand::{
+    SeedableRng,
+    rngs::StdRng,
+};
+
+// Private helper to build a chain of triangles sharing a vertex.
+fn build_graph() -> Hypergraph<usize, usize> {
+    let mut graph = Hypergraph::new();
+
+    let vertices = (0..7)
+        .map(|weight| graph.add_vertex(weight).unwrap())
+        .collect::<Vec<VertexIndex>>();
+
+    for (weight, triangle) in vertices.windows(3).step_by(2).enumerate() {
+        graph.add_hyperedge(triangle.to_vec(), weight).unwrap();
+    }
+
+    graph
+}
+
+#[test]
+fn integration_contagion_sir() {
+    let graph = build_graph();
+
+    // Create a deterministic random number generator.
+    let mut rng = StdRng::seed_from_u64(42);
+
+    // Certain infections and recoveries are fully deterministic.
+    let series = graph
+        .simulate_sir(&[VertexIndex(0)], 1., 1., 10, &mut rng)
+        .unwrap();
+
+    assert_eq!(series.vertices, (0..7).map(VertexIndex).collect::<Vec<_>>());
+    assert_eq!(series.steps(), 4);
+    assert_eq!(series.count(0, ContagionState::Infected), 1);
+    assert_eq!(series.count(1, ContagionState::Infected), 2);
+    assert_eq!(series.count(1, ContagionState::Recovered), 1);
+    assert_eq!(
+        series.get_state(2, VertexIndex(4)),
+        Some(ContagionState::Infected)
+    );
+    assert_eq!(
+        series.get_state(2, VertexIndex(5)),
+        Some(ContagionState::Susceptible)
+    );
+    assert_eq!(series.count(4, ContagionState::Recovered), 7);
+    assert_eq!(series.count(5, ContagionState::Recovered), 0);
+
+    // Without any transmission, the seeds recover on their own.
+    let series = graph
+        .simulate_sir(&[VertexIndex(3)], 0., 0.5, 100, &mut rng)
+        .unwrap();
+
+    assert_eq!(series.count(series.steps(), ContagionState::Recovered), 1);
+    assert_eq!(series.count(series.steps(), ContagionState::Susceptible), 6);
+
+    // The runs are reproducible and bounded.
+    let run = |seed| {
+        graph
+            .simulate_sir(
+                &[VertexIndex(0)],
+                0.5,
+                0.2,
+                3,
+                &mut StdRng::seed_from_u64(seed),
+            )
+            .unwrap()
+    };
+
+    assert_eq!(run(7), run(7));
+    assert!(run(7).steps() <= 3);
+
+    assert_eq!(
+        graph.simulate_sir(&[VertexIndex(0)], 1.5, 0.1, 10, &mut rng),
+        Err(HypergraphError::ContagionInvalidParameter(
+            "infection_rate".to_string()
+        )),
+        "should return an explicit error"
+    );
+    assert_eq!(
+        graph.simulate_sir(&[VertexIndex(9)], 0.5, 0.1, 10, &mut rng),
+        Err(HypergraphError::VertexIndexNotFound(VertexIndex(9))),
+        "should return an explicit error"
+    );
+}
+
+#[test]
+fn integration_contagion_threshold() {
+    let graph = build_graph();
+
+    // Create a deterministic random number generator.
+    let mut rng = StdRng::seed_from_u64(42);
+
+    // A single active vertex out of three is not enough.
+    let series = graph
+        .simulate_threshold(&[VertexIndex(0)], 0.5, 1., 10, &mut rng)
+        .unwrap();
+
+    assert_eq!(series.steps(), 0);
+
+    // The shared vertices propagate the activation along the chain.
+    let series = graph
+        .simulate_threshold(&[VertexIndex(0), VertexIndex(1)], 0.5, 1., 10, &mut rng)
+        .unwrap();
+
+    assert_eq!(series.steps(), 1);
+    assert_eq!(series.count(1, ContagionState::Infected), 3);
+
+    let series = graph
+        .simulate_threshold(&[VertexIndex(2)], 0.3, 1., 10, &mut rng)
+        .unwrap();
+
+    assert_eq!(series.steps(), 2);
+    assert_eq!(series.count(1, ContagionState::Infected), 5);
+    assert_eq!(series.count(2, ContagionState::Infected), 7);
+    assert_eq!(series.count(2, ContagionState::Recovered), 0);
+
+    assert_eq!(
+        graph.simulate_threshold(&[], 0.5, f64::NAN, 10, &mut rng),
+        Err(HypergraphError::ContagionInvalidParameter(
+            "activation_rate".to_string()
+        )),
+        "should return an explicit error"
+    );
+    assert_eq!(
+        Hypergraph::<usize, usize>::new().simulate_threshold(&[], 0.5, 0.5, 10, &mut rng),
+        Ok(ContagionSeries {
+            vertices: vec![],
+            states: vec![vec![]],
+        })
+    );
+}
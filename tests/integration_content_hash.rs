@@ -0,0 +1,81 @@
+//! Integration tests.
+
+mod common;
+
+use common::{
+    Hyperedge,
+    Vertex,
+};
+use hypergraph::Hypergraph;
+
+#[test]
+fn integration_content_hash() {
+    // Create two hypergraphs with the same content inserted in a different
+    // order.
+    let mut graph = Hypergraph::<Vertex, Hyperedge>::new();
+    let mut other = Hypergraph::<Vertex, Hyperedge>::new();
+
+    assert_eq!(
+        graph.content_hash(),
+        other.content_hash(),
+        "should get the same digest for empty hypergraphs"
+    );
+
+    let a = graph.add_vertex(Vertex::new("one")).unwrap();
+    let b = graph.add_vertex(Vertex::new("two")).unwrap();
+    let c = graph.add_vertex(Vertex::new("three")).unwrap();
+
+    let alpha = graph
+        .add_hyperedge(vec![a, b], Hyperedge::new("one", 1))
+        .unwrap();
+    graph
+        .add_hyperedge(vec![b, c, a], Hyperedge::new("two", 2))
+        .unwrap();
+
+    let other_c = other.add_vertex(Vertex::new("three")).unwrap();
+    let other_a = other.add_vertex(Vertex::new("one")).unwrap();
+    let other_b = other.add_vertex(Vertex::new("two")).unwrap();
+
+    other
+        .add_hyperedge(vec![other_b, other_c, other_a], Hyperedge::new("two", 2))
+        .unwrap();
+    other
+        .add_hyperedge(vec![other_a, other_b], Hyperedge::new("one", 1))
+        .unwrap();
+
+    assert_eq!(
+        graph.content_hash(),
+        other.content_hash(),
+        "should not depend on the insertion order"
+    );
+
+    let digest = graph.content_hash();
+
+    assert_eq!(
+        graph.content_hash(),
+        digest,
+        "should be stable between calls"
+    );
+
+    // Reversing a hyperedge changes the order of its vertices.
+    graph.reverse_hyperedge(alpha).unwrap();
+
+    assert_ne!(
+        graph.content_hash(),
+        digest,
+        "should depend on the order of the vertices within a hyperedge"
+    );
+
+    graph.reverse_hyperedge(alpha).unwrap();
+
+    assert_eq!(graph.content_hash(), digest, "should get back the digest");
+
+    // Updating a weight changes the digest.
+    graph.update_vertex_weight(c, Vertex::new("four")).unwrap();
+
+    assert_ne!(
+        graph.content_hash(),
+        digest,
+        "should depend on the weights of the vertices"
+    );
+}
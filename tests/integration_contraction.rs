@@ -99,3 +99,72 @@ fn integration_contration() {
         "should return an explicit error when the hyperedge doesn't contains the vertices"
     );
 }
+
+#[test]
+fn integration_contract_vertices_where() {
+    let mut graph = Hypergraph::<String, usize>::new();
+
+    let alice = graph.add_vertex("Alice".to_owned()).unwrap();
+    let alice_lowercase = graph.add_vertex("alice".to_owned()).unwrap();
+    let alice_padded = graph.add_vertex("ALICE ".to_owned()).unwrap();
+    let bob = graph.add_vertex("Bob".to_owned()).unwrap();
+    let bob_lowercase = graph.add_vertex("bob".to_owned()).unwrap();
+    let carol = graph.add_vertex("Carol".to_owned()).unwrap();
+
+    let alpha = graph.add_hyperedge(vec![alice, bob], 1).unwrap();
+    let beta = graph
+        .add_hyperedge(vec![alice_lowercase, bob_lowercase, carol], 2)
+        .unwrap();
+    let gamma = graph
+        .add_hyperedge(vec![alice_padded, alice_lowercase], 3)
+        .unwrap();
+    let delta = graph.add_hyperedge(vec![carol], 4).unwrap();
+
+    let normalize = |_: VertexIndex, weight: &String| Some(weight.trim().to_lowercase());
+
+    // Nothing to contract.
+    assert_eq!(
+        graph.contract_vertices_where(|_, _| None::<String>, |vertices| vertices[0]),
+        Ok(vec![])
+    );
+
+    // The target must be one of the contracted vertices.
+    let mut other = graph.clone();
+
+    assert_eq!(
+        other.contract_vertices_where(normalize, |_| carol),
+        Err(HypergraphError::VerticesInvalidContraction {
+            target: carol,
+            vertices: vec![alice, alice_lowercase, alice_padded],
+        }),
+        "should return an explicit error"
+    );
+    assert_eq!(other, graph, "should not mutate the hypergraph");
+
+    // Contract the vertices sharing the same normalized name.
+    assert_eq!(
+        graph.contract_vertices_where(normalize, |vertices| vertices[0]),
+        Ok(vec![
+            (alice, vec![alice_lowercase, alice_padded]),
+            (bob, vec![bob_lowercase]),
+        ])
+    );
+
+    assert_eq!(graph.count_vertices(), 3);
+    assert_eq!(graph.get_hyperedge_vertices(alpha), Ok(vec![alice, bob]));
+    assert_eq!(
+        graph.get_hyperedge_vertices(beta),
+        Ok(vec![alice, bob, carol])
+    );
+    assert_eq!(graph.get_hyperedge_vertices(gamma), Ok(vec![alice]));
+    assert_eq!(graph.get_hyperedge_vertices(delta), Ok(vec![carol]));
+    assert_eq!(
+        graph.get_vertex_weight(alice_lowercase),
+        Err(HypergraphError::VertexIndexNotFound(alice_lowercase)),
+        "should remove the contracted vertices"
+    );
+    assert_eq!(
+        graph.get_vertex_hyperedges(alice),
+        Ok(vec![alpha, beta, gamma])
+    );
+}
@@ -0,0 +1,53 @@
+//! Integration tests.
+
+use hypergraph::{
+    Hypergraph,
+    VertexIndex,
+};
+
+#[test]
+fn integration_degrees() {
+    // Create a new hypergraph.
+    let mut graph = Hypergraph::<usize, usize>::new();
+
+    assert_eq!(graph.get_all_vertex_degrees(), Ok(vec![]));
+
+    // Create some vertices.
+    let vertices = (0..6)
+        .map(|weight| graph.add_vertex(weight).unwrap())
+        .collect::<Vec<VertexIndex>>();
+
+    // Create some hyperedges, with repetitions and self-loops.
+    graph
+        .add_hyperedge(vec![vertices[0], vertices[1], vertices[2]], 0)
+        .unwrap();
+    graph
+        .add_hyperedge(vec![vertices[1], vertices[0], vertices[1], vertices[3]], 1)
+        .unwrap();
+    graph
+        .add_hyperedge(vec![vertices[2], vertices[2], vertices[4]], 2)
+        .unwrap();
+    graph.add_hyperedge(vec![vertices[5]], 3).unwrap();
+
+    // Remove a vertex to get holes in the stable indexes.
+    graph.remove_vertex(vertices[3]).unwrap();
+
+    let degrees = graph.get_all_vertex_degrees().unwrap();
+
+    assert_eq!(
+        degrees,
+        vec![
+            (vertices[0], 1, 2),
+            (vertices[1], 2, 2),
+            (vertices[2], 2, 2),
+            (vertices[4], 1, 0),
+            (vertices[5], 0, 0),
+        ]
+    );
+
+    // Match the degrees computed vertex by vertex.
+    for (vertex_index, degree_in, degree_out) in degrees {
+        assert_eq!(graph.get_vertex_degree_in(vertex_index), Ok(degree_in));
+        assert_eq!(graph.get_vertex_degree_out(vertex_index), Ok(degree_out));
+    }
+}
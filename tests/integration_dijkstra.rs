@@ -2,11 +2,18 @@
 
 mod common;
 
+use std::collections::HashMap;
+
 use common::{
     Hyperedge,
     Vertex,
 };
-use hypergraph::Hypergraph;
+use hypergraph::{
+    HyperedgeIndex,
+    Hypergraph,
+    VertexIndex,
+    errors::HypergraphError,
+};
 
 #[test]
 fn integration_dijkstra() {
@@ -56,7 +63,7 @@ fn integration_dijkstra() {
         .add_hyperedge(vec![a, b, e, d], hyperedge_two)
         .unwrap();
     let gamma = graph.add_hyperedge(vec![b, c, e], hyperedge_three).unwrap();
-    let _delta = graph.add_hyperedge(vec![b, d], hyperedge_four).unwrap();
+    let delta = graph.add_hyperedge(vec![b, d], hyperedge_four).unwrap();
 
     // Get the cheapest path via Dijkstra based on the hyperedges' costs.
     assert_eq!(
@@ -70,4 +77,115 @@ fn integration_dijkstra() {
         ]),
         "should follow a, b, c, e, d with their matching traversed hyperedges"
     );
+
+    // Restrict the traversal to some allowed hyperedges.
+    assert_eq!(
+        graph.get_dijkstra_connections_via(a, d, &[beta]),
+        Ok(vec![
+            (a, None),
+            (b, Some(beta)),
+            (e, Some(beta)),
+            (d, Some(beta))
+        ]),
+        "should follow a, b, e, d via beta only"
+    );
+    assert_eq!(
+        graph.get_dijkstra_connections_via(a, d, &[alpha, gamma]),
+        Ok(vec![]),
+        "should not find any path without beta and delta"
+    );
+    assert_eq!(
+        graph.get_dijkstra_connections_where(a, d, |hyperedge_index, _| hyperedge_index != beta),
+        Ok(vec![
+            (a, None),
+            (b, Some(alpha)),
+            (c, Some(gamma)),
+            (d, Some(delta))
+        ]),
+        "should reach d via delta"
+    );
+    assert_eq!(
+        graph.get_dijkstra_connections_via(a, d, &[HyperedgeIndex(9)]),
+        Err(HypergraphError::HyperedgeIndexNotFound(HyperedgeIndex(9))),
+        "should return an explicit error"
+    );
+
+    // Get the same path lazily.
+    let mut steps = graph.get_dijkstra_path_iter(a, d).unwrap();
+
+    assert_eq!(steps.next(), Some((a, None)), "should start from a");
+    assert_eq!(
+        steps.collect::<Vec<_>>(),
+        vec![
+            (b, Some(alpha)),
+            (c, Some(gamma)),
+            (e, Some(gamma)),
+            (d, Some(beta))
+        ],
+        "should yield the remaining steps"
+    );
+    assert_eq!(
+        graph.get_dijkstra_path_iter(d, a).unwrap().next(),
+        None,
+        "should be empty without any path"
+    );
+    assert_eq!(
+        graph
+            .get_dijkstra_path_iter(c, c)
+            .unwrap()
+            .collect::<Vec<_>>(),
+        vec![(c, None)],
+        "should only yield the vertex itself"
+    );
+
+    // Get the cost only.
+    assert_eq!(
+        graph.get_shortest_distance(a, d),
+        Ok(Some(10 + 1 + 1 + 20)),
+        "should sum the costs of alpha, gamma, gamma and beta"
+    );
+    assert_eq!(graph.get_shortest_distance(d, a), Ok(None));
+    assert_eq!(graph.get_shortest_distance(c, c), Ok(Some(0)));
+    assert_eq!(
+        graph.get_shortest_distance(a, VertexIndex(9)),
+        Err(HypergraphError::VertexIndexNotFound(VertexIndex(9))),
+        "should return an explicit error"
+    );
+
+    // Bound the exploration by a maximum cost.
+    assert_eq!(graph.get_shortest_distance_within(a, d, 32), Ok(Some(32)));
+    assert_eq!(
+        graph.get_shortest_distance_within(a, d, 31),
+        Ok(None),
+        "should not reach d within the budget"
+    );
+    assert_eq!(
+        graph.get_shortest_distances_from(a, Some(11)),
+        Ok(HashMap::from([(a, 0), (b, 10), (c, 11)])),
+        "should only reach a, b and c within the budget"
+    );
+    assert_eq!(
+        graph.get_shortest_distances_from(b, None),
+        Ok(HashMap::from([(b, 0), (c, 1), (e, 2), (d, 22)])),
+        "should reach all the successors of b"
+    );
+
+    // Reach every vertex from the nearest of many sources.
+    assert_eq!(
+        graph.get_dijkstra_from_many(&[a, e]),
+        Ok(HashMap::from([
+            (a, (a, 0)),
+            (b, (a, 10)),
+            (c, (a, 11)),
+            (e, (e, 0)),
+            (d, (e, 20))
+        ])),
+        "should reach d from e rather than from a"
+    );
+    assert_eq!(graph.get_dijkstra_from_many(&[]), Ok(HashMap::new()));
+    assert_eq!(
+        graph.get_dijkstra_from_many(&[a, VertexIndex(9)]),
+        Err(HypergraphError::VertexIndexNotFound(VertexIndex(9))),
+        "should return an explicit error"
+    );
 }
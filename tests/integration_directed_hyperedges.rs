@@ -0,0 +1,85 @@
+//! Integration tests.
+
+mod common;
+
+use common::{
+    Hyperedge,
+    Vertex,
+};
+use hypergraph::{
+    Hypergraph,
+    VertexIndex,
+    errors::HypergraphError,
+};
+
+#[test]
+fn integration_directed_hyperedges() {
+    // Create a new hypergraph.
+    let mut graph = Hypergraph::<Vertex, Hyperedge>::new();
+
+    // Create some vertices.
+    let a = graph.add_vertex(Vertex::new("a")).unwrap();
+    let b = graph.add_vertex(Vertex::new("b")).unwrap();
+    let c = graph.add_vertex(Vertex::new("c")).unwrap();
+    let d = graph.add_vertex(Vertex::new("d")).unwrap();
+
+    // Add some hyperedges.
+    let alpha = graph
+        .add_hyperedge(vec![a, b, c], Hyperedge::new("alpha", 1))
+        .unwrap();
+    let beta = graph
+        .add_hyperedge(vec![c, a], Hyperedge::new("beta", 1))
+        .unwrap();
+    let gamma = graph
+        .add_hyperedge(vec![b, b], Hyperedge::new("gamma", 1))
+        .unwrap();
+    let delta = graph
+        .add_hyperedge(vec![d], Hyperedge::new("delta", 1))
+        .unwrap();
+
+    assert_eq!(
+        graph.get_hyperedges_from(a),
+        Ok(vec![alpha]),
+        "should get the hyperedges leaving the first vertex"
+    );
+    assert_eq!(
+        graph.get_hyperedges_to(a),
+        Ok(vec![beta]),
+        "should get the hyperedges entering the first vertex"
+    );
+    assert_eq!(
+        graph.get_hyperedges_from(b),
+        Ok(vec![alpha, gamma]),
+        "should get the hyperedges leaving the second vertex"
+    );
+    assert_eq!(
+        graph.get_hyperedges_to(b),
+        Ok(vec![alpha, gamma]),
+        "should get the hyperedges entering the second vertex"
+    );
+    assert_eq!(
+        graph.get_hyperedges_from(c),
+        Ok(vec![beta]),
+        "should get the hyperedges leaving the third vertex"
+    );
+    assert_eq!(
+        graph.get_hyperedges_to(c),
+        Ok(vec![alpha]),
+        "should get the hyperedges entering the third vertex"
+    );
+    assert_eq!(
+        (graph.get_hyperedges_from(d), graph.get_hyperedges_to(d)),
+        (Ok(vec![]), Ok(vec![])),
+        "should get no hyperedges for a unary"
+    );
+    assert_eq!(
+        graph.get_vertex_hyperedges(d),
+        Ok(vec![delta]),
+        "should still get the unary hyperedge without direction"
+    );
+    assert_eq!(
+        graph.get_hyperedges_from(VertexIndex(4)),
+        Err(HypergraphError::VertexIndexNotFound(VertexIndex(4))),
+        "should return an explicit error"
+    );
+}
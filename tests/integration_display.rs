@@ -0,0 +1,52 @@
+//! Integration tests.
+
+mod common;
+
+use common::{
+    Hyperedge,
+    Vertex,
+};
+use hypergraph::Hypergraph;
+
+#[test]
+fn integration_display() {
+    // Create a new hypergraph.
+    let mut graph = Hypergraph::<Vertex, Hyperedge>::new();
+
+    assert_eq!(
+        graph.to_string(),
+        "Hypergraph: 0 vertices, 0 hyperedges\nVertex degrees: none\nHyperedge cardinalities: none\nVertices:\nHyperedges:",
+        "should summarize an empty hypergraph"
+    );
+
+    // Create some vertices.
+    let a = graph.add_vertex(Vertex::new("one")).unwrap();
+    let b = graph.add_vertex(Vertex::new("two")).unwrap();
+    let c = graph.add_vertex(Vertex::new("three")).unwrap();
+
+    // Add some hyperedges.
+    graph
+        .add_hyperedge(vec![a, b], Hyperedge::new("one", 1))
+        .unwrap();
+    graph
+        .add_hyperedge(vec![b, c, a], Hyperedge::new("two", 2))
+        .unwrap();
+
+    assert_eq!(
+        graph.to_string(),
+        "Hypergraph: 3 vertices, 2 hyperedges\nVertex degrees: min 1, max 2, mean 1.67\nHyperedge cardinalities: min 2, max 3, mean 2.50\nVertices:\n  0: one\n  1: two\n  2: three\nHyperedges:\n  0: one [0, 1]\n  1: two [1, 2, 0]",
+        "should summarize the hypergraph"
+    );
+
+    // Add enough vertices to truncate the listing.
+    for _ in 0..10 {
+        graph
+            .add_vertex_allow_duplicate(Vertex::new("four"))
+            .unwrap();
+    }
+
+    assert!(
+        graph.to_string().contains("  ... 3 more\nHyperedges:"),
+        "should truncate the listing of the vertices"
+    );
+}
@@ -0,0 +1,145 @@
+//! Integration tests.
+
+use hypergraph::{
+    HyperedgeIndex,
+    Hypergraph,
+    errors::HypergraphError,
+    palette_color,
+};
+
+#[test]
+fn integration_dot() {
+    // Create a new hypergraph.
+    let mut graph = Hypergraph::<usize, usize>::new();
+
+    // Create some vertices.
+    let a = graph.add_vertex(1).unwrap();
+    let b = graph.add_vertex(2).unwrap();
+    let c = graph.add_vertex(3).unwrap();
+
+    // Create some hyperedges.
+    graph.add_hyperedge(vec![a, b, c], 10).unwrap();
+    graph.add_hyperedge(vec![b, b], 20).unwrap();
+    graph.add_hyperedge(vec![c], 30).unwrap();
+
+    let dot = graph.to_graphviz_dot().unwrap();
+
+    assert_eq!(
+        dot,
+        r##"digraph {
+    edge [penwidth=0.5, arrowhead=normal, arrowsize=0.5, fontsize=8.0];
+    node [color=gray20, fontsize=8.0, fontcolor=white, style=filled, shape=circle];
+    rankdir=LR;
+    0 [label="1"];
+    1 [label="2"];
+    2 [label="3"];
+    0 -> 1 -> 2 [color="#e6194b", fontcolor="#e6194b", label="10"];
+    1 -> 1 [color="#3cb44b", fontcolor="#3cb44b", label="20"];
+    2 -> 2 [color="#4363d8", fontcolor="#4363d8", label="30", dir=none];
+}
+"##,
+        "should render the hypergraph to the dot format"
+    );
+
+    // The default colors follow the stable indexes, not the internal order.
+    let mut removed = graph.clone();
+
+    removed.remove_hyperedge(HyperedgeIndex(0)).unwrap();
+
+    assert!(
+        removed
+            .to_graphviz_dot()
+            .unwrap()
+            .contains("2 -> 2 [color=\"#4363d8\", fontcolor=\"#4363d8\", label=\"30\", dir=none];"),
+        "should keep the color of the hyperedge"
+    );
+    assert_eq!(
+        palette_color(HyperedgeIndex(10)),
+        palette_color(HyperedgeIndex(2))
+    );
+
+    // Custom color provider.
+    assert_eq!(
+        graph
+            .to_graphviz_dot_with(|hyperedge_index, weight| {
+                if *weight > 10 {
+                    format!("gray{}", hyperedge_index.0)
+                } else {
+                    "black".to_owned()
+                }
+            })
+            .unwrap()
+            .lines()
+            .skip(7)
+            .collect::<Vec<&str>>(),
+        vec![
+            r#"    0 -> 1 -> 2 [color="black", fontcolor="black", label="10"];"#,
+            r#"    1 -> 1 [color="gray1", fontcolor="gray1", label="20"];"#,
+            r#"    2 -> 2 [color="gray2", fontcolor="gray2", label="30", dir=none];"#,
+            "}",
+        ],
+        "should use the provided colors"
+    );
+
+    // Round-trip.
+    let imported = Hypergraph::<usize, usize>::from_graphviz_dot(&dot).unwrap();
+
+    assert_eq!(
+        imported.into_iter().collect::<Vec<(usize, Vec<usize>)>>(),
+        vec![(10, vec![1, 2, 3]), (20, vec![2, 2]), (30, vec![3])],
+        "should preserve the weights and the vertices of the hyperedges"
+    );
+
+    // Duplicate vertices survive the round-trip.
+    let mut duplicates = Hypergraph::<usize, usize>::new();
+
+    let x = duplicates.add_vertex(1).unwrap();
+    let y = duplicates.add_vertex_allow_duplicate(1).unwrap();
+
+    duplicates.add_hyperedge(vec![x, y], 10).unwrap();
+
+    let imported =
+        Hypergraph::<usize, usize>::from_graphviz_dot(&duplicates.to_graphviz_dot().unwrap())
+            .unwrap();
+
+    assert_eq!(imported.count_vertices(), 2, "should keep the duplicates");
+    assert_eq!(
+        imported.into_iter().collect::<Vec<(usize, Vec<usize>)>>(),
+        vec![(10, vec![1, 1])],
+        "should connect the duplicates"
+    );
+
+    // Invalid inputs.
+    assert_eq!(
+        Hypergraph::<usize, usize>::from_graphviz_dot("graph {}")
+            .map(|graph| graph.count_vertices()),
+        Err(HypergraphError::DotInvalidStatement(
+            "missing digraph header".to_owned()
+        )),
+        "should return an explicit error"
+    );
+    assert_eq!(
+        Hypergraph::<usize, usize>::from_graphviz_dot("digraph { 0 [label=\"x\"]; }")
+            .map(|graph| graph.count_vertices()),
+        Err(HypergraphError::DotInvalidWeight("x".to_owned())),
+        "should return an explicit error"
+    );
+    assert_eq!(
+        Hypergraph::<usize, usize>::from_graphviz_dot("digraph { 0 -> 1 [label=\"1\"]; }")
+            .map(|graph| graph.count_vertices()),
+        Err(HypergraphError::DotInvalidStatement(
+            "unknown node 0".to_owned()
+        )),
+        "should return an explicit error"
+    );
+    assert_eq!(
+        Hypergraph::<usize, usize>::from_graphviz_dot(
+            "digraph { 0 [label=\"1\"]; 0 [label=\"2\"]; }"
+        )
+        .map(|graph| graph.count_vertices()),
+        Err(HypergraphError::DotInvalidStatement(
+            "duplicate node 0".to_owned()
+        )),
+        "should return an explicit error"
+    );
+}
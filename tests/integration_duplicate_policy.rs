@@ -0,0 +1,229 @@
+//! Integration tests.
+
+mod common;
+
+use common::{
+    Hyperedge,
+    Vertex,
+};
+use hypergraph::{
+    DuplicatePolicy,
+    HyperedgeIndex,
+    Hypergraph,
+    HypergraphBuilder,
+    VertexDedupPolicy,
+    errors::HypergraphError,
+};
+
+#[test]
+fn integration_duplicate_policy() {
+    // The default policy allows duplicate vertices.
+    let mut graph = Hypergraph::<Vertex, Hyperedge>::new();
+
+    assert_eq!(
+        graph.get_duplicate_policy(),
+        DuplicatePolicy::AllowDuplicateVertices
+    );
+
+    let a = graph.add_vertex(Vertex::new("a")).unwrap();
+    let b = graph.add_vertex(Vertex::new("b")).unwrap();
+
+    assert!(
+        graph
+            .add_hyperedge(vec![a, b], Hyperedge::new("one", 1))
+            .is_ok()
+    );
+    assert!(
+        graph
+            .add_hyperedge(vec![a, b], Hyperedge::new("two", 1))
+            .is_ok()
+    );
+
+    // Reject the same vertices in the same order.
+    let mut graph = Hypergraph::<Vertex, Hyperedge>::with_duplicate_policy(
+        DuplicatePolicy::RejectDuplicateVertexLists,
+    );
+
+    let a = graph.add_vertex(Vertex::new("a")).unwrap();
+    let b = graph.add_vertex(Vertex::new("b")).unwrap();
+    let c = graph.add_vertex(Vertex::new("c")).unwrap();
+
+    let one = graph
+        .add_hyperedge(vec![a, b], Hyperedge::new("one", 1))
+        .unwrap();
+    let two = graph
+        .add_hyperedge(vec![b, a], Hyperedge::new("two", 1))
+        .unwrap();
+
+    assert_eq!(
+        graph.add_hyperedge(vec![a, b], Hyperedge::new("three", 1)),
+        Err(HypergraphError::HyperedgeVerticesAlreadyAssigned(one)),
+        "should return an explicit error listing the clashing hyperedge"
+    );
+    assert_eq!(
+        graph.reverse_hyperedge(two),
+        Err(HypergraphError::HyperedgeVerticesAlreadyAssigned(one)),
+        "should reject the update"
+    );
+    assert_eq!(
+        graph.get_hyperedge_vertices(two),
+        Ok(vec![b, a]),
+        "should leave the hyperedge untouched"
+    );
+
+    // Removing a vertex can't make hyperedges clash either.
+    assert_eq!(graph.remove_hyperedge(two), Ok(()));
+
+    let three = graph
+        .add_hyperedge(vec![a, c], Hyperedge::new("three", 1))
+        .unwrap();
+    let four = graph
+        .add_hyperedge(vec![a, b, c], Hyperedge::new("four", 1))
+        .unwrap();
+
+    assert_eq!(
+        graph.remove_vertex(b),
+        Err(HypergraphError::HyperedgeVerticesAlreadyAssigned(three)),
+        "should reject the removal"
+    );
+    assert_eq!(
+        graph.count_vertices(),
+        3,
+        "should leave the vertices untouched"
+    );
+    assert_eq!(graph.get_hyperedge_vertices(four), Ok(vec![a, b, c]));
+    assert_eq!(graph.remove_hyperedge(three), Ok(()));
+    assert_eq!(graph.remove_vertex(b), Ok(()), "should allow the removal");
+    assert_eq!(graph.get_hyperedge_vertices(one), Ok(vec![a]));
+
+    // Reject the same set of vertices.
+    let mut graph = Hypergraph::<Vertex, Hyperedge>::with_duplicate_policy(
+        DuplicatePolicy::RejectDuplicateVertexSets,
+    );
+
+    let a = graph.add_vertex(Vertex::new("a")).unwrap();
+    let b = graph.add_vertex(Vertex::new("b")).unwrap();
+    let c = graph.add_vertex(Vertex::new("c")).unwrap();
+
+    graph
+        .add_hyperedge(vec![a, b], Hyperedge::new("one", 1))
+        .unwrap();
+
+    assert_eq!(
+        graph.add_hyperedge(vec![b, a, b], Hyperedge::new("two", 1)),
+        Err(HypergraphError::HyperedgeVerticesAlreadyAssigned(
+            HyperedgeIndex(0)
+        )),
+        "should ignore the order and the repetitions"
+    );
+
+    let two = graph
+        .add_hyperedge(vec![b, c], Hyperedge::new("two", 1))
+        .unwrap();
+    let three = graph
+        .add_hyperedge(vec![c, b, a], Hyperedge::new("three", 1))
+        .unwrap();
+
+    assert_eq!(
+        graph.remove_vertex(c),
+        Err(HypergraphError::HyperedgeVerticesAlreadyAssigned(
+            HyperedgeIndex(0)
+        )),
+        "should reject the removal"
+    );
+
+    graph.clear_hyperedges().unwrap();
+
+    assert_eq!(
+        graph.get_duplicate_policy(),
+        DuplicatePolicy::RejectDuplicateVertexSets,
+        "should keep the policy"
+    );
+    assert!(graph.get_hyperedge_vertices(two).is_err());
+    assert!(graph.get_hyperedge_vertices(three).is_err());
+}
+
+#[test]
+fn integration_vertex_dedup_policy() {
+    // The default policy keeps the duplicate vertices.
+    let mut graph = Hypergraph::<Vertex, Hyperedge>::new();
+
+    assert_eq!(
+        graph.get_vertex_dedup_policy(),
+        VertexDedupPolicy::KeepDuplicates
+    );
+
+    let a = graph.add_vertex(Vertex::new("a")).unwrap();
+    let b = graph.add_vertex(Vertex::new("b")).unwrap();
+    let alpha = graph
+        .add_hyperedge(vec![a, b], Hyperedge::new("α", 1))
+        .unwrap();
+    let beta = graph
+        .add_hyperedge(vec![b, a], Hyperedge::new("β", 1))
+        .unwrap();
+
+    graph.join_hyperedges(&[alpha, beta]).unwrap();
+
+    assert_eq!(graph.get_hyperedge_vertices(alpha), Ok(vec![a, b, b, a]));
+
+    // Dedupe the consecutive vertices.
+    let mut graph = HypergraphBuilder::<Vertex, Hyperedge>::new()
+        .vertex_dedup_policy(VertexDedupPolicy::DedupConsecutive)
+        .build()
+        .unwrap();
+
+    let a = graph.add_vertex(Vertex::new("a")).unwrap();
+    let b = graph.add_vertex(Vertex::new("b")).unwrap();
+    let c = graph.add_vertex(Vertex::new("c")).unwrap();
+    let alpha = graph
+        .add_hyperedge(vec![a, b], Hyperedge::new("α", 1))
+        .unwrap();
+    let beta = graph
+        .add_hyperedge(vec![b, a], Hyperedge::new("β", 1))
+        .unwrap();
+
+    graph.join_hyperedges(&[alpha, beta]).unwrap();
+
+    assert_eq!(graph.get_hyperedge_vertices(alpha), Ok(vec![a, b, a]));
+
+    graph
+        .update_hyperedge_vertices(alpha, vec![c, c, a, a, c])
+        .unwrap();
+
+    assert_eq!(graph.get_hyperedge_vertices(alpha), Ok(vec![c, a, c]));
+
+    // Dedupe all the vertices, the snapshots preserving the policy.
+    let mut graph = HypergraphBuilder::<usize, usize>::new()
+        .vertex_dedup_policy(VertexDedupPolicy::DedupAll)
+        .build()
+        .unwrap();
+
+    let a = graph.add_vertex(1).unwrap();
+    let b = graph.add_vertex(2).unwrap();
+    let c = graph.add_vertex(3).unwrap();
+    let alpha = graph.add_hyperedge(vec![a, b], 10).unwrap();
+    let beta = graph.add_hyperedge(vec![b, c, a], 20).unwrap();
+    let gamma = graph.add_hyperedge(vec![b], 30).unwrap();
+
+    graph.join_hyperedges(&[alpha, beta]).unwrap();
+
+    assert_eq!(graph.get_hyperedge_vertices(alpha), Ok(vec![a, b, c]));
+
+    // Joining vertices which are already part of the first hyperedge leaves
+    // it untouched.
+    graph.join_hyperedges(&[alpha, gamma]).unwrap();
+
+    assert_eq!(graph.get_hyperedge_vertices(alpha), Ok(vec![a, b, c]));
+    assert_eq!(graph.count_hyperedges(), 1);
+
+    let mut bytes = vec![];
+
+    graph.write_snapshot(&mut bytes).unwrap();
+
+    let loaded = Hypergraph::<usize, usize>::read_snapshot(&bytes[..]).unwrap();
+
+    assert_eq!(
+        loaded.get_vertex_dedup_policy(),
+        VertexDedupPolicy::DedupAll
+    );
+}
@@ -0,0 +1,79 @@
+//! Integration tests.
+
+mod common;
+
+use common::{
+    Hyperedge,
+    Vertex,
+};
+use hypergraph::{
+    Hypergraph,
+    errors::HypergraphError,
+};
+
+#[test]
+fn integration_duplicates() {
+    // Create a new hypergraph.
+    let mut graph = Hypergraph::<Vertex, Hyperedge>::new();
+
+    // Create some vertice weights.
+    let anna = Vertex::new("Anna");
+    let bob = Vertex::new("Bob");
+
+    // Create some vertices sharing the same weight.
+    let a = graph.add_vertex(anna).unwrap();
+    let b = graph.add_vertex_allow_duplicate(anna).unwrap();
+    let c = graph.add_vertex_allow_duplicate(anna).unwrap();
+
+    assert_eq!(graph.count_vertices(), 3, "should have three vertices");
+    assert_eq!(
+        graph.add_vertex(anna),
+        Err(HypergraphError::VertexWeightAlreadyAssigned(a)),
+        "should still check the uniqueness of the regular vertices"
+    );
+    assert_eq!(
+        graph.get_vertex_weight(b),
+        Ok(&anna),
+        "should get the weight of a duplicated vertex"
+    );
+
+    // Add a hyperedge between the duplicated vertices.
+    let alpha = graph
+        .add_hyperedge(vec![a, b, c], Hyperedge::new("one", 10))
+        .unwrap();
+
+    assert_eq!(
+        graph.get_adjacent_vertices_from(b),
+        Ok(vec![c]),
+        "should distinguish the duplicated vertices"
+    );
+
+    // Updating the weight of a duplicated vertex keeps it duplicated.
+    graph.update_vertex_weight(b, bob).unwrap();
+    graph.update_vertex_weight(c, bob).unwrap();
+
+    assert_eq!(
+        graph.update_vertex_weight(a, bob),
+        Ok(()),
+        "should not conflict with duplicated vertices"
+    );
+    assert_eq!(
+        graph.add_vertex(bob),
+        Err(HypergraphError::VertexWeightAlreadyAssigned(a)),
+        "should conflict with the updated regular vertex"
+    );
+
+    // Removing a duplicated vertex leaves the other ones untouched.
+    graph.remove_vertex(b).unwrap();
+
+    assert_eq!(
+        graph.get_hyperedge_vertices(alpha),
+        Ok(vec![a, c]),
+        "should remove the vertex from the hyperedge"
+    );
+    assert_eq!(
+        graph.into_iter().collect::<Vec<(Hyperedge, Vec<Vertex>)>>(),
+        vec![(Hyperedge::new("one", 10), vec![bob, bob])],
+        "should iterate over the duplicated weights"
+    );
+}
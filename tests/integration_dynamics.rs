@@ -0,0 +1,87 @@
+//! Integration tests.
+
+use hypergraph::{
+    HyperedgeIndex,
+    Hypergraph,
+    VertexIndex,
+    VertexRef,
+    errors::HypergraphError,
+};
+
+// Private helper to check if a state, i.e. the last digit of a weight, is
+// infected.
+fn is_infected(weight: &usize) -> bool {
+    weight % 10 == 1
+}
+
+#[test]
+fn integration_dynamics() {
+    let mut graph = Hypergraph::<usize, usize>::new();
+
+    let a = graph.add_vertex(11).unwrap();
+    let b = graph.add_vertex(21).unwrap();
+    let c = graph.add_vertex(30).unwrap();
+    let d = graph.add_vertex(40).unwrap();
+
+    let alpha = graph.add_hyperedge(vec![a, b, c], 1).unwrap();
+    let beta = graph.add_hyperedge(vec![d, c], 2).unwrap();
+
+    // A susceptible vertex gets infected once all the other vertices of one
+    // of its hyperedges are infected.
+    let contagion = |vertex: VertexRef<'_, usize, usize>,
+                     hyperedges: &[HyperedgeIndex],
+                     neighbors: &[Vec<&usize>]| {
+        assert_eq!(hyperedges.len(), neighbors.len());
+
+        let weight = *vertex.weight();
+
+        if neighbors
+            .iter()
+            .any(|weights| weights.iter().all(|weight| is_infected(weight)))
+        {
+            weight - weight % 10 + 1
+        } else {
+            weight
+        }
+    };
+
+    // The next states only depend on the current ones.
+    assert_eq!(graph.step(contagion), Ok(1));
+    assert_eq!(graph.get_vertex_weight(c), Ok(&31));
+    assert_eq!(graph.get_vertex_weight(d), Ok(&40));
+    assert_eq!(graph.get_vertex_hyperedges(c), Ok(vec![alpha, beta]));
+
+    assert_eq!(graph.step(contagion), Ok(1));
+    assert_eq!(graph.get_vertex_weight(d), Ok(&41));
+
+    assert_eq!(graph.step(contagion), Ok(0), "should reach a steady state");
+
+    // The incident hyperedges come along with the other vertices.
+    graph
+        .step(|vertex, hyperedges, neighbors| {
+            if vertex.index() == c {
+                assert_eq!(hyperedges, &[alpha, beta]);
+                assert_eq!(neighbors, &[vec![&11, &21], vec![&41]]);
+            }
+
+            *vertex.weight()
+        })
+        .unwrap();
+
+    // Nothing is assigned on clash.
+    assert_eq!(
+        graph.step(|vertex, _, _| vertex.weight() / 10),
+        Ok(4),
+        "should assign all the weights"
+    );
+    assert_eq!(
+        graph.step(|_, _, _| 0),
+        Err(HypergraphError::VertexWeightAlreadyAssigned(VertexIndex(0))),
+        "should return an explicit error"
+    );
+    assert_eq!(graph.get_vertex_weight(b), Ok(&2));
+    assert_eq!(
+        graph.add_vertex(3),
+        Err(HypergraphError::VertexWeightAlreadyAssigned(c))
+    );
+}
@@ -0,0 +1,111 @@
+//! Integration tests.
+
+#![cfg(feature = "rand")]
+
+mod common;
+
+use common::{
+    Hyperedge,
+    Vertex,
+};
+use hypergraph::{
+    EmbeddingsOptions,
+    Hypergraph,
+    Progress,
+};
+use rand::{
+    SeedableRng,
+    rngs::StdRng,
+};
+
+// Cosine similarity of two embeddings.
+fn similarity(left: &[f32], right: &[f32]) -> f32 {
+    let dot = left.iter().zip(right).map(|(a, b)| a * b).sum::<f32>();
+    let norm = |vector: &[f32]| vector.iter().map(|a| a * a).sum::<f32>().sqrt();
+
+    dot / (norm(left) * norm(right))
+}
+
+#[test]
+fn integration_embeddings() {
+    // Create a new hypergraph.
+    let mut graph = Hypergraph::<Vertex, Hyperedge>::new();
+
+    // Create a deterministic random number generator.
+    let mut rng = StdRng::seed_from_u64(42);
+
+    // Create some vertice weights.
+    let vertex_one = Vertex::new("one");
+    let vertex_two = Vertex::new("two");
+    let vertex_three = Vertex::new("three");
+    let vertex_four = Vertex::new("four");
+    let vertex_five = Vertex::new("five");
+    let vertex_six = Vertex::new("six");
+
+    // Create some hyperedge weights.
+    let hyperedge_one = Hyperedge::new("one", 10);
+    let hyperedge_two = Hyperedge::new("two", 20);
+    let hyperedge_three = Hyperedge::new("three", 30);
+    let hyperedge_four = Hyperedge::new("four", 40);
+
+    // Create some vertices.
+    let a = graph.add_vertex(vertex_one).unwrap();
+    let b = graph.add_vertex(vertex_two).unwrap();
+    let c = graph.add_vertex(vertex_three).unwrap();
+    let d = graph.add_vertex(vertex_four).unwrap();
+    let e = graph.add_vertex(vertex_five).unwrap();
+    let f = graph.add_vertex(vertex_six).unwrap();
+
+    // Add two disconnected communities.
+    graph.add_hyperedge(vec![a, b, c], hyperedge_one).unwrap();
+    graph.add_hyperedge(vec![c, a], hyperedge_two).unwrap();
+    graph.add_hyperedge(vec![d, e, f], hyperedge_three).unwrap();
+    graph.add_hyperedge(vec![f, e], hyperedge_four).unwrap();
+
+    let options = EmbeddingsOptions {
+        dimensions: 8,
+        epochs: 10,
+        ..EmbeddingsOptions::default()
+    };
+    let embeddings = graph.get_vertex_embeddings(options, &mut rng).unwrap();
+
+    assert_eq!(embeddings.len(), 6, "should embed all the vertices");
+    assert!(
+        embeddings.values().all(|embedding| embedding.len() == 8),
+        "should have the requested number of dimensions"
+    );
+    assert!(
+        similarity(&embeddings[&a], &embeddings[&b]) > similarity(&embeddings[&a], &embeddings[&e]),
+        "should embed the vertices of the same community closer"
+    );
+    assert!(
+        similarity(&embeddings[&d], &embeddings[&f]) > similarity(&embeddings[&d], &embeddings[&c]),
+        "should embed the vertices of the same community closer"
+    );
+
+    // Progress reports.
+    let mut reports = vec![];
+
+    graph
+        .get_vertex_embeddings_with_progress(
+            EmbeddingsOptions {
+                epochs: 2,
+                walks_per_vertex: 1,
+                ..options
+            },
+            &mut rng,
+            |progress| reports.push(progress),
+        )
+        .unwrap();
+
+    assert_eq!(reports.len(), 18, "should report each walk and each step");
+    assert_eq!(
+        reports.last(),
+        Some(&Progress {
+            stage: "training",
+            completed: 12,
+            total: 12
+        }),
+        "should complete the training"
+    );
+}
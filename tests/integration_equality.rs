@@ -0,0 +1,66 @@
+//! Integration tests.
+
+use hypergraph::{
+    HyperedgeIndex,
+    Hypergraph,
+    VertexIndex,
+};
+
+// Create a small hypergraph with a removed vertex.
+fn create_graph() -> Hypergraph<&'static str, usize> {
+    let mut graph = Hypergraph::new();
+
+    let a = graph.add_vertex("a").unwrap();
+    let b = graph.add_vertex("b").unwrap();
+    let c = graph.add_vertex("c").unwrap();
+    let d = graph.add_vertex("d").unwrap();
+
+    graph.add_hyperedge(vec![a, b, c], 1).unwrap();
+    graph.add_hyperedge(vec![c, d], 2).unwrap();
+    graph.remove_vertex(b).unwrap();
+    graph.set_vertex_attr(a, "label", "first").unwrap();
+
+    graph
+}
+
+#[test]
+fn integration_equality() {
+    let graph = create_graph();
+
+    // Compare by content.
+    assert_eq!(graph, create_graph());
+    assert_eq!(graph, graph.clone());
+
+    let mut other = create_graph();
+
+    other.update_vertex_weight(VertexIndex(3), "e").unwrap();
+
+    assert_ne!(graph, other, "should compare the weights of the vertices");
+
+    let mut other = create_graph();
+
+    other
+        .update_hyperedge_vertices(HyperedgeIndex(1), vec![VertexIndex(3), VertexIndex(2)])
+        .unwrap();
+
+    assert_ne!(graph, other, "should compare the order of the vertices");
+
+    let mut other = create_graph();
+
+    other
+        .set_vertex_attr(VertexIndex(0), "label", "second")
+        .unwrap();
+
+    assert_ne!(graph, other, "should compare the attributes");
+
+    // Clone deeply, preserving the stable indexes.
+    let mut clone = graph.clone();
+
+    assert_eq!(clone.add_vertex("e"), Ok(VertexIndex(4)));
+    assert_eq!(graph.count_vertices(), 3);
+    assert_ne!(graph, clone);
+    assert_eq!(
+        clone.get_hyperedge_vertices(HyperedgeIndex(0)),
+        graph.get_hyperedge_vertices(HyperedgeIndex(0))
+    );
+}
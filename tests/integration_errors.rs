@@ -0,0 +1,46 @@
+//! Integration tests.
+
+mod common;
+
+use std::error::Error;
+
+use common::{
+    Hyperedge,
+    Vertex,
+};
+use hypergraph::{
+    Hypergraph,
+    VertexIndex,
+};
+
+// Private helper returning a boxed error, as done across API boundaries.
+fn add_twice(
+    graph: &mut Hypergraph<Vertex, Hyperedge>,
+) -> Result<(), Box<dyn Error + Send + Sync>> {
+    graph.add_vertex(Vertex::new("one"))?;
+    graph.add_vertex(Vertex::new("one"))?;
+
+    Ok(())
+}
+
+#[test]
+fn integration_errors() {
+    let mut graph = Hypergraph::<Vertex, Hyperedge>::new();
+
+    let error = add_twice(&mut graph).unwrap_err();
+
+    assert_eq!(
+        error.to_string(),
+        "Vertex weight was already assigned to VertexIndex 0",
+        "should convert the error into a boxed one"
+    );
+
+    assert_eq!(
+        graph
+            .update_vertex_weight(VertexIndex(0), Vertex::new("one"))
+            .unwrap_err()
+            .to_string(),
+        "VertexIndex 0 weight is unchanged (no-op)",
+        "should reference the vertex by index"
+    );
+}
@@ -0,0 +1,373 @@
+//! Integration tests.
+
+use hypergraph::{
+    FrozenHypergraph,
+    FrozenReport,
+    HyperedgeIndex,
+    Hypergraph,
+    VertexIndex,
+    errors::HypergraphError,
+    frozen::FROZEN_VERSION,
+};
+
+#[test]
+fn integration_frozen() {
+    // Create a new hypergraph.
+    let mut graph = Hypergraph::<usize, usize>::new();
+
+    // Create some vertices.
+    let a = graph.add_vertex(1).unwrap();
+    let b = graph.add_vertex(2).unwrap();
+    let c = graph.add_vertex(3).unwrap();
+    let d = graph.add_vertex(4).unwrap();
+    let e = graph.add_vertex(5).unwrap();
+
+    // Create some hyperedges.
+    let alpha = graph.add_hyperedge(vec![a, b, c], 10).unwrap();
+    let beta = graph.add_hyperedge(vec![b, d, b, e], 20).unwrap();
+    let gamma = graph.add_hyperedge(vec![e, c], 30).unwrap();
+
+    // Remove a vertex and a hyperedge to get holes in the stable indexes and
+    // an internal order differing from the stable one.
+    graph.remove_vertex(a).unwrap();
+    graph.remove_hyperedge(alpha).unwrap();
+
+    // Round-trip via a file.
+    let path = std::env::temp_dir().join(format!("hypergraph-{}.frozen", std::process::id()));
+
+    graph.save_frozen(&path).unwrap();
+
+    let frozen = FrozenHypergraph::<usize, usize>::load(&path).unwrap();
+
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(frozen.count_vertices(), 4);
+    assert_eq!(frozen.count_hyperedges(), 2);
+
+    for vertex_index in [b, c, d, e] {
+        assert_eq!(
+            frozen.get_vertex_weight(vertex_index),
+            graph.get_vertex_weight(vertex_index).copied(),
+            "should preserve the weights of the vertices"
+        );
+        assert_eq!(
+            frozen.get_vertex_hyperedges(vertex_index),
+            graph.get_vertex_hyperedges(vertex_index)
+        );
+        assert_eq!(
+            frozen.get_adjacent_vertices_from(vertex_index),
+            graph.get_adjacent_vertices_from(vertex_index)
+        );
+        assert_eq!(
+            frozen.get_adjacent_vertices_to(vertex_index),
+            graph.get_adjacent_vertices_to(vertex_index)
+        );
+        assert_eq!(
+            frozen.get_vertex_degree_in(vertex_index),
+            graph.get_vertex_degree_in(vertex_index)
+        );
+        assert_eq!(
+            frozen.get_vertex_degree_out(vertex_index),
+            graph.get_vertex_degree_out(vertex_index)
+        );
+    }
+
+    for hyperedge_index in [beta, gamma] {
+        assert_eq!(
+            frozen.get_hyperedge_weight(hyperedge_index),
+            graph.get_hyperedge_weight(hyperedge_index).copied(),
+            "should preserve the weights of the hyperedges"
+        );
+        assert_eq!(
+            frozen.get_hyperedge_vertices(hyperedge_index),
+            graph.get_hyperedge_vertices(hyperedge_index)
+        );
+    }
+
+    assert_eq!(frozen.get_adjacent_vertices_from(b), Ok(vec![d, e]));
+    assert_eq!(frozen.get_vertex_degree_in(b), Ok(1));
+
+    // Errors.
+    assert_eq!(
+        frozen.get_vertex_weight(a),
+        Err(HypergraphError::VertexIndexNotFound(a))
+    );
+    assert_eq!(
+        frozen.get_hyperedge_vertices(alpha),
+        Err(HypergraphError::HyperedgeIndexNotFound(alpha))
+    );
+    assert_eq!(
+        frozen.get_vertex_hyperedges(VertexIndex(42)),
+        Err(HypergraphError::VertexIndexNotFound(VertexIndex(42)))
+    );
+
+    let mut bytes = vec![];
+
+    graph.write_frozen(&mut bytes).unwrap();
+
+    assert!(
+        FrozenHypergraph::<usize, usize>::from_bytes(bytes.clone()).is_ok(),
+        "should read from bytes"
+    );
+    assert_eq!(
+        FrozenHypergraph::<usize, usize>::from_bytes(b"not frozen".to_vec()).unwrap_err(),
+        HypergraphError::FrozenInvalidHeader,
+        "should reject an invalid header"
+    );
+
+    let mut newer = bytes.clone();
+
+    newer[8..12].copy_from_slice(&(FROZEN_VERSION + 1).to_le_bytes());
+
+    assert_eq!(
+        FrozenHypergraph::<usize, usize>::from_bytes(newer).unwrap_err(),
+        HypergraphError::FrozenUnsupportedVersion(FROZEN_VERSION + 1),
+        "should reject a newer version"
+    );
+    assert!(
+        matches!(
+            FrozenHypergraph::<usize, usize>::from_bytes(bytes[..bytes.len() - 1].to_vec()),
+            Err(HypergraphError::FrozenCorrupted(_))
+        ),
+        "should reject a truncated file"
+    );
+
+    // Point the first incidence of the hyperedges to a missing vertex. It is
+    // located before the 6 incidences, the 3 weights offsets and the 8 bytes
+    // of weights.
+    let mut invalid = bytes.clone();
+    let position = bytes.len() - 8 - 8 * 3 - 8 * 6;
+
+    invalid[position..position + 8].copy_from_slice(&42_u64.to_le_bytes());
+
+    assert_eq!(
+        FrozenHypergraph::<usize, usize>::from_bytes(invalid).unwrap_err(),
+        HypergraphError::FrozenCorrupted("invalid incidence".to_owned()),
+        "should reject an invalid incidence"
+    );
+    assert!(
+        FrozenHypergraph::<u8, usize>::from_bytes(bytes.clone())
+            .unwrap()
+            .get_hyperedge_weight(HyperedgeIndex(1))
+            .is_ok(),
+        "should parse the weights with another type"
+    );
+    assert!(
+        matches!(
+            FrozenHypergraph::<usize, usize>::load(std::env::temp_dir().join("missing.frozen")),
+            Err(HypergraphError::FrozenIo(_))
+        ),
+        "should return an I/O error"
+    );
+}
+
+#[test]
+fn integration_frozen_page_rank() {
+    // Create a new hypergraph with a cycle.
+    let mut graph = Hypergraph::<usize, usize>::new();
+
+    let a = graph.add_vertex(1).unwrap();
+    let b = graph.add_vertex(2).unwrap();
+    let c = graph.add_vertex(3).unwrap();
+
+    graph.add_hyperedge(vec![a, b, c], 10).unwrap();
+    graph.add_hyperedge(vec![c, a], 20).unwrap();
+
+    let ranks = graph.freeze().unwrap().page_rank(0.85, 100, 1e-10);
+
+    assert_eq!(
+        ranks.iter().map(|(index, _)| *index).collect::<Vec<_>>(),
+        vec![a, b, c]
+    );
+    assert!(
+        ranks.iter().all(|(_, rank)| (rank - 1. / 3.).abs() < 1e-9),
+        "should rank the vertices of a cycle equally"
+    );
+
+    // Add a dangling vertex, sharing the rank of its predecessor.
+    let d = graph.add_vertex(4).unwrap();
+
+    graph.add_hyperedge(vec![b, d], 30).unwrap();
+
+    let ranks = graph.freeze().unwrap().page_rank(0.85, 100, 1e-10);
+
+    assert!((ranks.iter().map(|(_, rank)| rank).sum::<f64>() - 1.).abs() < 1e-9);
+    assert!(ranks[1].1 > ranks[3].1);
+    assert!((ranks[2].1 - ranks[3].1).abs() < 1e-9);
+
+    // An empty hypergraph has no ranks.
+    assert!(
+        Hypergraph::<usize, usize>::new()
+            .freeze()
+            .unwrap()
+            .page_rank(0.85, 100, 1e-10)
+            .is_empty()
+    );
+}
+
+#[test]
+fn integration_frozen_verify_store() {
+    let mut graph = Hypergraph::<usize, usize>::new();
+
+    let a = graph.add_vertex(1).unwrap();
+    let b = graph.add_vertex(2).unwrap();
+    let c = graph.add_vertex(3).unwrap();
+    let alpha = graph.add_hyperedge(vec![a, b], 4).unwrap();
+    let beta = graph.add_hyperedge(vec![b, c], 5).unwrap();
+
+    let path =
+        std::env::temp_dir().join(format!("hypergraph-verify-{}.frozen", std::process::id()));
+
+    graph.save_frozen(&path).unwrap();
+
+    assert_eq!(
+        FrozenHypergraph::<usize, usize>::verify_store(&path, true),
+        Ok(FrozenReport::default()),
+        "should find no damage"
+    );
+
+    // Make the first vertex list the second hyperedge instead of the first
+    // one. The incidences of the vertices follow the header, the indexes,
+    // the lookup and the offsets of the three vertices.
+    let mut bytes = std::fs::read(&path).unwrap();
+    let incidence = 64 + 3 * 8 + 6 * 8 + 4 * 8;
+
+    bytes[incidence..incidence + 8].copy_from_slice(&1u64.to_le_bytes());
+
+    // Break the weight of the first vertex, the weights being at the end.
+    let weights = bytes.len() - "12345".len();
+
+    bytes[weights] = b'x';
+
+    std::fs::write(&path, &bytes).unwrap();
+
+    let report = FrozenHypergraph::<usize, usize>::verify_store(&path, false).unwrap();
+
+    assert_eq!(
+        report,
+        FrozenReport {
+            dangling_vertices_references: vec![(a, beta)],
+            dangling_hyperedges_references: vec![(alpha, a)],
+            invalid_vertices_weights: vec![a],
+            invalid_hyperedges_weights: vec![],
+            repaired: false,
+        }
+    );
+    assert!(!report.is_consistent());
+    assert_eq!(
+        std::fs::read(&path).unwrap(),
+        bytes,
+        "should not modify the file without repair"
+    );
+
+    // Repair the incidences, the weight remaining invalid.
+    assert!(
+        FrozenHypergraph::<usize, usize>::verify_store(&path, true)
+            .unwrap()
+            .repaired
+    );
+
+    let report = FrozenHypergraph::<usize, usize>::verify_store(&path, false).unwrap();
+
+    assert!(report.dangling_vertices_references.is_empty());
+    assert!(report.dangling_hyperedges_references.is_empty());
+    assert_eq!(report.invalid_vertices_weights, vec![a]);
+
+    let frozen = FrozenHypergraph::<usize, usize>::load(&path).unwrap();
+
+    assert_eq!(frozen.get_vertex_hyperedges(a), Ok(vec![alpha]));
+    assert_eq!(frozen.get_vertex_hyperedges(b), Ok(vec![alpha, beta]));
+
+    drop(frozen);
+    std::fs::remove_file(&path).unwrap();
+
+    // A damaged layout is returned as an error.
+    std::fs::write(&path, &bytes[..bytes.len() - 1]).unwrap();
+
+    assert!(matches!(
+        FrozenHypergraph::<usize, usize>::verify_store(&path, true),
+        Err(HypergraphError::FrozenCorrupted(_))
+    ));
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn integration_frozen_thaw() {
+    let mut graph = Hypergraph::<usize, usize>::new();
+
+    let a = graph.add_vertex(1).unwrap();
+    let b = graph.add_vertex(2).unwrap();
+    let c = graph.add_vertex(3).unwrap();
+    let d = graph.add_vertex_allow_duplicate(3).unwrap();
+    let alpha = graph.add_hyperedge(vec![a, b], 4).unwrap();
+    let beta = graph.add_hyperedge(vec![b, c, d], 5).unwrap();
+    let gamma = graph.add_hyperedge(vec![d, a], 6).unwrap();
+
+    // Create gaps in the stable indexes.
+    graph.remove_vertex(a).unwrap();
+    graph.remove_hyperedge(alpha).unwrap();
+
+    let path = std::env::temp_dir().join(format!("hypergraph-thaw-{}.frozen", std::process::id()));
+
+    graph.save_frozen(&path).unwrap();
+
+    let mut thawed = Hypergraph::<usize, usize>::load_frozen(&path).unwrap();
+
+    std::fs::remove_file(&path).unwrap();
+
+    // The stable indexes are preserved.
+    assert_eq!(thawed.count_vertices(), 3);
+    assert_eq!(thawed.count_hyperedges(), 2);
+    assert_eq!(thawed.get_vertex_weight(d), Ok(&3));
+    assert_eq!(thawed.get_hyperedge_vertices(beta), Ok(vec![b, c, d]));
+    assert_eq!(thawed.get_hyperedge_vertices(gamma), Ok(vec![d]));
+    assert_eq!(thawed.get_vertex_hyperedges(b), Ok(vec![beta]));
+    assert_eq!(thawed.get_vertex_hyperedges(d), Ok(vec![beta, gamma]));
+    assert_eq!(
+        thawed.get_vertex_weight(a),
+        Err(HypergraphError::VertexIndexNotFound(a))
+    );
+
+    // The thawed hypergraph is mutable and keeps counting from the frozen
+    // indexes.
+    assert_eq!(thawed.add_vertex(7), Ok(VertexIndex(4)));
+    assert_eq!(thawed.add_hyperedge(vec![c, b], 8), Ok(HyperedgeIndex(3)));
+    assert_eq!(thawed.get_adjacent_vertices_from(c), Ok(vec![b, d]));
+
+    // The round trip works in memory as well.
+    assert_eq!(
+        graph
+            .freeze()
+            .unwrap()
+            .thaw()
+            .unwrap()
+            .get_hyperedges_connecting(c, d),
+        Ok(vec![beta])
+    );
+}
+
+#[cfg(feature = "mmap")]
+#[test]
+#[allow(unsafe_code)]
+fn integration_frozen_mapped() {
+    let mut graph = Hypergraph::<usize, usize>::new();
+
+    let a = graph.add_vertex(1).unwrap();
+    let b = graph.add_vertex(2).unwrap();
+    let alpha = graph.add_hyperedge(vec![a, b], 10).unwrap();
+
+    let path =
+        std::env::temp_dir().join(format!("hypergraph-mapped-{}.frozen", std::process::id()));
+
+    graph.save_frozen(&path).unwrap();
+
+    // SAFETY: the file isn't modified while it is mapped.
+    let frozen = unsafe { FrozenHypergraph::<usize, usize>::load_mapped(&path) }.unwrap();
+
+    assert_eq!(frozen.count_vertices(), 2);
+    assert_eq!(frozen.get_hyperedge_weight(alpha), Ok(10));
+    assert_eq!(frozen.thaw(), Ok(graph));
+
+    std::fs::remove_file(&path).unwrap();
+}
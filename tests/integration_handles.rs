@@ -0,0 +1,60 @@
+//! Integration tests.
+
+use hypergraph::{
+    HyperedgeIndex,
+    Hypergraph,
+    VertexIndex,
+    errors::HypergraphError,
+};
+
+#[test]
+fn integration_handles() {
+    // Create a new hypergraph.
+    let mut graph = Hypergraph::<usize, usize>::new();
+
+    // Create some vertices.
+    let a = graph.add_vertex(1).unwrap();
+    let b = graph.add_vertex(2).unwrap();
+    let c = graph.add_vertex(3).unwrap();
+
+    // Create some hyperedges.
+    let alpha = graph.add_hyperedge(vec![a, b, c], 10).unwrap();
+    let beta = graph.add_hyperedge(vec![c, b], 20).unwrap();
+
+    // Explore a vertex.
+    let vertex = graph.vertex(b).unwrap();
+
+    assert_eq!(vertex.index(), b);
+    assert_eq!(vertex.weight(), &2);
+    assert_eq!(vertex.hyperedges(), Ok(vec![alpha, beta]));
+    assert_eq!(vertex.degree_in(), Ok(2));
+    assert_eq!(vertex.degree_out(), Ok(1));
+    assert_eq!(vertex.adjacent_from(), Ok(vec![c]));
+    assert_eq!(vertex.adjacent_to(), Ok(vec![a, c]));
+
+    // Explore a hyperedge.
+    let hyperedge = graph.hyperedge(beta).unwrap();
+
+    assert_eq!(hyperedge.index(), beta);
+    assert_eq!(hyperedge.weight(), &20);
+    assert_eq!(hyperedge.vertices(), Ok(vec![c, b]));
+    assert_eq!(
+        hyperedge
+            .vertex_refs()
+            .unwrap()
+            .iter()
+            .map(|vertex| *vertex.weight())
+            .collect::<Vec<usize>>(),
+        vec![3, 2]
+    );
+
+    // Unknown indexes.
+    assert_eq!(
+        graph.vertex(VertexIndex(3)).err(),
+        Some(HypergraphError::VertexIndexNotFound(VertexIndex(3)))
+    );
+    assert_eq!(
+        graph.hyperedge(HyperedgeIndex(2)).err(),
+        Some(HypergraphError::HyperedgeIndexNotFound(HyperedgeIndex(2)))
+    );
+}
@@ -0,0 +1,119 @@
+//! Integration tests.
+
+use hypergraph::{
+    HyperedgeIndex,
+    JournalEntry,
+    JournalOperation,
+    JournaledHypergraph,
+    VertexIndex,
+    errors::HypergraphError,
+};
+
+// Private helper to get the sequence numbers and the operations of some
+// entries.
+fn summarize(history: Vec<JournalEntry>) -> Vec<(usize, JournalOperation)> {
+    history
+        .into_iter()
+        .map(|entry| (entry.sequence_number, entry.operation))
+        .collect()
+}
+
+#[test]
+fn integration_history() {
+    let path =
+        std::env::temp_dir().join(format!("hypergraph-history-{}.journal", std::process::id()));
+
+    let mut graph = JournaledHypergraph::<usize, usize>::create(&path).unwrap();
+
+    let a = graph.add_vertex(1).unwrap();
+    let b = graph.add_vertex(2).unwrap();
+    let c = graph.add_vertex(3).unwrap();
+    let alpha = graph.add_hyperedge(vec![a, b], 10).unwrap();
+
+    graph.set_vertex_attr(a, "name", "a").unwrap();
+
+    let beta = graph.add_hyperedge(vec![b, c], 20).unwrap();
+
+    graph.update_hyperedge_weight(alpha, 11).unwrap();
+    graph.remove_vertex(a).unwrap();
+    graph.tag_hyperedge(beta, "red").unwrap();
+    graph.remap_indexes().unwrap();
+    graph.set_metadata("source", "test").unwrap();
+
+    assert_eq!(graph.get_sequence_number(), 11);
+
+    // Time-travel to some past states.
+    let empty = graph.as_of(0).unwrap();
+
+    assert_eq!(empty.count_vertices(), 0);
+    assert_eq!(empty.get_sequence_number(), 0);
+
+    let past = graph.as_of(4).unwrap();
+
+    assert_eq!(past.count_vertices(), 3);
+    assert_eq!(past.get_hyperedge_weight(alpha), Ok(&10));
+    assert_eq!(past.get_hyperedge_vertices(alpha), Ok(vec![a, b]));
+    assert_eq!(graph.as_of(7).unwrap().get_hyperedge_weight(alpha), Ok(&11));
+    assert_eq!(graph.as_of(11).unwrap().into_inner(), *graph);
+    assert_eq!(
+        graph.as_of(12).err(),
+        Some(HypergraphError::JournalSequenceNumberNotFound(12)),
+        "should return an explicit error"
+    );
+
+    // The entities are followed across the remapping, b and c being now
+    // the first two vertices.
+    assert_eq!(
+        summarize(graph.history_of(VertexIndex(0)).unwrap()),
+        vec![
+            (2, JournalOperation::AddVertex),
+            (4, JournalOperation::AddHyperedge),
+            (6, JournalOperation::AddHyperedge),
+            (10, JournalOperation::RemapIndexes),
+        ]
+    );
+    assert_eq!(
+        summarize(graph.history_of(VertexIndex(1)).unwrap()),
+        vec![
+            (3, JournalOperation::AddVertex),
+            (6, JournalOperation::AddHyperedge),
+            (10, JournalOperation::RemapIndexes),
+        ]
+    );
+    assert_eq!(
+        summarize(graph.history_of(alpha).unwrap()),
+        vec![
+            (4, JournalOperation::AddHyperedge),
+            (7, JournalOperation::UpdateHyperedgeWeight),
+            (8, JournalOperation::RemoveVertex),
+        ]
+    );
+    assert_eq!(
+        summarize(graph.history_of(beta).unwrap()),
+        vec![
+            (6, JournalOperation::AddHyperedge),
+            (9, JournalOperation::TagHyperedge),
+        ]
+    );
+    assert_eq!(graph.history_of(HyperedgeIndex(2)), Ok(vec![]));
+
+    // A recycled index only refers to its last entity.
+    graph.remove_vertex(VertexIndex(1)).unwrap();
+    graph.recycle_indexes().unwrap();
+
+    assert_eq!(graph.add_vertex(4), Ok(VertexIndex(1)));
+    assert_eq!(
+        summarize(graph.history_of(VertexIndex(1)).unwrap()),
+        vec![(14, JournalOperation::AddVertex)]
+    );
+
+    // The sequence number is restored when reopening the journal.
+    drop(graph);
+
+    let graph = JournaledHypergraph::<usize, usize>::open(&path).unwrap();
+
+    assert_eq!(graph.get_sequence_number(), 14);
+    assert_eq!(graph.as_of(4).unwrap().count_hyperedges(), 1);
+
+    std::fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,52 @@
+//! Integration tests.
+
+mod common;
+
+use common::{
+    Hyperedge,
+    Vertex,
+};
+use hypergraph::Hypergraph;
+
+#[test]
+fn integration_hitting_set() {
+    // Create a new hypergraph.
+    let mut graph = Hypergraph::<Vertex, Hyperedge>::new();
+
+    // Create some vertices.
+    let a = graph.add_vertex(Vertex::new("one")).unwrap();
+    let b = graph.add_vertex(Vertex::new("two")).unwrap();
+    let c = graph.add_vertex(Vertex::new("three")).unwrap();
+    let d = graph.add_vertex(Vertex::new("four")).unwrap();
+    let e = graph.add_vertex(Vertex::new("five")).unwrap();
+    graph.add_vertex(Vertex::new("six")).unwrap();
+
+    assert_eq!(
+        graph.minimum_hitting_set(),
+        Ok(vec![]),
+        "should get an empty set without hyperedges"
+    );
+
+    // Add some hyperedges.
+    graph
+        .add_hyperedge(vec![a, b], Hyperedge::new("one", 1))
+        .unwrap();
+    graph
+        .add_hyperedge(vec![b, c, b], Hyperedge::new("two", 1))
+        .unwrap();
+    graph
+        .add_hyperedge(vec![b, d], Hyperedge::new("three", 1))
+        .unwrap();
+    graph
+        .add_hyperedge(vec![d, e], Hyperedge::new("four", 1))
+        .unwrap();
+    graph
+        .add_hyperedge(vec![e], Hyperedge::new("five", 1))
+        .unwrap();
+
+    assert_eq!(
+        graph.minimum_hitting_set(),
+        Ok(vec![b, e]),
+        "should hit every hyperedge with the fewest vertices"
+    );
+}
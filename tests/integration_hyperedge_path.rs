@@ -0,0 +1,70 @@
+//! Integration tests.
+
+mod common;
+
+use common::{
+    Hyperedge,
+    Vertex,
+};
+use hypergraph::{
+    HyperedgeIndex,
+    Hypergraph,
+    errors::HypergraphError,
+};
+
+#[test]
+fn integration_hyperedge_path() {
+    // Create a new hypergraph.
+    let mut graph = Hypergraph::<Vertex, Hyperedge>::new();
+
+    // Create some vertices.
+    let a = graph.add_vertex(Vertex::new("a")).unwrap();
+    let b = graph.add_vertex(Vertex::new("b")).unwrap();
+    let c = graph.add_vertex(Vertex::new("c")).unwrap();
+    let d = graph.add_vertex(Vertex::new("d")).unwrap();
+    let e = graph.add_vertex(Vertex::new("e")).unwrap();
+    let f = graph.add_vertex(Vertex::new("f")).unwrap();
+
+    // Add some hyperedges.
+    let alpha = graph
+        .add_hyperedge(vec![a, b], Hyperedge::new("alpha", 1))
+        .unwrap();
+    let beta = graph
+        .add_hyperedge(vec![c, b], Hyperedge::new("beta", 1))
+        .unwrap();
+    let gamma = graph
+        .add_hyperedge(vec![c, d], Hyperedge::new("gamma", 1))
+        .unwrap();
+    let delta = graph
+        .add_hyperedge(vec![d, a, e], Hyperedge::new("delta", 1))
+        .unwrap();
+    let epsilon = graph
+        .add_hyperedge(vec![f], Hyperedge::new("epsilon", 1))
+        .unwrap();
+
+    assert_eq!(
+        graph.get_hyperedge_path(alpha, alpha),
+        Ok(vec![alpha]),
+        "should get the hyperedge itself"
+    );
+    assert_eq!(
+        graph.get_hyperedge_path(beta, gamma),
+        Ok(vec![beta, gamma]),
+        "should get adjacent hyperedges regardless of the direction"
+    );
+    assert_eq!(
+        graph.get_hyperedge_path(beta, delta),
+        Ok(vec![beta, gamma, delta]),
+        "should get one of the shortest paths"
+    );
+    assert_eq!(
+        graph.get_hyperedge_path(alpha, epsilon),
+        Ok(vec![]),
+        "should get an empty path"
+    );
+    assert_eq!(
+        graph.get_hyperedge_path(alpha, HyperedgeIndex(5)),
+        Err(HypergraphError::HyperedgeIndexNotFound(HyperedgeIndex(5))),
+        "should return an explicit error"
+    );
+}
@@ -0,0 +1,127 @@
+//! Integration tests.
+
+mod common;
+
+use common::{
+    Hyperedge,
+    Vertex,
+};
+use hypergraph::{
+    HyperedgeIndex,
+    Hypergraph,
+    VertexIndex,
+    errors::HypergraphError,
+};
+
+#[test]
+fn integration_incidence_roles() {
+    // Create a new hypergraph.
+    let mut graph = Hypergraph::<Vertex, Hyperedge>::new();
+
+    // Create some vertices.
+    let hydrogen = graph.add_vertex(Vertex::new("hydrogen")).unwrap();
+    let oxygen = graph.add_vertex(Vertex::new("oxygen")).unwrap();
+    let platinum = graph.add_vertex(Vertex::new("platinum")).unwrap();
+    let water = graph.add_vertex(Vertex::new("water")).unwrap();
+
+    // Add a hyperedge with roles and another one without.
+    let reaction = graph
+        .add_hyperedge_with_roles(
+            vec![
+                (hydrogen, "input"),
+                (oxygen, "input"),
+                (platinum, "catalyst"),
+                (water, "output"),
+            ],
+            Hyperedge::new("combustion", 1),
+        )
+        .unwrap();
+    let plain = graph
+        .add_hyperedge(vec![water, platinum], Hyperedge::new("plain", 1))
+        .unwrap();
+
+    assert_eq!(
+        graph.get_hyperedge_vertices(reaction),
+        Ok(vec![hydrogen, oxygen, platinum, water]),
+        "should keep the usual adjacency semantics"
+    );
+    assert_eq!(
+        graph.get_hyperedge_roles(reaction),
+        Ok(vec![
+            (hydrogen, Some("input")),
+            (oxygen, Some("input")),
+            (platinum, Some("catalyst")),
+            (water, Some("output")),
+        ]),
+        "should get the roles of the vertices"
+    );
+    assert_eq!(
+        graph.get_hyperedge_roles(plain),
+        Ok(vec![(water, None), (platinum, None)]),
+        "should get no roles"
+    );
+    assert_eq!(
+        graph.get_hyperedge_vertices_by_role(reaction, "input"),
+        Ok(vec![hydrogen, oxygen]),
+        "should filter the vertices by role"
+    );
+    assert_eq!(
+        graph.get_vertex_hyperedges_by_role(platinum, "catalyst"),
+        Ok(vec![reaction]),
+        "should filter the hyperedges by role"
+    );
+    assert_eq!(
+        graph.get_vertex_hyperedges_by_role(platinum, "input"),
+        Ok(vec![]),
+        "should get no hyperedges"
+    );
+    assert_eq!(
+        graph.get_hyperedge_roles(HyperedgeIndex(2)),
+        Err(HypergraphError::HyperedgeIndexNotFound(HyperedgeIndex(2))),
+        "should return an explicit error"
+    );
+    assert_eq!(
+        graph.add_hyperedge_with_roles(
+            vec![(VertexIndex(4), "input")],
+            Hyperedge::new("missing", 1)
+        ),
+        Err(HypergraphError::VertexIndexNotFound(VertexIndex(4))),
+        "should return an explicit error"
+    );
+
+    // Reversing the hyperedge reverses the roles.
+    assert_eq!(graph.reverse_hyperedge(reaction), Ok(()));
+    assert_eq!(
+        graph.get_hyperedge_vertices_by_role(reaction, "output"),
+        Ok(vec![water]),
+        "should follow the reversed vertices"
+    );
+    assert_eq!(
+        graph.get_hyperedge_roles(reaction).unwrap()[0],
+        (water, Some("output")),
+        "should reverse the roles"
+    );
+
+    // Removing a vertex keeps the roles of the other ones.
+    assert_eq!(graph.remove_vertex(platinum), Ok(()));
+    assert_eq!(
+        graph.get_hyperedge_roles(reaction),
+        Ok(vec![
+            (water, Some("output")),
+            (oxygen, Some("input")),
+            (hydrogen, Some("input")),
+        ]),
+        "should drop the role of the removed vertex"
+    );
+
+    // Updating the vertices drops the roles.
+    assert_eq!(
+        graph.update_hyperedge_vertices(reaction, vec![hydrogen, water]),
+        Ok(())
+    );
+    assert_eq!(
+        graph.get_hyperedge_roles(reaction),
+        Ok(vec![(hydrogen, None), (water, None)]),
+        "should drop the roles"
+    );
+}
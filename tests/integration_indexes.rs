@@ -0,0 +1,42 @@
+//! Integration tests.
+
+use hypergraph::{
+    HyperedgeIndex,
+    VertexIndex,
+};
+
+#[test]
+fn integration_indexes() {
+    // Convert from and to usize.
+    assert_eq!(VertexIndex::from(3), VertexIndex(3));
+    assert_eq!(HyperedgeIndex::from(3), HyperedgeIndex(3));
+    assert_eq!(usize::from(VertexIndex(3)), 3);
+    assert_eq!(usize::from(HyperedgeIndex(3)), 3);
+    assert_eq!(VertexIndex(3).as_usize(), 3);
+    assert_eq!(HyperedgeIndex(3).as_usize(), 3);
+
+    // Convert from u32.
+    assert_eq!(VertexIndex::try_from(7u32), Ok(VertexIndex(7)));
+    assert_eq!(HyperedgeIndex::try_from(7u32), Ok(HyperedgeIndex(7)));
+
+    // Display the indexes.
+    assert_eq!(VertexIndex(5).to_string(), "5");
+    assert_eq!(HyperedgeIndex(5).to_string(), "5");
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn integration_indexes_serde() {
+    // The indexes are serialized as plain numbers.
+    assert_eq!(serde_json::to_string(&VertexIndex(2)).unwrap(), "2");
+    assert_eq!(serde_json::to_string(&HyperedgeIndex(4)).unwrap(), "4");
+
+    assert_eq!(
+        serde_json::from_str::<Vec<VertexIndex>>("[0,1]").unwrap(),
+        vec![VertexIndex(0), VertexIndex(1)]
+    );
+    assert_eq!(
+        serde_json::from_str::<HyperedgeIndex>("9").unwrap(),
+        HyperedgeIndex(9)
+    );
+}
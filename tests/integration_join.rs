@@ -8,6 +8,7 @@ use common::{
 };
 use hypergraph::{
     Hypergraph,
+    JournaledHypergraph,
     errors::HypergraphError,
 };
 
@@ -92,3 +93,66 @@ fn integration_contration() {
         "should return an explicit error"
     );
 }
+
+#[test]
+fn integration_join_with() {
+    let mut graph = Hypergraph::<usize, usize>::new();
+
+    let a = graph.add_vertex(1).unwrap();
+    let b = graph.add_vertex(2).unwrap();
+    let c = graph.add_vertex(3).unwrap();
+
+    let alpha = graph.add_hyperedge(vec![a, b], 10).unwrap();
+    let beta = graph.add_hyperedge(vec![b, c], 20).unwrap();
+    let gamma = graph.add_hyperedge(vec![c], 30).unwrap();
+    let delta = graph.add_hyperedge(vec![c, a], 50).unwrap();
+
+    let sum = |weights: Vec<usize>| weights.into_iter().sum::<usize>();
+
+    // The combined weight must not be assigned to another hyperedge.
+    let other = graph.clone();
+
+    assert_eq!(
+        graph.join_hyperedges_with(&[alpha, beta], sum),
+        Err(HypergraphError::HyperedgeWeightAlreadyAssigned(gamma)),
+        "should return an explicit error"
+    );
+    assert_eq!(graph, other, "should not mutate the hypergraph");
+
+    // Sum the costs of the joined hyperedges.
+    assert_eq!(graph.join_hyperedges_with(&[alpha, gamma], sum), Ok(()));
+    assert_eq!(graph.get_hyperedge_weight(alpha), Ok(&40));
+    assert_eq!(graph.get_hyperedge_vertices(alpha), Ok(vec![a, b, c]));
+
+    // The combined weight can be the one of a joined hyperedge.
+    assert_eq!(
+        graph.join_hyperedges_with(&[beta, delta], |weights| weights[1]),
+        Ok(())
+    );
+    assert_eq!(graph.get_hyperedge_weight(beta), Ok(&50));
+    assert_eq!(graph.count_hyperedges(), 2);
+}
+
+#[test]
+fn integration_join_with_journal() {
+    let path = std::env::temp_dir().join(format!("hypergraph-join-{}.journal", std::process::id()));
+
+    let mut graph = JournaledHypergraph::<usize, usize>::create(&path).unwrap();
+
+    let a = graph.add_vertex(1).unwrap();
+    let b = graph.add_vertex(2).unwrap();
+    let alpha = graph.add_hyperedge(vec![a], 10).unwrap();
+    let beta = graph.add_hyperedge(vec![b], 20).unwrap();
+
+    graph
+        .join_hyperedges_with(&[alpha, beta], |weights| weights.into_iter().sum())
+        .unwrap();
+
+    let graph = graph.into_inner();
+    let replayed = Hypergraph::<usize, usize>::replay(&path).unwrap();
+
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(replayed, graph, "should replay the join");
+    assert_eq!(replayed.get_hyperedge_weight(alpha), Ok(&30));
+}
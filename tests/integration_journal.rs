@@ -0,0 +1,212 @@
+//! Integration tests.
+
+use std::io::Write;
+
+use hypergraph::{
+    HyperedgeIndex,
+    Hypergraph,
+    JournaledHypergraph,
+    Value,
+    VertexIndex,
+    errors::HypergraphError,
+    journal::JOURNAL_VERSION,
+};
+
+#[test]
+fn integration_journal() {
+    let path = std::env::temp_dir().join(format!("hypergraph-{}.journal", std::process::id()));
+
+    // Create a new journaled hypergraph.
+    let mut graph = JournaledHypergraph::<usize, usize>::create(&path).unwrap();
+
+    // Create some vertices.
+    let a = graph.add_vertex(1).unwrap();
+    let b = graph.add_vertex(2).unwrap();
+    let c = graph.add_vertex(3).unwrap();
+    let d = graph.add_vertex_allow_duplicate(3).unwrap();
+
+    // Create some hyperedges.
+    let alpha = graph.add_hyperedge(vec![a, b, c], 10).unwrap();
+    let beta = graph.add_hyperedge(vec![c, a], 20).unwrap();
+    let gamma = graph
+        .add_hyperedge_with_roles(vec![(d, "input"), (b, "output")], 30)
+        .unwrap();
+
+    graph.set_vertex_attr(b, "name", "bob").unwrap();
+    graph.set_hyperedge_property(gamma, "cost", 1.5).unwrap();
+    graph.update_vertex_weight(c, 4).unwrap();
+    graph.reverse_hyperedge(beta).unwrap();
+
+    // Failed mutations are not recorded.
+    assert_eq!(
+        graph.add_vertex(2),
+        Err(HypergraphError::VertexWeightAlreadyAssigned(b))
+    );
+
+    // Remove a vertex and a hyperedge to get holes in the stable indexes.
+    graph.remove_vertex(a).unwrap();
+    graph.remove_hyperedge(alpha).unwrap();
+
+    // Queries are available on the journaled hypergraph.
+    assert_eq!(graph.count_vertices(), 3);
+
+    let graph = graph.into_inner();
+
+    // The journal is detected by its magic bytes.
+    assert!(Hypergraph::<usize, usize>::is_journal(&path));
+    assert!(!Hypergraph::<usize, usize>::is_journal(
+        std::env::temp_dir().join("hypergraph-missing.journal")
+    ));
+
+    // Replay the journal.
+    let replayed = Hypergraph::<usize, usize>::replay(&path).unwrap();
+
+    assert_eq!(
+        replayed.content_hash(),
+        graph.content_hash(),
+        "should reconstruct the same content"
+    );
+    assert_eq!(
+        replayed.get_vertex_weight(c),
+        Ok(&4),
+        "should preserve the stable indexes of the vertices"
+    );
+    assert_eq!(
+        replayed.get_hyperedge_vertices(beta),
+        Ok(vec![c]),
+        "should preserve the stable indexes of the hyperedges"
+    );
+    assert_eq!(
+        replayed.get_vertex_attr(b, "name"),
+        Ok(Some(&Value::from("bob"))),
+        "should replay the attributes"
+    );
+    assert_eq!(
+        replayed.get_hyperedge_property(gamma, "cost"),
+        Ok(Some(&Value::Float(1.5))),
+        "should replay the properties"
+    );
+    assert_eq!(
+        replayed.get_hyperedge_vertices_by_role(gamma, "input"),
+        Ok(vec![d]),
+        "should replay the roles"
+    );
+
+    // Simulate a crash while appending a record.
+    let mut file = std::fs::OpenOptions::new()
+        .append(true)
+        .open(&path)
+        .unwrap();
+
+    file.write_all(&[42, 0, 0]).unwrap();
+    drop(file);
+
+    assert_eq!(
+        Hypergraph::<usize, usize>::replay(&path)
+            .unwrap()
+            .content_hash(),
+        graph.content_hash(),
+        "should ignore a truncated trailing record"
+    );
+
+    // Reopen the journal and keep appending to it.
+    let mut reopened = JournaledHypergraph::<usize, usize>::open(&path).unwrap();
+
+    assert_eq!(reopened.add_vertex(5), Ok(VertexIndex(4)));
+    assert_eq!(reopened.add_hyperedge(vec![b], 40), Ok(HyperedgeIndex(3)));
+
+    let reopened = reopened.into_inner();
+
+    assert_eq!(
+        Hypergraph::<usize, usize>::replay(&path)
+            .unwrap()
+            .content_hash(),
+        reopened.content_hash(),
+        "should append after the truncated record"
+    );
+
+    // Clearing is recorded too.
+    let mut reopened = JournaledHypergraph::<usize, usize>::open(&path).unwrap();
+
+    reopened.clear().unwrap();
+
+    assert_eq!(
+        Hypergraph::<usize, usize>::replay(&path)
+            .unwrap()
+            .count_vertices(),
+        0
+    );
+
+    // Joining with an aggregated weight is recorded as a single record.
+    let mut reopened = JournaledHypergraph::<usize, usize>::open(&path).unwrap();
+
+    let e = reopened.add_vertex(6).unwrap();
+    let f = reopened.add_vertex(7).unwrap();
+    let delta = reopened.add_hyperedge(vec![e], 50).unwrap();
+    let epsilon = reopened.add_hyperedge(vec![f], 60).unwrap();
+    let sequence_number = reopened.get_sequence_number();
+
+    reopened
+        .join_hyperedges_with(&[delta, epsilon], |weights| weights.iter().sum())
+        .unwrap();
+
+    assert_eq!(reopened.get_sequence_number(), sequence_number + 1);
+
+    let reopened = reopened.into_inner();
+    let replayed = Hypergraph::<usize, usize>::replay(&path).unwrap();
+
+    assert_eq!(replayed.get_hyperedge_weight(delta), Ok(&110));
+    assert_eq!(
+        replayed.content_hash(),
+        reopened.content_hash(),
+        "should replay the join along with its weight"
+    );
+
+    // Errors.
+    std::fs::write(&path, b"not a journal").unwrap();
+
+    assert_eq!(
+        Hypergraph::<usize, usize>::replay(&path).unwrap_err(),
+        HypergraphError::JournalInvalidHeader,
+        "should reject an invalid header"
+    );
+
+    let mut newer = b"HGJOURNL".to_vec();
+
+    newer.extend_from_slice(&(JOURNAL_VERSION + 1).to_le_bytes());
+    std::fs::write(&path, newer).unwrap();
+
+    assert_eq!(
+        Hypergraph::<usize, usize>::replay(&path).unwrap_err(),
+        HypergraphError::JournalUnsupportedVersion(JOURNAL_VERSION + 1),
+        "should reject a newer version"
+    );
+
+    // A record whose length spans the following records, e.g. after a
+    // partial write, is rejected instead of swallowing them.
+    let mut graph = JournaledHypergraph::<usize, usize>::create(&path).unwrap();
+
+    graph.add_vertex(1).unwrap();
+    graph.add_vertex(2).unwrap();
+
+    let mut bytes = std::fs::read(&path).unwrap();
+
+    bytes[12..20].copy_from_slice(&28_u64.to_le_bytes());
+    std::fs::write(&path, bytes).unwrap();
+
+    assert_eq!(
+        Hypergraph::<usize, usize>::replay(&path).unwrap_err(),
+        HypergraphError::JournalCorrupted("trailing bytes in record".to_owned()),
+        "should reject a record with trailing bytes"
+    );
+
+    std::fs::remove_file(&path).unwrap();
+
+    assert!(
+        matches!(
+            Hypergraph::<usize, usize>::replay(&path),
+            Err(HypergraphError::JournalIo(_))
+        ),
+        "should return an I/O error"
+    );
+}
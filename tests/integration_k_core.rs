@@ -0,0 +1,83 @@
+//! Integration tests.
+
+mod common;
+
+use std::collections::HashMap;
+
+use common::{
+    Hyperedge,
+    Vertex,
+};
+use hypergraph::Hypergraph;
+
+#[test]
+fn integration_k_core() {
+    // Create a new hypergraph.
+    let mut graph = Hypergraph::<Vertex, Hyperedge>::new();
+
+    // Create some vertice weights.
+    let vertex_one = Vertex::new("one");
+    let vertex_two = Vertex::new("two");
+    let vertex_three = Vertex::new("three");
+    let vertex_four = Vertex::new("four");
+    let vertex_five = Vertex::new("five");
+
+    // Create some hyperedge weights.
+    let hyperedge_one = Hyperedge::new("one", 10);
+    let hyperedge_two = Hyperedge::new("two", 20);
+    let hyperedge_three = Hyperedge::new("three", 30);
+    let hyperedge_four = Hyperedge::new("four", 40);
+    let hyperedge_five = Hyperedge::new("five", 50);
+
+    // Create some vertices.
+    let a = graph.add_vertex(vertex_one).unwrap();
+    let b = graph.add_vertex(vertex_two).unwrap();
+    let c = graph.add_vertex(vertex_three).unwrap();
+    let d = graph.add_vertex(vertex_four).unwrap();
+    let e = graph.add_vertex(vertex_five).unwrap();
+
+    // Add some hyperedges.
+    graph.add_hyperedge(vec![a, b], hyperedge_one).unwrap();
+    graph.add_hyperedge(vec![b, c], hyperedge_two).unwrap();
+    graph.add_hyperedge(vec![c, a], hyperedge_three).unwrap();
+    graph
+        .add_hyperedge(vec![a, b, c, c], hyperedge_four)
+        .unwrap();
+    graph.add_hyperedge(vec![c, d], hyperedge_five).unwrap();
+
+    assert_eq!(
+        graph.core_numbers(),
+        Ok(HashMap::from([(a, 3), (b, 3), (c, 3), (d, 1), (e, 0)])),
+        "should get the core numbers of the vertices"
+    );
+
+    // The 2-core strips the periphery.
+    let core = graph.k_core(2).unwrap();
+
+    assert_eq!(core.count_vertices(), 3, "should have three vertices");
+    assert_eq!(
+        core.into_iter().collect::<Vec<(Hyperedge, Vec<Vertex>)>>(),
+        vec![
+            (hyperedge_one, vec![vertex_one, vertex_two]),
+            (hyperedge_two, vec![vertex_two, vertex_three]),
+            (hyperedge_three, vec![vertex_three, vertex_one]),
+            (
+                hyperedge_four,
+                vec![vertex_one, vertex_two, vertex_three, vertex_three]
+            ),
+        ],
+        "should keep the induced hyperedges"
+    );
+
+    // The 0-core is the whole hypergraph.
+    let core = graph.k_core(0).unwrap();
+
+    assert_eq!(core.count_vertices(), 5, "should have five vertices");
+    assert_eq!(core.count_hyperedges(), 5, "should have five hyperedges");
+
+    // The 4-core is empty.
+    let core = graph.k_core(4).unwrap();
+
+    assert_eq!(core.count_vertices(), 0, "should have no vertices");
+    assert_eq!(core.count_hyperedges(), 0, "should have no hyperedges");
+}
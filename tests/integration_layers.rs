@@ -0,0 +1,174 @@
+//! Integration tests.
+
+mod common;
+
+use common::{
+    Hyperedge,
+    Vertex,
+};
+use hypergraph::{
+    Hypergraph,
+    JournaledHypergraph,
+    LayerIndex,
+    LayerMask,
+    errors::HypergraphError,
+};
+
+#[test]
+fn integration_layers() {
+    // Create a new hypergraph.
+    let mut graph = Hypergraph::<Vertex, Hyperedge>::new();
+
+    let a = graph.add_vertex(Vertex::new("a")).unwrap();
+    let b = graph.add_vertex(Vertex::new("b")).unwrap();
+    let c = graph.add_vertex(Vertex::new("c")).unwrap();
+    let d = graph.add_vertex(Vertex::new("d")).unwrap();
+
+    let alpha = graph
+        .add_hyperedge(vec![a, b], Hyperedge::new("alpha", 1))
+        .unwrap();
+    let beta = graph
+        .add_hyperedge(vec![b, c], Hyperedge::new("beta", 1))
+        .unwrap();
+    let gamma = graph
+        .add_hyperedge(vec![a, c], Hyperedge::new("gamma", 10))
+        .unwrap();
+    let delta = graph
+        .add_hyperedge(vec![c, d], Hyperedge::new("delta", 1))
+        .unwrap();
+
+    let social = graph.add_layer("social").unwrap();
+    let work = graph.add_layer("work").unwrap();
+
+    assert_eq!(
+        graph.add_layer("social"),
+        Err(HypergraphError::LayerNameAlreadyAssigned(social)),
+        "should return an explicit error"
+    );
+    assert_eq!(graph.get_layers(), vec![(social, "social"), (work, "work")]);
+    assert_eq!(graph.get_layer_by_name("work"), Some(work));
+    assert_eq!(graph.get_layer_by_name("family"), None);
+    assert_eq!(graph.get_layer_name(social), Ok("social"));
+
+    // Assign the hyperedges to the layers, delta being left out.
+    assert_eq!(graph.set_hyperedge_layer(alpha, Some(social)), Ok(None));
+    assert_eq!(graph.set_hyperedge_layer(gamma, Some(social)), Ok(None));
+    assert_eq!(graph.set_hyperedge_layer(beta, Some(social)), Ok(None));
+    assert_eq!(
+        graph.set_hyperedge_layer(beta, Some(work)),
+        Ok(Some(social)),
+        "should move the hyperedge to the other layer"
+    );
+    assert_eq!(
+        graph.set_hyperedge_layer(delta, Some(LayerIndex(2))),
+        Err(HypergraphError::LayerIndexNotFound(LayerIndex(2))),
+        "should return an explicit error"
+    );
+
+    assert_eq!(graph.get_hyperedge_layer(beta), Ok(Some(work)));
+    assert_eq!(graph.get_hyperedge_layer(delta), Ok(None));
+    assert_eq!(graph.get_layer_hyperedges(social), Ok(vec![alpha, gamma]));
+    assert_eq!(graph.get_layer_hyperedges(work), Ok(vec![beta]));
+    assert_eq!(graph.get_layer_vertices(social), Ok(vec![a, b, c]));
+    assert_eq!(graph.get_layer_vertices(work), Ok(vec![b, c]));
+
+    // Inter-layer coupling.
+    assert_eq!(graph.get_vertex_layers(b), Ok(vec![social, work]));
+    assert_eq!(graph.get_vertex_layers(a), Ok(vec![social]));
+    assert_eq!(graph.get_vertex_layers(d), Ok(vec![]));
+    assert_eq!(graph.get_coupled_vertices(social, work), Ok(vec![b, c]));
+
+    // Traverse the layers of a mask only.
+    let both = [social, work].iter().copied().collect::<LayerMask>();
+
+    assert_eq!(
+        graph.get_dijkstra_connections_in_layers(a, c, &both),
+        Ok(vec![(a, None), (b, Some(alpha)), (c, Some(beta))])
+    );
+    assert_eq!(
+        graph.get_dijkstra_connections_in_layers(a, c, &LayerMask::from(&[social][..])),
+        Ok(vec![(a, None), (c, Some(gamma))])
+    );
+    assert_eq!(
+        graph.get_dijkstra_connections_in_layers(a, d, &both),
+        Ok(vec![]),
+        "should not traverse the hyperedges without any layer"
+    );
+    assert_eq!(
+        graph.get_hyperedges_in_layers(&LayerMask::from(&[work][..])),
+        vec![beta]
+    );
+    assert_eq!(graph.get_hyperedges_in_layers(&LayerMask::new()), vec![]);
+
+    // The layers are part of the equality.
+    let mut other = graph.clone();
+
+    other.set_hyperedge_layer(delta, Some(work)).unwrap();
+
+    assert_ne!(other, graph);
+
+    // Removing a hyperedge removes it from its layer.
+    graph.remove_hyperedge(alpha).unwrap();
+
+    assert_eq!(graph.get_layer_hyperedges(social), Ok(vec![gamma]));
+
+    // Removing a layer keeps its hyperedges.
+    graph.remove_layer(work).unwrap();
+
+    assert_eq!(graph.get_hyperedge_layer(beta), Ok(None));
+    assert_eq!(graph.count_hyperedges(), 3);
+    assert_eq!(
+        graph.remove_layer(work),
+        Err(HypergraphError::LayerIndexNotFound(work)),
+        "should return an explicit error"
+    );
+
+    // The stable indexes of the layers are not reused.
+    assert_eq!(graph.add_layer("work"), Ok(LayerIndex(2)));
+}
+
+#[test]
+fn integration_layers_persistence() {
+    let path =
+        std::env::temp_dir().join(format!("hypergraph-layers-{}.journal", std::process::id()));
+
+    let mut graph = JournaledHypergraph::<usize, usize>::create(&path).unwrap();
+
+    let a = graph.add_vertex(1).unwrap();
+    let b = graph.add_vertex(2).unwrap();
+    let alpha = graph.add_hyperedge(vec![a, b], 10).unwrap();
+    let beta = graph.add_hyperedge(vec![b, a], 20).unwrap();
+
+    let social = graph.add_layer("social").unwrap();
+    let work = graph.add_layer("work").unwrap();
+    let family = graph.add_layer("family").unwrap();
+
+    graph.set_hyperedge_layer(alpha, Some(social)).unwrap();
+    graph.set_hyperedge_layer(beta, Some(work)).unwrap();
+    graph.set_hyperedge_layer(beta, None).unwrap();
+    graph.set_hyperedge_layer(beta, Some(family)).unwrap();
+    graph.remove_layer(work).unwrap();
+
+    let graph = graph.into_inner();
+    let replayed = Hypergraph::<usize, usize>::replay(&path).unwrap();
+
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(replayed, graph, "should replay the layers");
+    assert_eq!(
+        replayed.get_layers(),
+        vec![(social, "social"), (family, "family")]
+    );
+    assert_eq!(replayed.get_hyperedge_layer(beta), Ok(Some(family)));
+
+    // Round-trip via a snapshot.
+    let mut bytes = vec![];
+
+    graph.write_snapshot(&mut bytes).unwrap();
+
+    let mut loaded = Hypergraph::<usize, usize>::read_snapshot(&bytes[..]).unwrap();
+
+    assert_eq!(loaded, graph, "should preserve the layers");
+    assert_eq!(loaded.get_hyperedge_layer(alpha), Ok(Some(social)));
+    assert_eq!(loaded.add_layer("work"), Ok(LayerIndex(3)));
+}
@@ -0,0 +1,54 @@
+//! Integration tests.
+
+mod common;
+
+use common::{
+    Hyperedge,
+    Vertex,
+};
+use hypergraph::{
+    HyperedgeIndex,
+    Hypergraph,
+    VertexIndex,
+    errors::HypergraphError,
+    hypergraph,
+};
+
+#[test]
+fn integration_macro() {
+    let a = Vertex::new("a");
+
+    // Declare the hypergraph literally.
+    let graph: Hypergraph<Vertex, Hyperedge> = hypergraph! {
+        vertices: [a, b = Vertex::new("b"), c = Vertex::new("c")],
+        hyperedges: {
+            Hyperedge::new("ab", 1) => [a, b],
+            Hyperedge::new("bcc", 2) => [b, c, c],
+        },
+    }
+    .unwrap();
+
+    assert_eq!(graph.count_vertices(), 3);
+    assert_eq!(graph.count_hyperedges(), 2);
+    assert_eq!(graph.get_vertex_weight(VertexIndex(0)), Ok(&a));
+    assert_eq!(
+        graph.get_hyperedge_vertices(HyperedgeIndex(1)),
+        Ok(vec![VertexIndex(1), VertexIndex(2), VertexIndex(2)])
+    );
+
+    // The hyperedges are optional.
+    let graph: Hypergraph<Vertex, Hyperedge> = hypergraph! { vertices: [a] }.unwrap();
+
+    assert_eq!(graph.count_vertices(), 1);
+    assert_eq!(graph.count_hyperedges(), 0);
+
+    // The errors are reported by the build.
+    let result: Result<Hypergraph<usize, usize>, HypergraphError> = hypergraph! {
+        vertices: [x = 1, y = 1],
+    };
+
+    assert_eq!(
+        result.err(),
+        Some(HypergraphError::VertexWeightAlreadyAssigned(VertexIndex(0)))
+    );
+}
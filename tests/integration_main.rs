@@ -53,7 +53,7 @@ fn integration_main() {
     );
     assert_eq!(
         graph.add_vertex(enola),
-        Err(HypergraphError::VertexWeightAlreadyAssigned(enola)),
+        Err(HypergraphError::VertexWeightAlreadyAssigned(VertexIndex(4))),
         "should return an explicit error since this weight is already in use"
     );
 
@@ -119,7 +119,7 @@ fn integration_main() {
     assert_eq!(
         graph.add_hyperedge(vec![VertexIndex(3)], fourth_hyperedge,),
         Err(HypergraphError::HyperedgeWeightAlreadyAssigned(
-            fourth_hyperedge
+            HyperedgeIndex(3)
         )),
         "should return an explicit error since this weight is already in use"
     );
@@ -136,7 +136,7 @@ fn integration_main() {
     assert_eq!(
         graph.add_hyperedge(vec![], sixth_hyperedge),
         Err(HypergraphError::HyperedgeCreationNoVertices(
-            Hyperedge::new("nope", 0)
+            sixth_hyperedge.to_string()
         )),
         "should return an explicit error since the vertices are missing"
     );
@@ -194,6 +194,19 @@ fn integration_main() {
         Err(HypergraphError::HyperedgeIndexNotFound(HyperedgeIndex(5))),
         "should be out-of-bound and return an explicit error"
     );
+    assert_eq!(
+        graph
+            .get_hyperedge_vertices_iter(HyperedgeIndex(0))
+            .map(Iterator::collect::<Vec<VertexIndex>>),
+        graph.get_hyperedge_vertices(HyperedgeIndex(0)),
+        "should lazily iterate over the vertices of the first hyperedge"
+    );
+    assert!(
+        graph
+            .get_hyperedge_vertices_iter(HyperedgeIndex(5))
+            .is_err(),
+        "should be out-of-bound and return an explicit error"
+    );
 
     // Get the hyperedges of some vertices as vectors of HyperedgeIndex
     // and vectors of vectors of VertexIndex (full version).
@@ -389,6 +402,31 @@ fn integration_main() {
         "should be out-of-bound and return an explicit error"
     );
 
+    // Get the hyperedges connecting two vertices along with the positions.
+    assert_eq!(
+        graph.get_hyperedges_connecting_with_positions(VertexIndex(1), VertexIndex(3)),
+        Ok(vec![
+            (HyperedgeIndex(0), vec![2]),
+            (HyperedgeIndex(1), vec![2])
+        ]),
+        "should get two matches at the third window"
+    );
+    assert_eq!(
+        graph.get_hyperedges_connecting_with_positions(VertexIndex(0), VertexIndex(3)),
+        Ok(vec![(HyperedgeIndex(2), vec![1])]),
+        "should get one match at the second window"
+    );
+    assert_eq!(
+        graph.get_hyperedges_connecting_with_positions(VertexIndex(3), VertexIndex(0)),
+        Ok(vec![]),
+        "should get no match"
+    );
+    assert_eq!(
+        graph.get_hyperedges_connecting_with_positions(VertexIndex(0), VertexIndex(5)),
+        Err(HypergraphError::VertexIndexNotFound(VertexIndex(5))),
+        "should be out-of-bound and return an explicit error"
+    );
+
     // Get the adjacent vertices from a vertex.
     assert_eq!(
         graph.get_adjacent_vertices_from(VertexIndex(0)),
@@ -578,19 +616,37 @@ fn integration_main() {
     // Check the eventual errors.
     assert_eq!(
         graph.update_hyperedge_weight(HyperedgeIndex(0), first_hyperedge),
-        Err(HypergraphError::HyperedgeWeightUnchanged {
-            index: HyperedgeIndex(0),
-            weight: first_hyperedge,
-        }),
+        Err(HypergraphError::HyperedgeWeightUnchanged(HyperedgeIndex(0))),
         "should return an explicit error since this weight has not changed"
     );
     assert_eq!(
         graph.update_hyperedge_weight(HyperedgeIndex(0), fourth_hyperedge),
         Err(HypergraphError::HyperedgeWeightAlreadyAssigned(
-            fourth_hyperedge
+            HyperedgeIndex(3)
         )),
         "should return an explicit error since this weight is already assigned"
     );
+    assert_eq!(
+        graph.get_hyperedge_vertices(HyperedgeIndex(0)),
+        Ok(vec![
+            VertexIndex(0),
+            VertexIndex(1),
+            VertexIndex(1),
+            VertexIndex(3)
+        ]),
+        "should keep the vertices of the first hyperedge"
+    );
+    // The previous weight is released and can be assigned again.
+    assert_eq!(
+        graph.update_hyperedge_weight(HyperedgeIndex(0), Hyperedge::new("pass the pink ball", 1)),
+        Ok(()),
+        "should reuse the previous weight of the first hyperedge"
+    );
+    assert_eq!(
+        graph.update_hyperedge_weight(HyperedgeIndex(0), first_hyperedge),
+        Ok(()),
+        "should update the weight of the first hyperedge again"
+    );
 
     // Update the vertices of some hyperedges.
     assert_eq!(
@@ -752,6 +808,13 @@ fn integration_main() {
         Err(HypergraphError::HyperedgeIndexNotFound(HyperedgeIndex(0))),
         "should be out-of-bound and return an explicit error"
     );
+    assert_eq!(
+        graph.update_hyperedge_weight(HyperedgeIndex(1), fourth_hyperedge),
+        Err(HypergraphError::HyperedgeWeightAlreadyAssigned(
+            HyperedgeIndex(3)
+        )),
+        "should still track the weight of the swapped hyperedge"
+    );
 
     // Check the hypergraph integrity.
     assert_eq!(graph.count_vertices(), 5);
@@ -928,3 +991,34 @@ fn integration_main() {
     assert_eq!(graph.count_vertices(), 0, "should have no vertices");
     assert_eq!(graph.count_hyperedges(), 0, "should have no hyperedges");
 }
+
+#[test]
+fn integration_connecting_positions() {
+    let mut graph = Hypergraph::<Vertex, Hyperedge>::new();
+
+    let a = graph.add_vertex(Vertex::new("a")).unwrap();
+    let b = graph.add_vertex(Vertex::new("b")).unwrap();
+    let c = graph.add_vertex(Vertex::new("c")).unwrap();
+
+    let alpha = graph
+        .add_hyperedge(vec![a, b, a, b, c], Hyperedge::new("alpha", 1))
+        .unwrap();
+    let beta = graph
+        .add_hyperedge(vec![c, a, b], Hyperedge::new("beta", 1))
+        .unwrap();
+
+    // A transition occurring several times in a sequence is reported once
+    // per window.
+    assert_eq!(
+        graph.get_hyperedges_connecting_with_positions(a, b),
+        Ok(vec![(alpha, vec![0, 2]), (beta, vec![1])])
+    );
+    assert_eq!(
+        graph.get_hyperedges_connecting(a, b),
+        Ok(vec![alpha, alpha, beta])
+    );
+    assert_eq!(
+        graph.get_hyperedges_connecting_with_positions(b, c),
+        Ok(vec![(alpha, vec![3])])
+    );
+}
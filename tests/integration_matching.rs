@@ -0,0 +1,55 @@
+//! Integration tests.
+
+mod common;
+
+use common::{
+    Hyperedge,
+    Vertex,
+};
+use hypergraph::Hypergraph;
+
+#[test]
+fn integration_matching() {
+    // Create a new hypergraph.
+    let mut graph = Hypergraph::<Vertex, Hyperedge>::new();
+
+    assert_eq!(
+        graph.get_maximum_matching(),
+        Ok(vec![]),
+        "should get an empty matching"
+    );
+
+    // Create some vertices.
+    let a = graph.add_vertex(Vertex::new("one")).unwrap();
+    let b = graph.add_vertex(Vertex::new("two")).unwrap();
+    let c = graph.add_vertex(Vertex::new("three")).unwrap();
+    let d = graph.add_vertex(Vertex::new("four")).unwrap();
+    let e = graph.add_vertex(Vertex::new("five")).unwrap();
+
+    // Add some hyperedges. Picking the large one first would block the
+    // two others.
+    let alpha = graph
+        .add_hyperedge(vec![a, b, c, d], Hyperedge::new("one", 1))
+        .unwrap();
+    let beta = graph
+        .add_hyperedge(vec![a, b, a], Hyperedge::new("two", 1))
+        .unwrap();
+    let gamma = graph
+        .add_hyperedge(vec![c, d], Hyperedge::new("three", 1))
+        .unwrap();
+    let delta = graph
+        .add_hyperedge(vec![b, e], Hyperedge::new("four", 1))
+        .unwrap();
+
+    let matching = graph.get_maximum_matching().unwrap();
+
+    assert_eq!(
+        matching,
+        vec![beta, gamma],
+        "should get the pairwise disjoint hyperedges"
+    );
+    assert!(
+        !matching.contains(&alpha) && !matching.contains(&delta),
+        "should skip the overlapping hyperedges"
+    );
+}
@@ -0,0 +1,83 @@
+//! Integration tests.
+
+mod common;
+
+use common::{
+    Hyperedge,
+    Vertex,
+};
+use hypergraph::{
+    HyperedgeIndex,
+    Hypergraph,
+    MemoryUsage,
+};
+
+#[test]
+fn integration_memory() {
+    // Create a new hypergraph.
+    let mut graph = Hypergraph::<Vertex, Hyperedge>::new();
+
+    assert_eq!(
+        graph.estimated_memory_bytes(),
+        MemoryUsage::default(),
+        "should not allocate anything"
+    );
+
+    // Create some vertices.
+    let a = graph.add_vertex(Vertex::new("one")).unwrap();
+    let b = graph.add_vertex(Vertex::new("two")).unwrap();
+
+    let usage = graph.estimated_memory_bytes();
+
+    assert!(usage.vertices > 0, "should account for the vertices");
+    assert!(usage.mappings > 0, "should account for the mappings");
+    assert_eq!(usage.hyperedges, 0, "should not have any hyperedge");
+
+    // Add a hyperedge along with some attributes and a search index.
+    graph
+        .add_hyperedge(vec![a, b, a], Hyperedge::new("one", 1))
+        .unwrap();
+    graph.set_vertex_attr(a, "name", "alpha").unwrap();
+    graph.enable_vertex_search();
+
+    let usage = graph.estimated_memory_bytes();
+
+    assert!(usage.hyperedges > 0, "should account for the hyperedges");
+    assert!(usage.attributes > 0, "should account for the attributes");
+    assert!(
+        usage.search_indexes > 0,
+        "should account for the search indexes"
+    );
+    assert_eq!(
+        usage.total(),
+        usage.vertices
+            + usage.hyperedges
+            + usage.mappings
+            + usage.attributes
+            + usage.search_indexes
+            + usage.adjacency_cache,
+        "should sum all the components"
+    );
+
+    // Reserve some capacity ahead of a bulk build.
+    graph.reserve(1000, 1000);
+
+    let reserved = graph.estimated_memory_bytes();
+
+    assert!(reserved.vertices > usage.vertices);
+    assert!(reserved.hyperedges > usage.hyperedges);
+    assert!(reserved.mappings > usage.mappings);
+
+    // Release the unused capacity.
+    graph.shrink_to_fit();
+
+    let shrunk = graph.estimated_memory_bytes();
+
+    assert!(shrunk.vertices < reserved.vertices);
+    assert!(shrunk.hyperedges < reserved.hyperedges);
+    assert!(shrunk.mappings < reserved.mappings);
+    assert_eq!(
+        graph.get_hyperedge_vertices(HyperedgeIndex(0)),
+        Ok(vec![a, b, a])
+    );
+}
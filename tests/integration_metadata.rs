@@ -0,0 +1,108 @@
+//! Integration tests.
+
+use hypergraph::{
+    Hypergraph,
+    JournaledHypergraph,
+    Value,
+    errors::HypergraphError,
+};
+
+#[test]
+fn integration_metadata() {
+    // Create a new hypergraph.
+    let mut graph = Hypergraph::<usize, usize>::new();
+
+    let a = graph.add_vertex(1).unwrap();
+    let b = graph.add_vertex(2).unwrap();
+
+    graph.add_hyperedge(vec![a, b], 10).unwrap();
+
+    assert_eq!(graph.set_metadata("name", "friends"), None);
+    assert_eq!(graph.set_metadata("created_at", 1_700_000_000_i64), None);
+    assert_eq!(graph.set_metadata("source", "survey.csv"), None);
+    assert_eq!(
+        graph.set_metadata("source", "survey-v2.csv"),
+        Some(Value::from("survey.csv")),
+        "should return the previous value"
+    );
+    assert_eq!(graph.get_metadata("name"), Some(&Value::from("friends")));
+    assert_eq!(graph.get_metadata("missing"), None);
+    assert_eq!(
+        graph.iter_metadata().collect::<Vec<(&str, &Value)>>(),
+        vec![
+            ("name", &Value::from("friends")),
+            ("created_at", &Value::Integer(1_700_000_000)),
+            ("source", &Value::from("survey-v2.csv")),
+        ],
+        "should iterate in insertion order"
+    );
+
+    // The metadata are shown by the summary.
+    assert!(
+        graph.to_string().starts_with(
+            "Hypergraph: 2 vertices, 1 hyperedges\nMetadata:\n  name: friends\n  created_at: 1700000000\n  source: survey-v2.csv\nVertex degrees:"
+        ),
+        "should show the metadata"
+    );
+
+    // The metadata are part of the equality.
+    let mut other = graph.clone();
+
+    assert_eq!(other, graph);
+    assert_eq!(
+        other.remove_metadata("source"),
+        Some(Value::from("survey-v2.csv"))
+    );
+    assert_eq!(other.remove_metadata("source"), None);
+    assert_ne!(other, graph);
+
+    // Round-trip via a snapshot.
+    let mut bytes = vec![];
+
+    graph.write_snapshot(&mut bytes).unwrap();
+
+    let loaded = Hypergraph::<usize, usize>::read_snapshot(&bytes[..]).unwrap();
+
+    assert_eq!(loaded, graph, "should preserve the metadata");
+
+    // A truncated snapshot is rejected.
+    bytes.truncate(bytes.len() - 1);
+
+    assert!(
+        matches!(
+            Hypergraph::<usize, usize>::read_snapshot(&bytes[..]),
+            Err(HypergraphError::SnapshotCorrupted(_))
+        ),
+        "should reject a truncated snapshot"
+    );
+
+    // Clearing the hypergraph keeps the metadata.
+    graph.clear();
+
+    assert_eq!(graph.get_metadata("name"), Some(&Value::from("friends")));
+}
+
+#[test]
+fn integration_metadata_journal() {
+    let path = std::env::temp_dir().join(format!(
+        "hypergraph-metadata-{}.journal",
+        std::process::id()
+    ));
+
+    let mut graph = JournaledHypergraph::<usize, usize>::create(&path).unwrap();
+
+    graph.set_metadata("name", "friends").unwrap();
+    graph.set_metadata("source", "survey.csv").unwrap();
+    graph.remove_metadata("source").unwrap();
+
+    let graph = graph.into_inner();
+    let replayed = Hypergraph::<usize, usize>::replay(&path).unwrap();
+
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(replayed, graph, "should replay the metadata");
+    assert_eq!(
+        replayed.iter_metadata().collect::<Vec<(&str, &Value)>>(),
+        vec![("name", &Value::from("friends"))]
+    );
+}
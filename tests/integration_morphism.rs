@@ -0,0 +1,114 @@
+//! Integration tests.
+
+mod common;
+
+use common::{
+    Hyperedge,
+    Vertex,
+};
+use hypergraph::{
+    Hypergraph,
+    Value,
+    errors::HypergraphError,
+};
+
+#[test]
+fn integration_morphism() {
+    let mut graph = Hypergraph::<Vertex, Hyperedge>::new();
+
+    let a = graph.add_vertex(Vertex::new("a")).unwrap();
+    let b = graph.add_vertex(Vertex::new("b")).unwrap();
+    let c = graph.add_vertex(Vertex::new("c")).unwrap();
+    let d = graph.add_vertex(Vertex::new("d")).unwrap();
+
+    let alpha = graph
+        .add_hyperedge(vec![a, b, c], Hyperedge::new("alpha", 1))
+        .unwrap();
+    let beta = graph
+        .add_hyperedge(vec![b, d], Hyperedge::new("beta", 2))
+        .unwrap();
+    let gamma = graph
+        .add_hyperedge(vec![d, c], Hyperedge::new("gamma", 3))
+        .unwrap();
+
+    // Leave some gaps in the stable indexes.
+    graph.remove_vertex(a).unwrap();
+    graph.remove_hyperedge(beta).unwrap();
+    graph.set_vertex_attr(c, "rank", 1).unwrap();
+    graph.tag_hyperedge(gamma, "loop").unwrap();
+
+    // Map the weights of the vertices.
+    let names = graph.map_vertices(|vertex| vertex.to_string()).unwrap();
+
+    assert_eq!(names.count_vertices(), 3);
+    assert_eq!(names.get_vertex_weight(c), Ok(&"c".to_owned()));
+    assert_eq!(
+        names.get_vertex_weight(a),
+        Err(HypergraphError::VertexIndexNotFound(a)),
+        "should preserve the stable indexes"
+    );
+    assert_eq!(names.get_hyperedge_vertices(alpha), Ok(vec![b, c]));
+    assert_eq!(
+        names.get_hyperedge_weight(gamma),
+        Ok(&Hyperedge::new("gamma", 3))
+    );
+    assert_eq!(names.get_vertex_attr(c, "rank"), Ok(Some(&Value::from(1))));
+    assert_eq!(names.get_hyperedges_by_tag("loop"), vec![gamma]);
+    assert_eq!(
+        names.get_dijkstra_connections(b, d),
+        graph.get_dijkstra_connections(b, d),
+        "should preserve the topology"
+    );
+
+    // Map the weights of the hyperedges.
+    let costs = graph
+        .map_hyperedges(|hyperedge| usize::from(*hyperedge))
+        .unwrap();
+
+    assert_eq!(costs.get_hyperedge_weight(alpha), Ok(&1));
+    assert_eq!(costs.get_hyperedge_weight(gamma), Ok(&3));
+    assert_eq!(costs.get_vertex_weight(d), Ok(&Vertex::new("d")));
+
+    // Map both at once.
+    let compact = graph
+        .map_weights(
+            |vertex| vertex.to_string(),
+            |hyperedge| usize::from(*hyperedge),
+        )
+        .unwrap();
+
+    assert_eq!(compact.get_vertex_weight(b), Ok(&"b".to_owned()));
+    assert_eq!(
+        compact
+            .map_weights(|name| name.len(), |cost| cost * 10)
+            .unwrap()
+            .get_hyperedge_weight(gamma),
+        Ok(&30)
+    );
+
+    // The cached costs follow the mapped weights.
+    graph.build_adjacency_cache().unwrap();
+
+    let scaled = graph
+        .map_hyperedges(|hyperedge| usize::from(*hyperedge) * 10)
+        .unwrap();
+
+    assert!(scaled.has_adjacency_cache());
+    assert_eq!(scaled.get_shortest_distance(b, c), Ok(Some(10)));
+
+    // The clashing vertices are kept apart.
+    let clashing = graph.map_vertices(|_| 0_usize).unwrap();
+
+    assert_eq!(clashing.count_vertices(), 3);
+    assert_eq!(clashing.get_vertex_weight(d), Ok(&0));
+    assert_eq!(clashing.get_hyperedge_vertices(gamma), Ok(vec![d, c]));
+
+    // The clashing hyperedges return an error.
+    assert_eq!(
+        graph
+            .map_hyperedges(|_| 0_usize)
+            .map(|graph| graph.count_hyperedges()),
+        Err(HypergraphError::HyperedgeWeightAlreadyAssigned(alpha)),
+        "should return an explicit error"
+    );
+}
@@ -0,0 +1,116 @@
+//! Integration tests.
+
+use hypergraph::{
+    Hypergraph,
+    VertexIndex,
+    errors::HypergraphError,
+};
+
+#[test]
+fn integration_oracle() {
+    let mut graph = Hypergraph::<usize, usize>::new();
+
+    // Create a grid-like hypergraph along with an isolated component.
+    let vertices = (0..9)
+        .map(|weight| graph.add_vertex(weight).unwrap())
+        .collect::<Vec<VertexIndex>>();
+
+    let mut weight = 0;
+
+    for row in 0..3 {
+        for column in 0..3 {
+            let vertex = vertices[row * 3 + column];
+
+            if column < 2 {
+                weight += 1;
+                graph
+                    .add_hyperedge(vec![vertex, vertices[row * 3 + column + 1]], weight)
+                    .unwrap();
+            }
+
+            if row < 2 {
+                weight += 1;
+                graph
+                    .add_hyperedge(vec![vertex, vertices[row * 3 + column + 3]], weight)
+                    .unwrap();
+            }
+        }
+    }
+
+    let island = graph.add_vertex(100).unwrap();
+    let shore = graph.add_vertex(101).unwrap();
+
+    graph.add_hyperedge(vec![island, shore], 1000).unwrap();
+
+    assert_eq!(
+        graph.get_distance_bounds(vertices[0], vertices[8]),
+        Err(HypergraphError::DistanceOracleNotBuilt),
+        "should return an explicit error"
+    );
+
+    let landmarks = graph.build_distance_oracle(3).unwrap();
+
+    assert!(graph.has_distance_oracle());
+    assert_eq!(landmarks.len(), 3);
+    assert!(
+        landmarks.contains(&island) || landmarks.contains(&shore),
+        "should favor the unreached vertices"
+    );
+
+    // The oracle is exact for all the pairs.
+    for from in vertices.iter().chain([island, shore].iter()) {
+        for to in vertices.iter().chain([island, shore].iter()) {
+            let distance = graph.get_shortest_distance(*from, *to).unwrap();
+
+            assert_eq!(
+                graph.get_shortest_distance_with_oracle(*from, *to),
+                Ok(distance)
+            );
+
+            match graph.get_distance_bounds(*from, *to).unwrap() {
+                Some((lower, upper)) => {
+                    let distance = distance.unwrap_or(usize::MAX);
+
+                    assert!(lower <= distance);
+                    assert!(upper.map_or(true, |upper| distance <= upper));
+                }
+                None => assert_eq!(distance, None, "should only prove the unreachable"),
+            }
+        }
+    }
+
+    // The distances from a landmark are exact.
+    let landmark = landmarks[0];
+    let distance = graph.get_shortest_distance(landmark, vertices[4]).unwrap();
+
+    assert_eq!(
+        graph.get_distance_bounds(landmark, vertices[4]),
+        Ok(distance.map(|distance| (distance, Some(distance))))
+    );
+    assert_eq!(graph.get_distance_bounds(island, vertices[0]), Ok(None));
+
+    // The oracle is dropped on mutation.
+    graph
+        .update_hyperedge_weight(
+            graph.get_hyperedges_connecting(island, shore).unwrap()[0],
+            2000,
+        )
+        .unwrap();
+
+    assert!(!graph.has_distance_oracle());
+    assert_eq!(
+        graph.get_shortest_distance_with_oracle(island, shore),
+        Err(HypergraphError::DistanceOracleNotBuilt),
+        "should return an explicit error"
+    );
+
+    graph.build_distance_oracle(1).unwrap();
+    graph.remove_vertex(island).unwrap();
+
+    assert!(!graph.has_distance_oracle());
+
+    graph.build_distance_oracle(0).unwrap();
+    graph.clear_distance_oracle();
+
+    assert!(!graph.has_distance_oracle());
+}
@@ -0,0 +1,146 @@
+//! Integration tests.
+
+use hypergraph::{
+    HyperedgeIndex,
+    Hypergraph,
+    VertexDedupPolicy,
+    VertexIndex,
+    errors::HypergraphError,
+};
+
+#[test]
+fn integration_canonicalize_hyperedge() {
+    let mut graph = Hypergraph::<usize, usize>::new();
+
+    let a = graph.add_vertex(1).unwrap();
+    let b = graph.add_vertex(2).unwrap();
+    let c = graph.add_vertex(3).unwrap();
+
+    let alpha = graph
+        .add_hyperedge_with_roles(vec![(c, "object"), (a, "subject"), (b, "verb")], 10)
+        .unwrap();
+    let beta = graph.add_hyperedge(vec![b, a, b, c], 20).unwrap();
+    let gamma = graph.add_hyperedge(vec![a, b], 30).unwrap();
+
+    // The roles follow the sorted vertices.
+    assert_eq!(
+        graph.canonicalize_hyperedge(alpha, VertexDedupPolicy::DedupAll),
+        Ok(true)
+    );
+    assert_eq!(graph.get_hyperedge_vertices(alpha), Ok(vec![a, b, c]));
+    assert_eq!(
+        graph.get_hyperedge_roles(alpha),
+        Ok(vec![
+            (a, Some("subject")),
+            (b, Some("verb")),
+            (c, Some("object"))
+        ])
+    );
+
+    // The duplicates are kept or merged according to the policy.
+    let mut other = graph.clone();
+
+    assert_eq!(
+        other.canonicalize_hyperedge(beta, VertexDedupPolicy::KeepDuplicates),
+        Ok(true)
+    );
+    assert_eq!(other.get_hyperedge_vertices(beta), Ok(vec![a, b, b, c]));
+    assert_eq!(
+        graph.canonicalize_hyperedge(beta, VertexDedupPolicy::DedupConsecutive),
+        Ok(true)
+    );
+    assert_eq!(graph.get_hyperedge_vertices(beta), Ok(vec![a, b, c]));
+
+    // Nothing to do for the canonical hyperedges.
+    assert_eq!(
+        graph.canonicalize_hyperedge(gamma, VertexDedupPolicy::DedupAll),
+        Ok(false)
+    );
+    assert_eq!(
+        graph.canonicalize_hyperedge(HyperedgeIndex(3), VertexDedupPolicy::DedupAll),
+        Err(HypergraphError::HyperedgeIndexNotFound(HyperedgeIndex(3))),
+        "should return an explicit error"
+    );
+}
+
+#[test]
+fn integration_sort_hyperedges_by() {
+    let mut graph = Hypergraph::<usize, usize>::new();
+
+    let a = graph.add_vertex(1).unwrap();
+    let b = graph.add_vertex(2).unwrap();
+    let c = graph.add_vertex(3).unwrap();
+
+    let alpha = graph.add_hyperedge(vec![a, b, c], 30).unwrap();
+    let beta = graph.add_hyperedge(vec![b], 10).unwrap();
+    let gamma = graph.add_hyperedge(vec![c, a], 20).unwrap();
+
+    graph.tag_hyperedge(alpha, "large").unwrap();
+    graph.set_hyperedge_property(beta, "kind", "unary").unwrap();
+
+    let reference = graph.clone();
+
+    // Sort by weight while keeping the stable indexes.
+    let remapping = graph
+        .sort_hyperedges_by(|hyperedge| *hyperedge.weight(), false)
+        .unwrap();
+
+    assert!(remapping.hyperedges.is_empty());
+    assert_eq!(graph, reference, "should only change the internal order");
+    assert_eq!(
+        graph
+            .clone()
+            .into_iter()
+            .map(|(weight, _)| weight)
+            .collect::<Vec<usize>>(),
+        vec![10, 20, 30]
+    );
+    assert_eq!(graph.get_vertex_hyperedges(a), Ok(vec![alpha, gamma]));
+    assert_eq!(graph.get_hyperedge_vertices(alpha), Ok(vec![a, b, c]));
+
+    // Sort by cardinality, the sort being stable, and relabel the indexes.
+    let remapping = graph
+        .sort_hyperedges_by(
+            |hyperedge| {
+                hyperedge
+                    .vertices()
+                    .map(|vertices| vertices.len())
+                    .unwrap_or(0)
+            },
+            true,
+        )
+        .unwrap();
+
+    assert_eq!(remapping.hyperedges[&beta], HyperedgeIndex(0));
+    assert_eq!(remapping.hyperedges[&gamma], HyperedgeIndex(1));
+    assert_eq!(remapping.hyperedges[&alpha], HyperedgeIndex(2));
+    assert_eq!(graph.get_hyperedge_weight(HyperedgeIndex(0)), Ok(&10));
+    assert_eq!(
+        graph.get_hyperedge_vertices(HyperedgeIndex(1)),
+        Ok(vec![c, a])
+    );
+    assert_eq!(
+        graph.get_hyperedges_by_tag("large"),
+        vec![HyperedgeIndex(2)],
+        "should follow the stable indexes"
+    );
+    assert!(
+        graph
+            .get_hyperedge_property(HyperedgeIndex(0), "kind")
+            .unwrap()
+            .is_some()
+    );
+    assert_eq!(
+        graph.get_dijkstra_connections(b, a),
+        Ok(vec![
+            (b, None),
+            (c, Some(HyperedgeIndex(2))),
+            (a, Some(HyperedgeIndex(1)))
+        ]),
+        "should keep the connections"
+    );
+    assert_eq!(
+        graph.get_vertex_hyperedges(VertexIndex(1)),
+        Ok(vec![HyperedgeIndex(2), HyperedgeIndex(0)])
+    );
+}
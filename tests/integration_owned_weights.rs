@@ -0,0 +1,119 @@
+//! Integration tests.
+
+use std::fmt::{
+    Display,
+    Formatter,
+    Result,
+};
+
+use hypergraph::{
+    Hypergraph,
+    errors::HypergraphError,
+};
+
+// Hyperedge owning its name, hence not `Copy`.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+struct Relation {
+    cost: usize,
+    name: String,
+}
+
+impl Relation {
+    fn new(name: &str, cost: usize) -> Self {
+        Self {
+            cost,
+            name: name.to_owned(),
+        }
+    }
+}
+
+impl Display for Relation {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result {
+        write!(formatter, "{}", self.name)
+    }
+}
+
+impl From<Relation> for usize {
+    fn from(Relation { cost, .. }: Relation) -> Self {
+        cost
+    }
+}
+
+#[test]
+fn integration_owned_weights() {
+    // Create a new hypergraph with heap-allocated weights.
+    let mut graph = Hypergraph::<String, Relation>::new();
+
+    graph.enable_vertex_search();
+    graph.enable_hyperedge_search();
+
+    let a = graph.add_vertex(String::from("Ava Smith")).unwrap();
+    let b = graph.add_vertex(String::from("Bianca Jones")).unwrap();
+    let c = graph.add_vertex(String::from("Charles Smith")).unwrap();
+
+    assert_eq!(
+        graph.add_vertex(String::from("Ava Smith")),
+        Err(HypergraphError::VertexWeightAlreadyAssigned(a)),
+        "should return an explicit error"
+    );
+
+    let alpha = graph
+        .add_hyperedge(vec![a, b], Relation::new("friends", 5))
+        .unwrap();
+    let beta = graph
+        .add_hyperedge(vec![b, c], Relation::new("colleagues", 2))
+        .unwrap();
+    let gamma = graph
+        .add_hyperedge(vec![a, c], Relation::new("neighbours", 10))
+        .unwrap();
+
+    assert_eq!(
+        graph.add_hyperedge(vec![c], Relation::new("friends", 5)),
+        Err(HypergraphError::HyperedgeWeightAlreadyAssigned(alpha)),
+        "should return an explicit error"
+    );
+
+    // The getters return references to the owned weights.
+    assert_eq!(graph.get_vertex_weight(a), Ok(&String::from("Ava Smith")));
+    assert_eq!(
+        graph.get_hyperedge_weight(beta),
+        Ok(&Relation::new("colleagues", 2))
+    );
+
+    // The costs are taken from the owned weights.
+    assert_eq!(
+        graph.get_dijkstra_connections(a, c),
+        Ok(vec![(a, None), (b, Some(alpha)), (c, Some(beta))])
+    );
+
+    // Update the weights.
+    graph
+        .update_vertex_weight(b, String::from("Bianca Smith"))
+        .unwrap();
+    graph
+        .update_hyperedge_weight(gamma, Relation::new("neighbors", 1))
+        .unwrap();
+
+    assert_eq!(graph.search_vertices("smith"), Ok(vec![a, b, c]));
+    assert_eq!(graph.search_hyperedges("neighbors"), Ok(vec![gamma]));
+    assert_eq!(graph.search_hyperedges("neighbours"), Ok(vec![]));
+    assert_eq!(
+        graph.get_dijkstra_connections(a, c),
+        Ok(vec![(a, None), (c, Some(gamma))])
+    );
+
+    // Remove a vertex and clone the hypergraph.
+    graph.remove_vertex(b).unwrap();
+
+    let clone = graph.clone();
+
+    assert_eq!(clone, graph);
+    assert_eq!(
+        clone.get_vertex_weights(&[c, a]),
+        Ok(vec![
+            &String::from("Charles Smith"),
+            &String::from("Ava Smith")
+        ])
+    );
+    assert_eq!(clone.count_hyperedges(), 3);
+}
@@ -0,0 +1,112 @@
+//! Integration tests.
+
+use hypergraph::{
+    HyperedgeIndex,
+    Hypergraph,
+    HypergraphParts,
+    VertexIndex,
+    errors::HypergraphError,
+};
+
+#[test]
+fn integration_parts() {
+    let mut graph = Hypergraph::<String, usize>::new();
+
+    let a = graph.add_vertex("a".to_owned()).unwrap();
+    let b = graph.add_vertex("b".to_owned()).unwrap();
+    let c = graph.add_vertex("c".to_owned()).unwrap();
+    let d = graph.add_vertex_allow_duplicate("c".to_owned()).unwrap();
+
+    let alpha = graph.add_hyperedge(vec![a, b, c], 10).unwrap();
+    let beta = graph.add_hyperedge(vec![b, d], 20).unwrap();
+    let gamma = graph.add_hyperedge(vec![d, a], 30).unwrap();
+
+    // Leave some gaps in the stable indexes.
+    graph.remove_vertex(b).unwrap();
+    graph.remove_hyperedge(alpha).unwrap();
+
+    let expected = graph.clone();
+    let parts = graph.into_parts();
+
+    assert_eq!(
+        parts,
+        HypergraphParts {
+            vertices: vec!["a".to_owned(), "c".to_owned(), "c".to_owned()],
+            hyperedges: vec![(vec![1, 0], 30), (vec![1], 20)],
+            vertices_indexes: vec![a, d, c],
+            hyperedges_indexes: vec![gamma, beta],
+        }
+    );
+
+    // Round-trip.
+    let mut graph = Hypergraph::from_parts(parts).unwrap();
+
+    assert_eq!(graph, expected, "should preserve the hypergraph");
+    assert_eq!(graph.get_vertex_hyperedges(d), Ok(vec![gamma, beta]));
+    assert_eq!(graph.add_vertex("e".to_owned()), Ok(VertexIndex(4)));
+    assert_eq!(graph.add_hyperedge(vec![c], 40), Ok(HyperedgeIndex(3)));
+
+    // Invalid parts.
+    let parts = || HypergraphParts {
+        vertices: vec![1, 2],
+        hyperedges: vec![(vec![0, 1], 10)],
+        vertices_indexes: vec![VertexIndex(0), VertexIndex(1)],
+        hyperedges_indexes: vec![HyperedgeIndex(0)],
+    };
+
+    assert!(Hypergraph::<usize, usize>::from_parts(parts()).is_ok());
+    assert_eq!(
+        Hypergraph::<usize, usize>::from_parts(HypergraphParts {
+            vertices_indexes: vec![VertexIndex(0)],
+            ..parts()
+        })
+        .map(|graph| graph.count_vertices()),
+        Err(HypergraphError::PartsInvalid(
+            "mismatched vertices indexes".to_owned()
+        )),
+        "should return an explicit error"
+    );
+    assert_eq!(
+        Hypergraph::<usize, usize>::from_parts(HypergraphParts {
+            vertices_indexes: vec![VertexIndex(1), VertexIndex(1)],
+            ..parts()
+        })
+        .map(|graph| graph.count_vertices()),
+        Err(HypergraphError::PartsInvalid(
+            "duplicate vertex index".to_owned()
+        )),
+        "should return an explicit error"
+    );
+    assert_eq!(
+        Hypergraph::<usize, usize>::from_parts(HypergraphParts {
+            hyperedges: vec![(vec![0, 2], 10)],
+            ..parts()
+        })
+        .map(|graph| graph.count_vertices()),
+        Err(HypergraphError::InternalVertexIndexNotFound(2)),
+        "should return an explicit error"
+    );
+    assert_eq!(
+        Hypergraph::<usize, usize>::from_parts(HypergraphParts {
+            hyperedges: vec![(vec![0], 10), (vec![1], 10)],
+            hyperedges_indexes: vec![HyperedgeIndex(5), HyperedgeIndex(6)],
+            ..parts()
+        })
+        .map(|graph| graph.count_vertices()),
+        Err(HypergraphError::HyperedgeWeightAlreadyAssigned(
+            HyperedgeIndex(5)
+        )),
+        "should return an explicit error"
+    );
+    assert_eq!(
+        Hypergraph::<usize, usize>::from_parts(HypergraphParts {
+            hyperedges: vec![(vec![], 10)],
+            ..parts()
+        })
+        .map(|graph| graph.count_vertices()),
+        Err(HypergraphError::PartsInvalid(
+            "hyperedge without vertices".to_owned()
+        )),
+        "should return an explicit error"
+    );
+}
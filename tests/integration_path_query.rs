@@ -0,0 +1,106 @@
+//! Integration tests.
+
+use hypergraph::{
+    HyperedgeIndex,
+    Hypergraph,
+    VertexIndex,
+    errors::HypergraphError,
+};
+
+#[test]
+fn integration_path_query() {
+    let mut graph = Hypergraph::<usize, usize>::new();
+
+    let a = graph.add_vertex(1).unwrap();
+    let b = graph.add_vertex(2).unwrap();
+    let c = graph.add_vertex(3).unwrap();
+    let d = graph.add_vertex(4).unwrap();
+    let e = graph.add_vertex(5).unwrap();
+
+    let alpha = graph.add_hyperedge(vec![a, b], 1).unwrap();
+    let beta = graph.add_hyperedge(vec![b, c], 2).unwrap();
+    let gamma = graph.add_hyperedge(vec![c, d], 3).unwrap();
+    let delta = graph.add_hyperedge(vec![a, d], 100).unwrap();
+    let epsilon = graph.add_hyperedge(vec![b, e], 4).unwrap();
+    let zeta = graph.add_hyperedge(vec![e, d], 5).unwrap();
+
+    // Without any constraint, this is the cheapest path.
+    assert_eq!(
+        graph.path_query(a, d).find(),
+        Ok(vec![
+            (a, None),
+            (b, Some(alpha)),
+            (c, Some(beta)),
+            (d, Some(gamma))
+        ])
+    );
+    assert_eq!(
+        graph.path_query(a, d).find(),
+        graph.get_dijkstra_connections(a, d)
+    );
+
+    // Mandatory waypoints.
+    assert_eq!(
+        graph.path_query(a, d).via(&[e]).find(),
+        Ok(vec![
+            (a, None),
+            (b, Some(alpha)),
+            (e, Some(epsilon)),
+            (d, Some(zeta))
+        ])
+    );
+    assert_eq!(
+        graph.path_query(a, a).via(&[b]).find(),
+        Ok(vec![]),
+        "should not find any path back from the waypoint"
+    );
+
+    // Forbidden vertices and hyperedges.
+    assert_eq!(
+        graph.path_query(a, d).avoiding_vertices(&[c]).find(),
+        Ok(vec![
+            (a, None),
+            (b, Some(alpha)),
+            (e, Some(epsilon)),
+            (d, Some(zeta))
+        ])
+    );
+    assert_eq!(
+        graph.path_query(a, d).avoiding_hyperedges(&[alpha]).find(),
+        Ok(vec![(a, None), (d, Some(delta))])
+    );
+    assert_eq!(
+        graph
+            .path_query(a, d)
+            .avoiding_vertices(&[b])
+            .avoiding_hyperedges(&[delta])
+            .find(),
+        Ok(vec![])
+    );
+    assert_eq!(
+        graph
+            .path_query(a, d)
+            .via(&[e])
+            .avoiding_vertices(&[e])
+            .find(),
+        Ok(vec![]),
+        "should not visit an avoided waypoint"
+    );
+
+    assert_eq!(
+        graph
+            .path_query(a, d)
+            .avoiding_vertices(&[VertexIndex(5)])
+            .find(),
+        Err(HypergraphError::VertexIndexNotFound(VertexIndex(5))),
+        "should return an explicit error"
+    );
+    assert_eq!(
+        graph
+            .path_query(a, d)
+            .avoiding_hyperedges(&[HyperedgeIndex(6)])
+            .find(),
+        Err(HypergraphError::HyperedgeIndexNotFound(HyperedgeIndex(6))),
+        "should return an explicit error"
+    );
+}
@@ -0,0 +1,129 @@
+//! Integration tests.
+
+#![cfg(feature = "petgraph")]
+
+mod common;
+
+use common::{
+    Hyperedge,
+    Vertex,
+};
+use hypergraph::{
+    HyperedgeIndex,
+    Hypergraph,
+    IncidenceNode,
+    VertexIndex,
+};
+use petgraph::{
+    graph::{
+        DiGraph,
+        NodeIndex,
+    },
+    visit::EdgeRef,
+};
+
+#[test]
+fn integration_petgraph() {
+    // Create a new hypergraph.
+    let mut graph = Hypergraph::<Vertex, Hyperedge>::new();
+
+    // Create some vertice weights.
+    let vertex_one = Vertex::new("one");
+    let vertex_two = Vertex::new("two");
+    let vertex_three = Vertex::new("three");
+
+    // Create some hyperedge weights.
+    let hyperedge_one = Hyperedge::new("one", 10);
+    let hyperedge_two = Hyperedge::new("two", 20);
+
+    // Create some vertices.
+    let a = graph.add_vertex(vertex_one).unwrap();
+    let b = graph.add_vertex(vertex_two).unwrap();
+    let c = graph.add_vertex(vertex_three).unwrap();
+
+    // Add some hyperedges.
+    graph.add_hyperedge(vec![a, b, c], hyperedge_one).unwrap();
+    graph.add_hyperedge(vec![c, b, b], hyperedge_two).unwrap();
+
+    // Bipartite incidence graph.
+    let incidence = graph.to_petgraph().unwrap();
+
+    assert_eq!(incidence.node_count(), 5, "should have five nodes");
+    assert_eq!(incidence.edge_count(), 6, "should have six edges");
+    assert_eq!(
+        incidence.node_weight(NodeIndex::new(0)),
+        Some(&IncidenceNode::Vertex(VertexIndex(0), vertex_one)),
+        "should insert the vertices first"
+    );
+    assert_eq!(
+        incidence.node_weight(NodeIndex::new(4)),
+        Some(&IncidenceNode::Hyperedge(HyperedgeIndex(1), hyperedge_two)),
+        "should insert the hyperedges afterwards"
+    );
+    assert_eq!(
+        incidence
+            .edges(NodeIndex::new(4))
+            .map(|edge| (edge.target().index(), *edge.weight()))
+            .collect::<Vec<(usize, usize)>>(),
+        vec![(1, 2), (1, 1), (2, 0)],
+        "should connect the hyperedge to its vertices with their positions"
+    );
+
+    // Weighted 2-section.
+    let projection = graph.to_petgraph_projection().unwrap();
+
+    assert_eq!(projection.node_count(), 3, "should have three nodes");
+    assert_eq!(
+        projection.node_weight(NodeIndex::new(2)),
+        Some(&(c, vertex_three)),
+        "should hold the indexes of the vertices"
+    );
+    assert_eq!(
+        projection
+            .raw_edges()
+            .iter()
+            .map(|edge| (edge.source().index(), edge.target().index(), edge.weight))
+            .collect::<Vec<(usize, usize, usize)>>(),
+        vec![(0, 1, 1), (0, 2, 1), (1, 2, 2)],
+        "should count the shared hyperedges of each pair of vertices"
+    );
+
+    // The nodes can be mapped back to the vertices after a removal, which
+    // reorders the internal indexes.
+    let mut pruned = graph.clone();
+
+    pruned.remove_vertex(a).unwrap();
+
+    assert_eq!(
+        pruned
+            .to_petgraph_projection()
+            .unwrap()
+            .node_weights()
+            .map(|(vertex_index, _)| *vertex_index)
+            .collect::<Vec<VertexIndex>>(),
+        vec![c, b],
+        "should map the nodes to the remaining vertices"
+    );
+
+    // Conversion from an ordinary graph.
+    let mut ordinary = DiGraph::<Vertex, Hyperedge>::new();
+
+    let x = ordinary.add_node(vertex_one);
+    let y = ordinary.add_node(vertex_two);
+
+    ordinary.add_edge(x, y, hyperedge_one);
+    ordinary.add_edge(y, y, hyperedge_two);
+
+    let converted = Hypergraph::from_petgraph(&ordinary).unwrap();
+
+    assert_eq!(
+        converted
+            .into_iter()
+            .collect::<Vec<(Hyperedge, Vec<Vertex>)>>(),
+        vec![
+            (hyperedge_one, vec![vertex_one, vertex_two]),
+            (hyperedge_two, vec![vertex_two, vertex_two])
+        ],
+        "should convert each edge to a hyperedge"
+    );
+}
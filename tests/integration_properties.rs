@@ -0,0 +1,104 @@
+//! Integration tests.
+
+mod common;
+
+use common::{
+    Hyperedge,
+    Vertex,
+};
+use hypergraph::{
+    HyperedgeIndex,
+    Hypergraph,
+    Value,
+    errors::HypergraphError,
+};
+
+#[test]
+fn integration_properties() {
+    // Create a new hypergraph.
+    let mut graph = Hypergraph::<Vertex, Hyperedge>::new();
+
+    // Create some vertices.
+    let a = graph.add_vertex(Vertex::new("one")).unwrap();
+    let b = graph.add_vertex(Vertex::new("two")).unwrap();
+    let c = graph.add_vertex(Vertex::new("three")).unwrap();
+
+    // Add some hyperedges.
+    let alpha = graph
+        .add_hyperedge(vec![a, b], Hyperedge::new("one", 10))
+        .unwrap();
+    let beta = graph
+        .add_hyperedge(vec![b, c], Hyperedge::new("two", 20))
+        .unwrap();
+    let gamma = graph
+        .add_hyperedge(vec![c], Hyperedge::new("three", 30))
+        .unwrap();
+
+    // Attach some properties.
+    assert_eq!(
+        graph.set_hyperedge_property(gamma, "kind", "follows"),
+        Ok(None),
+        "should set a new property"
+    );
+    graph
+        .set_hyperedge_property(alpha, "kind", "follows")
+        .unwrap();
+    graph.set_hyperedge_property(alpha, "since", 2020).unwrap();
+    graph.set_hyperedge_property(beta, "kind", "likes").unwrap();
+
+    assert_eq!(
+        graph.get_hyperedge_property(alpha, "since"),
+        Ok(Some(&Value::Integer(2020))),
+        "should get the property"
+    );
+    assert_eq!(
+        graph.set_hyperedge_property(HyperedgeIndex(9), "kind", "likes"),
+        Err(HypergraphError::HyperedgeIndexNotFound(HyperedgeIndex(9))),
+        "should return an explicit error"
+    );
+    assert_eq!(
+        graph.find_hyperedges_by_property("kind", "follows"),
+        vec![alpha, gamma],
+        "should find the hyperedges by property"
+    );
+    assert_eq!(
+        graph.find_hyperedges_by_property("kind", 1),
+        vec![],
+        "should find no hyperedges"
+    );
+    assert_eq!(
+        graph.remove_hyperedge_property(alpha, "kind"),
+        Ok(Some(Value::from("follows"))),
+        "should remove the property"
+    );
+    assert_eq!(
+        graph.find_hyperedges_by_property("kind", "follows"),
+        vec![gamma],
+        "should not find the hyperedge anymore"
+    );
+
+    // Properties follow the hyperedges.
+    graph.remove_hyperedge(gamma).unwrap();
+
+    assert_eq!(
+        graph.find_hyperedges_by_property("kind", "follows"),
+        vec![],
+        "should drop the properties of the removed hyperedge"
+    );
+
+    graph.prune_hyperedges_by_cardinality(2, 2).unwrap();
+
+    assert_eq!(
+        graph.get_hyperedge_property(beta, "kind"),
+        Ok(Some(&Value::from("likes"))),
+        "should keep the properties of the remaining hyperedges"
+    );
+
+    graph.clear_hyperedges().unwrap();
+
+    assert_eq!(
+        graph.find_hyperedges_by_property("kind", "likes"),
+        vec![],
+        "should clear the properties"
+    );
+}
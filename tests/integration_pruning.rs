@@ -0,0 +1,136 @@
+//! Integration tests.
+
+mod common;
+
+use common::{
+    Hyperedge,
+    Vertex,
+};
+use hypergraph::{
+    HyperedgeIndex,
+    Hypergraph,
+    VertexIndex,
+    errors::HypergraphError,
+};
+
+#[test]
+fn integration_pruning() {
+    // Create a new hypergraph.
+    let mut graph = Hypergraph::<Vertex, Hyperedge>::new();
+
+    // Create some vertice weights.
+    let vertex_one = Vertex::new("one");
+    let vertex_two = Vertex::new("two");
+    let vertex_three = Vertex::new("three");
+    let vertex_four = Vertex::new("four");
+    let vertex_five = Vertex::new("five");
+
+    // Create some hyperedge weights.
+    let hyperedge_one = Hyperedge::new("one", 10);
+    let hyperedge_two = Hyperedge::new("two", 20);
+    let hyperedge_three = Hyperedge::new("three", 30);
+    let hyperedge_four = Hyperedge::new("four", 40);
+
+    // Create some vertices.
+    let a = graph.add_vertex(vertex_one).unwrap();
+    let b = graph.add_vertex(vertex_two).unwrap();
+    let c = graph.add_vertex(vertex_three).unwrap();
+    let d = graph.add_vertex(vertex_four).unwrap();
+    let e = graph.add_vertex(vertex_five).unwrap();
+
+    // Add some hyperedges.
+    let alpha = graph.add_hyperedge(vec![a], hyperedge_one).unwrap();
+    let beta = graph.add_hyperedge(vec![b, d], hyperedge_two).unwrap();
+    let gamma = graph
+        .add_hyperedge(vec![a, b, d, d], hyperedge_three)
+        .unwrap();
+    let delta = graph.add_hyperedge(vec![d, b, a], hyperedge_four).unwrap();
+
+    // Nothing to prune.
+    assert_eq!(
+        graph.prune_hyperedges_by_cardinality(1, 4),
+        Ok(0),
+        "should not remove any hyperedge"
+    );
+
+    // Prune the unaries and the hyperedges with more than three vertices.
+    assert_eq!(
+        graph.prune_hyperedges_by_cardinality(2, 3),
+        Ok(2),
+        "should remove two hyperedges"
+    );
+    assert_eq!(graph.count_hyperedges(), 2, "should have two hyperedges");
+    assert_eq!(
+        graph.get_hyperedge_weight(alpha),
+        Err(HypergraphError::HyperedgeIndexNotFound(alpha)),
+        "should remove the unary"
+    );
+    assert_eq!(
+        graph.get_hyperedge_weight(gamma),
+        Err(HypergraphError::HyperedgeIndexNotFound(gamma)),
+        "should remove the hyperedge with four vertices"
+    );
+    assert_eq!(
+        graph.get_hyperedge_vertices(delta),
+        Ok(vec![d, b, a]),
+        "should keep the stable index of the remaining hyperedges"
+    );
+    assert_eq!(
+        graph.get_vertex_hyperedges(d),
+        Ok(vec![beta, delta]),
+        "should remap the hyperedges of the vertices"
+    );
+    assert_eq!(
+        graph.get_hyperedges_connecting(b, d),
+        Ok(vec![beta]),
+        "should keep the connections"
+    );
+
+    // Prune the isolated vertices.
+    assert_eq!(
+        graph.prune_isolated_vertices(),
+        Ok(2),
+        "should remove two vertices"
+    );
+    assert_eq!(graph.count_vertices(), 3, "should have three vertices");
+    assert_eq!(
+        graph.get_vertex_weight(c),
+        Err(HypergraphError::VertexIndexNotFound(c)),
+        "should remove the isolated vertex"
+    );
+    assert_eq!(
+        graph.get_vertex_weight(e),
+        Err(HypergraphError::VertexIndexNotFound(e)),
+        "should remove the isolated vertex"
+    );
+    assert_eq!(
+        graph.get_hyperedge_vertices(beta),
+        Ok(vec![b, d]),
+        "should remap the vertices of the hyperedges"
+    );
+    assert_eq!(
+        graph.get_adjacent_vertices_from(d),
+        Ok(vec![b]),
+        "should keep the adjacency"
+    );
+    assert_eq!(
+        graph.prune_isolated_vertices(),
+        Ok(0),
+        "should not remove any vertex"
+    );
+
+    // Adding new entities should still be consistent.
+    let f = graph.add_vertex(vertex_three).unwrap();
+
+    assert_eq!(f, VertexIndex(5), "should not reuse the stable indexes");
+    assert_eq!(
+        graph.add_hyperedge(vec![a, f], hyperedge_one),
+        Ok(HyperedgeIndex(4)),
+        "should not reuse the stable indexes"
+    );
+    assert_eq!(
+        graph.get_vertex_hyperedges(f),
+        Ok(vec![HyperedgeIndex(4)]),
+        "should update the vertex hyperedges"
+    );
+}
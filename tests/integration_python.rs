@@ -0,0 +1,63 @@
+//! Integration tests.
+
+#![cfg(feature = "python")]
+
+use hypergraph::python::python_module;
+use pyo3::{
+    ffi::c_str,
+    prelude::*,
+    types::IntoPyDict,
+    wrap_pymodule,
+};
+
+#[test]
+fn integration_python() {
+    pyo3::prepare_freethreaded_python();
+
+    Python::with_gil(|py| {
+        let module = wrap_pymodule!(python_module)(py);
+
+        py.run(
+            c_str!(
+                r#"
+graph = hypergraph.Hypergraph()
+
+a = graph.add_vertex("a")
+b = graph.add_vertex("b")
+c = graph.add_vertex('{"name": "c"}')
+
+alpha = graph.add_hyperedge([a, b], "alpha", 1)
+beta = graph.add_hyperedge([b, c], "beta", 2)
+
+assert graph.count_vertices() == 3
+assert graph.count_hyperedges() == 2
+assert graph.get_vertex_weight(c) == '{"name": "c"}'
+assert graph.get_hyperedge_weight(beta) == ("beta", 2)
+assert graph.get_adjacent_vertices_to(c) == [b]
+assert graph.get_dijkstra_connections(a, c) == [(a, None), (b, alpha), (c, beta)]
+
+try:
+    graph.add_vertex("a")
+    assert False
+except ValueError as error:
+    assert "already assigned" in str(error)
+
+try:
+    graph.add_hyperedge([a, c], "alpha", 3)
+    assert False
+except ValueError as error:
+    assert "already assigned" in str(error)
+
+try:
+    graph.add_hyperedge([], "gamma", 1)
+    assert False
+except ValueError as error:
+    assert str(error) == "Hyperedge weight gamma vertices are missing"
+"#
+            ),
+            None,
+            Some(&[("hypergraph", module)].into_py_dict(py).unwrap()),
+        )
+        .unwrap();
+    });
+}
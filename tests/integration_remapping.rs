@@ -0,0 +1,241 @@
+//! Integration tests.
+
+use hypergraph::{
+    HyperedgeIndex,
+    Hypergraph,
+    JournaledHypergraph,
+    RecycledIndexes,
+    Value,
+    VertexIndex,
+    errors::HypergraphError,
+};
+
+// Private helper to build a hypergraph with gaps in its stable indexes.
+fn build_graph() -> Hypergraph<usize, usize> {
+    let mut graph = Hypergraph::new();
+
+    let a = graph.add_vertex(1).unwrap();
+    let b = graph.add_vertex(2).unwrap();
+    let c = graph.add_vertex(3).unwrap();
+    let d = graph.add_vertex(4).unwrap();
+
+    let alpha = graph.add_hyperedge(vec![a, b], 10).unwrap();
+    graph.add_hyperedge(vec![b, c], 20).unwrap();
+    graph.add_hyperedge(vec![c, d], 30).unwrap();
+    graph.add_hyperedge(vec![d, a], 40).unwrap();
+
+    graph.remove_hyperedge(alpha).unwrap();
+    graph.remove_vertex(b).unwrap();
+
+    graph
+}
+
+#[test]
+fn integration_remapping_snapshot() {
+    let graph = build_graph();
+
+    let mut bytes = vec![];
+
+    graph.write_snapshot(&mut bytes).unwrap();
+
+    let mut loaded = Hypergraph::<usize, usize>::read_snapshot(&bytes[..]).unwrap();
+
+    // The stable indexes refer to the same entities after a reload.
+    assert_eq!(loaded.get_hyperedge_weight(HyperedgeIndex(3)), Ok(&40));
+    assert_eq!(
+        loaded.get_hyperedge_vertices(HyperedgeIndex(3)),
+        Ok(vec![VertexIndex(3), VertexIndex(0)])
+    );
+    assert_eq!(loaded.get_vertex_weight(VertexIndex(2)), Ok(&3));
+    assert_eq!(
+        loaded.get_vertex_weight(VertexIndex(1)),
+        Err(HypergraphError::VertexIndexNotFound(VertexIndex(1)))
+    );
+
+    // The counters are preserved as well so the indexes are never reused.
+    assert_eq!(loaded.add_vertex(5), Ok(VertexIndex(4)));
+    assert_eq!(
+        loaded.add_hyperedge(vec![VertexIndex(4)], 50),
+        Ok(HyperedgeIndex(4))
+    );
+}
+
+#[test]
+fn integration_remapping() {
+    let mut graph = build_graph();
+
+    graph.set_vertex_attr(VertexIndex(3), "name", "d").unwrap();
+    graph
+        .set_hyperedge_property(HyperedgeIndex(3), "kind", "loop")
+        .unwrap();
+    graph.tag_hyperedge(HyperedgeIndex(2), "red").unwrap();
+    graph.enable_vertex_search();
+
+    let remapping = graph.remap_indexes().unwrap();
+
+    assert_eq!(remapping.vertices.len(), 3);
+    assert_eq!(remapping.vertices[&VertexIndex(0)], VertexIndex(0));
+    assert_eq!(remapping.vertices[&VertexIndex(2)], VertexIndex(1));
+    assert_eq!(remapping.vertices[&VertexIndex(3)], VertexIndex(2));
+    assert_eq!(remapping.hyperedges.len(), 3);
+    assert_eq!(remapping.hyperedges[&HyperedgeIndex(1)], HyperedgeIndex(0));
+    assert_eq!(remapping.hyperedges[&HyperedgeIndex(2)], HyperedgeIndex(1));
+    assert_eq!(remapping.hyperedges[&HyperedgeIndex(3)], HyperedgeIndex(2));
+
+    // The indexes are compact and everything follows them.
+    assert_eq!(graph.get_vertex_weight(VertexIndex(1)), Ok(&3));
+    assert_eq!(graph.get_hyperedge_weight(HyperedgeIndex(2)), Ok(&40));
+    assert_eq!(
+        graph.get_hyperedge_vertices(HyperedgeIndex(2)),
+        Ok(vec![VertexIndex(2), VertexIndex(0)])
+    );
+    assert_eq!(
+        graph.get_vertex_attr(VertexIndex(2), "name"),
+        Ok(Some(&Value::from("d")))
+    );
+    assert_eq!(
+        graph.get_hyperedge_property(HyperedgeIndex(2), "kind"),
+        Ok(Some(&Value::from("loop")))
+    );
+    assert_eq!(graph.get_hyperedges_by_tag("red"), vec![HyperedgeIndex(1)]);
+    assert_eq!(graph.search_vertices("3"), Ok(vec![VertexIndex(1)]));
+
+    // The counters are reset accordingly.
+    assert_eq!(graph.add_vertex(5), Ok(VertexIndex(3)));
+    assert_eq!(
+        graph.add_hyperedge(vec![VertexIndex(3)], 50),
+        Ok(HyperedgeIndex(3))
+    );
+}
+
+#[test]
+fn integration_remapping_duplicates() {
+    let mut graph = Hypergraph::<usize, usize>::new();
+
+    let a = graph.add_vertex(1).unwrap();
+    let b = graph.add_vertex(2).unwrap();
+    let c = graph.add_vertex_allow_duplicate(3).unwrap();
+
+    graph.add_hyperedge(vec![a, c], 10).unwrap();
+    graph.remove_vertex(b).unwrap();
+    graph.remap_indexes().unwrap();
+
+    // The upcoming duplicates don't clash with the remapped ones.
+    assert_eq!(graph.add_vertex_allow_duplicate(3), Ok(VertexIndex(2)));
+    assert_eq!(graph.count_vertices(), 3);
+    assert_eq!(
+        graph.get_vertex_hyperedges(VertexIndex(1)),
+        Ok(vec![HyperedgeIndex(0)])
+    );
+}
+
+#[test]
+fn integration_remapping_journal() {
+    let path = std::env::temp_dir().join(format!(
+        "hypergraph-remapping-{}.journal",
+        std::process::id()
+    ));
+
+    let mut graph = JournaledHypergraph::<usize, usize>::create(&path).unwrap();
+
+    let a = graph.add_vertex(1).unwrap();
+    let b = graph.add_vertex(2).unwrap();
+    let c = graph.add_vertex(3).unwrap();
+
+    graph.add_hyperedge(vec![a, c], 10).unwrap();
+    graph.remove_vertex(b).unwrap();
+    graph.remap_indexes().unwrap();
+    graph.add_vertex(4).unwrap();
+
+    let graph = graph.into_inner();
+    let replayed = Hypergraph::<usize, usize>::replay(&path).unwrap();
+
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(replayed, graph, "should replay the remapping");
+    assert_eq!(replayed.get_vertex_weight(VertexIndex(2)), Ok(&4));
+}
+
+#[test]
+fn integration_recycling() {
+    let mut graph = build_graph();
+
+    // The indexes are never reused implicitly.
+    assert_eq!(graph.add_vertex(5), Ok(VertexIndex(4)));
+    graph.remove_vertex(VertexIndex(4)).unwrap();
+
+    let recycled = graph.recycle_indexes();
+
+    assert_eq!(
+        recycled,
+        RecycledIndexes {
+            vertices: vec![VertexIndex(1), VertexIndex(4)],
+            hyperedges: vec![HyperedgeIndex(0)],
+        }
+    );
+    assert_eq!(
+        graph.recycle_indexes(),
+        RecycledIndexes::default(),
+        "should not return the already recycled indexes"
+    );
+
+    // The indexes in use are left untouched.
+    assert_eq!(graph.get_vertex_weight(VertexIndex(3)), Ok(&4));
+    assert_eq!(graph.get_hyperedge_weight(HyperedgeIndex(3)), Ok(&40));
+
+    // The lowest recycled index comes first, then the counter which has
+    // been lowered after the highest used index.
+    assert_eq!(graph.add_vertex(6), Ok(VertexIndex(1)));
+    assert_eq!(graph.add_vertex_allow_duplicate(6), Ok(VertexIndex(4)));
+    assert_eq!(graph.add_vertex(7), Ok(VertexIndex(5)));
+    assert_eq!(
+        graph.add_hyperedge(vec![VertexIndex(1)], 50),
+        Ok(HyperedgeIndex(0))
+    );
+    assert_eq!(
+        graph.add_hyperedge(vec![VertexIndex(4)], 60),
+        Ok(HyperedgeIndex(4))
+    );
+
+    // The recycled indexes are preserved by the snapshots.
+    let mut graph = build_graph();
+
+    graph.recycle_indexes();
+
+    let mut bytes = vec![];
+
+    graph.write_snapshot(&mut bytes).unwrap();
+
+    let mut loaded = Hypergraph::<usize, usize>::read_snapshot(&bytes[..]).unwrap();
+
+    assert_eq!(loaded.add_vertex(5), Ok(VertexIndex(1)));
+    assert_eq!(loaded.add_vertex(6), Ok(VertexIndex(4)));
+}
+
+#[test]
+fn integration_recycling_journal() {
+    let path = std::env::temp_dir().join(format!(
+        "hypergraph-recycling-{}.journal",
+        std::process::id()
+    ));
+
+    let mut graph = JournaledHypergraph::<usize, usize>::create(&path).unwrap();
+
+    let a = graph.add_vertex(1).unwrap();
+    let b = graph.add_vertex(2).unwrap();
+    let c = graph.add_vertex(3).unwrap();
+
+    graph.add_hyperedge(vec![a, c], 10).unwrap();
+    graph.remove_vertex(b).unwrap();
+    graph.recycle_indexes().unwrap();
+
+    assert_eq!(graph.add_vertex(4), Ok(b));
+
+    let graph = graph.into_inner();
+    let replayed = Hypergraph::<usize, usize>::replay(&path).unwrap();
+
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(replayed, graph, "should replay the recycling");
+    assert_eq!(replayed.get_vertex_weight(b), Ok(&4));
+}
@@ -0,0 +1,65 @@
+//! Integration tests.
+
+use hypergraph::{
+    Hypergraph,
+    VertexIndex,
+};
+
+#[test]
+fn integration_reversal() {
+    // Create a new hypergraph.
+    let mut graph = Hypergraph::<usize, usize>::new();
+
+    // Create some vertices.
+    let a = graph.add_vertex(1).unwrap();
+    let b = graph.add_vertex(2).unwrap();
+    let c = graph.add_vertex(3).unwrap();
+    let d = graph.add_vertex(4).unwrap();
+
+    // Create some hyperedges, including a palindrome.
+    let alpha = graph
+        .add_hyperedge_with_roles(vec![(a, "x"), (b, "y"), (c, "z")], 1)
+        .unwrap();
+    let beta = graph.add_hyperedge(vec![c, d], 2).unwrap();
+    let gamma = graph.add_hyperedge(vec![b, d, b], 3).unwrap();
+
+    // Query the reversed view.
+    let reversed = graph.reversed();
+
+    assert_eq!(reversed.get_hyperedge_vertices(alpha), Ok(vec![c, b, a]));
+    assert_eq!(reversed.get_adjacent_vertices_from(c), Ok(vec![b]));
+    assert_eq!(reversed.get_adjacent_vertices_to(c), Ok(vec![d]));
+    assert_eq!(reversed.get_hyperedges_connecting(d, c), Ok(vec![beta]));
+    assert_eq!(reversed.get_vertex_degree_in(c), Ok(1));
+    assert_eq!(reversed.get_vertex_degree_out(c), Ok(1));
+    assert_eq!(
+        reversed.get_dijkstra_connections(d, a),
+        Ok(vec![(d, None), (b, Some(gamma)), (a, Some(alpha))])
+    );
+    assert_eq!(reversed.get_dijkstra_connections(a, d), Ok(vec![]));
+
+    // Reverse all the hyperedges in place.
+    graph.reverse_all_hyperedges();
+
+    assert_eq!(graph.get_hyperedge_vertices(alpha), Ok(vec![c, b, a]));
+    assert_eq!(graph.get_hyperedge_vertices(beta), Ok(vec![d, c]));
+    assert_eq!(graph.get_hyperedge_vertices(gamma), Ok(vec![b, d, b]));
+    assert_eq!(
+        graph.get_hyperedge_roles(alpha),
+        Ok(vec![(c, Some("z")), (b, Some("y")), (a, Some("x"))])
+    );
+    assert_eq!(graph.get_adjacent_vertices_from(c), Ok(vec![b]));
+    assert_eq!(
+        graph.get_dijkstra_connections(d, a),
+        Ok(vec![(d, None), (b, Some(gamma)), (a, Some(alpha))])
+    );
+
+    // Reverse back.
+    graph.reverse_all_hyperedges();
+
+    assert_eq!(graph.get_hyperedge_vertices(alpha), Ok(vec![a, b, c]));
+    assert_eq!(
+        graph.get_adjacent_vertices_from(VertexIndex(2)),
+        Ok(vec![d])
+    );
+}
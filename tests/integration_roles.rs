@@ -0,0 +1,70 @@
+//! Integration tests.
+
+mod common;
+
+use common::{
+    Hyperedge,
+    Vertex,
+};
+use hypergraph::{
+    HyperedgeIndex,
+    Hypergraph,
+    errors::HypergraphError,
+};
+
+#[test]
+fn integration_roles() {
+    // Create a new hypergraph.
+    let mut graph = Hypergraph::<Vertex, Hyperedge>::new();
+
+    // Create some vertices.
+    let a = graph.add_vertex(Vertex::new("one")).unwrap();
+    let b = graph.add_vertex(Vertex::new("two")).unwrap();
+    let c = graph.add_vertex(Vertex::new("three")).unwrap();
+    let d = graph.add_vertex(Vertex::new("four")).unwrap();
+
+    // Add some hyperedges.
+    let alpha = graph
+        .add_hyperedge(vec![a, b, c, b], Hyperedge::new("one", 10))
+        .unwrap();
+    let beta = graph
+        .add_hyperedge(vec![d], Hyperedge::new("two", 20))
+        .unwrap();
+
+    assert_eq!(
+        graph.is_source_of(a, alpha),
+        Ok(true),
+        "should be the source"
+    );
+    assert_eq!(
+        graph.is_source_of(b, alpha),
+        Ok(false),
+        "should not be the source"
+    );
+    assert_eq!(graph.is_sink_of(b, alpha), Ok(true), "should be the sink");
+    assert_eq!(
+        graph.is_sink_of(c, alpha),
+        Ok(false),
+        "should not be the sink"
+    );
+    assert_eq!(
+        (graph.is_source_of(d, beta), graph.is_sink_of(d, beta)),
+        (Ok(true), Ok(true)),
+        "should be both the source and the sink of a unary"
+    );
+    assert_eq!(
+        graph.get_vertex_positions_in_hyperedge(b, alpha),
+        Ok(vec![1, 3]),
+        "should get all the positions of the vertex"
+    );
+    assert_eq!(
+        graph.get_vertex_positions_in_hyperedge(d, alpha),
+        Ok(vec![]),
+        "should get no positions"
+    );
+    assert_eq!(
+        graph.is_sink_of(a, HyperedgeIndex(5)),
+        Err(HypergraphError::HyperedgeIndexNotFound(HyperedgeIndex(5))),
+        "should return an explicit error"
+    );
+}
@@ -0,0 +1,181 @@
+//! Integration tests.
+
+#![cfg(feature = "rand")]
+
+mod common;
+
+use common::{
+    Hyperedge,
+    Vertex,
+};
+use hypergraph::{
+    Hypergraph,
+    VertexIndex,
+    errors::HypergraphError,
+};
+use rand::{
+    SeedableRng,
+    rngs::StdRng,
+};
+
+#[test]
+fn integration_sampling() {
+    // Create a new hypergraph.
+    let mut graph = Hypergraph::<Vertex, Hyperedge>::new();
+
+    // Create a deterministic random number generator.
+    let mut rng = StdRng::seed_from_u64(42);
+
+    // Create some vertice weights.
+    let vertex_one = Vertex::new("one");
+    let vertex_two = Vertex::new("two");
+    let vertex_three = Vertex::new("three");
+    let vertex_four = Vertex::new("four");
+
+    // Create some hyperedge weights.
+    let hyperedge_one = Hyperedge::new("one", 10);
+    let hyperedge_two = Hyperedge::new("two", 20);
+
+    // Create some vertices.
+    let a = graph.add_vertex(vertex_one).unwrap();
+    let b = graph.add_vertex(vertex_two).unwrap();
+    let c = graph.add_vertex(vertex_three).unwrap();
+    let d = graph.add_vertex(vertex_four).unwrap();
+
+    // Add some hyperedges.
+    let alpha = graph.add_hyperedge(vec![a, b], hyperedge_one).unwrap();
+    let beta = graph.add_hyperedge(vec![b, c], hyperedge_two).unwrap();
+
+    // Random walks.
+    let walk = graph.random_walk(a, 20, &mut rng).unwrap();
+
+    assert_eq!(walk.len(), 20, "should visit twenty vertices");
+    assert_eq!(walk[0], a, "should start from the given vertex");
+    assert!(
+        walk.windows(2).all(|pair| graph
+            .get_hyperedges_connecting(pair[0], pair[1])
+            .is_ok_and(|hyperedges| !hyperedges.is_empty())
+            || graph
+                .get_hyperedges_connecting(pair[1], pair[0])
+                .is_ok_and(|hyperedges| !hyperedges.is_empty())
+            || pair[0] == pair[1]),
+        "should only move along the hyperedges"
+    );
+    assert_eq!(
+        graph.random_walk(d, 20, &mut rng),
+        Ok(vec![d]),
+        "should stop on a vertex without hyperedges"
+    );
+    assert_eq!(
+        graph.random_walk(a, 0, &mut rng),
+        Ok(vec![]),
+        "should return an empty walk"
+    );
+    assert_eq!(
+        graph.random_walk(VertexIndex(9), 5, &mut rng),
+        Err(HypergraphError::VertexIndexNotFound(VertexIndex(9))),
+        "should return an explicit error"
+    );
+
+    // Sampling.
+    let mut vertices = graph.sample_vertices(3, &mut rng).unwrap();
+
+    assert_eq!(vertices.len(), 3, "should sample three vertices");
+
+    vertices.sort();
+    vertices.dedup();
+
+    assert_eq!(vertices.len(), 3, "should sample distinct vertices");
+
+    let mut vertices = graph.sample_vertices(10, &mut rng).unwrap();
+
+    vertices.sort();
+
+    assert_eq!(vertices, vec![a, b, c, d], "should sample all the vertices");
+
+    let mut hyperedges = graph.sample_hyperedges(5, &mut rng).unwrap();
+
+    hyperedges.sort();
+
+    assert_eq!(
+        hyperedges,
+        vec![alpha, beta],
+        "should sample all the hyperedges"
+    );
+}
+
+#[test]
+fn integration_weighted_sampling() {
+    // Create a new hypergraph.
+    let mut graph = Hypergraph::<usize, usize>::new();
+
+    // Create a deterministic random number generator.
+    let mut rng = StdRng::seed_from_u64(42);
+
+    // Create some vertices.
+    let a = graph.add_vertex(0).unwrap();
+    let b = graph.add_vertex(1).unwrap();
+    let c = graph.add_vertex(3).unwrap();
+
+    // Add some hyperedges.
+    let alpha = graph.add_hyperedge(vec![a, b], 10).unwrap();
+    let beta = graph.add_hyperedge(vec![b, c], 30).unwrap();
+
+    // Use the weights as sampling weights.
+    let sampler = graph
+        .get_vertices_weighted_sampler(|_, weight| *weight as f64)
+        .unwrap();
+
+    assert_eq!(sampler.len(), 2, "should skip the zero weights");
+
+    let mut counts = [0; 3];
+
+    for _ in 0..4000 {
+        counts[sampler.sample(&mut rng).unwrap().0] += 1;
+    }
+
+    assert_eq!(counts[0], 0, "should never draw a zero weight");
+    assert!(
+        (900..1100).contains(&counts[1]) && (2900..3100).contains(&counts[2]),
+        "should draw proportionally to the weights"
+    );
+
+    let mut counts = [0; 2];
+
+    for _ in 0..4000 {
+        counts[graph
+            .sample_hyperedge_weighted(&mut rng, |_, weight| *weight as f64)
+            .unwrap()
+            .unwrap()
+            .0] += 1;
+    }
+
+    assert!(
+        (900..1100).contains(&counts[0]) && (2900..3100).contains(&counts[1]),
+        "should draw proportionally to the weights"
+    );
+    assert_eq!(
+        graph.sample_hyperedge_weighted(&mut rng, |index, _| if index == alpha { 1. } else { 0. }),
+        Ok(Some(alpha))
+    );
+    assert_eq!(
+        graph.sample_vertex_weighted(&mut rng, |_, _| 0.),
+        Ok(None),
+        "should return None when all the weights are zero"
+    );
+
+    // Errors.
+    assert_eq!(
+        graph.sample_vertex_weighted(&mut rng, |index, _| if index == b { -1. } else { 1. }),
+        Err(HypergraphError::VertexSamplingInvalidWeight(b))
+    );
+    assert_eq!(
+        graph.get_hyperedges_weighted_sampler(|_, _| f64::NAN),
+        Err(HypergraphError::HyperedgeSamplingInvalidWeight(alpha))
+    );
+    assert_eq!(
+        graph.get_hyperedges_weighted_sampler(|_, _| f64::MAX),
+        Err(HypergraphError::HyperedgeSamplingInvalidWeight(beta)),
+        "should reject an infinite total"
+    );
+}
@@ -0,0 +1,103 @@
+//! Integration tests.
+
+mod common;
+
+use common::{
+    Hyperedge,
+    Vertex,
+};
+use hypergraph::{
+    Hypergraph,
+    errors::HypergraphError,
+};
+
+#[test]
+fn integration_search() {
+    // Create a new hypergraph.
+    let mut graph = Hypergraph::<Vertex, Hyperedge>::new();
+
+    // Create some vertices.
+    let a = graph.add_vertex(Vertex::new("Anna Smith")).unwrap();
+    let b = graph.add_vertex(Vertex::new("Annabel Lee")).unwrap();
+
+    assert_eq!(
+        graph.search_vertices("anna"),
+        Err(HypergraphError::SearchIndexNotEnabled),
+        "should return an explicit error"
+    );
+
+    // Enabling the search indexes the existing vertices.
+    graph.enable_vertex_search();
+
+    let c = graph.add_vertex(Vertex::new("Bob Smith")).unwrap();
+
+    assert_eq!(
+        graph.search_vertices("ANNA"),
+        Ok(vec![a, b]),
+        "should rank the exact matches before the prefix ones"
+    );
+    assert_eq!(
+        graph.search_vertices("smith, anna"),
+        Ok(vec![a, c, b]),
+        "should rank by the number of matched tokens"
+    );
+    assert_eq!(
+        graph.search_vertices("zoe"),
+        Ok(vec![]),
+        "should not find any vertex"
+    );
+
+    // The index follows the mutations.
+    graph
+        .update_vertex_weight(c, Vertex::new("Bob Jones"))
+        .unwrap();
+    graph.remove_vertex(a).unwrap();
+
+    assert_eq!(
+        graph.search_vertices("smith"),
+        Ok(vec![]),
+        "should not find the updated and removed vertices"
+    );
+    assert_eq!(
+        graph.search_vertices("jon"),
+        Ok(vec![c]),
+        "should find the updated vertex"
+    );
+
+    // Custom extractor on hyperedges.
+    graph.enable_hyperedge_search_with(|weight| format!("{weight} relation"));
+
+    let alpha = graph
+        .add_hyperedge(vec![b, c], Hyperedge::new("friend", 1))
+        .unwrap();
+    let beta = graph
+        .add_hyperedge(vec![c, b], Hyperedge::new("friendly rival", 2))
+        .unwrap();
+
+    assert_eq!(
+        graph.search_hyperedges("friend"),
+        Ok(vec![alpha, beta]),
+        "should search the hyperedges"
+    );
+    assert_eq!(
+        graph.search_hyperedges("relation"),
+        Ok(vec![alpha, beta]),
+        "should use the custom extractor"
+    );
+
+    graph.remove_hyperedge(alpha).unwrap();
+
+    assert_eq!(
+        graph.search_hyperedges("friend"),
+        Ok(vec![beta]),
+        "should not find the removed hyperedge"
+    );
+
+    graph.disable_hyperedge_search();
+
+    assert_eq!(
+        graph.search_hyperedges("friend"),
+        Err(HypergraphError::SearchIndexNotEnabled),
+        "should return an explicit error"
+    );
+}
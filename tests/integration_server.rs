@@ -0,0 +1,305 @@
+//! Integration tests.
+
+#![cfg(feature = "server")]
+
+use std::{
+    io::{
+        Read,
+        Write,
+    },
+    net::{
+        SocketAddr,
+        TcpListener,
+        TcpStream,
+    },
+    time::Duration,
+};
+
+use hypergraph::{
+    HypergraphServer,
+    SnapshotStore,
+};
+use serde_json::{
+    Value,
+    json,
+};
+
+// Private helper to run a server over a store on a free local port.
+fn spawn_server(root: &std::path::Path) -> SocketAddr {
+    let address = TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap();
+    let server = HypergraphServer::new(SnapshotStore::<usize, usize>::open(root).unwrap());
+
+    std::thread::spawn(move || server.run(address));
+
+    // Wait for the server to accept the connections.
+    for _ in 0..100 {
+        if TcpStream::connect(address).is_ok() {
+            break;
+        }
+
+        std::thread::sleep(Duration::from_millis(10));
+    }
+
+    address
+}
+
+// Private helper to send a request and to get the status code along with
+// the JSON body, if any.
+fn request(address: SocketAddr, method: &str, path: &str, body: Option<Value>) -> (u16, Value) {
+    let body = body.map(|body| body.to_string()).unwrap_or_default();
+    let mut stream = TcpStream::connect(address).unwrap();
+
+    write!(
+        stream,
+        "{method} {path} HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{body}",
+        body.len()
+    )
+    .unwrap();
+
+    let mut response = String::new();
+
+    stream.read_to_string(&mut response).unwrap();
+
+    let status = response[9..12].parse().unwrap();
+    let (_, body) = response.split_once("\r\n\r\n").unwrap();
+
+    (status, serde_json::from_str(body).unwrap_or(Value::Null))
+}
+
+#[test]
+fn integration_server() {
+    let root = std::env::temp_dir().join(format!("hypergraph-server-{}", std::process::id()));
+    let address = spawn_server(&root);
+
+    assert_eq!(
+        request(address, "GET", "/namespaces", None),
+        (200, json!([]))
+    );
+
+    // Populate a namespace.
+    assert_eq!(
+        request(
+            address,
+            "POST",
+            "/namespaces/acme/vertices",
+            Some(json!({ "weight": "1" }))
+        ),
+        (201, json!({ "index": 0, "version": 1 }))
+    );
+    request(
+        address,
+        "POST",
+        "/namespaces/acme/vertices",
+        Some(json!({ "weight": "2" })),
+    );
+    request(
+        address,
+        "POST",
+        "/namespaces/acme/vertices",
+        Some(json!({ "weight": "3" })),
+    );
+
+    assert_eq!(
+        request(
+            address,
+            "POST",
+            "/namespaces/acme/hyperedges",
+            Some(json!({ "vertices": [0, 1], "weight": "10" }))
+        ),
+        (201, json!({ "index": 0, "version": 4 }))
+    );
+    request(
+        address,
+        "POST",
+        "/namespaces/acme/hyperedges",
+        Some(json!({ "vertices": [1, 2], "weight": "20" })),
+    );
+
+    assert_eq!(
+        request(address, "GET", "/namespaces", None),
+        (200, json!(["acme"]))
+    );
+    assert_eq!(
+        request(address, "GET", "/namespaces/acme", None),
+        (200, json!({ "vertices": 3, "hyperedges": 2 }))
+    );
+
+    // Query it.
+    assert_eq!(
+        request(address, "GET", "/namespaces/acme/vertices/1", None),
+        (
+            200,
+            json!({ "index": 1, "weight": "2", "version": 2, "hyperedges": [0, 1] })
+        )
+    );
+    assert_eq!(
+        request(address, "GET", "/namespaces/acme/hyperedges/1", None),
+        (
+            200,
+            json!({ "index": 1, "weight": "20", "version": 5, "vertices": [1, 2] })
+        )
+    );
+    assert_eq!(
+        request(address, "GET", "/namespaces/acme/vertices/0/adjacent", None),
+        (200, json!([1]))
+    );
+    assert_eq!(
+        request(address, "GET", "/namespaces/acme/paths/0/2", None),
+        (
+            200,
+            json!([
+                { "vertex": 0, "hyperedge": null },
+                { "vertex": 1, "hyperedge": 0 },
+                { "vertex": 2, "hyperedge": 1 },
+            ])
+        )
+    );
+
+    // Update it conditionally on the versions.
+    assert_eq!(
+        request(
+            address,
+            "PUT",
+            "/namespaces/acme/vertices/0",
+            Some(json!({ "weight": "4", "expected_version": 1 }))
+        ),
+        (200, json!({ "index": 0, "version": 6 }))
+    );
+    assert_eq!(
+        request(
+            address,
+            "PUT",
+            "/namespaces/acme/vertices/0",
+            Some(json!({ "weight": "5", "expected_version": 1 }))
+        )
+        .0,
+        409
+    );
+    assert_eq!(
+        request(
+            address,
+            "PUT",
+            "/namespaces/acme/hyperedges/0",
+            Some(json!({ "vertices": [0, 2], "weight": "30" }))
+        ),
+        (200, json!({ "index": 0, "version": 8 }))
+    );
+
+    // Remove from it.
+    assert_eq!(
+        request(address, "DELETE", "/namespaces/acme/hyperedges/1", None).0,
+        204
+    );
+    assert_eq!(
+        request(address, "DELETE", "/namespaces/acme/vertices/1", None).0,
+        204
+    );
+
+    // The errors are reported with their status codes.
+    assert_eq!(
+        request(address, "GET", "/namespaces/acme/vertices/1", None),
+        (404, json!({ "error": "VertexIndex 1 was not found" }))
+    );
+    assert_eq!(request(address, "GET", "/namespaces/globex", None).0, 404);
+    assert_eq!(
+        request(
+            address,
+            "POST",
+            "/namespaces/acme/vertices",
+            Some(json!({ "weight": "a" }))
+        ),
+        (400, json!({ "error": "Server weight a can't be parsed" }))
+    );
+    assert_eq!(
+        request(
+            address,
+            "POST",
+            "/namespaces/acme/vertices",
+            Some(json!({ "weight": "2" }))
+        )
+        .0,
+        201
+    );
+    assert_eq!(
+        request(
+            address,
+            "POST",
+            "/namespaces/acme/vertices",
+            Some(json!({ "weight": "2" }))
+        )
+        .0,
+        409
+    );
+
+    // Every mutation is written through to the snapshots.
+    let mut store = SnapshotStore::<usize, usize>::open(&root).unwrap();
+    let graph = store.get("acme").unwrap();
+
+    assert_eq!(graph.count_vertices(), 3);
+    assert_eq!(graph.count_hyperedges(), 1);
+    assert_eq!(graph.get_vertex_weight(hypergraph::VertexIndex(0)), Ok(&4));
+
+    // Dropping a namespace removes its snapshot.
+    assert_eq!(request(address, "DELETE", "/namespaces/acme", None).0, 204);
+    assert_eq!(
+        request(address, "GET", "/namespaces", None),
+        (200, json!([]))
+    );
+
+    std::fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn integration_server_conflicts() {
+    let root = std::env::temp_dir().join(format!(
+        "hypergraph-server-conflicts-{}",
+        std::process::id()
+    ));
+    let address = spawn_server(&root);
+
+    request(
+        address,
+        "POST",
+        "/namespaces/acme/vertices",
+        Some(json!({ "weight": "1" })),
+    );
+
+    // Another writer updates the snapshot behind the back of the server.
+    let mut store = SnapshotStore::<usize, usize>::open(&root).unwrap();
+
+    store.update("acme", |graph| graph.add_vertex(2)).unwrap();
+    store.flush("acme").unwrap();
+
+    // The write is rejected and the namespace is reloaded.
+    assert_eq!(
+        request(
+            address,
+            "POST",
+            "/namespaces/acme/vertices",
+            Some(json!({ "weight": "3" }))
+        ),
+        (
+            409,
+            json!({ "error": "Store namespace acme was modified by another writer" })
+        )
+    );
+    assert_eq!(
+        request(address, "GET", "/namespaces/acme", None),
+        (200, json!({ "vertices": 2, "hyperedges": 0 }))
+    );
+    assert_eq!(
+        request(
+            address,
+            "POST",
+            "/namespaces/acme/vertices",
+            Some(json!({ "weight": "3" }))
+        )
+        .0,
+        201
+    );
+
+    std::fs::remove_dir_all(&root).unwrap();
+}
@@ -0,0 +1,108 @@
+//! Integration tests.
+
+use hypergraph::{
+    Hypergraph,
+    VertexIndex,
+    errors::HypergraphError,
+};
+
+#[test]
+fn integration_similarity() {
+    // Create a new hypergraph.
+    let mut graph = Hypergraph::<usize, usize>::new();
+
+    // Create some vertices.
+    let a = graph.add_vertex(1).unwrap();
+    let b = graph.add_vertex(2).unwrap();
+    let c = graph.add_vertex(3).unwrap();
+    let d = graph.add_vertex(4).unwrap();
+
+    assert_eq!(
+        graph.find_similar_vertices(a, 2),
+        Err(HypergraphError::VertexEmbeddingNotFound(a))
+    );
+
+    // Set some embeddings.
+    graph
+        .set_vertex_embeddings(vec![
+            (a, vec![1., 0.]),
+            (b, vec![2., 0.2]),
+            (c, vec![0., 1.]),
+            (d, vec![-1., 0.]),
+        ])
+        .unwrap();
+
+    assert_eq!(
+        graph.set_vertex_embedding(a, vec![1., 0., 0.]),
+        Err(HypergraphError::VertexEmbeddingInvalidDimensions {
+            index: a,
+            expected: 2,
+            actual: 3
+        })
+    );
+    assert_eq!(
+        graph.set_vertex_embedding(VertexIndex(4), vec![1., 0.]),
+        Err(HypergraphError::VertexIndexNotFound(VertexIndex(4)))
+    );
+    assert_eq!(graph.get_vertex_embedding(c), Ok(Some(&[0., 1.][..])));
+
+    // Find the most similar vertices.
+    let similar = graph.find_similar_vertices(a, 2).unwrap();
+
+    assert_eq!(
+        similar.iter().map(|(index, _)| *index).collect::<Vec<_>>(),
+        vec![b, c]
+    );
+    assert!((similar[1].1 - 0.).abs() < 1e-6);
+
+    // The embeddings are dropped along with the vertices.
+    graph.remove_vertex(b).unwrap();
+
+    assert_eq!(
+        graph
+            .find_similar_vertices(a, 3)
+            .unwrap()
+            .iter()
+            .map(|(index, _)| *index)
+            .collect::<Vec<_>>(),
+        vec![c, d]
+    );
+
+    graph.remove_vertex_embedding(c).unwrap();
+
+    assert_eq!(graph.get_vertex_embedding(c), Ok(None));
+}
+
+#[test]
+fn integration_similarity_partitioned() {
+    // Create enough vertices to partition the embeddings, spread on a
+    // circle.
+    let mut graph = Hypergraph::<usize, usize>::new();
+
+    let vertices = (0..256)
+        .map(|weight| graph.add_vertex(weight).unwrap())
+        .collect::<Vec<VertexIndex>>();
+
+    graph
+        .set_vertex_embeddings(vertices.iter().enumerate().map(|(position, vertex)| {
+            let angle = position as f32 * std::f32::consts::TAU / 256.;
+
+            (*vertex, vec![angle.cos(), angle.sin()])
+        }))
+        .unwrap();
+
+    // The neighbors on the circle are the most similar.
+    let mut similar = graph
+        .find_similar_vertices(vertices[10], 4)
+        .unwrap()
+        .into_iter()
+        .map(|(index, _)| index)
+        .collect::<Vec<VertexIndex>>();
+
+    similar.sort_unstable();
+
+    assert_eq!(
+        similar,
+        vec![vertices[8], vertices[9], vertices[11], vertices[12]]
+    );
+}
@@ -0,0 +1,142 @@
+//! Integration tests.
+
+use hypergraph::{
+    DuplicatePolicy,
+    HyperedgeIndex,
+    Hypergraph,
+    Value,
+    VertexIndex,
+    errors::HypergraphError,
+    snapshot::SNAPSHOT_VERSION,
+};
+
+#[test]
+fn integration_snapshot() {
+    // Create a new hypergraph.
+    let mut graph = Hypergraph::<usize, usize>::with_duplicate_policy(
+        DuplicatePolicy::RejectDuplicateVertexLists,
+    );
+
+    // Create some vertices.
+    let a = graph.add_vertex(1).unwrap();
+    let b = graph.add_vertex(2).unwrap();
+    let c = graph.add_vertex(3).unwrap();
+    let d = graph.add_vertex_allow_duplicate(3).unwrap();
+
+    // Create some hyperedges.
+    let alpha = graph.add_hyperedge(vec![a, b, c], 10).unwrap();
+    graph.add_hyperedge(vec![c, a], 20).unwrap();
+    let gamma = graph
+        .add_hyperedge_with_roles(vec![(d, "input"), (b, "output")], 30)
+        .unwrap();
+
+    graph.set_vertex_attr(b, "name", "bob").unwrap();
+    graph.set_hyperedge_property(gamma, "cost", 1.5).unwrap();
+
+    // Remove a vertex and a hyperedge to get holes in the stable indexes.
+    graph.remove_vertex(a).unwrap();
+    graph.remove_hyperedge(alpha).unwrap();
+
+    // Round-trip via a file.
+    let path = std::env::temp_dir().join(format!("hypergraph-{}.snapshot", std::process::id()));
+
+    graph.save_snapshot(&path).unwrap();
+
+    let loaded = Hypergraph::<usize, usize>::load_snapshot(&path).unwrap();
+
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(
+        loaded.content_hash(),
+        graph.content_hash(),
+        "should restore the same content"
+    );
+    assert_eq!(
+        loaded.get_vertex_weight(VertexIndex(3)),
+        Ok(&3),
+        "should preserve the stable indexes of the vertices"
+    );
+    assert_eq!(
+        loaded.get_hyperedge_vertices(gamma),
+        Ok(vec![d, b]),
+        "should preserve the stable indexes of the hyperedges"
+    );
+    assert_eq!(
+        loaded.get_vertex_hyperedges(b),
+        graph.get_vertex_hyperedges(b),
+        "should preserve the hyperedges of the vertices"
+    );
+    assert_eq!(
+        loaded.get_vertex_attr(b, "name"),
+        Ok(Some(&Value::from("bob"))),
+        "should restore the attributes"
+    );
+    assert_eq!(
+        loaded.get_hyperedge_property(gamma, "cost"),
+        Ok(Some(&Value::Float(1.5))),
+        "should restore the properties"
+    );
+    assert_eq!(
+        loaded.get_hyperedge_vertices_by_role(gamma, "input"),
+        Ok(vec![d]),
+        "should restore the roles"
+    );
+    assert_eq!(
+        loaded.get_duplicate_policy(),
+        DuplicatePolicy::RejectDuplicateVertexLists,
+        "should restore the duplicate policy"
+    );
+
+    // The counters of the stable indexes are restored too.
+    let mut loaded = loaded;
+
+    assert_eq!(loaded.add_vertex(5), Ok(VertexIndex(4)));
+    assert_eq!(loaded.add_hyperedge(vec![b], 40), Ok(HyperedgeIndex(3)));
+
+    // Errors.
+    let mut bytes = vec![];
+
+    graph.write_snapshot(&mut bytes).unwrap();
+
+    assert_eq!(
+        Hypergraph::<usize, usize>::read_snapshot(&b"not a snapshot"[..]).unwrap_err(),
+        HypergraphError::SnapshotInvalidHeader,
+        "should reject an invalid header"
+    );
+
+    let mut newer = bytes.clone();
+
+    newer[8..12].copy_from_slice(&(SNAPSHOT_VERSION + 1).to_le_bytes());
+
+    assert_eq!(
+        Hypergraph::<usize, usize>::read_snapshot(&newer[..]).unwrap_err(),
+        HypergraphError::SnapshotUnsupportedVersion(SNAPSHOT_VERSION + 1),
+        "should reject a newer version"
+    );
+    assert!(
+        matches!(
+            Hypergraph::<usize, usize>::read_snapshot(&bytes[..bytes.len() - 1]),
+            Err(HypergraphError::SnapshotCorrupted(_))
+        ),
+        "should reject a truncated snapshot"
+    );
+
+    let mut concatenated = bytes.clone();
+
+    concatenated.extend_from_slice(&bytes);
+
+    assert_eq!(
+        Hypergraph::<usize, usize>::read_snapshot(&concatenated[..]).unwrap_err(),
+        HypergraphError::SnapshotCorrupted("trailing bytes".to_owned()),
+        "should reject trailing bytes"
+    );
+    assert!(
+        Hypergraph::<u8, usize>::read_snapshot(&bytes[..]).is_ok(),
+        "should parse the weights with another type"
+    );
+    assert!(
+        Hypergraph::<usize, usize>::load_snapshot(std::env::temp_dir().join("missing.snapshot"))
+            .is_err(),
+        "should return an I/O error"
+    );
+}
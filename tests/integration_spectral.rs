@@ -0,0 +1,82 @@
+//! Integration tests.
+
+#![cfg(feature = "ndarray")]
+
+mod common;
+
+use common::{
+    Hyperedge,
+    Vertex,
+};
+use hypergraph::Hypergraph;
+use ndarray::{
+    Array2,
+    array,
+};
+
+#[test]
+fn integration_spectral() {
+    // Create a new hypergraph.
+    let mut graph = Hypergraph::<Vertex, Hyperedge>::new();
+
+    // Create some vertice weights.
+    let vertex_one = Vertex::new("one");
+    let vertex_two = Vertex::new("two");
+    let vertex_three = Vertex::new("three");
+    let vertex_four = Vertex::new("four");
+
+    // Create some hyperedge weights.
+    let hyperedge_one = Hyperedge::new("one", 2);
+    let hyperedge_two = Hyperedge::new("two", 1);
+
+    // Create some vertices.
+    let a = graph.add_vertex(vertex_one).unwrap();
+    let b = graph.add_vertex(vertex_two).unwrap();
+    let c = graph.add_vertex(vertex_three).unwrap();
+    let d = graph.add_vertex(vertex_four).unwrap();
+
+    // Add some hyperedges.
+    graph.add_hyperedge(vec![a, b, c], hyperedge_one).unwrap();
+    graph.add_hyperedge(vec![c, b, b], hyperedge_two).unwrap();
+
+    assert_eq!(
+        graph.to_incidence_matrix(),
+        Ok((
+            vec![a, b, c, d],
+            array![[1., 0.], [1., 1.], [1., 1.], [0., 0.]]
+        )),
+        "should get the incidence matrix"
+    );
+
+    assert_eq!(
+        graph.to_clique_expansion_adjacency_matrix(),
+        Ok((
+            vec![a, b, c, d],
+            array![
+                [0., 1., 1., 0.],
+                [1., 0., 2., 0.],
+                [1., 2., 0., 0.],
+                [0., 0., 0., 0.]
+            ]
+        )),
+        "should count the shared hyperedges"
+    );
+
+    let (vertices, laplacian) = graph.to_normalized_laplacian_matrix().unwrap();
+    let off_diagonal = -2. / 3. / 6_f64.sqrt();
+    let expected: Array2<f64> = array![
+        [2. / 3., off_diagonal, off_diagonal, 0.],
+        [off_diagonal, 11. / 18., -7. / 18., 0.],
+        [off_diagonal, -7. / 18., 11. / 18., 0.],
+        [0., 0., 0., 1.]
+    ];
+
+    assert_eq!(vertices, vec![a, b, c, d], "should index the vertices");
+    assert!(
+        laplacian
+            .iter()
+            .zip(expected.iter())
+            .all(|(left, right)| (left - right).abs() < 1e-12),
+        "should get the normalized Laplacian"
+    );
+}
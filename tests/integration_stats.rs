@@ -0,0 +1,88 @@
+//! Integration tests.
+
+use hypergraph::Hypergraph;
+
+#[test]
+fn integration_clustering_coefficients() {
+    // Create a new hypergraph.
+    let mut graph = Hypergraph::<&str, usize>::new();
+
+    assert_eq!(graph.average_clustering_coefficient(), Ok(0.));
+
+    let a = graph.add_vertex("a").unwrap();
+    let b = graph.add_vertex("b").unwrap();
+    let c = graph.add_vertex("c").unwrap();
+    let d = graph.add_vertex("d").unwrap();
+
+    // A single hyperedge doesn't form any triangle.
+    graph.add_hyperedge(vec![a, b, c], 1).unwrap();
+
+    let coefficients = graph.clustering_coefficients().unwrap();
+
+    assert_eq!(coefficients.len(), 4);
+    assert!(coefficients.values().all(|coefficient| *coefficient == 0.));
+
+    // Nor does a pair of hyperedges overlapping on the same vertices.
+    graph.add_hyperedge(vec![a, b], 2).unwrap();
+
+    assert_eq!(graph.clustering_coefficients().unwrap()[&a], 0.);
+
+    // Close the triangles via distinct hyperedges, the direction being
+    // ignored.
+    graph.clear_hyperedges().unwrap();
+    graph.add_hyperedge(vec![a, b], 1).unwrap();
+    graph.add_hyperedge(vec![c, b], 2).unwrap();
+    graph.add_hyperedge(vec![c, a], 3).unwrap();
+    graph.add_hyperedge(vec![a, d], 4).unwrap();
+
+    let coefficients = graph.clustering_coefficients().unwrap();
+
+    assert!((coefficients[&a] - 1. / 3.).abs() < f64::EPSILON);
+    assert_eq!(coefficients[&b], 1.);
+    assert_eq!(coefficients[&c], 1.);
+    assert_eq!(coefficients[&d], 0.);
+    assert!((graph.average_clustering_coefficient().unwrap() - 7. / 12.).abs() < f64::EPSILON);
+
+    // A triangle can also span a larger hyperedge.
+    graph.add_hyperedge(vec![b, d, c], 5).unwrap();
+
+    let coefficients = graph.clustering_coefficients().unwrap();
+
+    assert_eq!(coefficients[&a], 1.);
+    assert_eq!(coefficients[&d], 1.);
+}
+
+#[test]
+fn integration_assortativity() {
+    // Create a new hypergraph.
+    let mut graph = Hypergraph::<&str, usize>::new();
+
+    assert_eq!(graph.degree_assortativity(), Ok(None));
+    assert_eq!(graph.cardinality_degree_correlation(), Ok(None));
+
+    let a = graph.add_vertex("a").unwrap();
+    let b = graph.add_vertex("b").unwrap();
+    let c = graph.add_vertex("c").unwrap();
+    let d = graph.add_vertex("d").unwrap();
+
+    // All the vertices of a triangle have the same degree.
+    graph.add_hyperedge(vec![a, b, c], 1).unwrap();
+
+    assert_eq!(graph.degree_assortativity(), Ok(None));
+    assert_eq!(graph.cardinality_degree_correlation(), Ok(None));
+
+    // A star is perfectly disassortative.
+    graph.clear_hyperedges().unwrap();
+    graph.add_hyperedge(vec![a, b], 1).unwrap();
+    graph.add_hyperedge(vec![c, a], 2).unwrap();
+    graph.add_hyperedge(vec![a, d], 3).unwrap();
+
+    assert!((graph.degree_assortativity().unwrap().unwrap() + 1.).abs() < 1e-12);
+
+    // The vertices of the larger hyperedge belong to fewer hyperedges.
+    graph.clear_hyperedges().unwrap();
+    graph.add_hyperedge(vec![a, b, c], 1).unwrap();
+    graph.add_hyperedge(vec![a, d], 2).unwrap();
+
+    assert!((graph.cardinality_degree_correlation().unwrap().unwrap() + 1. / 6.).abs() < 1e-12);
+}
@@ -0,0 +1,277 @@
+//! Integration tests.
+
+use hypergraph::{
+    Constraints,
+    Hypergraph,
+    SnapshotStore,
+    errors::HypergraphError,
+};
+
+#[test]
+fn integration_store() {
+    let root = std::env::temp_dir().join(format!("hypergraph-store-{}", std::process::id()));
+
+    let mut store = SnapshotStore::<usize, usize>::open(&root).unwrap();
+
+    assert_eq!(store.list_namespaces(), Ok(vec![]));
+
+    // Populate two namespaces independently.
+    store
+        .update("acme", |graph| {
+            let a = graph.add_vertex(1)?;
+            let b = graph.add_vertex(2)?;
+
+            graph.add_hyperedge(vec![a, b], 3)
+        })
+        .unwrap();
+    store.update("globex", |graph| graph.add_vertex(4)).unwrap();
+
+    assert_eq!(
+        store.list_namespaces(),
+        Ok(vec!["acme".to_owned(), "globex".to_owned()])
+    );
+
+    // Only the flushed namespaces are persisted.
+    store.flush("acme").unwrap();
+
+    let mut reopened = SnapshotStore::<usize, usize>::open(&root).unwrap();
+
+    assert_eq!(reopened.list_namespaces(), Ok(vec!["acme".to_owned()]));
+    assert_eq!(reopened.get("acme").unwrap().count_vertices(), 2);
+    assert_eq!(reopened.get("acme").unwrap().count_hyperedges(), 1);
+    assert_eq!(
+        reopened.get("globex").err(),
+        Some(HypergraphError::StoreNamespaceNotFound("globex".to_owned()))
+    );
+
+    store.flush_all().unwrap();
+
+    assert_eq!(reopened.get("globex").unwrap().count_vertices(), 1);
+
+    // The cached hypergraph is kept until evicted.
+    store.update("globex", |graph| graph.add_vertex(5)).unwrap();
+    store.evict("globex");
+
+    assert_eq!(store.get("globex").unwrap().count_vertices(), 1);
+
+    // Drop a namespace.
+    store.drop_namespace("acme").unwrap();
+
+    assert_eq!(store.list_namespaces(), Ok(vec!["globex".to_owned()]));
+    assert_eq!(
+        store.drop_namespace("acme"),
+        Err(HypergraphError::StoreNamespaceNotFound("acme".to_owned()))
+    );
+    assert_eq!(
+        store.update("../escape", |_| Ok(())).err(),
+        Some(HypergraphError::StoreInvalidNamespace(
+            "../escape".to_owned()
+        ))
+    );
+
+    std::fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn integration_store_conflicts() {
+    let root =
+        std::env::temp_dir().join(format!("hypergraph-store-conflicts-{}", std::process::id()));
+
+    let mut first = SnapshotStore::<usize, usize>::open(&root).unwrap();
+    let mut second = SnapshotStore::<usize, usize>::open(&root).unwrap();
+
+    let a = first.update("acme", |graph| graph.add_vertex(1)).unwrap();
+
+    first.flush("acme").unwrap();
+
+    // Both writers load the same snapshot and update the same vertex.
+    let version = first.get("acme").unwrap().get_vertex_version(a).unwrap();
+
+    second
+        .update("acme", |graph| {
+            graph.update_vertex_weight_if_version(a, 2, version)
+        })
+        .unwrap();
+    first
+        .update("acme", |graph| {
+            graph.update_vertex_weight_if_version(a, 3, version)
+        })
+        .unwrap();
+
+    second.flush("acme").unwrap();
+
+    // The last writer doesn't silently overwrite the first one.
+    assert_eq!(
+        first.flush("acme"),
+        Err(HypergraphError::StoreNamespaceConflict("acme".to_owned()))
+    );
+
+    // Reload and retry conditionally on the version of the vertex.
+    first.evict("acme");
+
+    assert_eq!(first.get("acme").unwrap().get_vertex_weight(a), Ok(&2));
+    assert_eq!(
+        first.update("acme", |graph| {
+            graph.update_vertex_weight_if_version(a, 3, version)
+        }),
+        Err(HypergraphError::VertexVersionConflict {
+            index: a,
+            expected: version,
+            actual: version + 1,
+        })
+    );
+    assert_eq!(
+        first.update("acme", |graph| {
+            graph.update_vertex_weight_if_version(a, 3, version + 1)
+        }),
+        Ok(version + 2)
+    );
+
+    first.flush("acme").unwrap();
+    first.flush("acme").unwrap();
+
+    // The snapshots are replaced atomically, without leftovers.
+    assert!(
+        std::fs::read_dir(&root)
+            .unwrap()
+            .all(|entry| entry.unwrap().path().extension() == Some("snapshot".as_ref()))
+    );
+
+    // An external change of the snapshot is caught even when its length is
+    // unchanged.
+    let path = root.join("acme.snapshot");
+    let mut bytes = std::fs::read(&path).unwrap();
+    let last = bytes.len() - 1;
+
+    bytes[last] ^= 1;
+    std::fs::write(&path, bytes).unwrap();
+
+    assert_eq!(
+        first.flush("acme"),
+        Err(HypergraphError::StoreNamespaceConflict("acme".to_owned()))
+    );
+
+    // A namespace created concurrently conflicts as well.
+    second.update("globex", |_| Ok(())).unwrap();
+    first.update("globex", |_| Ok(())).unwrap();
+    first.flush("globex").unwrap();
+
+    assert_eq!(
+        second.flush("globex"),
+        Err(HypergraphError::StoreNamespaceConflict("globex".to_owned()))
+    );
+
+    std::fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn integration_store_constraints() {
+    let root = std::env::temp_dir().join(format!(
+        "hypergraph-store-constraints-{}",
+        std::process::id()
+    ));
+    let limits = Constraints {
+        max_vertices: Some(1),
+        ..Constraints::default()
+    };
+
+    // The constraints of a namespace are persisted in its snapshot.
+    let mut store = SnapshotStore::<usize, usize>::open(&root).unwrap();
+
+    assert_eq!(store.get_constraints(), None);
+
+    store
+        .update("acme", |graph| {
+            *graph = Hypergraph::with_constraints(limits);
+
+            graph.add_vertex(1)
+        })
+        .unwrap();
+    store.flush("acme").unwrap();
+
+    let mut reopened = SnapshotStore::<usize, usize>::open(&root).unwrap();
+
+    assert_eq!(
+        reopened.update("acme", |graph| graph.add_vertex(2)),
+        Err(HypergraphError::VerticesCountExceeded { max: 1 }),
+        "should still enforce the limit once reloaded"
+    );
+
+    // The constraints of the store are enforced for every namespace, either
+    // created or loaded.
+    let mut limited = SnapshotStore::<usize, usize>::open_with_constraints(
+        &root,
+        Constraints {
+            max_vertices: Some(2),
+            ..Constraints::default()
+        },
+    )
+    .unwrap();
+
+    assert!(limited.update("acme", |graph| graph.add_vertex(2)).is_ok());
+    assert_eq!(
+        limited.update("acme", |graph| graph.add_vertex(3)),
+        Err(HypergraphError::VerticesCountExceeded { max: 2 })
+    );
+    assert_eq!(
+        limited.update("globex", |graph| {
+            graph.add_vertex(1)?;
+            graph.add_vertex(2)?;
+            graph.add_vertex(3)
+        }),
+        Err(HypergraphError::VerticesCountExceeded { max: 2 })
+    );
+
+    // Replacing a hypergraph doesn't drop the constraints of the store.
+    limited
+        .update("globex", |graph| {
+            *graph = Hypergraph::new();
+
+            Ok(())
+        })
+        .unwrap();
+
+    assert_eq!(
+        limited.get("globex").unwrap().get_constraints(),
+        Constraints {
+            max_vertices: Some(2),
+            ..Constraints::default()
+        }
+    );
+    assert_eq!(
+        limited.update("globex", |graph| {
+            *graph = Hypergraph::new();
+
+            for weight in 0..3 {
+                graph.add_vertex(weight)?;
+            }
+
+            Ok(())
+        }),
+        Err(HypergraphError::VerticesCountExceeded { max: 2 }),
+        "should evict a hypergraph breaking the constraints"
+    );
+    assert_eq!(
+        limited.get("globex").err(),
+        Some(HypergraphError::StoreNamespaceNotFound("globex".to_owned()))
+    );
+
+    limited.flush("acme").unwrap();
+
+    // A snapshot breaking the constraints of the store is rejected.
+    let mut stricter = SnapshotStore::<usize, usize>::open_with_constraints(
+        &root,
+        Constraints {
+            max_vertices: Some(1),
+            ..Constraints::default()
+        },
+    )
+    .unwrap();
+
+    assert_eq!(
+        stricter.get("acme").err(),
+        Some(HypergraphError::VerticesCountExceeded { max: 1 })
+    );
+
+    std::fs::remove_dir_all(&root).unwrap();
+}
@@ -0,0 +1,78 @@
+//! Integration tests.
+
+mod common;
+
+use common::{
+    Hyperedge,
+    Vertex,
+};
+use hypergraph::{
+    HyperedgeIndex,
+    Hypergraph,
+    errors::HypergraphError,
+};
+
+#[test]
+fn integration_structures() {
+    // Create a new hypergraph.
+    let mut graph = Hypergraph::<Vertex, Hyperedge>::new();
+
+    // Create some vertices.
+    let a = graph.add_vertex(Vertex::new("a")).unwrap();
+    let b = graph.add_vertex(Vertex::new("b")).unwrap();
+    let c = graph.add_vertex(Vertex::new("c")).unwrap();
+
+    // Add some hyperedges.
+    let simple = graph
+        .add_hyperedge(vec![a, b, c], Hyperedge::new("simple", 1))
+        .unwrap();
+    let looping = graph
+        .add_hyperedge(vec![a, b, b, c], Hyperedge::new("looping", 1))
+        .unwrap();
+    let unary = graph
+        .add_hyperedge(vec![c], Hyperedge::new("unary", 1))
+        .unwrap();
+    let cycle = graph
+        .add_hyperedge(vec![a, b, a], Hyperedge::new("cycle", 1))
+        .unwrap();
+    let unary_loop = graph
+        .add_hyperedge(vec![a, a], Hyperedge::new("unary loop", 1))
+        .unwrap();
+
+    assert_eq!(graph.is_self_looping(simple), Ok(false));
+    assert_eq!(graph.is_self_looping(looping), Ok(true));
+    assert_eq!(
+        graph.is_self_looping(unary),
+        Ok(false),
+        "should not consider a unary as self-looping"
+    );
+    assert_eq!(
+        graph.is_self_looping(cycle),
+        Ok(false),
+        "should not consider a cycle as self-looping"
+    );
+    assert_eq!(graph.is_self_looping(unary_loop), Ok(true));
+    assert_eq!(
+        graph.is_self_looping(HyperedgeIndex(5)),
+        Err(HypergraphError::HyperedgeIndexNotFound(HyperedgeIndex(5))),
+        "should return an explicit error"
+    );
+    assert_eq!(
+        graph.get_self_loops(),
+        Ok(vec![looping, unary_loop]),
+        "should get the self-loops"
+    );
+    assert_eq!(
+        graph.get_unary_hyperedges(),
+        Ok(vec![unary]),
+        "should get the unaries"
+    );
+
+    // Remove a hyperedge to shift the internal indexes.
+    assert_eq!(graph.remove_hyperedge(simple), Ok(()));
+    assert_eq!(
+        graph.get_self_loops(),
+        Ok(vec![unary_loop, looping]),
+        "should get the self-loops after the removal"
+    );
+}
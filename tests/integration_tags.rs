@@ -0,0 +1,132 @@
+//! Integration tests.
+
+mod common;
+
+use common::{
+    Hyperedge,
+    Vertex,
+};
+use hypergraph::{
+    HyperedgeIndex,
+    Hypergraph,
+    JournaledHypergraph,
+    errors::HypergraphError,
+};
+
+#[test]
+fn integration_tags() {
+    // Create a new hypergraph.
+    let mut graph = Hypergraph::<Vertex, Hyperedge>::new();
+
+    let a = graph.add_vertex(Vertex::new("a")).unwrap();
+    let b = graph.add_vertex(Vertex::new("b")).unwrap();
+    let c = graph.add_vertex(Vertex::new("c")).unwrap();
+
+    let alpha = graph
+        .add_hyperedge(vec![a, b, c], Hyperedge::new("alpha", 1))
+        .unwrap();
+    let beta = graph
+        .add_hyperedge(vec![a, c], Hyperedge::new("beta", 5))
+        .unwrap();
+    let gamma = graph
+        .add_hyperedge(vec![b, a], Hyperedge::new("gamma", 1))
+        .unwrap();
+
+    assert_eq!(graph.tag_hyperedge(alpha, "layer-A"), Ok(true));
+    assert_eq!(graph.tag_hyperedge(gamma, "layer-A"), Ok(true));
+    assert_eq!(graph.tag_hyperedge(beta, "layer-B"), Ok(true));
+    assert_eq!(graph.tag_hyperedge(alpha, "layer-C"), Ok(true));
+    assert_eq!(
+        graph.tag_hyperedge(alpha, "layer-A"),
+        Ok(false),
+        "should not tag a hyperedge twice"
+    );
+    assert_eq!(
+        graph.tag_hyperedge(HyperedgeIndex(3), "layer-A"),
+        Err(HypergraphError::HyperedgeIndexNotFound(HyperedgeIndex(3))),
+        "should return an explicit error"
+    );
+
+    assert_eq!(graph.get_tags(), vec!["layer-A", "layer-B", "layer-C"]);
+    assert_eq!(graph.get_hyperedges_by_tag("layer-A"), vec![alpha, gamma]);
+    assert_eq!(graph.get_hyperedges_by_tag("layer-B"), vec![beta]);
+    assert_eq!(graph.get_hyperedges_by_tag("layer-D"), vec![]);
+    assert_eq!(
+        graph.get_hyperedge_tags(alpha),
+        Ok(vec!["layer-A", "layer-C"])
+    );
+
+    // Traverse the tagged hyperedges only.
+    assert_eq!(
+        graph.get_dijkstra_connections_with_tags(a, c, &["layer-B"]),
+        Ok(vec![(a, None), (c, Some(beta))])
+    );
+    assert_eq!(
+        graph.get_dijkstra_connections_with_tags(a, c, &["layer-A"]),
+        Ok(vec![(a, None), (b, Some(alpha)), (c, Some(alpha))])
+    );
+    assert_eq!(
+        graph.get_dijkstra_connections_with_tags(c, a, &["layer-A"]),
+        Ok(vec![]),
+        "should not find a path"
+    );
+
+    // Untag the hyperedges.
+    assert_eq!(graph.untag_hyperedge(beta, "layer-B"), Ok(true));
+    assert_eq!(graph.untag_hyperedge(beta, "layer-B"), Ok(false));
+    assert_eq!(graph.untag_hyperedge(beta, "layer-D"), Ok(false));
+    assert_eq!(graph.get_hyperedge_tags(beta), Ok(vec![]));
+
+    // Removing a hyperedge removes its tags, the unused ones being dropped.
+    graph.remove_hyperedge(alpha).unwrap();
+
+    assert_eq!(graph.get_tags(), vec!["layer-A"]);
+    assert_eq!(graph.get_hyperedges_by_tag("layer-A"), vec![gamma]);
+
+    // The tags are part of the equality.
+    let mut other = graph.clone();
+
+    other.tag_hyperedge(beta, "layer-A").unwrap();
+
+    assert_ne!(other, graph);
+
+    // Pruning the hyperedges removes their tags as well.
+    graph.prune_hyperedges_by_cardinality(3, 3).unwrap();
+
+    assert_eq!(graph.get_tags(), Vec::<&str>::new());
+}
+
+#[test]
+fn integration_tags_persistence() {
+    let path = std::env::temp_dir().join(format!("hypergraph-tags-{}.journal", std::process::id()));
+
+    let mut graph = JournaledHypergraph::<usize, usize>::create(&path).unwrap();
+
+    let a = graph.add_vertex(1).unwrap();
+    let b = graph.add_vertex(2).unwrap();
+    let alpha = graph.add_hyperedge(vec![a, b], 10).unwrap();
+    let beta = graph.add_hyperedge(vec![b, a], 20).unwrap();
+
+    graph.tag_hyperedge(alpha, "layer-A").unwrap();
+    graph.tag_hyperedge(beta, "layer-A").unwrap();
+    graph.tag_hyperedge(beta, "layer-B").unwrap();
+    graph.untag_hyperedge(beta, "layer-A").unwrap();
+
+    let graph = graph.into_inner();
+    let replayed = Hypergraph::<usize, usize>::replay(&path).unwrap();
+
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(replayed, graph, "should replay the tags");
+    assert_eq!(replayed.get_hyperedges_by_tag("layer-B"), vec![beta]);
+
+    // Round-trip via a snapshot.
+    let mut bytes = vec![];
+
+    graph.write_snapshot(&mut bytes).unwrap();
+
+    let loaded = Hypergraph::<usize, usize>::read_snapshot(&bytes[..]).unwrap();
+
+    assert_eq!(loaded, graph, "should preserve the tags");
+    assert_eq!(loaded.get_hyperedges_by_tag("layer-A"), vec![alpha]);
+}
@@ -0,0 +1,128 @@
+//! Integration tests.
+
+mod common;
+
+use std::fmt::{
+    Display,
+    Formatter,
+    Result,
+};
+
+use common::Vertex;
+use hypergraph::{
+    Hypergraph,
+    TemporalHyperedgeTrait,
+    VertexIndex,
+    errors::HypergraphError,
+};
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
+struct Event<'a> {
+    cost: usize,
+    name: &'a str,
+    time: Option<u32>,
+}
+
+impl<'a> Event<'a> {
+    fn new(name: &'a str, cost: usize, time: Option<u32>) -> Self {
+        Event { cost, name, time }
+    }
+}
+
+impl Display for Event<'_> {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result {
+        write!(formatter, "{}", self.name)
+    }
+}
+
+impl From<Event<'_>> for usize {
+    fn from(Event { cost, .. }: Event<'_>) -> Self {
+        cost
+    }
+}
+
+impl TemporalHyperedgeTrait for Event<'_> {
+    type Timestamp = u32;
+
+    fn timestamp(&self) -> Option<u32> {
+        self.time
+    }
+}
+
+#[test]
+fn integration_temporal() {
+    // Create a new hypergraph.
+    let mut graph = Hypergraph::<Vertex, Event>::new();
+
+    // Create some vertices.
+    let a = graph.add_vertex(Vertex::new("one")).unwrap();
+    let b = graph.add_vertex(Vertex::new("two")).unwrap();
+    let c = graph.add_vertex(Vertex::new("three")).unwrap();
+
+    // Add some time-stamped hyperedges.
+    let alpha = graph
+        .add_hyperedge(vec![a, b], Event::new("alpha", 1, Some(10)))
+        .unwrap();
+    let beta = graph
+        .add_hyperedge(vec![b, c], Event::new("beta", 1, Some(20)))
+        .unwrap();
+    let gamma = graph
+        .add_hyperedge(vec![a, c], Event::new("gamma", 5, Some(30)))
+        .unwrap();
+    graph
+        .add_hyperedge(vec![a, b, c], Event::new("delta", 1, None))
+        .unwrap();
+
+    assert_eq!(
+        graph.hyperedges_between(10, 20),
+        Ok(vec![alpha, beta]),
+        "should get the hyperedges within the inclusive window"
+    );
+    assert_eq!(
+        graph.hyperedges_between(21, 29),
+        Ok(vec![]),
+        "should get no hyperedges"
+    );
+    assert_eq!(
+        graph.hyperedges_between(0, u32::MAX),
+        Ok(vec![alpha, beta, gamma]),
+        "should skip the hyperedges without timestamp"
+    );
+
+    assert_eq!(
+        graph.get_adjacent_vertices_from(a),
+        Ok(vec![b, c]),
+        "should get all the adjacent vertices"
+    );
+    assert_eq!(
+        graph.get_adjacent_vertices_from_between(a, 25, 35),
+        Ok(vec![c]),
+        "should only get the adjacent vertices within the window"
+    );
+    assert_eq!(
+        graph.get_adjacent_vertices_to_between(c, 0, 20),
+        Ok(vec![b]),
+        "should only get the adjacent vertices within the window"
+    );
+
+    assert_eq!(
+        graph.get_dijkstra_connections_between(a, c, 0, 20),
+        Ok(vec![(a, None), (b, Some(alpha)), (c, Some(beta))]),
+        "should only traverse the hyperedges within the window"
+    );
+    assert_eq!(
+        graph.get_dijkstra_connections_between(a, c, 30, 30),
+        Ok(vec![(a, None), (c, Some(gamma))]),
+        "should only traverse the hyperedges within the window"
+    );
+    assert_eq!(
+        graph.get_dijkstra_connections_between(a, c, 0, 10),
+        Ok(vec![]),
+        "should not find any path"
+    );
+    assert_eq!(
+        graph.get_dijkstra_connections_between(VertexIndex(9), c, 0, 10),
+        Err(HypergraphError::VertexIndexNotFound(VertexIndex(9))),
+        "should return an explicit error"
+    );
+}
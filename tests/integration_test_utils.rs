@@ -0,0 +1,63 @@
+//! Integration tests.
+
+#![cfg(feature = "test-utils")]
+
+use hypergraph::{
+    Hypergraph,
+    test_utils::{
+        MAX_CARDINALITY,
+        hypergraphs,
+        mutations,
+    },
+};
+use proptest::prelude::*;
+
+// Check the invariants of the crate via the public methods.
+fn assert_invariants(graph: Hypergraph<u8, u8>) {
+    let hyperedges = graph.count_hyperedges();
+
+    assert_eq!(graph.count_hyperedges_with_cardinality(0), 0);
+    assert_eq!(
+        (1..=MAX_CARDINALITY)
+            .map(|cardinality| graph.count_hyperedges_with_cardinality(cardinality))
+            .sum::<usize>(),
+        hyperedges
+    );
+
+    let weights = graph
+        .into_iter()
+        .map(|(weight, vertices)| {
+            assert!(!vertices.is_empty());
+
+            weight
+        })
+        .collect::<std::collections::HashSet<u8>>();
+
+    assert_eq!(weights.len(), hyperedges);
+}
+
+proptest! {
+    #[test]
+    fn integration_test_utils_hypergraphs(
+        graph in hypergraphs(any::<u8>(), any::<u8>(), 16, 16)
+    ) {
+        prop_assert!(graph.count_vertices() <= 16);
+        prop_assert!(graph.count_hyperedges() <= 16);
+
+        assert_invariants(graph);
+    }
+
+    #[test]
+    fn integration_test_utils_mutations(
+        mut graph in hypergraphs(0..32u8, 0..32u8, 8, 8),
+        mutations in mutations(0..32u8, 0..32u8, 32)
+    ) {
+        for mutation in mutations {
+            // Invalid mutations, e.g. with duplicated weights, are rejected
+            // by the hypergraph.
+            let _ = mutation.apply(&mut graph);
+        }
+
+        assert_invariants(graph);
+    }
+}
@@ -0,0 +1,72 @@
+//! Integration tests.
+
+mod common;
+
+use std::collections::BTreeMap;
+
+use common::{
+    Hyperedge,
+    Vertex,
+};
+use hypergraph::{
+    HyperedgeRef,
+    Hypergraph,
+};
+
+#[test]
+fn integration_top_k() {
+    // Create a new hypergraph.
+    let mut graph = Hypergraph::<Vertex, Hyperedge>::new();
+
+    let a = graph.add_vertex(Vertex::new("a")).unwrap();
+    let b = graph.add_vertex(Vertex::new("b")).unwrap();
+    let c = graph.add_vertex(Vertex::new("c")).unwrap();
+    let d = graph.add_vertex(Vertex::new("d")).unwrap();
+
+    let alpha = graph
+        .add_hyperedge(vec![a, b], Hyperedge::new("alpha", 5))
+        .unwrap();
+    let beta = graph
+        .add_hyperedge(vec![b, c, b], Hyperedge::new("beta", 20))
+        .unwrap();
+    let gamma = graph
+        .add_hyperedge(vec![c, b], Hyperedge::new("gamma", 5))
+        .unwrap();
+    let delta = graph
+        .add_hyperedge(vec![a], Hyperedge::new("delta", 12))
+        .unwrap();
+
+    let cost = |hyperedge: HyperedgeRef<'_, Vertex, Hyperedge>| usize::from(*hyperedge.weight());
+
+    // The heaviest hyperedges, the earliest inserted one first on ties.
+    assert_eq!(graph.top_k_hyperedges_by(cost, 2), Ok(vec![beta, delta]));
+    assert_eq!(
+        graph.top_k_hyperedges_by(cost, 10),
+        Ok(vec![beta, delta, alpha, gamma])
+    );
+    assert_eq!(graph.top_k_hyperedges_by(cost, 0), Ok(vec![]));
+    assert_eq!(
+        graph.top_k_hyperedges_by(|hyperedge| hyperedge.vertices().unwrap().len(), 1),
+        Ok(vec![beta])
+    );
+
+    // The most connected vertices.
+    assert_eq!(graph.top_k_vertices_by_degree(2), Ok(vec![(b, 3), (a, 2)]));
+    assert_eq!(
+        graph.top_k_vertices_by_degree(4),
+        Ok(vec![(b, 3), (a, 2), (c, 2), (d, 0)])
+    );
+
+    // The histogram of the costs, by bucket of ten.
+    assert_eq!(
+        graph.get_hyperedges_histogram_by(|hyperedge| cost(hyperedge) / 10 * 10),
+        Ok([(0, 2), (10, 1), (20, 1)]
+            .iter()
+            .copied()
+            .collect::<BTreeMap<usize, usize>>())
+    );
+    assert_eq!(
+        Hypergraph::<Vertex, Hyperedge>::new().get_hyperedges_histogram_by(cost),
+        Ok(BTreeMap::new())
+    );
+}
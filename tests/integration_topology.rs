@@ -0,0 +1,73 @@
+//! Integration tests.
+
+use hypergraph::{
+    CardinalityDistribution,
+    HyperedgeIndex,
+    Hypergraph,
+    TopologyBuilder,
+    errors::HypergraphError,
+};
+
+#[test]
+fn integration_topology() {
+    // Build a hypergraph with a uniform distribution of cardinalities.
+    let builder = TopologyBuilder::new(100, 50)
+        .cardinality(CardinalityDistribution::Uniform { min: 2, max: 5 })
+        .seed(7);
+    let graph: Hypergraph<usize, usize> = builder.build(|i| i, |i| i).unwrap();
+
+    assert_eq!(graph.count_vertices(), 100, "should have 100 vertices");
+    assert_eq!(graph.count_hyperedges(), 50, "should have 50 hyperedges");
+    assert!(
+        (0..50).all(|i| {
+            let cardinality = graph
+                .get_hyperedge_vertices(HyperedgeIndex(i))
+                .unwrap()
+                .len();
+
+            (2..=5).contains(&cardinality)
+        }),
+        "should respect the cardinality distribution"
+    );
+
+    let other: Hypergraph<usize, usize> = builder.build(|i| i, |i| i).unwrap();
+
+    assert_eq!(
+        graph.content_hash(),
+        other.content_hash(),
+        "should be deterministic for a given seed"
+    );
+
+    // With a full overlap, all the hyperedges reuse the vertices of the
+    // first one.
+    let graph: Hypergraph<usize, usize> = TopologyBuilder::new(100, 20)
+        .cardinality(CardinalityDistribution::PowerLaw {
+            min: 1,
+            max: 10,
+            exponent: 2.,
+        })
+        .overlap(1.)
+        .build(|i| i, |i| i)
+        .unwrap();
+    let first = graph.get_hyperedge_vertices(HyperedgeIndex(0)).unwrap();
+
+    assert!(
+        (1..20).all(|i| {
+            graph
+                .get_hyperedge_vertices(HyperedgeIndex(i))
+                .unwrap()
+                .iter()
+                .all(|vertex| first.contains(vertex))
+        }),
+        "should only reuse the vertices of the previous hyperedges"
+    );
+
+    // Hyperedges can't be created without vertices.
+    assert_eq!(
+        TopologyBuilder::new(0, 1)
+            .build::<usize, usize, _, _>(|i| i, |i| i)
+            .unwrap_err(),
+        HypergraphError::HyperedgeCreationNoVertices("0".to_owned()),
+        "should return an explicit error"
+    );
+}
@@ -0,0 +1,85 @@
+//! Integration tests.
+
+#![cfg(feature = "tracing")]
+
+mod common;
+
+use std::sync::{
+    Arc,
+    Mutex,
+};
+
+use common::{
+    Hyperedge,
+    Vertex,
+};
+use hypergraph::Hypergraph;
+use tracing::{
+    Event,
+    Metadata,
+    Subscriber,
+    span::{
+        Attributes,
+        Id,
+        Record,
+    },
+};
+
+// Minimal subscriber recording the names of the created spans.
+#[derive(Clone, Default)]
+struct Recorder(Arc<Mutex<Vec<&'static str>>>);
+
+impl Subscriber for Recorder {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, span: &Attributes<'_>) -> Id {
+        let mut spans = self.0.lock().unwrap();
+
+        spans.push(span.metadata().name());
+
+        Id::from_u64(spans.len() as u64)
+    }
+
+    fn record(&self, _: &Id, _: &Record<'_>) {}
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, _: &Event<'_>) {}
+
+    fn enter(&self, _: &Id) {}
+
+    fn exit(&self, _: &Id) {}
+}
+
+#[test]
+fn integration_tracing() {
+    let recorder = Recorder::default();
+
+    tracing::subscriber::with_default(recorder.clone(), || {
+        let mut graph = Hypergraph::<Vertex, Hyperedge>::new();
+
+        let a = graph.add_vertex(Vertex::new("one")).unwrap();
+        let b = graph.add_vertex(Vertex::new("two")).unwrap();
+
+        graph
+            .add_hyperedge(vec![a, b], Hyperedge::new("one", 1))
+            .unwrap();
+        graph.get_dijkstra_connections(a, b).unwrap();
+        graph.remove_vertex(b).unwrap();
+    });
+
+    assert_eq!(
+        *recorder.0.lock().unwrap(),
+        vec![
+            "add_vertex",
+            "add_vertex",
+            "add_hyperedge",
+            "get_filtered_dijkstra_connections",
+            "remove_vertex",
+            "update_hyperedge_vertices"
+        ],
+        "should record a span per mutation and expensive query"
+    );
+}
@@ -0,0 +1,64 @@
+//! Integration tests.
+
+use hypergraph::{
+    Hypergraph,
+    Severity,
+    ValidationCode,
+};
+
+#[test]
+fn integration_validation() {
+    let mut graph = Hypergraph::<usize, usize>::new();
+
+    let a = graph.add_vertex(1).unwrap();
+    let b = graph.add_vertex(2).unwrap();
+    let c = graph.add_vertex(3).unwrap();
+
+    graph
+        .add_hyperedge_with_roles(vec![(a, "from"), (b, "to")], 10)
+        .unwrap();
+    graph.add_hyperedge(vec![b, c], 20).unwrap();
+    graph.remove_vertex(a).unwrap();
+
+    let report = graph.validate();
+
+    assert!(report.is_valid(), "should keep the hypergraph consistent");
+    assert_eq!(report.count(Severity::Error), 0);
+    assert_eq!(report.count(Severity::Warning), 0);
+
+    // An isolated vertex is reported for information only.
+    let d = graph.add_vertex(4).unwrap();
+
+    let report = graph.validate();
+
+    assert!(report.is_valid());
+    assert_eq!(report.count(Severity::Info), 1);
+    assert_eq!(report.issues[0].code, ValidationCode::IsolatedVertex);
+    assert_eq!(report.issues[0].vertices, vec![d]);
+    assert_eq!(report.clone().into_result(), Ok(report));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn integration_validation_serde() {
+    let mut graph = Hypergraph::<usize, usize>::new();
+
+    graph.add_vertex(1).unwrap();
+
+    let report = graph.validate();
+    let json = serde_json::to_value(&report).unwrap();
+
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "issues": [{
+                "severity": "info",
+                "code": "isolated_vertex",
+                "message": "vertex 1 doesn't belong to any hyperedge",
+                "vertices": [0],
+                "hyperedges": [],
+            }],
+        })
+    );
+    assert_eq!(serde_json::from_value(json).ok(), Some(report));
+}
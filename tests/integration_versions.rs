@@ -0,0 +1,135 @@
+//! Integration tests.
+
+use hypergraph::{
+    HyperedgeIndex,
+    Hypergraph,
+    VertexIndex,
+    errors::HypergraphError,
+};
+
+#[test]
+fn integration_versions() {
+    let mut graph = Hypergraph::<usize, usize>::new();
+
+    let a = graph.add_vertex(1).unwrap();
+    let b = graph.add_vertex(2).unwrap();
+    let alpha = graph.add_hyperedge(vec![a, b], 10).unwrap();
+
+    // The versions are issued by a counter shared by the entities.
+    assert_eq!(graph.get_vertex_version(a), Ok(1));
+    assert_eq!(graph.get_vertex_version(b), Ok(2));
+    assert_eq!(graph.get_hyperedge_version(alpha), Ok(3));
+    assert_eq!(
+        graph.get_vertex_version(VertexIndex(2)),
+        Err(HypergraphError::VertexIndexNotFound(VertexIndex(2)))
+    );
+    assert_eq!(
+        graph.get_hyperedge_version(HyperedgeIndex(1)),
+        Err(HypergraphError::HyperedgeIndexNotFound(HyperedgeIndex(1)))
+    );
+
+    // The updates bump the versions, conditionally or not.
+    graph.update_vertex_weight(a, 3).unwrap();
+
+    assert_eq!(graph.get_vertex_version(a), Ok(4));
+    assert_eq!(graph.get_vertex_version(b), Ok(2));
+    assert_eq!(graph.update_vertex_weight_if_version(a, 4, 4), Ok(5));
+    assert_eq!(
+        graph.update_vertex_weight_if_version(a, 5, 4),
+        Err(HypergraphError::VertexVersionConflict {
+            index: a,
+            expected: 4,
+            actual: 5,
+        }),
+        "should reject a stale version"
+    );
+    assert_eq!(graph.get_vertex_weight(a), Ok(&4));
+
+    assert_eq!(
+        graph.update_hyperedge_weight_if_version(alpha, 20, 3),
+        Ok(6)
+    );
+    assert_eq!(
+        graph.update_hyperedge_vertices_if_version(alpha, vec![b, a], 6),
+        Ok(7)
+    );
+    assert_eq!(
+        graph.update_hyperedge_vertices_if_version(alpha, vec![a], 6),
+        Err(HypergraphError::HyperedgeVersionConflict {
+            index: alpha,
+            expected: 6,
+            actual: 7,
+        }),
+        "should reject a stale version"
+    );
+    assert_eq!(graph.get_hyperedge_vertices(alpha), Ok(vec![b, a]));
+
+    // A failed update doesn't bump the version.
+    assert_eq!(
+        graph.update_hyperedge_weight_if_version(alpha, 20, 7),
+        Err(HypergraphError::HyperedgeWeightUnchanged(alpha))
+    );
+    assert_eq!(graph.get_hyperedge_version(alpha), Ok(7));
+
+    // The versions are preserved by the snapshots.
+    let mut bytes = vec![];
+
+    graph.write_snapshot(&mut bytes).unwrap();
+
+    let mut loaded = Hypergraph::<usize, usize>::read_snapshot(&bytes[..]).unwrap();
+
+    assert_eq!(loaded.get_vertex_version(a), Ok(5));
+    assert_eq!(loaded.get_hyperedge_version(alpha), Ok(7));
+
+    // Removing a vertex updates the vertices of its hyperedges, while the
+    // remapping issues a new version to the moved entities.
+    loaded.remove_vertex(a).unwrap();
+
+    assert_eq!(loaded.get_hyperedge_version(alpha), Ok(8));
+
+    loaded.remap_indexes().unwrap();
+
+    assert_eq!(loaded.get_vertex_version(VertexIndex(0)), Ok(9));
+    assert_eq!(loaded.get_hyperedge_version(alpha), Ok(8));
+
+    // A stale version of a removed entity doesn't match the new entity
+    // reusing its recycled index.
+    let c = graph.add_vertex(6).unwrap();
+    let version = graph.get_vertex_version(c).unwrap();
+
+    graph.remove_vertex(c).unwrap();
+    graph.recycle_indexes();
+
+    assert_eq!(graph.add_vertex(7), Ok(c));
+    assert_eq!(
+        graph.update_vertex_weight_if_version(c, 8, version),
+        Err(HypergraphError::VertexVersionConflict {
+            index: c,
+            expected: version,
+            actual: version + 1,
+        }),
+        "should reject the stale version of the removed vertex"
+    );
+
+    let version = graph.get_hyperedge_version(alpha).unwrap();
+
+    graph.remove_hyperedge(alpha).unwrap();
+    graph.recycle_indexes();
+
+    assert_eq!(graph.add_hyperedge(vec![a], 30), Ok(alpha));
+    assert!(
+        graph
+            .update_hyperedge_weight_if_version(alpha, 40, version)
+            .is_err(),
+        "should reject the stale version of the removed hyperedge"
+    );
+
+    // Same after clearing the hypergraph.
+    graph.clear();
+
+    assert_eq!(graph.add_vertex(1), Ok(a));
+    assert!(
+        graph.update_vertex_weight_if_version(a, 2, 1).is_err(),
+        "should reject the versions issued before clearing"
+    );
+}
@@ -0,0 +1,57 @@
+//! Integration tests.
+
+#![cfg(feature = "wasm")]
+
+use hypergraph::wasm::JsHypergraph;
+
+#[test]
+fn integration_wasm() {
+    // Create a new hypergraph.
+    let mut graph = JsHypergraph::new();
+
+    // Create some vertices.
+    let a = graph.add_vertex("a".to_owned()).unwrap();
+    let b = graph.add_vertex("b".to_owned()).unwrap();
+    let c = graph.add_vertex("c".to_owned()).unwrap();
+
+    // Create some hyperedges.
+    let alpha = graph
+        .add_hyperedge(vec![a, b], "alpha".to_owned(), 1)
+        .unwrap();
+    let beta = graph
+        .add_hyperedge(vec![b, c], "beta".to_owned(), 2)
+        .unwrap();
+
+    assert_eq!(graph.count_vertices(), 3, "should have three vertices");
+    assert_eq!(graph.count_hyperedges(), 2, "should have two hyperedges");
+    assert_eq!(
+        graph.get_vertex_weight(b).unwrap(),
+        "b",
+        "should resolve the label of the vertex"
+    );
+    assert_eq!(
+        graph.get_hyperedge_weight(beta).unwrap(),
+        "beta",
+        "should resolve the label of the hyperedge"
+    );
+    assert_eq!(
+        graph.get_hyperedge_vertices(alpha).unwrap(),
+        vec![a, b],
+        "should expose the vertices as plain indexes"
+    );
+    assert_eq!(
+        graph.get_dijkstra_connections(a, c).unwrap(),
+        vec![a, b, c],
+        "should find the cheapest path"
+    );
+
+    // Remove a vertex.
+    graph.remove_vertex(a).unwrap();
+
+    assert_eq!(graph.count_hyperedges(), 2, "should keep the hyperedges");
+    assert_eq!(
+        graph.get_adjacent_vertices_from(b).unwrap(),
+        vec![c],
+        "should update the adjacency"
+    );
+}
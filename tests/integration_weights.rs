@@ -0,0 +1,51 @@
+//! Integration tests.
+
+mod common;
+
+use common::{
+    Hyperedge,
+    Vertex,
+};
+use hypergraph::{
+    HyperedgeIndex,
+    Hypergraph,
+    VertexIndex,
+    errors::HypergraphError,
+};
+
+#[test]
+fn integration_weights() {
+    // Create a new hypergraph.
+    let mut graph = Hypergraph::<Vertex, Hyperedge>::new();
+
+    let vertex_one = Vertex::new("one");
+    let vertex_two = Vertex::new("two");
+    let hyperedge_one = Hyperedge::new("one", 1);
+    let hyperedge_two = Hyperedge::new("two", 2);
+
+    let a = graph.add_vertex(vertex_one).unwrap();
+    let b = graph.add_vertex(vertex_two).unwrap();
+    let alpha = graph.add_hyperedge(vec![a, b], hyperedge_one).unwrap();
+    let beta = graph.add_hyperedge(vec![b], hyperedge_two).unwrap();
+
+    // Get the weights by reference, in the requested order.
+    assert_eq!(
+        graph.get_vertex_weights(&[b, a, b]),
+        Ok(vec![&vertex_two, &vertex_one, &vertex_two])
+    );
+    assert_eq!(graph.get_vertex_weights(&[]), Ok(vec![]));
+    assert_eq!(
+        graph.get_vertex_weights(&[a, VertexIndex(2)]),
+        Err(HypergraphError::VertexIndexNotFound(VertexIndex(2))),
+        "should return an explicit error"
+    );
+    assert_eq!(
+        graph.get_hyperedge_weights(&[beta, alpha]),
+        Ok(vec![&hyperedge_two, &hyperedge_one])
+    );
+    assert_eq!(
+        graph.get_hyperedge_weights(&[HyperedgeIndex(2)]),
+        Err(HypergraphError::HyperedgeIndexNotFound(HyperedgeIndex(2))),
+        "should return an explicit error"
+    );
+}